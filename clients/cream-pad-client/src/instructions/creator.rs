@@ -0,0 +1,853 @@
+use crate::instructions::build_instruction;
+use crate::pda::{
+    find_asset_mint, find_auction_config, find_auction_round_config, find_auction_vault_config,
+    find_collection_auction_config, find_collection_auction_round_config, find_master_edition,
+    find_metadata, find_token_account,
+};
+use crate::BackAuthority;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use anchor_spl::{associated_token, metadata};
+use cream_pad::instructions::creator::{
+    CancelPadInputParams, CloseRoundInputParams, CrankRoundInputParams,
+    EndCollectionRoundInputParams, EndRoundInputParams, FundCrankRewardInputParams,
+    GiveCollectionUpdateAuthorityInputParams, InitializeCollectionPadInputParams,
+    InitializePadInputParams, LockAndDistributeInputParams, MintTreasuryAssetInputParams,
+    SettlePadInputParams, StartNextCollectionRoundInputParams, StartNextRoundInputParams,
+    TakeCollectionUpdateAuthorityInputParams, TreasuryAndDistributeInputParams,
+    UnlockUnsoldSupplyInputParams, UpdateCollectionPadInputParams, UpdatePadInputParams,
+    WithdrawCancelledSupplyInputParams, WithdrawCrankRewardInputParams, FIRST_ROUND,
+};
+
+///////////// PAD ///////////////
+
+pub struct InitializePadAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub creator: Pubkey,
+
+    pub back_authority: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn initialize_pad(
+    accounts: &InitializePadAccounts,
+    params: InitializePadInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::InitializePadInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            creator: accounts.creator,
+            back_authority: accounts.back_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            auction_config,
+            auction_round_config: find_auction_round_config(&auction_config, FIRST_ROUND).0,
+            token_mint_account: accounts.token_mint,
+            auction_config_token_account: find_token_account(
+                &auction_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            creator_token_account: find_token_account(
+                &accounts.creator,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        None,
+        cream_pad::instruction::InitializePad { params },
+    )
+}
+
+pub struct UpdatePadAccounts {
+    pub creator: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn update_pad(accounts: &UpdatePadAccounts, params: UpdatePadInputParams) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::UpdatePadInputAccounts {
+            creator: accounts.creator,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config: find_auction_config(&params.pad_name, &accounts.token_mint).0,
+            token_mint_account: accounts.token_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::UpdatePad { params },
+    )
+}
+
+pub struct EndRoundAccounts {
+    pub ender: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn end_round(accounts: &EndRoundAccounts, params: EndRoundInputParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::EndRoundInputAccounts {
+            ender: accounts.ender,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_round_config: find_auction_round_config(&auction_config, &params.round_index).0,
+            token_mint_account: accounts.token_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::EndRound { params },
+    )
+}
+
+pub struct StartNextRoundAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub starter: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn start_next_round(
+    accounts: &StartNextRoundAccounts,
+    params: StartNextRoundInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::StartNextRoundInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            starter: accounts.starter,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            previous_auction_round_config: find_auction_round_config(
+                &auction_config,
+                &params.previous_round_index,
+            )
+            .0,
+            next_auction_round_config: find_auction_round_config(
+                &auction_config,
+                &params.next_round_index,
+            )
+            .0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::StartNextRound { params },
+    )
+}
+
+pub struct LockAndDistributeAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub supply_locker: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn lock_and_distribute(
+    accounts: &LockAndDistributeAccounts,
+    params: LockAndDistributeInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_vault_config: Pubkey = find_auction_vault_config(&auction_config).0;
+
+    build_instruction(
+        cream_pad::accounts::LockAndDistributeInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            supply_locker: accounts.supply_locker,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_vault_config,
+            token_mint_account: accounts.token_mint,
+            auction_config_token_account: find_token_account(
+                &auction_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            auction_vault_config_token_account: find_token_account(
+                &auction_vault_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::LockAndDistribute { params },
+    )
+}
+
+pub struct UnlockUnsoldSupplyAccounts {
+    pub creator: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn unlock_unsold_supply(
+    accounts: &UnlockUnsoldSupplyAccounts,
+    params: UnlockUnsoldSupplyInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_vault_config: Pubkey = find_auction_vault_config(&auction_config).0;
+
+    build_instruction(
+        cream_pad::accounts::UnlockUnsoldSupplyInputAccounts {
+            creator: accounts.creator,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_vault_config,
+            token_mint_account: accounts.token_mint,
+            auction_vault_config_token_account: find_token_account(
+                &auction_vault_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            creator_token_account: find_token_account(
+                &accounts.creator,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::UnlockUnsoldSupply { params },
+    )
+}
+
+pub struct CancelPadAccounts {
+    pub creator: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn cancel_pad(accounts: &CancelPadAccounts, params: CancelPadInputParams) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::CancelPadInputAccounts {
+            creator: accounts.creator,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config: find_auction_config(&params.pad_name, &accounts.token_mint).0,
+            token_mint_account: accounts.token_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::CancelPad { params },
+    )
+}
+
+pub struct WithdrawCancelledSupplyAccounts {
+    pub creator: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn withdraw_cancelled_supply(
+    accounts: &WithdrawCancelledSupplyAccounts,
+    params: WithdrawCancelledSupplyInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::WithdrawCancelledSupplyInputAccounts {
+            creator: accounts.creator,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            token_mint_account: accounts.token_mint,
+            auction_config_token_account: find_token_account(
+                &auction_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            creator_token_account: find_token_account(
+                &accounts.creator,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::WithdrawCancelledSupply { params },
+    )
+}
+
+pub struct SettlePadAccounts {
+    /// Creator or back authority of the pad
+    pub settler: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub payment_token_mint: Pubkey,
+
+    pub payment_token_program: Pubkey,
+
+    /// `payment_receiver` of the pad
+    pub payment_receiver: Pubkey,
+
+    /// `fee_receiver` of the cream pad config
+    pub fee_receiver: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+
+    /// Every round with a buy, in increasing order, on uniform clearing price pads
+    pub bought_rounds: Vec<u16>,
+}
+
+pub fn settle_pad(accounts: &SettlePadAccounts, params: SettlePadInputParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_vault_config: Pubkey = find_auction_vault_config(&auction_config).0;
+
+    let mut instruction: Instruction = build_instruction(
+        cream_pad::accounts::SettlePadInputAccounts {
+            settler: accounts.settler,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_vault_config,
+            token_mint_account: accounts.token_mint,
+            payment_token_mint_account: accounts.payment_token_mint,
+            auction_vault_config_payment_token_account: find_token_account(
+                &auction_vault_config,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            payment_receiver: accounts.payment_receiver,
+            payment_receiver_token_account: find_token_account(
+                &accounts.payment_receiver,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            fee_receiver: accounts.fee_receiver,
+            fee_receiver_payment_token_account: find_token_account(
+                &accounts.fee_receiver,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            payment_token_program: accounts.payment_token_program,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::SettlePad { params },
+    );
+
+    for round in accounts.bought_rounds.iter() {
+        instruction.accounts.push(AccountMeta::new_readonly(
+            find_auction_round_config(&auction_config, &round.to_string()).0,
+            false,
+        ));
+    }
+
+    instruction
+}
+
+pub struct CrankRoundAccounts {
+    /// Anyone, pays the next round rent and receives the crank reward
+    pub cranker: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    /// The cranked round is the pad's `tmax`, no next round is opened
+    pub is_last_round: bool,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn crank_round(accounts: &CrankRoundAccounts, params: CrankRoundInputParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::CrankRoundInputAccounts {
+            cranker: accounts.cranker,
+            cream_pad_config: accounts.cream_pad_config,
+            auction_config,
+            auction_vault_config: find_auction_vault_config(&auction_config).0,
+            auction_round_config: find_auction_round_config(&auction_config, &params.round_index).0,
+            next_auction_round_config: (!accounts.is_last_round)
+                .then(|| find_auction_round_config(&auction_config, &params.next_round_index).0),
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        None,
+        cream_pad::instruction::CrankRound { params },
+    )
+}
+
+pub struct FundCrankRewardAccounts {
+    pub creator: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn fund_crank_reward(
+    accounts: &FundCrankRewardAccounts,
+    params: FundCrankRewardInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::FundCrankRewardInputAccounts {
+            creator: accounts.creator,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_vault_config: find_auction_vault_config(&auction_config).0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::FundCrankReward { params },
+    )
+}
+
+pub struct WithdrawCrankRewardAccounts {
+    pub creator: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn withdraw_crank_reward(
+    accounts: &WithdrawCrankRewardAccounts,
+    params: WithdrawCrankRewardInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::WithdrawCrankRewardInputAccounts {
+            creator: accounts.creator,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_vault_config: find_auction_vault_config(&auction_config).0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::WithdrawCrankReward { params },
+    )
+}
+
+pub struct CloseRoundAccounts {
+    pub closer: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    /// recorded on the round, the creator for migrated rounds
+    pub rent_payer: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn close_round(accounts: &CloseRoundAccounts, params: CloseRoundInputParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::CloseRoundInputAccounts {
+            closer: accounts.closer,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_round_config: find_auction_round_config(&auction_config, &params.round_index).0,
+            rent_payer: accounts.rent_payer,
+            token_mint_account: accounts.token_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::CloseRound { params },
+    )
+}
+
+///////////// COLLECTION PAD ///////////////
+
+pub struct InitializeCollectionPadAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub creator: Pubkey,
+
+    pub back_authority: Pubkey,
+
+    pub current_collection_update_authority: Pubkey,
+
+    pub collection_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn initialize_collection_pad(
+    accounts: &InitializeCollectionPadAccounts,
+    params: InitializeCollectionPadInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::InitializeCollectionPadInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            creator: accounts.creator,
+            back_authority: accounts.back_authority,
+            current_collection_update_authority: accounts.current_collection_update_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            collection_auction_config,
+            collection_auction_round_config: find_collection_auction_round_config(
+                &collection_auction_config,
+                FIRST_ROUND,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            collection_metadata: find_metadata(&accounts.collection_mint),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            token_metadata_program: metadata::ID,
+        },
+        None,
+        cream_pad::instruction::InitializeCollectionPad { params },
+    )
+}
+
+pub struct UpdateCollectionPadAccounts {
+    pub creator: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn update_collection_pad(
+    accounts: &UpdateCollectionPadAccounts,
+    params: UpdateCollectionPadInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::UpdateCollectionPadInputAccounts {
+            creator: accounts.creator,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config: find_collection_auction_config(
+                &params.pad_name,
+                &accounts.collection_mint,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::UpdateCollectionPad { params },
+    )
+}
+
+pub struct EndCollectionRoundAccounts {
+    pub ender: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn end_collection_round(
+    accounts: &EndCollectionRoundAccounts,
+    params: EndCollectionRoundInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::EndCollectionRoundInputAccounts {
+            ender: accounts.ender,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config,
+            collection_auction_round_config: find_collection_auction_round_config(
+                &collection_auction_config,
+                &params.round_index,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::EndCollectionRound { params },
+    )
+}
+
+pub struct StartNextCollectionRoundAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub starter: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn start_next_collection_round(
+    accounts: &StartNextCollectionRoundAccounts,
+    params: StartNextCollectionRoundInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::StartNextCollectionRoundInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            starter: accounts.starter,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config,
+            previous_collection_auction_round_config: find_collection_auction_round_config(
+                &collection_auction_config,
+                &params.previous_round_index,
+            )
+            .0,
+            next_collection_auction_round_config: find_collection_auction_round_config(
+                &collection_auction_config,
+                &params.next_round_index,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::StartNextCollectionRound { params },
+    )
+}
+
+pub struct TakeCollectionUpdateAuthorityAccounts {
+    pub back_authority: Pubkey,
+
+    pub current_collection_update_authority: Pubkey,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn take_collection_update_authority(
+    accounts: &TakeCollectionUpdateAuthorityAccounts,
+    params: TakeCollectionUpdateAuthorityInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::TakeCollectionUpdateAuthorityInputAccounts {
+            back_authority: accounts.back_authority,
+            current_collection_update_authority: accounts.current_collection_update_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            collection_auction_config: find_collection_auction_config(
+                &params.pad_name,
+                &accounts.collection_mint,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            collection_metadata: find_metadata(&accounts.collection_mint),
+            token_metadata_program: metadata::ID,
+        },
+        None,
+        cream_pad::instruction::TakeCollectionUpdateAuthority { params },
+    )
+}
+
+pub struct GiveCollectionUpdateAuthorityAccounts {
+    pub back_authority: Pubkey,
+
+    pub new_collection_update_authority: Pubkey,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn give_collection_update_authority(
+    accounts: &GiveCollectionUpdateAuthorityAccounts,
+    params: GiveCollectionUpdateAuthorityInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::GiveCollectionUpdateAuthorityInputAccounts {
+            back_authority: accounts.back_authority,
+            new_collection_update_authority: accounts.new_collection_update_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            collection_auction_config: find_collection_auction_config(
+                &params.pad_name,
+                &accounts.collection_mint,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            collection_metadata: find_metadata(&accounts.collection_mint),
+            token_metadata_program: metadata::ID,
+        },
+        None,
+        cream_pad::instruction::GiveCollectionUpdateAuthority { params },
+    )
+}
+
+pub struct TreasuryAndDistributeAccounts {
+    pub supply_distributor: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn treasury_and_distribute(
+    accounts: &TreasuryAndDistributeAccounts,
+    params: TreasuryAndDistributeInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::TreasuryAndDistributeInputAccounts {
+            supply_distributor: accounts.supply_distributor,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config: find_collection_auction_config(
+                &params.pad_name,
+                &accounts.collection_mint,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::TreasuryAndDistribute { params },
+    )
+}
+
+pub struct MintTreasuryAssetAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    /// `treasury` of the cream pad config
+    pub treasury: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn mint_treasury_asset(
+    accounts: &MintTreasuryAssetAccounts,
+    params: MintTreasuryAssetInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let asset_mint: Pubkey = find_asset_mint(&collection_auction_config, &params.asset_uuid).0;
+
+    build_instruction(
+        cream_pad::accounts::MintTreasuryAssetInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config,
+            collection_mint_account: accounts.collection_mint,
+            asset_mint_account: asset_mint,
+            collection_metadata: find_metadata(&accounts.collection_mint),
+            collection_master_edition: find_master_edition(&accounts.collection_mint),
+            treasury: accounts.treasury,
+            treasury_asset_token_account: find_token_account(
+                &accounts.treasury,
+                &asset_mint,
+                &accounts.token_program,
+            ),
+            asset_metadata: find_metadata(&asset_mint),
+            asset_master_edition: find_master_edition(&asset_mint),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            token_metadata_program: metadata::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::MintTreasuryAsset { params },
+    )
+}
@@ -0,0 +1,297 @@
+use crate::instructions::build_instruction;
+use crate::pda::{
+    find_auction_config, find_collection_auction_config, find_cream_pad_config,
+    find_pending_config_change,
+};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar;
+use anchor_lang::system_program;
+use cream_pad::instructions::manager::{
+    AcceptAuthorityTransferInputParams, ApproveConfigChangeInputParams,
+    CancelConfigChangeInputParams, ExecuteConfigChangeInputParams, InitializeInputParams,
+    ProposeAuthorityTransferInputParams, ProposeConfigChangeInputParams,
+    SetCollectionPadFeeInputParams, SetCollectionPadPauseFlagsInputParams, SetPadFeeInputParams,
+    SetPadPauseFlagsInputParams, SetPauseFlagsInputParams, UpdateConfigInputParams,
+};
+
+pub struct InitializeAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub signing_authority: Pubkey,
+}
+
+pub fn initialize(accounts: &InitializeAccounts, params: InitializeInputParams) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::InitializeInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: find_cream_pad_config(&params.tenant_id).0,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        None,
+        cream_pad::instruction::Initialize { params },
+    )
+}
+
+pub struct UpdateConfigAccounts {
+    pub signing_authority: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn update_config(
+    accounts: &UpdateConfigAccounts,
+    params: UpdateConfigInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::UpdateConfigInputAccounts {
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: accounts.cream_pad_config,
+        },
+        None,
+        cream_pad::instruction::UpdateConfig { params },
+    )
+}
+
+/// Shared by the signing authority's per pad fee and pause setters
+pub struct SetPadAccounts {
+    pub signing_authority: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn set_pad_fee(accounts: &SetPadAccounts, params: SetPadFeeInputParams) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::SetPadFeeInputAccounts {
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            auction_config: find_auction_config(&params.pad_name, &accounts.token_mint).0,
+            token_mint_account: accounts.token_mint,
+        },
+        None,
+        cream_pad::instruction::SetPadFee { params },
+    )
+}
+
+/// Shared by the signing authority's per collection pad fee and pause setters
+pub struct SetCollectionPadAccounts {
+    pub signing_authority: Pubkey,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn set_collection_pad_fee(
+    accounts: &SetCollectionPadAccounts,
+    params: SetCollectionPadFeeInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::SetCollectionPadFeeInputAccounts {
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            collection_auction_config: find_collection_auction_config(
+                &params.pad_name,
+                &accounts.collection_mint,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+        },
+        None,
+        cream_pad::instruction::SetCollectionPadFee { params },
+    )
+}
+
+pub struct ProposeConfigChangeAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub proposer: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn propose_config_change(
+    accounts: &ProposeConfigChangeAccounts,
+    params: ProposeConfigChangeInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::ProposeConfigChangeInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            proposer: accounts.proposer,
+            cream_pad_config: accounts.cream_pad_config,
+            pending_config_change: find_pending_config_change(
+                &accounts.cream_pad_config,
+                &params.config_change_index,
+            )
+            .0,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+        },
+        None,
+        cream_pad::instruction::ProposeConfigChange { params },
+    )
+}
+
+/// Approving, executing and cancelling are all signed by one of the governance signers
+pub struct ConfigChangeAccounts {
+    pub governance_signer: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn approve_config_change(
+    accounts: &ConfigChangeAccounts,
+    params: ApproveConfigChangeInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::ApproveConfigChangeInputAccounts {
+            governance_signer: accounts.governance_signer,
+            cream_pad_config: accounts.cream_pad_config,
+            pending_config_change: find_pending_config_change(
+                &accounts.cream_pad_config,
+                &params.config_change_index,
+            )
+            .0,
+        },
+        None,
+        cream_pad::instruction::ApproveConfigChange { params },
+    )
+}
+
+pub fn execute_config_change(
+    accounts: &ConfigChangeAccounts,
+    params: ExecuteConfigChangeInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::ExecuteConfigChangeInputAccounts {
+            governance_signer: accounts.governance_signer,
+            cream_pad_config: accounts.cream_pad_config,
+            pending_config_change: find_pending_config_change(
+                &accounts.cream_pad_config,
+                &params.config_change_index,
+            )
+            .0,
+        },
+        None,
+        cream_pad::instruction::ExecuteConfigChange { params },
+    )
+}
+
+pub fn cancel_config_change(
+    accounts: &ConfigChangeAccounts,
+    params: CancelConfigChangeInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::CancelConfigChangeInputAccounts {
+            governance_signer: accounts.governance_signer,
+            cream_pad_config: accounts.cream_pad_config,
+            pending_config_change: find_pending_config_change(
+                &accounts.cream_pad_config,
+                &params.config_change_index,
+            )
+            .0,
+        },
+        None,
+        cream_pad::instruction::CancelConfigChange { params },
+    )
+}
+
+pub struct ProposeAuthorityTransferAccounts {
+    pub signing_authority: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn propose_authority_transfer(
+    accounts: &ProposeAuthorityTransferAccounts,
+    params: ProposeAuthorityTransferInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::ProposeAuthorityTransferInputAccounts {
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: accounts.cream_pad_config,
+        },
+        None,
+        cream_pad::instruction::ProposeAuthorityTransfer { params },
+    )
+}
+
+pub struct AcceptAuthorityTransferAccounts {
+    pub new_authority: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn accept_authority_transfer(
+    accounts: &AcceptAuthorityTransferAccounts,
+    params: AcceptAuthorityTransferInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::AcceptAuthorityTransferInputAccounts {
+            new_authority: accounts.new_authority,
+            cream_pad_config: accounts.cream_pad_config,
+        },
+        None,
+        cream_pad::instruction::AcceptAuthorityTransfer { params },
+    )
+}
+
+pub struct SetPauseFlagsAccounts {
+    pub signing_authority: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn set_pause_flags(
+    accounts: &SetPauseFlagsAccounts,
+    params: SetPauseFlagsInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::SetPauseFlagsInputAccounts {
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: accounts.cream_pad_config,
+        },
+        None,
+        cream_pad::instruction::SetPauseFlags { params },
+    )
+}
+
+pub fn set_pad_pause_flags(
+    accounts: &SetPadAccounts,
+    params: SetPadPauseFlagsInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::SetPadPauseFlagsInputAccounts {
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            auction_config: find_auction_config(&params.pad_name, &accounts.token_mint).0,
+            token_mint_account: accounts.token_mint,
+        },
+        None,
+        cream_pad::instruction::SetPadPauseFlags { params },
+    )
+}
+
+pub fn set_collection_pad_pause_flags(
+    accounts: &SetCollectionPadAccounts,
+    params: SetCollectionPadPauseFlagsInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::SetCollectionPadPauseFlagsInputAccounts {
+            signing_authority: accounts.signing_authority,
+            cream_pad_config: accounts.cream_pad_config,
+            collection_auction_config: find_collection_auction_config(
+                &params.pad_name,
+                &accounts.collection_mint,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+        },
+        None,
+        cream_pad::instruction::SetCollectionPadPauseFlags { params },
+    )
+}
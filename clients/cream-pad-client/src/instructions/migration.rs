@@ -0,0 +1,361 @@
+use crate::instructions::build_instruction;
+use crate::pda::{
+    find_auction_config, find_auction_round_config, find_collection_auction_config,
+    find_collection_auction_round_config, find_cream_pad_config,
+    find_user_auction_buy_receipt_config, find_user_auction_config, find_user_auction_round_config,
+    find_user_auction_unsold_distribution_config, find_user_collection_auction_buy_receipt_config,
+    find_user_collection_auction_config, find_user_collection_auction_round_config,
+    find_user_collection_auction_unsold_distribution_config,
+};
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program;
+use cream_pad::instructions::migration::{
+    MigrateCollectionPadInputParams, MigrateCollectionPadRoundInputParams,
+    MigrateConfigInputParams, MigratePadInputParams, MigratePadRoundInputParams,
+    MigrateUserCollectionPadBuyReceiptInputParams, MigrateUserCollectionPadInputParams,
+    MigrateUserCollectionPadRoundInputParams,
+    MigrateUserCollectionPadUnsoldDistributionInputParams, MigrateUserPadBuyReceiptInputParams,
+    MigrateUserPadInputParams, MigrateUserPadRoundInputParams,
+    MigrateUserPadUnsoldDistributionInputParams,
+};
+
+pub struct MigratePadAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub token_mint: Pubkey,
+}
+
+pub fn migrate_pad(accounts: &MigratePadAccounts, params: MigratePadInputParams) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::MigratePadInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            cream_pad_config: find_cream_pad_config("").0,
+            auction_config: find_auction_config(&params.pad_name, &accounts.token_mint).0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigratePad { params },
+    )
+}
+
+pub fn migrate_pad_round(
+    accounts: &MigratePadAccounts,
+    params: MigratePadRoundInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::MigratePadRoundInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            auction_config,
+            auction_round_config: find_auction_round_config(&auction_config, &params.round_index).0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigratePadRound { params },
+    )
+}
+
+pub struct MigrateCollectionPadAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub collection_mint: Pubkey,
+}
+
+pub fn migrate_collection_pad(
+    accounts: &MigrateCollectionPadAccounts,
+    params: MigrateCollectionPadInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::MigrateCollectionPadInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            cream_pad_config: find_cream_pad_config("").0,
+            collection_auction_config: find_collection_auction_config(
+                &params.pad_name,
+                &accounts.collection_mint,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateCollectionPad { params },
+    )
+}
+
+pub fn migrate_collection_pad_round(
+    accounts: &MigrateCollectionPadAccounts,
+    params: MigrateCollectionPadRoundInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateCollectionPadRoundInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            collection_auction_config,
+            collection_auction_round_config: find_collection_auction_round_config(
+                &collection_auction_config,
+                &params.round_index,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateCollectionPadRound { params },
+    )
+}
+
+pub struct MigrateConfigAccounts {
+    pub fee_and_rent_payer: Pubkey,
+}
+
+pub fn migrate_config(
+    accounts: &MigrateConfigAccounts,
+    params: MigrateConfigInputParams,
+) -> Instruction {
+    build_instruction(
+        cream_pad::accounts::MigrateConfigInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            cream_pad_config: find_cream_pad_config("").0,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateConfig { params },
+    )
+}
+
+/// Shared by the pad's user account migrations
+pub struct MigrateUserPadAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub token_mint: Pubkey,
+
+    pub user: Pubkey,
+}
+
+pub fn migrate_user_pad(
+    accounts: &MigrateUserPadAccounts,
+    params: MigrateUserPadInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserPadInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            auction_config,
+            user: accounts.user,
+            user_auction_config: find_user_auction_config(&auction_config, &accounts.user).0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserPad { params },
+    )
+}
+
+pub fn migrate_user_pad_round(
+    accounts: &MigrateUserPadAccounts,
+    params: MigrateUserPadRoundInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_round_config: Pubkey =
+        find_auction_round_config(&auction_config, &params.round_index).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserPadRoundInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            auction_config,
+            auction_round_config,
+            user: accounts.user,
+            user_auction_config,
+            user_auction_round_config: find_user_auction_round_config(
+                &auction_round_config,
+                &user_auction_config,
+            )
+            .0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserPadRound { params },
+    )
+}
+
+pub fn migrate_user_pad_buy_receipt(
+    accounts: &MigrateUserPadAccounts,
+    params: MigrateUserPadBuyReceiptInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserPadBuyReceiptInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            auction_config,
+            user: accounts.user,
+            user_auction_config,
+            user_auction_buy_receipt_config: find_user_auction_buy_receipt_config(
+                &user_auction_config,
+                &params.buy_index,
+            )
+            .0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserPadBuyReceipt { params },
+    )
+}
+
+pub fn migrate_user_pad_unsold_distribution(
+    accounts: &MigrateUserPadAccounts,
+    params: MigrateUserPadUnsoldDistributionInputParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserPadUnsoldDistributionInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            auction_config,
+            user: accounts.user,
+            user_auction_config,
+            user_auction_unsold_distribution_config: find_user_auction_unsold_distribution_config(
+                &user_auction_config,
+            )
+            .0,
+            token_mint_account: accounts.token_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserPadUnsoldDistribution { params },
+    )
+}
+
+/// Shared by the collection pad's user account migrations
+pub struct MigrateUserCollectionPadAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub collection_mint: Pubkey,
+
+    pub user: Pubkey,
+}
+
+pub fn migrate_user_collection_pad(
+    accounts: &MigrateUserCollectionPadAccounts,
+    params: MigrateUserCollectionPadInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserCollectionPadInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            collection_auction_config,
+            user: accounts.user,
+            user_collection_auction_config: find_user_collection_auction_config(
+                &collection_auction_config,
+                &accounts.user,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserCollectionPad { params },
+    )
+}
+
+pub fn migrate_user_collection_pad_round(
+    accounts: &MigrateUserCollectionPadAccounts,
+    params: MigrateUserCollectionPadRoundInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let collection_auction_round_config: Pubkey =
+        find_collection_auction_round_config(&collection_auction_config, &params.round_index).0;
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserCollectionPadRoundInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            collection_auction_config,
+            collection_auction_round_config,
+            user: accounts.user,
+            user_collection_auction_config,
+            user_collection_auction_round_config: find_user_collection_auction_round_config(
+                &collection_auction_round_config,
+                &user_collection_auction_config,
+            )
+            .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserCollectionPadRound { params },
+    )
+}
+
+pub fn migrate_user_collection_pad_buy_receipt(
+    accounts: &MigrateUserCollectionPadAccounts,
+    params: MigrateUserCollectionPadBuyReceiptInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserCollectionPadBuyReceiptInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            collection_auction_config,
+            user: accounts.user,
+            user_collection_auction_config,
+            user_collection_auction_buy_receipt_config:
+                find_user_collection_auction_buy_receipt_config(
+                    &user_collection_auction_config,
+                    &params.buy_index,
+                )
+                .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserCollectionPadBuyReceipt { params },
+    )
+}
+
+pub fn migrate_user_collection_pad_unsold_distribution(
+    accounts: &MigrateUserCollectionPadAccounts,
+    params: MigrateUserCollectionPadUnsoldDistributionInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::MigrateUserCollectionPadUnsoldDistributionInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            collection_auction_config,
+            user: accounts.user,
+            user_collection_auction_config,
+            user_collection_auction_unsold_distribution_config:
+                find_user_collection_auction_unsold_distribution_config(
+                    &user_collection_auction_config,
+                )
+                .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+        },
+        None,
+        cream_pad::instruction::MigrateUserCollectionPadUnsoldDistribution { params },
+    )
+}
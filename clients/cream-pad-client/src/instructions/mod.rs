@@ -0,0 +1,37 @@
+use crate::BackAuthority;
+use anchor_lang::prelude::AccountMeta;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{InstructionData, ToAccountMetas};
+
+pub use creator::*;
+pub use manager::*;
+pub use migration::*;
+pub use user::*;
+
+pub mod creator;
+pub mod manager;
+pub mod migration;
+pub mod user;
+
+// The back authority is a plain account in every struct, it only signs when the config requires it
+fn build_instruction(
+    accounts: impl ToAccountMetas,
+    back_authority: Option<&BackAuthority>,
+    data: impl InstructionData,
+) -> Instruction {
+    let mut account_metas: Vec<AccountMeta> = accounts.to_account_metas(None);
+
+    if let Some(back_authority) = back_authority {
+        for account_meta in account_metas.iter_mut() {
+            if account_meta.pubkey == back_authority.key {
+                account_meta.is_signer |= back_authority.is_required;
+            }
+        }
+    }
+
+    Instruction {
+        program_id: cream_pad::ID,
+        accounts: account_metas,
+        data: data.data(),
+    }
+}
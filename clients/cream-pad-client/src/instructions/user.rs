@@ -0,0 +1,850 @@
+use crate::instructions::build_instruction;
+use crate::pda::{
+    find_asset_mint, find_auction_config, find_auction_round_config, find_auction_vault_config,
+    find_collection_auction_config, find_collection_auction_round_config, find_master_edition,
+    find_metadata, find_referrer_config, find_token_account, find_user_auction_buy_receipt_config,
+    find_user_auction_config, find_user_auction_round_config,
+    find_user_auction_unsold_distribution_config, find_user_auction_vesting_config,
+    find_user_collection_auction_buy_receipt_config, find_user_collection_auction_config,
+    find_user_collection_auction_round_config,
+    find_user_collection_auction_unsold_distribution_config,
+};
+use crate::BackAuthority;
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::{ed25519_program, sysvar};
+use anchor_lang::system_program;
+use anchor_lang::AnchorSerialize;
+use anchor_spl::{associated_token, metadata};
+use cream_pad::instructions::user::{
+    BuyCollectionAssetParams, BuyParams, ClaimCollectionAssetDistributionParams,
+    ClaimDistributionParams, ClaimRebateParams, ClaimReferralRewardsParams, ClaimVestedParams,
+    CloseBuyReceiptParams, CloseUserRoundParams, FillBoughtCollectionAssetInputParams,
+    FillClaimedCollectionAssetDistributionInputParams, RefundParams,
+};
+use cream_pad::states::BuyIntent;
+
+///////////// PAD ///////////////
+
+pub struct BuyAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    /// `payment_mint` of the pad, the system program for pads paid in native SOL
+    pub payment_token_mint: Pubkey,
+
+    pub payment_token_program: Pubkey,
+
+    /// `payment_receiver` of the pad
+    pub payment_receiver: Pubkey,
+
+    /// `fee_receiver` of the cream pad config
+    pub fee_receiver: Pubkey,
+
+    /// `have_payment_escrow` of the pad, payments then go to the auction vault and the receivers
+    /// are left out
+    pub have_payment_escrow: bool,
+
+    /// `have_vesting` of the pad, bought tokens are then credited to the user vesting account
+    pub have_vesting: bool,
+
+    /// `oracle` of the paid payment mint, when it is oracle priced
+    pub payment_oracle: Option<Pubkey>,
+
+    /// `have_referral` of the pad, `params.referrer` then gets its referrer config
+    pub have_referral: bool,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn buy(accounts: &BuyAccounts, params: BuyParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_round_config: Pubkey =
+        find_auction_round_config(&auction_config, &params.current_round_index).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+    let auction_vault_config: Pubkey = find_auction_vault_config(&auction_config).0;
+    let is_native_payment: bool = accounts.payment_token_mint == system_program::ID;
+    let have_payment_token_accounts: bool = !accounts.have_payment_escrow && !is_native_payment;
+    let referrer_config: Option<Pubkey> = (accounts.have_referral
+        && params.referrer != Pubkey::default())
+    .then(|| find_referrer_config(&auction_config, &params.referrer).0);
+    let is_buy_intent: bool = params.buy_intent.is_some();
+
+    let mut instruction: Instruction = build_instruction(
+        cream_pad::accounts::BuyInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_round_config,
+            user_auction_config,
+            user_auction_round_config: find_user_auction_round_config(
+                &auction_round_config,
+                &user_auction_config,
+            )
+            .0,
+            user_auction_buy_receipt_config: find_user_auction_buy_receipt_config(
+                &user_auction_config,
+                &params.buy_index,
+            )
+            .0,
+            user_auction_vesting_config: accounts
+                .have_vesting
+                .then(|| find_user_auction_vesting_config(&user_auction_config).0),
+            token_mint_account: accounts.token_mint,
+            payment_token_mint_account: (!is_native_payment).then_some(accounts.payment_token_mint),
+            user_payment_token_account: (!is_native_payment).then(|| {
+                find_token_account(
+                    &accounts.user,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            user_token_account: find_token_account(
+                &accounts.user,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            auction_config_token_account: find_token_account(
+                &auction_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            payment_receiver: (!accounts.have_payment_escrow).then_some(accounts.payment_receiver),
+            payment_receiver_token_account: have_payment_token_accounts.then(|| {
+                find_token_account(
+                    &accounts.payment_receiver,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            fee_receiver: (!accounts.have_payment_escrow).then_some(accounts.fee_receiver),
+            fee_receiver_payment_token_account: have_payment_token_accounts.then(|| {
+                find_token_account(
+                    &accounts.fee_receiver,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            payment_oracle: accounts.payment_oracle,
+            referrer_config,
+            referrer_config_payment_token_account: referrer_config.map(|referrer_config| {
+                find_token_account(
+                    &referrer_config,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            auction_vault_config: accounts.have_payment_escrow.then_some(auction_vault_config),
+            auction_vault_config_payment_token_account: accounts.have_payment_escrow.then(|| {
+                find_token_account(
+                    &auction_vault_config,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            token_program: accounts.token_program,
+            payment_token_program: accounts.payment_token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::Buy { params },
+    );
+
+    // a relayed buy goes through without the user's signature
+    for account_meta in instruction.accounts.iter_mut() {
+        if account_meta.pubkey == accounts.user {
+            account_meta.is_signer = !is_buy_intent;
+        }
+    }
+
+    instruction
+}
+
+/// Message the user signs off-chain for a relayed buy.
+pub fn buy_intent_message(buy_intent: &BuyIntent) -> Vec<u8> {
+    buy_intent.try_to_vec().unwrap()
+}
+
+/// Ed25519 program instruction carrying the user's buy intent signature, it has to go right
+/// before the relayed `buy`.
+pub fn verify_buy_intent(
+    user: &Pubkey,
+    signature: &[u8; 64],
+    buy_intent: &BuyIntent,
+) -> Instruction {
+    let message: Vec<u8> = buy_intent_message(buy_intent);
+
+    // count, padding and a single offsets entry, followed by the key, signature and message
+    let public_key_offset: u16 = 16;
+    let signature_offset: u16 = public_key_offset + 32;
+    let message_offset: u16 = signature_offset + 64;
+
+    let mut data: Vec<u8> = vec![1, 0];
+    for value in [
+        signature_offset,
+        u16::MAX,
+        public_key_offset,
+        u16::MAX,
+        message_offset,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(user.as_ref());
+    data.extend_from_slice(signature);
+    data.extend_from_slice(&message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+pub struct ClaimDistributionAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn claim_distribution(
+    accounts: &ClaimDistributionAccounts,
+    params: ClaimDistributionParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::ClaimDistributionInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            user_auction_config,
+            user_auction_unsold_distribution_config: find_user_auction_unsold_distribution_config(
+                &user_auction_config,
+            )
+            .0,
+            token_mint_account: accounts.token_mint,
+            auction_config_token_account: find_token_account(
+                &auction_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            user_token_account: find_token_account(
+                &accounts.user,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::ClaimDistribution { params },
+    )
+}
+
+pub struct RefundAccounts {
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub payment_token_mint: Pubkey,
+
+    pub payment_token_program: Pubkey,
+
+    /// `have_vesting` of the pad
+    pub have_vesting: bool,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn refund(accounts: &RefundAccounts, params: RefundParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_vault_config: Pubkey = find_auction_vault_config(&auction_config).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::RefundInputAccounts {
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_vault_config,
+            user_auction_config,
+            user_auction_buy_receipt_config: find_user_auction_buy_receipt_config(
+                &user_auction_config,
+                &params.buy_index,
+            )
+            .0,
+            user_auction_vesting_config: accounts
+                .have_vesting
+                .then(|| find_user_auction_vesting_config(&user_auction_config).0),
+            token_mint_account: accounts.token_mint,
+            payment_token_mint_account: accounts.payment_token_mint,
+            user_token_account: find_token_account(
+                &accounts.user,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            user_payment_token_account: find_token_account(
+                &accounts.user,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            auction_config_token_account: find_token_account(
+                &auction_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            auction_vault_config_payment_token_account: find_token_account(
+                &auction_vault_config,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            token_program: accounts.token_program,
+            payment_token_program: accounts.payment_token_program,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::Refund { params },
+    )
+}
+
+pub struct ClaimRebateAccounts {
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub payment_token_mint: Pubkey,
+
+    pub payment_token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn claim_rebate(accounts: &ClaimRebateAccounts, params: ClaimRebateParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_vault_config: Pubkey = find_auction_vault_config(&auction_config).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::ClaimRebateInputAccounts {
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_vault_config,
+            user_auction_config,
+            user_auction_buy_receipt_config: find_user_auction_buy_receipt_config(
+                &user_auction_config,
+                &params.buy_index,
+            )
+            .0,
+            token_mint_account: accounts.token_mint,
+            payment_token_mint_account: accounts.payment_token_mint,
+            user_payment_token_account: find_token_account(
+                &accounts.user,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            auction_vault_config_payment_token_account: find_token_account(
+                &auction_vault_config,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            payment_token_program: accounts.payment_token_program,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::ClaimRebate { params },
+    )
+}
+
+pub struct CloseBuyReceiptAccounts {
+    pub closer: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub user: Pubkey,
+
+    /// recorded on the receipt, the user for migrated receipts
+    pub rent_payer: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn close_buy_receipt(
+    accounts: &CloseBuyReceiptAccounts,
+    params: CloseBuyReceiptParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::CloseBuyReceiptInputAccounts {
+            closer: accounts.closer,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            user: accounts.user,
+            user_auction_config,
+            user_auction_buy_receipt_config: find_user_auction_buy_receipt_config(
+                &user_auction_config,
+                &params.buy_index,
+            )
+            .0,
+            rent_payer: accounts.rent_payer,
+            token_mint_account: accounts.token_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::CloseBuyReceipt { params },
+    )
+}
+
+pub struct CloseUserRoundAccounts {
+    pub closer: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub user: Pubkey,
+
+    /// recorded on the user round, the user for migrated accounts
+    pub rent_payer: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn close_user_round(
+    accounts: &CloseUserRoundAccounts,
+    params: CloseUserRoundParams,
+) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let auction_round_config: Pubkey =
+        find_auction_round_config(&auction_config, &params.round_index).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::CloseUserRoundInputAccounts {
+            closer: accounts.closer,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            auction_round_config,
+            user: accounts.user,
+            user_auction_config,
+            user_auction_round_config: find_user_auction_round_config(
+                &auction_round_config,
+                &user_auction_config,
+            )
+            .0,
+            rent_payer: accounts.rent_payer,
+            token_mint_account: accounts.token_mint,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::CloseUserRound { params },
+    )
+}
+
+pub struct ClaimVestedAccounts {
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub token_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn claim_vested(accounts: &ClaimVestedAccounts, params: ClaimVestedParams) -> Instruction {
+    let auction_config: Pubkey = find_auction_config(&params.pad_name, &accounts.token_mint).0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::ClaimVestedInputAccounts {
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            auction_config,
+            user_auction_config,
+            user_auction_vesting_config: find_user_auction_vesting_config(&user_auction_config).0,
+            token_mint_account: accounts.token_mint,
+            auction_config_token_account: find_token_account(
+                &auction_config,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            user_token_account: find_token_account(
+                &accounts.user,
+                &accounts.token_mint,
+                &accounts.token_program,
+            ),
+            token_program: accounts.token_program,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::ClaimVested { params },
+    )
+}
+
+pub struct ClaimReferralRewardsAccounts {
+    pub referrer: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    /// auction config or collection auction config the rewards were earned on
+    pub pad_config: Pubkey,
+
+    pub payment_token_mint: Pubkey,
+
+    pub payment_token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn claim_referral_rewards(
+    accounts: &ClaimReferralRewardsAccounts,
+    params: ClaimReferralRewardsParams,
+) -> Instruction {
+    let referrer_config: Pubkey = find_referrer_config(&accounts.pad_config, &accounts.referrer).0;
+
+    build_instruction(
+        cream_pad::accounts::ClaimReferralRewardsInputAccounts {
+            referrer: accounts.referrer,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            referrer_config,
+            pad_config: accounts.pad_config,
+            payment_token_mint_account: accounts.payment_token_mint,
+            referrer_config_payment_token_account: find_token_account(
+                &referrer_config,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            referrer_payment_token_account: find_token_account(
+                &accounts.referrer,
+                &accounts.payment_token_mint,
+                &accounts.payment_token_program,
+            ),
+            payment_token_program: accounts.payment_token_program,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::ClaimReferralRewards { params },
+    )
+}
+
+///////////// COLLECTION PAD ///////////////
+
+pub struct BuyCollectionAssetAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub user: Pubkey,
+
+    /// Receives the minting fee, so it is always passed writable
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    /// `payment_mint` of the pad, the system program for pads paid in native SOL
+    pub payment_token_mint: Pubkey,
+
+    pub payment_token_program: Pubkey,
+
+    /// `payment_receiver` of the collection pad
+    pub payment_receiver: Pubkey,
+
+    /// `fee_receiver` of the cream pad config
+    pub fee_receiver: Pubkey,
+
+    /// `have_referral` of the collection pad, `params.referrer` then gets its referrer config
+    pub have_referral: bool,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn buy_collection_asset(
+    accounts: &BuyCollectionAssetAccounts,
+    params: BuyCollectionAssetParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let collection_auction_round_config: Pubkey = find_collection_auction_round_config(
+        &collection_auction_config,
+        &params.current_round_index,
+    )
+    .0;
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &accounts.user).0;
+    let is_native_payment: bool = accounts.payment_token_mint == system_program::ID;
+    let referrer_config: Option<Pubkey> = (accounts.have_referral
+        && params.referrer != Pubkey::default())
+    .then(|| find_referrer_config(&collection_auction_config, &params.referrer).0);
+
+    build_instruction(
+        cream_pad::accounts::BuyCollectionAssetInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config,
+            collection_auction_round_config,
+            user_collection_auction_config,
+            user_collection_auction_round_config: find_user_collection_auction_round_config(
+                &collection_auction_round_config,
+                &user_collection_auction_config,
+            )
+            .0,
+            user_collection_auction_buy_receipt_config:
+                find_user_collection_auction_buy_receipt_config(
+                    &user_collection_auction_config,
+                    &params.buy_index,
+                )
+                .0,
+            collection_mint_account: accounts.collection_mint,
+            payment_token_mint_account: (!is_native_payment).then_some(accounts.payment_token_mint),
+            user_payment_token_account: (!is_native_payment).then(|| {
+                find_token_account(
+                    &accounts.user,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            payment_receiver: accounts.payment_receiver,
+            payment_receiver_token_account: (!is_native_payment).then(|| {
+                find_token_account(
+                    &accounts.payment_receiver,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            fee_receiver: accounts.fee_receiver,
+            fee_receiver_payment_token_account: (!is_native_payment).then(|| {
+                find_token_account(
+                    &accounts.fee_receiver,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            referrer_config,
+            referrer_config_payment_token_account: referrer_config.map(|referrer_config| {
+                find_token_account(
+                    &referrer_config,
+                    &accounts.payment_token_mint,
+                    &accounts.payment_token_program,
+                )
+            }),
+            payment_token_program: accounts.payment_token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::BuyCollectionAsset { params },
+    )
+}
+
+pub struct FillBoughtCollectionAssetAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn fill_bought_collection_asset(
+    accounts: &FillBoughtCollectionAssetAccounts,
+    params: FillBoughtCollectionAssetInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &accounts.user).0;
+    let asset_mint: Pubkey = find_asset_mint(&collection_auction_config, &params.asset_uuid).0;
+
+    build_instruction(
+        cream_pad::accounts::FillBoughtCollectionAssetInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config,
+            user_collection_auction_config,
+            user_collection_auction_buy_receipt_config:
+                find_user_collection_auction_buy_receipt_config(
+                    &user_collection_auction_config,
+                    &params.buy_index,
+                )
+                .0,
+            collection_mint_account: accounts.collection_mint,
+            asset_mint_account: asset_mint,
+            collection_metadata: find_metadata(&accounts.collection_mint),
+            collection_master_edition: find_master_edition(&accounts.collection_mint),
+            user_asset_token_account: find_token_account(
+                &accounts.user,
+                &asset_mint,
+                &accounts.token_program,
+            ),
+            asset_metadata: find_metadata(&asset_mint),
+            asset_master_edition: find_master_edition(&asset_mint),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            token_metadata_program: metadata::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::FillBoughtCollectionAsset { params },
+    )
+}
+
+pub struct ClaimCollectionAssetDistributionAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn claim_collection_asset_distribution(
+    accounts: &ClaimCollectionAssetDistributionAccounts,
+    params: ClaimCollectionAssetDistributionParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &accounts.user).0;
+
+    build_instruction(
+        cream_pad::accounts::ClaimCollectionAssetDistributionInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config,
+            user_collection_auction_config,
+            user_collection_auction_unsold_distribution_config:
+                find_user_collection_auction_unsold_distribution_config(
+                    &user_collection_auction_config,
+                )
+                .0,
+            collection_mint_account: accounts.collection_mint,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::ClaimCollectionAssetDistribution { params },
+    )
+}
+
+pub struct FillClaimedCollectionAssetDistributionAccounts {
+    pub fee_and_rent_payer: Pubkey,
+
+    pub user: Pubkey,
+
+    pub back_authority: BackAuthority,
+
+    pub collection_mint: Pubkey,
+
+    pub token_program: Pubkey,
+
+    pub cream_pad_config: Pubkey,
+}
+
+pub fn fill_claimed_collection_asset_distribution(
+    accounts: &FillClaimedCollectionAssetDistributionAccounts,
+    params: FillClaimedCollectionAssetDistributionInputParams,
+) -> Instruction {
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(&params.pad_name, &accounts.collection_mint).0;
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &accounts.user).0;
+    let asset_mint: Pubkey = find_asset_mint(&collection_auction_config, &params.asset_uuid).0;
+
+    build_instruction(
+        cream_pad::accounts::FillClaimedCollectionAssetDistributionInputAccounts {
+            fee_and_rent_payer: accounts.fee_and_rent_payer,
+            user: accounts.user,
+            cream_pad_config: accounts.cream_pad_config,
+            back_authority: accounts.back_authority.key,
+            collection_auction_config,
+            user_collection_auction_config,
+            user_collection_auction_unsold_distribution_config:
+                find_user_collection_auction_unsold_distribution_config(
+                    &user_collection_auction_config,
+                )
+                .0,
+            collection_mint_account: accounts.collection_mint,
+            asset_mint_account: asset_mint,
+            collection_metadata: find_metadata(&accounts.collection_mint),
+            collection_master_edition: find_master_edition(&accounts.collection_mint),
+            user_asset_token_account: find_token_account(
+                &accounts.user,
+                &asset_mint,
+                &accounts.token_program,
+            ),
+            asset_metadata: find_metadata(&asset_mint),
+            asset_master_edition: find_master_edition(&asset_mint),
+            token_program: accounts.token_program,
+            associated_token_program: associated_token::ID,
+            system_program: system_program::ID,
+            rent: sysvar::rent::ID,
+            token_metadata_program: metadata::ID,
+            instructions_sysvar: sysvar::instructions::ID,
+        },
+        Some(&accounts.back_authority),
+        cream_pad::instruction::FillClaimedCollectionAssetDistribution { params },
+    )
+}
@@ -0,0 +1,19 @@
+//! Off-chain client for the cream pad program.
+//!
+//! `pda` derives every program address from typed inputs, `instructions` builds a ready to sign
+//! `Instruction` for each entrypoint, deriving every PDA and token account it needs.
+
+use anchor_lang::prelude::Pubkey;
+
+pub mod instructions;
+pub mod pda;
+
+pub use cream_pad::ID;
+
+/// Back authority of the cream pad config, it only has to sign when the config requires it
+#[derive(Clone, Copy, Debug)]
+pub struct BackAuthority {
+    pub key: Pubkey,
+
+    pub is_required: bool,
+}
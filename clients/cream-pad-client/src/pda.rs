@@ -0,0 +1,247 @@
+use anchor_lang::prelude::Pubkey;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::metadata::mpl_token_metadata::accounts::{MasterEdition, Metadata};
+use cream_pad::states::{
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_PREFIX, AUCTION_VAULT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    CREAM_PAD_ACCOUNT_PREFIX, PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX, REFERRER_ACCOUNT_PREFIX,
+    USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+    USER_AUCTION_ROUND_ACCOUNT_PREFIX, USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX,
+    USER_AUCTION_VESTING_ACCOUNT_PREFIX, USER_COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX,
+};
+
+/// Round and buy indexes are seeded as their decimal string, e.g. `index_seed(1) == "1"`
+pub fn index_seed(index: u64) -> String {
+    index.to_string()
+}
+
+///////////// CONFIG ///////////////
+
+/// `tenant_id` is empty for the default config
+pub fn find_cream_pad_config(tenant_id: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[CREAM_PAD_ACCOUNT_PREFIX.as_ref(), tenant_id.as_ref()],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_pending_config_change(
+    cream_pad_config: &Pubkey,
+    config_change_index: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX.as_ref(),
+            cream_pad_config.as_ref(),
+            config_change_index.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+///////////// PAD ///////////////
+
+pub fn find_auction_config(pad_name: &str, token_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AUCTION_ACCOUNT_PREFIX.as_ref(),
+            pad_name.as_ref(),
+            token_mint.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_auction_vault_config(auction_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[AUCTION_VAULT_PREFIX.as_ref(), auction_config.as_ref()],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_auction_round_config(auction_config: &Pubkey, round_index: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+            auction_config.as_ref(),
+            round_index.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_auction_config(auction_config: &Pubkey, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+            auction_config.as_ref(),
+            user.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_auction_round_config(
+    auction_round_config: &Pubkey,
+    user_auction_config: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+            auction_round_config.as_ref(),
+            user_auction_config.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_auction_buy_receipt_config(
+    user_auction_config: &Pubkey,
+    buy_index: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_ref(),
+            user_auction_config.as_ref(),
+            buy_index.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_auction_unsold_distribution_config(user_auction_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX.as_ref(),
+            user_auction_config.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_auction_vesting_config(user_auction_config: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_AUCTION_VESTING_ACCOUNT_PREFIX.as_ref(),
+            user_auction_config.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+/// `pad_config` is the auction config or the collection auction config
+pub fn find_referrer_config(pad_config: &Pubkey, referrer: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            REFERRER_ACCOUNT_PREFIX.as_ref(),
+            pad_config.as_ref(),
+            referrer.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+///////////// COLLECTION PAD ///////////////
+
+pub fn find_collection_auction_config(pad_name: &str, collection_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COLLECTION_AUCTION_ACCOUNT_PREFIX.as_ref(),
+            pad_name.as_ref(),
+            collection_mint.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_collection_auction_round_config(
+    collection_auction_config: &Pubkey,
+    round_index: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+            collection_auction_config.as_ref(),
+            round_index.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_collection_auction_config(
+    collection_auction_config: &Pubkey,
+    user: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_COLLECTION_AUCTION_ACCOUNT_PREFIX.as_ref(),
+            collection_auction_config.as_ref(),
+            user.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_collection_auction_round_config(
+    collection_auction_round_config: &Pubkey,
+    user_collection_auction_config: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+            collection_auction_round_config.as_ref(),
+            user_collection_auction_config.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_collection_auction_buy_receipt_config(
+    user_collection_auction_config: &Pubkey,
+    buy_index: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_ref(),
+            user_collection_auction_config.as_ref(),
+            buy_index.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_user_collection_auction_unsold_distribution_config(
+    user_collection_auction_config: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX.as_ref(),
+            user_collection_auction_config.as_ref(),
+        ],
+        &cream_pad::ID,
+    )
+}
+
+pub fn find_asset_mint(collection_auction_config: &Pubkey, asset_uuid: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[collection_auction_config.as_ref(), asset_uuid.as_ref()],
+        &cream_pad::ID,
+    )
+}
+
+///////////// EXTERNAL ///////////////
+
+pub fn find_metadata(mint: &Pubkey) -> Pubkey {
+    Metadata::find_pda(mint).0
+}
+
+pub fn find_master_edition(mint: &Pubkey) -> Pubkey {
+    MasterEdition::find_pda(mint).0
+}
+
+pub fn find_token_account(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    get_associated_token_address_with_program_id(owner, mint, token_program)
+}
@@ -0,0 +1,280 @@
+//! Checks the first accounts of the `buy` and `buy_collection_asset` builders against the order
+//! and the signer and writable flags of the handlers' account structs.
+
+use cream_pad::instructions::user::{BuyCollectionAssetParams, BuyParams};
+use cream_pad::states::BuyIntent;
+use cream_pad_client::instructions::{
+    buy, buy_collection_asset, BuyAccounts, BuyCollectionAssetAccounts,
+};
+use cream_pad_client::pda::*;
+use cream_pad_client::BackAuthority;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+
+const PAD_NAME: &str = "pad";
+
+struct Keys {
+    fee_and_rent_payer: Pubkey,
+    user: Pubkey,
+    back_authority: Pubkey,
+    mint: Pubkey,
+    payment_mint: Pubkey,
+    cream_pad_config: Pubkey,
+}
+
+fn keys() -> Keys {
+    Keys {
+        fee_and_rent_payer: Pubkey::new_unique(),
+        user: Pubkey::new_unique(),
+        back_authority: Pubkey::new_unique(),
+        mint: Pubkey::new_unique(),
+        payment_mint: Pubkey::new_unique(),
+        cream_pad_config: Pubkey::new_unique(),
+    }
+}
+
+fn buy_accounts(keys: &Keys, is_back_authority_required: bool, have_vesting: bool) -> BuyAccounts {
+    BuyAccounts {
+        fee_and_rent_payer: keys.fee_and_rent_payer,
+        user: keys.user,
+        back_authority: BackAuthority {
+            key: keys.back_authority,
+            is_required: is_back_authority_required,
+        },
+        token_mint: keys.mint,
+        token_program: anchor_spl::token::ID,
+        payment_token_mint: keys.payment_mint,
+        payment_token_program: anchor_spl::token::ID,
+        payment_receiver: Pubkey::new_unique(),
+        fee_receiver: Pubkey::new_unique(),
+        have_payment_escrow: false,
+        have_vesting,
+        payment_oracle: None,
+        have_referral: false,
+        cream_pad_config: keys.cream_pad_config,
+    }
+}
+
+fn buy_params(buy_intent: Option<BuyIntent>) -> BuyParams {
+    BuyParams {
+        pad_name: PAD_NAME.to_string(),
+        current_round_index: index_seed(3),
+        buy_index: index_seed(5),
+        amount: 10,
+        whitelist_allocation: 0,
+        whitelist_proof: vec![],
+        referrer: Pubkey::default(),
+        buy_intent,
+        max_price_per_token: None,
+        max_total_payment: None,
+        cream_pad_config_bump: 0,
+        auction_config_bump: 0,
+        auction_round_config_bump: 0,
+        auction_vault_config_bump: 0,
+    }
+}
+
+fn buy_collection_asset_accounts(
+    keys: &Keys,
+    is_back_authority_required: bool,
+) -> BuyCollectionAssetAccounts {
+    BuyCollectionAssetAccounts {
+        fee_and_rent_payer: keys.fee_and_rent_payer,
+        user: keys.user,
+        back_authority: BackAuthority {
+            key: keys.back_authority,
+            is_required: is_back_authority_required,
+        },
+        collection_mint: keys.mint,
+        payment_token_mint: keys.payment_mint,
+        payment_token_program: anchor_spl::token::ID,
+        payment_receiver: Pubkey::new_unique(),
+        fee_receiver: Pubkey::new_unique(),
+        have_referral: false,
+        cream_pad_config: keys.cream_pad_config,
+    }
+}
+
+fn buy_collection_asset_params() -> BuyCollectionAssetParams {
+    BuyCollectionAssetParams {
+        pad_name: PAD_NAME.to_string(),
+        current_round_index: index_seed(3),
+        buy_index: index_seed(5),
+        amount: 1,
+        whitelist_allocation: 0,
+        whitelist_proof: vec![],
+        referrer: Pubkey::default(),
+        max_price_per_token: None,
+        max_total_payment: None,
+        cream_pad_config_bump: 0,
+        collection_auction_config_bump: 0,
+        collection_auction_round_config_bump: 0,
+        user_collection_auction_buy_receipt_config_bump: 0,
+    }
+}
+
+fn account_meta(pubkey: Pubkey, is_signer: bool, is_writable: bool) -> AccountMeta {
+    AccountMeta {
+        pubkey,
+        is_signer,
+        is_writable,
+    }
+}
+
+/// Accounts 0..=10 of `BuyInputAccounts`, a missing vesting account is passed as the program id
+fn expected_buy_accounts(
+    keys: &Keys,
+    is_user_signer: bool,
+    is_back_authority_required: bool,
+    have_vesting: bool,
+) -> Vec<AccountMeta> {
+    let auction_config: Pubkey = find_auction_config(PAD_NAME, &keys.mint).0;
+    let auction_round_config: Pubkey = find_auction_round_config(&auction_config, "3").0;
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &keys.user).0;
+
+    vec![
+        account_meta(keys.fee_and_rent_payer, true, true),
+        account_meta(keys.user, is_user_signer, true),
+        account_meta(keys.cream_pad_config, false, false),
+        account_meta(keys.back_authority, is_back_authority_required, false),
+        account_meta(auction_config, false, true),
+        account_meta(auction_round_config, false, true),
+        account_meta(user_auction_config, false, true),
+        account_meta(
+            find_user_auction_round_config(&auction_round_config, &user_auction_config).0,
+            false,
+            true,
+        ),
+        account_meta(
+            find_user_auction_buy_receipt_config(&user_auction_config, "5").0,
+            false,
+            true,
+        ),
+        if have_vesting {
+            account_meta(
+                find_user_auction_vesting_config(&user_auction_config).0,
+                false,
+                true,
+            )
+        } else {
+            account_meta(cream_pad::ID, false, false)
+        },
+        account_meta(keys.mint, false, false),
+    ]
+}
+
+#[test]
+fn buy_orders_the_handler_accounts() {
+    let keys: Keys = keys();
+
+    let instruction: Instruction = buy(&buy_accounts(&keys, false, true), buy_params(None));
+
+    assert_eq!(instruction.program_id, cream_pad::ID);
+    assert_eq!(
+        instruction.accounts[..11],
+        expected_buy_accounts(&keys, true, false, true)[..]
+    );
+}
+
+#[test]
+fn buy_passes_the_program_id_for_a_missing_vesting_account() {
+    let keys: Keys = keys();
+
+    let instruction: Instruction = buy(&buy_accounts(&keys, false, false), buy_params(None));
+
+    assert_eq!(
+        instruction.accounts[..11],
+        expected_buy_accounts(&keys, true, false, false)[..]
+    );
+}
+
+#[test]
+fn buy_signs_with_a_required_back_authority() {
+    let keys: Keys = keys();
+
+    let instruction: Instruction = buy(&buy_accounts(&keys, true, true), buy_params(None));
+
+    assert_eq!(
+        instruction.accounts[..11],
+        expected_buy_accounts(&keys, true, true, true)[..]
+    );
+}
+
+#[test]
+fn buy_relayed_with_an_intent_leaves_the_user_unsigned() {
+    let keys: Keys = keys();
+    let buy_intent: BuyIntent = BuyIntent {
+        pad: find_auction_config(PAD_NAME, &keys.mint).0,
+        round: 3,
+        amount: 10,
+        payment_mint: keys.payment_mint,
+        max_price: 1_000_000_000,
+        max_total_payment: 10_000_000_000,
+        expires_at: 1_700_000_000,
+        nonce: 5,
+        referrer: Pubkey::default(),
+    };
+
+    let instruction: Instruction = buy(
+        &buy_accounts(&keys, false, true),
+        buy_params(Some(buy_intent)),
+    );
+
+    assert_eq!(
+        instruction.accounts[..11],
+        expected_buy_accounts(&keys, false, false, true)[..]
+    );
+}
+
+#[test]
+fn buy_collection_asset_orders_the_handler_accounts() {
+    for is_back_authority_required in [false, true] {
+        let keys: Keys = keys();
+
+        let instruction: Instruction = buy_collection_asset(
+            &buy_collection_asset_accounts(&keys, is_back_authority_required),
+            buy_collection_asset_params(),
+        );
+
+        let collection_auction_config: Pubkey =
+            find_collection_auction_config(PAD_NAME, &keys.mint).0;
+        let collection_auction_round_config: Pubkey =
+            find_collection_auction_round_config(&collection_auction_config, "3").0;
+        let user_collection_auction_config: Pubkey =
+            find_user_collection_auction_config(&collection_auction_config, &keys.user).0;
+
+        // the back authority receives the minting fee, so it is writable either way
+        let expected: Vec<AccountMeta> = vec![
+            account_meta(keys.fee_and_rent_payer, true, true),
+            account_meta(keys.user, true, true),
+            account_meta(keys.cream_pad_config, false, false),
+            account_meta(keys.back_authority, is_back_authority_required, true),
+            account_meta(collection_auction_config, false, true),
+            account_meta(collection_auction_round_config, false, true),
+            account_meta(user_collection_auction_config, false, true),
+            account_meta(
+                find_user_collection_auction_round_config(
+                    &collection_auction_round_config,
+                    &user_collection_auction_config,
+                )
+                .0,
+                false,
+                true,
+            ),
+            account_meta(
+                find_user_collection_auction_buy_receipt_config(
+                    &user_collection_auction_config,
+                    "5",
+                )
+                .0,
+                false,
+                true,
+            ),
+            account_meta(keys.mint, false, false),
+            account_meta(keys.payment_mint, false, false),
+        ];
+
+        assert_eq!(instruction.program_id, cream_pad::ID);
+        assert_eq!(instruction.accounts[..11], expected[..]);
+    }
+}
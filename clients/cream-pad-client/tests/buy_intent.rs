@@ -0,0 +1,98 @@
+//! Runs the ed25519 instruction `verify_buy_intent` builds through the runtime's own ed25519
+//! precompile verification.
+
+use cream_pad::states::BuyIntent;
+use cream_pad_client::instructions::{buy_intent_message, verify_buy_intent};
+use solana_sdk::ed25519_instruction::verify;
+use solana_sdk::feature_set::FeatureSet;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+fn buy_intent() -> BuyIntent {
+    BuyIntent {
+        pad: Pubkey::new_unique(),
+        round: 1,
+        amount: 10,
+        payment_mint: Pubkey::new_unique(),
+        max_price: 1_000_000_000,
+        max_total_payment: 10_000_000_000,
+        expires_at: 1_700_000_000,
+        nonce: 0,
+        referrer: Pubkey::default(),
+    }
+}
+
+fn signature(user: &Keypair, buy_intent: &BuyIntent) -> [u8; 64] {
+    user.sign_message(&buy_intent_message(buy_intent)).into()
+}
+
+fn verify_instruction(instruction: &Instruction) -> bool {
+    verify(
+        &instruction.data,
+        &[&instruction.data],
+        &FeatureSet::all_enabled(),
+    )
+    .is_ok()
+}
+
+#[test]
+fn buy_intent_message_is_the_borsh_layout() {
+    let buy_intent: BuyIntent = buy_intent();
+
+    // the layout wallets and the TS client sign, field by field
+    let mut message: Vec<u8> = vec![];
+    message.extend_from_slice(buy_intent.pad.as_ref());
+    message.extend_from_slice(&buy_intent.round.to_le_bytes());
+    message.extend_from_slice(&buy_intent.amount.to_le_bytes());
+    message.extend_from_slice(buy_intent.payment_mint.as_ref());
+    message.extend_from_slice(&buy_intent.max_price.to_le_bytes());
+    message.extend_from_slice(&buy_intent.max_total_payment.to_le_bytes());
+    message.extend_from_slice(&buy_intent.expires_at.to_le_bytes());
+    message.extend_from_slice(&buy_intent.nonce.to_le_bytes());
+    message.extend_from_slice(buy_intent.referrer.as_ref());
+
+    assert_eq!(buy_intent_message(&buy_intent), message);
+    assert_eq!(message.len(), 32 + 2 + 8 + 32 + 8 + 8 + 8 + 8 + 32);
+}
+
+#[test]
+fn verify_buy_intent_passes_the_ed25519_program() {
+    let user: Keypair = Keypair::new();
+    let buy_intent: BuyIntent = buy_intent();
+
+    let instruction: Instruction =
+        verify_buy_intent(&user.pubkey(), &signature(&user, &buy_intent), &buy_intent);
+
+    assert_eq!(instruction.program_id, solana_sdk::ed25519_program::ID);
+    assert!(verify_instruction(&instruction));
+}
+
+#[test]
+fn verify_buy_intent_fails_for_another_signer() {
+    let user: Keypair = Keypair::new();
+    let relayer: Keypair = Keypair::new();
+    let buy_intent: BuyIntent = buy_intent();
+
+    let instruction: Instruction = verify_buy_intent(
+        &user.pubkey(),
+        &signature(&relayer, &buy_intent),
+        &buy_intent,
+    );
+
+    assert!(!verify_instruction(&instruction));
+}
+
+#[test]
+fn verify_buy_intent_fails_for_a_tampered_intent() {
+    let user: Keypair = Keypair::new();
+    let buy_intent: BuyIntent = buy_intent();
+
+    let mut tampered: BuyIntent = buy_intent.clone();
+    tampered.amount = 11;
+
+    let instruction: Instruction =
+        verify_buy_intent(&user.pubkey(), &signature(&user, &buy_intent), &tampered);
+
+    assert!(!verify_instruction(&instruction));
+}
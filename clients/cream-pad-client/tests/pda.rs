@@ -0,0 +1,203 @@
+//! Checks every PDA helper against the seeds the program itself derives its accounts from.
+
+use cream_pad::states::{
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_PREFIX, AUCTION_VAULT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    CREAM_PAD_ACCOUNT_PREFIX, PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX, REFERRER_ACCOUNT_PREFIX,
+    USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+    USER_AUCTION_ROUND_ACCOUNT_PREFIX, USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX,
+    USER_AUCTION_VESTING_ACCOUNT_PREFIX, USER_COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX,
+};
+use cream_pad_client::pda::*;
+use solana_sdk::pubkey::Pubkey;
+
+const PAD_NAME: &str = "pad";
+
+fn program_address(seeds: &[&[u8]]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(seeds, &cream_pad::ID)
+}
+
+#[test]
+fn index_seed_is_the_decimal_string() {
+    assert_eq!(index_seed(0), "0");
+    assert_eq!(index_seed(1), "1");
+    assert_eq!(index_seed(12), "12");
+    assert_eq!(index_seed(u64::MAX), "18446744073709551615");
+}
+
+#[test]
+fn config_pdas_match_the_program_seeds() {
+    let cream_pad_config: Pubkey = Pubkey::new_unique();
+
+    assert_eq!(
+        find_cream_pad_config(""),
+        program_address(&[CREAM_PAD_ACCOUNT_PREFIX.as_bytes(), b""])
+    );
+    assert_eq!(
+        find_cream_pad_config("tenant"),
+        program_address(&[CREAM_PAD_ACCOUNT_PREFIX.as_bytes(), b"tenant"])
+    );
+    assert_eq!(
+        find_pending_config_change(&cream_pad_config, &index_seed(3)),
+        program_address(&[
+            PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX.as_bytes(),
+            cream_pad_config.as_ref(),
+            b"3",
+        ])
+    );
+}
+
+#[test]
+fn pad_pdas_match_the_program_seeds() {
+    let token_mint: Pubkey = Pubkey::new_unique();
+    let user: Pubkey = Pubkey::new_unique();
+    let referrer: Pubkey = Pubkey::new_unique();
+
+    let auction_config: Pubkey = find_auction_config(PAD_NAME, &token_mint).0;
+    assert_eq!(
+        find_auction_config(PAD_NAME, &token_mint),
+        program_address(&[
+            AUCTION_ACCOUNT_PREFIX.as_bytes(),
+            PAD_NAME.as_bytes(),
+            token_mint.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_auction_vault_config(&auction_config),
+        program_address(&[AUCTION_VAULT_PREFIX.as_bytes(), auction_config.as_ref()])
+    );
+
+    let auction_round_config: Pubkey =
+        find_auction_round_config(&auction_config, &index_seed(10)).0;
+    assert_eq!(
+        find_auction_round_config(&auction_config, &index_seed(10)),
+        program_address(&[
+            AUCTION_ROUND_ACCOUNT_PREFIX.as_bytes(),
+            auction_config.as_ref(),
+            b"10",
+        ])
+    );
+
+    let user_auction_config: Pubkey = find_user_auction_config(&auction_config, &user).0;
+    assert_eq!(
+        find_user_auction_config(&auction_config, &user),
+        program_address(&[
+            USER_AUCTION_ACCOUNT_PREFIX.as_bytes(),
+            auction_config.as_ref(),
+            user.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_user_auction_round_config(&auction_round_config, &user_auction_config),
+        program_address(&[
+            USER_AUCTION_ROUND_ACCOUNT_PREFIX.as_bytes(),
+            auction_round_config.as_ref(),
+            user_auction_config.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_user_auction_buy_receipt_config(&user_auction_config, &index_seed(7)),
+        program_address(&[
+            USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_bytes(),
+            user_auction_config.as_ref(),
+            b"7",
+        ])
+    );
+    assert_eq!(
+        find_user_auction_unsold_distribution_config(&user_auction_config),
+        program_address(&[
+            USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX.as_bytes(),
+            user_auction_config.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_user_auction_vesting_config(&user_auction_config),
+        program_address(&[
+            USER_AUCTION_VESTING_ACCOUNT_PREFIX.as_bytes(),
+            user_auction_config.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_referrer_config(&auction_config, &referrer),
+        program_address(&[
+            REFERRER_ACCOUNT_PREFIX.as_bytes(),
+            auction_config.as_ref(),
+            referrer.as_ref(),
+        ])
+    );
+}
+
+#[test]
+fn collection_pad_pdas_match_the_program_seeds() {
+    let collection_mint: Pubkey = Pubkey::new_unique();
+    let user: Pubkey = Pubkey::new_unique();
+
+    let collection_auction_config: Pubkey =
+        find_collection_auction_config(PAD_NAME, &collection_mint).0;
+    assert_eq!(
+        find_collection_auction_config(PAD_NAME, &collection_mint),
+        program_address(&[
+            COLLECTION_AUCTION_ACCOUNT_PREFIX.as_bytes(),
+            PAD_NAME.as_bytes(),
+            collection_mint.as_ref(),
+        ])
+    );
+
+    let collection_auction_round_config: Pubkey =
+        find_collection_auction_round_config(&collection_auction_config, &index_seed(2)).0;
+    assert_eq!(
+        find_collection_auction_round_config(&collection_auction_config, &index_seed(2)),
+        program_address(&[
+            COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX.as_bytes(),
+            collection_auction_config.as_ref(),
+            b"2",
+        ])
+    );
+
+    let user_collection_auction_config: Pubkey =
+        find_user_collection_auction_config(&collection_auction_config, &user).0;
+    assert_eq!(
+        find_user_collection_auction_config(&collection_auction_config, &user),
+        program_address(&[
+            USER_COLLECTION_AUCTION_ACCOUNT_PREFIX.as_bytes(),
+            collection_auction_config.as_ref(),
+            user.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_user_collection_auction_round_config(
+            &collection_auction_round_config,
+            &user_collection_auction_config,
+        ),
+        program_address(&[
+            USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX.as_bytes(),
+            collection_auction_round_config.as_ref(),
+            user_collection_auction_config.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_user_collection_auction_buy_receipt_config(
+            &user_collection_auction_config,
+            &index_seed(11),
+        ),
+        program_address(&[
+            USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_bytes(),
+            user_collection_auction_config.as_ref(),
+            b"11",
+        ])
+    );
+    assert_eq!(
+        find_user_collection_auction_unsold_distribution_config(&user_collection_auction_config),
+        program_address(&[
+            USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX.as_bytes(),
+            user_collection_auction_config.as_ref(),
+        ])
+    );
+    assert_eq!(
+        find_asset_mint(&collection_auction_config, "asset"),
+        program_address(&[collection_auction_config.as_ref(), b"asset"])
+    );
+}
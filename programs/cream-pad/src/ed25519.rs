@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::Instruction;
+
+/// Size of the signature count, its padding byte and one signature offsets entry.
+const SIGNATURE_OFFSETS_END: usize = 2 + 14;
+
+/// Signer and message of an ed25519 program instruction that verifies exactly one signature
+/// over data it carries itself, `None` for anything else.
+pub fn ed25519_signed_message(instruction: &Instruction) -> Option<(Pubkey, &[u8])> {
+    if instruction.program_id != ed25519_program::ID {
+        return None;
+    }
+
+    let data: &[u8] = &instruction.data;
+    if data.len() < SIGNATURE_OFFSETS_END || data[0] != 1 {
+        return None;
+    }
+
+    let offset = |index: usize| -> usize {
+        u16::from_le_bytes([data[2 + index * 2], data[3 + index * 2]]) as usize
+    };
+
+    // signature, public key and message all have to point at this instruction
+    if [1, 3, 6]
+        .iter()
+        .any(|index| offset(*index) != u16::MAX as usize)
+    {
+        return None;
+    }
+
+    let public_key: &[u8] = data.get(offset(2)..offset(2) + 32)?;
+    let message: &[u8] = data.get(offset(4)..offset(4) + offset(5))?;
+
+    Some((Pubkey::try_from(public_key).ok()?, message))
+}
+
+/// Ed25519 program instruction verifying `public_key`'s signature over `message`, laid out the
+/// way the client builds it, the signature itself is left zeroed.
+#[cfg(test)]
+pub(crate) fn ed25519_test_instruction(public_key: &Pubkey, message: &[u8]) -> Instruction {
+    let mut data: Vec<u8> = vec![1, 0];
+    for value in [
+        48,
+        u16::MAX,
+        16,
+        u16::MAX,
+        112,
+        message.len() as u16,
+        u16::MAX,
+    ] {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+    data.extend_from_slice(public_key.as_ref());
+    data.extend_from_slice(&[0; 64]);
+    data.extend_from_slice(message);
+
+    Instruction {
+        program_id: ed25519_program::ID,
+        accounts: vec![],
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNER: Pubkey = Pubkey::new_from_array([7; 32]);
+
+    #[test]
+    fn ed25519_signed_message_reads_signer_and_message() {
+        let instruction: Instruction = ed25519_test_instruction(&SIGNER, b"intent");
+
+        assert_eq!(
+            ed25519_signed_message(&instruction),
+            Some((SIGNER, b"intent".as_slice()))
+        );
+    }
+
+    #[test]
+    fn ed25519_signed_message_rejects_other_programs() {
+        let mut instruction: Instruction = ed25519_test_instruction(&SIGNER, b"intent");
+        instruction.program_id = crate::ID;
+
+        assert_eq!(ed25519_signed_message(&instruction), None);
+    }
+
+    #[test]
+    fn ed25519_signed_message_rejects_several_signatures() {
+        let mut instruction: Instruction = ed25519_test_instruction(&SIGNER, b"intent");
+        instruction.data[0] = 2;
+
+        assert_eq!(ed25519_signed_message(&instruction), None);
+    }
+
+    #[test]
+    fn ed25519_signed_message_rejects_data_in_other_instructions() {
+        // signature, public key and message instruction indexes in turn
+        for index in [4, 8, 14] {
+            let mut instruction: Instruction = ed25519_test_instruction(&SIGNER, b"intent");
+            instruction.data[index..index + 2].copy_from_slice(&0u16.to_le_bytes());
+
+            assert_eq!(ed25519_signed_message(&instruction), None);
+        }
+    }
+
+    #[test]
+    fn ed25519_signed_message_rejects_out_of_bounds_offsets() {
+        let mut instruction: Instruction = ed25519_test_instruction(&SIGNER, b"intent");
+        instruction.data.truncate(115);
+
+        assert_eq!(ed25519_signed_message(&instruction), None);
+
+        instruction.data.truncate(SIGNATURE_OFFSETS_END - 1);
+
+        assert_eq!(ed25519_signed_message(&instruction), None);
+    }
+}
@@ -134,4 +134,208 @@ pub enum CreamPadError {
 
     #[msg("Not eligible for collection distribution")]
     NotEligibleForCollectionDistribution,
+
+    #[msg("Account not migrated")]
+    AccountNotMigrated,
+
+    #[msg("Account already migrated")]
+    AccountAlreadyMigrated,
+
+    #[msg("Invalid account discriminator")]
+    InvalidAccountDiscriminator,
+
+    #[msg("Invalid whitelist rounds")]
+    InvalidWhitelistRounds,
+
+    #[msg("Invalid whitelist proof")]
+    InvalidWhitelistProof,
+
+    #[msg("Whitelist allocation exceeded")]
+    WhitelistAllocationExceeded,
+
+    #[msg("Auction cannot be cancelled")]
+    AuctionCannotBeCancelled,
+
+    #[msg("Auction not cancelled")]
+    AuctionNotCancelled,
+
+    #[msg("Auction payment not escrowed")]
+    AuctionPaymentNotEscrowed,
+
+    #[msg("Receipt already refunded")]
+    ReceiptAlreadyRefunded,
+
+    #[msg("Invalid payment settler")]
+    InvalidPaymentSettler,
+
+    #[msg("Auction payment cannot be settled")]
+    AuctionPaymentCannotBeSettled,
+
+    #[msg("Auction payment already settled")]
+    AuctionPaymentAlreadySettled,
+
+    #[msg("Uniform clearing price requires payment escrow")]
+    UniformClearingPriceRequiresEscrow,
+
+    #[msg("Auction has no uniform clearing price")]
+    AuctionNotUniformClearingPrice,
+
+    #[msg("Rebate already claimed")]
+    RebateAlreadyClaimed,
+
+    #[msg("Invalid vesting schedule")]
+    InvalidVestingSchedule,
+
+    #[msg("Auction has no vesting")]
+    AuctionHasNoVesting,
+
+    #[msg("Vesting not started")]
+    VestingNotStarted,
+
+    #[msg("Nothing vested to claim")]
+    NothingVestedToClaim,
+
+    #[msg("Invalid step decay")]
+    InvalidStepDecay,
+
+    #[msg("Invalid sigmoid decay")]
+    InvalidSigmoidDecay,
+
+    #[msg("Invalid price table")]
+    InvalidPriceTable,
+
+    #[msg("Auction has no crank")]
+    AuctionHasNoCrank,
+
+    #[msg("Invalid round schedule")]
+    InvalidRoundSchedule,
+
+    #[msg("Round gap not elapsed")]
+    RoundGapNotElapsed,
+
+    #[msg("Invalid start at")]
+    InvalidStartAt,
+
+    #[msg("Auction not started")]
+    AuctionNotStarted,
+
+    #[msg("Native payment cannot be escrowed")]
+    NativePaymentCannotBeEscrowed,
+
+    #[msg("Invalid payment mints")]
+    InvalidPaymentMints,
+
+    #[msg("Payment mints cannot be escrowed")]
+    PaymentMintsCannotBeEscrowed,
+
+    #[msg("Invalid oracle price")]
+    InvalidOraclePrice,
+
+    #[msg("Invalid referral")]
+    InvalidReferral,
+
+    #[msg("Invalid referrer")]
+    InvalidReferrer,
+
+    #[msg("No referral rewards to claim")]
+    NoReferralRewardsToClaim,
+
+    #[msg("Invalid fee tiers")]
+    InvalidFeeTiers,
+
+    #[msg("Invalid governance")]
+    InvalidGovernance,
+
+    #[msg("Config changes require governance")]
+    GovernanceRequired,
+
+    #[msg("Governance is not enabled")]
+    GovernanceNotEnabled,
+
+    #[msg("Invalid governance signer")]
+    InvalidGovernanceSigner,
+
+    #[msg("Invalid config change index")]
+    InvalidConfigChangeIndex,
+
+    #[msg("Config change is not pending")]
+    ConfigChangeNotPending,
+
+    #[msg("Config change already approved")]
+    ConfigChangeAlreadyApproved,
+
+    #[msg("Not enough config change approvals")]
+    NotEnoughConfigChangeApprovals,
+
+    #[msg("Config change is timelocked")]
+    ConfigChangeTimelocked,
+
+    #[msg("Invalid pending authority")]
+    InvalidPendingAuthority,
+
+    #[msg("Buys are paused")]
+    BuysPaused,
+
+    #[msg("New pads are paused")]
+    NewPadsPaused,
+
+    #[msg("Claims are paused")]
+    ClaimsPaused,
+
+    #[msg("Collection minting is paused")]
+    CollectionMintingPaused,
+
+    #[msg("Invalid tenant id")]
+    InvalidTenantId,
+
+    #[msg("Invalid cream pad config")]
+    InvalidCreamPadConfig,
+
+    #[msg("Auction not finished")]
+    AuctionNotFinished,
+
+    #[msg("Invalid rent payer")]
+    InvalidRentPayer,
+
+    #[msg("Rebate not claimed")]
+    RebateNotClaimed,
+
+    #[msg("Buy intent required when the user doesn't sign")]
+    MissingBuyIntent,
+
+    #[msg("Invalid buy intent")]
+    InvalidBuyIntent,
+
+    #[msg("Invalid buy intent signature")]
+    InvalidBuyIntentSignature,
+
+    #[msg("Buy intent expired")]
+    BuyIntentExpired,
+
+    #[msg("Buy intent max price exceeded")]
+    BuyIntentMaxPriceExceeded,
+
+    #[msg("Native payment needs the user's signature")]
+    BuyIntentNativePayment,
+
+    #[msg("Price above max price per token")]
+    MaxPricePerTokenExceeded,
+
+    #[msg("Payment above max total payment")]
+    MaxTotalPaymentExceeded,
+
+    #[msg("Auction payment not settled")]
+    AuctionPaymentNotSettled,
+
+    #[msg("Invalid auction round accounts")]
+    InvalidAuctionRoundAccounts,
+
+    #[msg("Auction vault cannot cover the rebates")]
+    InsufficientRebateReserve,
+
+    #[msg("Payment amount overflows at this payment rate")]
+    PaymentRateOverflow,
+
+    #[msg("Buy intent max total payment exceeded")]
+    BuyIntentMaxTotalPaymentExceeded,
 }
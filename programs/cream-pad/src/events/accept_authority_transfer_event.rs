@@ -0,0 +1,13 @@
+use crate::states::AuthorityType;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct AcceptAuthorityTransferEvent {
+    pub timestamp: i64,
+
+    pub authority_type: AuthorityType,
+
+    pub previous_authority: Pubkey,
+
+    pub new_authority: Pubkey,
+}
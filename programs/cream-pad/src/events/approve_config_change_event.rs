@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ApproveConfigChangeEvent {
+    pub timestamp: i64,
+
+    pub governance_signer: Pubkey,
+
+    pub config_change_index: u64,
+
+    pub approval_count: u8,
+}
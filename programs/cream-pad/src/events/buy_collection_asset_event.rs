@@ -25,4 +25,16 @@ pub struct BuyCollectionAssetEvent {
     pub total_price: u64,
 
     pub is_ended_and_sold_out: bool,
-}
\ No newline at end of file
+
+    pub is_whitelist_round: bool,
+
+    /// default pubkey when the buy names no referrer
+    pub referrer: Pubkey,
+
+    pub referral_reward: u64,
+
+    pub rent_payer: Pubkey,
+
+    /// lamports of rent for the accounts this buy created
+    pub rent: u64,
+}
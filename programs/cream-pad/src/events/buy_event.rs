@@ -23,4 +23,25 @@ pub struct BuyEvent {
     pub total_price: u64,
 
     pub is_ended_and_sold_out: bool,
-}
\ No newline at end of file
+
+    pub is_whitelist_round: bool,
+
+    /// mint the user paid with, the system program for native SOL
+    pub payment_mint: Pubkey,
+
+    /// paid in `payment_mint` units, normalized to 9 decimals, fee included
+    pub payment_total_price: u64,
+
+    /// default pubkey when the buy names no referrer
+    pub referrer: Pubkey,
+
+    pub referral_reward: u64,
+
+    /// submitted by a relayer with the user's signed buy intent
+    pub is_buy_intent: bool,
+
+    pub rent_payer: Pubkey,
+
+    /// lamports of rent for the accounts this buy created
+    pub rent: u64,
+}
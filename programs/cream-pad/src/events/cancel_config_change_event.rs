@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CancelConfigChangeEvent {
+    pub timestamp: i64,
+
+    pub governance_signer: Pubkey,
+
+    pub config_change_index: u64,
+}
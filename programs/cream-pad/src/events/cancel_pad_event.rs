@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CancelPadEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub total_supply_sold: u64,
+
+    pub total_payment: u64,
+}
@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimRebateEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub user: Pubkey,
+
+    pub user_buy_index: String,
+
+    pub clearing_price: u64,
+
+    pub rebate: u64,
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimReferralRewardsEvent {
+    pub timestamp: i64,
+
+    pub referrer: Pubkey,
+
+    pub pad_config: Pubkey,
+
+    pub amount: u64,
+
+    pub total_claimed_reward: u64,
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ClaimVestedEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub user: Pubkey,
+
+    pub amount: u64,
+
+    pub total_claimed_amount: u64,
+}
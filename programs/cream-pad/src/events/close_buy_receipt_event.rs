@@ -0,0 +1,19 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CloseBuyReceiptEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub user: Pubkey,
+
+    pub user_buy_index: String,
+
+    pub rent_payer: Pubkey,
+
+    /// lamports handed back to the rent payer
+    pub rent: u64,
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CloseRoundEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    pub rent_payer: Pubkey,
+
+    /// lamports handed back to the rent payer
+    pub rent: u64,
+}
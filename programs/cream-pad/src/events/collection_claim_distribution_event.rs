@@ -11,4 +11,9 @@ pub struct CollectionClaimDistributionEvent {
     pub user: Pubkey,
 
     pub amount: u64,
+
+    pub rent_payer: Pubkey,
+
+    /// lamports of rent for the unsold distribution account
+    pub rent: u64,
 }
\ No newline at end of file
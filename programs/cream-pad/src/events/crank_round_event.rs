@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct CrankRoundEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub cranker: Pubkey,
+
+    pub round_index: String,
+
+    pub boost: i128,
+
+    /// empty when the cranked round was the last one
+    pub next_round_index: String,
+
+    pub current_price: u64,
+
+    pub reward: u64,
+}
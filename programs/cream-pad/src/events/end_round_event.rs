@@ -10,5 +10,5 @@ pub struct EndRoundEvent {
 
     pub round_index: String,
 
-    pub boost: f64,
+    pub boost: i128,
 }
\ No newline at end of file
@@ -33,4 +33,18 @@ pub struct InitializeCollectionPadEvent {
     pub starting_index: u64,
 
     pub ending_index: u64,
+
+    pub have_whitelist: bool,
+
+    pub whitelist_merkle_root: [u8; 32],
+
+    pub whitelist_rounds: u16,
+
+    pub have_referral: bool,
+
+    pub referral_base_point: u16,
+
+    pub fee_base_point: u16,
+
+    pub minting_fee: u64,
 }
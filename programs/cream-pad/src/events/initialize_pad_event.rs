@@ -29,4 +29,36 @@ pub struct InitializePadEvent {
     pub have_buy_limit: bool,
 
     pub buy_limit: u64,
+
+    pub have_whitelist: bool,
+
+    pub whitelist_merkle_root: [u8; 32],
+
+    pub whitelist_rounds: u16,
+
+    pub have_payment_escrow: bool,
+
+    pub have_uniform_clearing_price: bool,
+
+    pub have_vesting: bool,
+
+    pub vesting_tge_base_point: u16,
+
+    pub vesting_cliff_duration: i64,
+
+    pub vesting_duration: i64,
+
+    pub have_crank: bool,
+
+    pub crank_round_duration: i64,
+
+    pub crank_reward: u64,
+
+    pub start_at: i64,
+
+    pub have_referral: bool,
+
+    pub referral_base_point: u16,
+
+    pub fee_base_point: u16,
 }
@@ -0,0 +1,12 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateCollectionPadEvent {
+    pub timestamp: i64,
+
+    pub collection_mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub version: u8,
+}
@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateCollectionPadRoundEvent {
+    pub timestamp: i64,
+
+    pub collection_mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    pub version: u8,
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateConfigEvent {
+    pub timestamp: i64,
+
+    pub signing_authority: Pubkey,
+}
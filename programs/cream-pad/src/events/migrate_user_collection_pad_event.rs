@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateUserCollectionPadEvent {
+    pub timestamp: i64,
+
+    pub collection_mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub user: Pubkey,
+
+    pub version: u8,
+}
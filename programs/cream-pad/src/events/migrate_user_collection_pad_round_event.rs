@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateUserCollectionPadRoundEvent {
+    pub timestamp: i64,
+
+    pub collection_mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    pub user: Pubkey,
+
+    pub version: u8,
+}
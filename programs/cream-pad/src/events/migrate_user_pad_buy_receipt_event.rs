@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct MigrateUserPadBuyReceiptEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub buy_index: String,
+
+    pub user: Pubkey,
+
+    pub version: u8,
+}
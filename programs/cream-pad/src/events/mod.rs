@@ -18,6 +18,46 @@ pub use buy_collection_asset_event::*;
 pub use fill_bought_collection_asset_event::*;
 pub use collection_claim_distribution_event::*;
 pub use fill_claimed_collection_asset_distribution_event::*;
+pub use migrate_pad_event::*;
+pub use migrate_pad_round_event::*;
+pub use migrate_collection_pad_event::*;
+pub use migrate_collection_pad_round_event::*;
+pub use cancel_pad_event::*;
+pub use withdraw_cancelled_supply_event::*;
+pub use refund_event::*;
+pub use settle_pad_event::*;
+pub use claim_rebate_event::*;
+pub use claim_vested_event::*;
+pub use crank_round_event::*;
+pub use fund_crank_reward_event::*;
+pub use withdraw_crank_reward_event::*;
+pub use round_schedule_event::*;
+pub use collection_round_schedule_event::*;
+pub use payment_mints_event::*;
+pub use claim_referral_rewards_event::*;
+pub use migrate_config_event::*;
+pub use set_pad_fee_event::*;
+pub use set_collection_pad_fee_event::*;
+pub use propose_config_change_event::*;
+pub use approve_config_change_event::*;
+pub use execute_config_change_event::*;
+pub use cancel_config_change_event::*;
+pub use propose_authority_transfer_event::*;
+pub use accept_authority_transfer_event::*;
+pub use set_pause_flags_event::*;
+pub use set_pad_pause_flags_event::*;
+pub use set_collection_pad_pause_flags_event::*;
+pub use migrate_user_pad_event::*;
+pub use migrate_user_pad_round_event::*;
+pub use migrate_user_pad_buy_receipt_event::*;
+pub use migrate_user_pad_unsold_distribution_event::*;
+pub use migrate_user_collection_pad_event::*;
+pub use migrate_user_collection_pad_round_event::*;
+pub use migrate_user_collection_pad_buy_receipt_event::*;
+pub use migrate_user_collection_pad_unsold_distribution_event::*;
+pub use close_buy_receipt_event::*;
+pub use close_user_round_event::*;
+pub use close_round_event::*;
 
 pub mod buy_event;
 pub mod claim_distribution_event;
@@ -39,3 +79,43 @@ pub mod buy_collection_asset_event;
 pub mod fill_bought_collection_asset_event;
 pub mod collection_claim_distribution_event;
 pub mod fill_claimed_collection_asset_distribution_event;
+pub mod migrate_pad_event;
+pub mod migrate_pad_round_event;
+pub mod migrate_collection_pad_event;
+pub mod migrate_collection_pad_round_event;
+pub mod cancel_pad_event;
+pub mod withdraw_cancelled_supply_event;
+pub mod refund_event;
+pub mod settle_pad_event;
+pub mod claim_rebate_event;
+pub mod claim_vested_event;
+pub mod crank_round_event;
+pub mod fund_crank_reward_event;
+pub mod withdraw_crank_reward_event;
+pub mod round_schedule_event;
+pub mod collection_round_schedule_event;
+pub mod payment_mints_event;
+pub mod claim_referral_rewards_event;
+pub mod migrate_config_event;
+pub mod set_pad_fee_event;
+pub mod set_collection_pad_fee_event;
+pub mod propose_config_change_event;
+pub mod approve_config_change_event;
+pub mod execute_config_change_event;
+pub mod cancel_config_change_event;
+pub mod propose_authority_transfer_event;
+pub mod accept_authority_transfer_event;
+pub mod set_pause_flags_event;
+pub mod set_pad_pause_flags_event;
+pub mod set_collection_pad_pause_flags_event;
+pub mod migrate_user_pad_event;
+pub mod migrate_user_pad_round_event;
+pub mod migrate_user_pad_buy_receipt_event;
+pub mod migrate_user_pad_unsold_distribution_event;
+pub mod migrate_user_collection_pad_event;
+pub mod migrate_user_collection_pad_round_event;
+pub mod migrate_user_collection_pad_buy_receipt_event;
+pub mod migrate_user_collection_pad_unsold_distribution_event;
+pub mod close_buy_receipt_event;
+pub mod close_user_round_event;
+pub mod close_round_event;
\ No newline at end of file
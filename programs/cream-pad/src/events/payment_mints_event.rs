@@ -0,0 +1,17 @@
+use crate::states::PaymentMint;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct PaymentMintsEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub payment_oracle_program: Pubkey,
+
+    pub payment_oracle_max_age: i64,
+
+    pub payment_mints: Vec<PaymentMint>,
+}
@@ -0,0 +1,13 @@
+use crate::states::AuthorityType;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProposeAuthorityTransferEvent {
+    pub timestamp: i64,
+
+    pub authority_type: AuthorityType,
+
+    pub current_authority: Pubkey,
+
+    pub pending_authority: Pubkey,
+}
@@ -0,0 +1,15 @@
+use crate::states::ConfigChange;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct ProposeConfigChangeEvent {
+    pub timestamp: i64,
+
+    pub proposer: Pubkey,
+
+    pub config_change_index: u64,
+
+    pub change: ConfigChange,
+
+    pub executable_at: i64,
+}
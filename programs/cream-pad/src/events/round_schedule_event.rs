@@ -0,0 +1,13 @@
+use crate::states::RoundSchedule;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct RoundScheduleEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub round_schedule: Vec<RoundSchedule>,
+}
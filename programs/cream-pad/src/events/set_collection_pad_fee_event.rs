@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SetCollectionPadFeeEvent {
+    pub timestamp: i64,
+
+    pub collection_mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub have_fee_override: bool,
+
+    pub fee_override_base_point: u16,
+
+    pub fee_override_minting_fee: u64,
+}
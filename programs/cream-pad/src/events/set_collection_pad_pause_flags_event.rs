@@ -0,0 +1,13 @@
+use crate::states::PauseFlags;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SetCollectionPadPauseFlagsEvent {
+    pub timestamp: i64,
+
+    pub collection_mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub pause_flags: PauseFlags,
+}
@@ -0,0 +1,13 @@
+use crate::states::PauseFlags;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SetPadPauseFlagsEvent {
+    pub timestamp: i64,
+
+    pub mint: Pubkey,
+
+    pub pad_name: String,
+
+    pub pause_flags: PauseFlags,
+}
@@ -0,0 +1,9 @@
+use crate::states::PauseFlags;
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct SetPauseFlagsEvent {
+    pub timestamp: i64,
+
+    pub pause_flags: PauseFlags,
+}
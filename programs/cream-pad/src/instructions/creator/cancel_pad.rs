@@ -0,0 +1,128 @@
+use crate::states::{
+    AuctionAccount, AuctionStatus, CreamPadAccount, AUCTION_ACCOUNT_PREFIX,
+    AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_version, check_back_authority, check_can_cancel, check_cream_pad_config,
+    check_creator, check_is_program_working, check_signer_exist,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token_interface::Mint;
+
+use crate::events::CancelPadEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CancelPadInputParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CancelPadInputParams)]
+pub struct CancelPadInputAccounts<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_cancel_pad<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelPadInputAccounts<'info>>,
+    params: &CancelPadInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_creator(auction_config.creator, ctx.accounts.creator.key())?;
+
+    check_can_cancel(
+        auction_config.status.clone(),
+        auction_config.have_payment_escrow,
+        auction_config.is_payment_settled,
+        auction_config.total_user_buy_count,
+        auction_config.total_rebate_claimed_count,
+    )?;
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+    auction_config.status = AuctionStatus::Cancelled;
+    auction_config.cancelled_at = timestamp;
+
+    // Event
+    let event: CancelPadEvent = CancelPadEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        total_supply_sold: auction_config.total_supply_sold,
+        total_payment: auction_config.total_payment,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+use crate::events::CloseRoundEvent;
+use crate::states::{
+    AuctionAccount, AuctionRoundAccount, CreamPadAccount, AUCTION_ACCOUNT_PREFIX,
+    AUCTION_ACCOUNT_VERSION, AUCTION_ROUND_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_VERSION,
+    CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_version, check_back_authority, check_cream_pad_config, check_is_auction_finished,
+    check_is_program_working, check_rent_payer, check_signer_exist, get_rent_payer,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CloseRoundInputParams {
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub auction_round_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CloseRoundInputParams)]
+pub struct CloseRoundInputAccounts<'info> {
+    /// anyone, the rent always goes back to the rent payer
+    pub closer: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+        AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        params.round_index.as_ref(),
+        ],
+        bump = params.auction_round_config_bump,
+    )]
+    pub auction_round_config: Box<Account<'info, AuctionRoundAccount>>,
+
+    /// CHECK: rent_payer, checked against the round
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_close_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseRoundInputAccounts<'info>>,
+    params: &CloseRoundInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    let auction_round_config: &Account<AuctionRoundAccount> = &ctx.accounts.auction_round_config;
+
+    // Checks
+
+    check_account_version(auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_account_version(auction_round_config.version, AUCTION_ROUND_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(auction_config.cream_pad_config, cream_pad_config.key())?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_is_auction_finished(
+        auction_config.status.clone(),
+        auction_config.have_payment_escrow,
+        auction_config.is_payment_settled,
+    )?;
+
+    // migrated rounds were paid for by the creator
+    let rent_payer: Pubkey =
+        get_rent_payer(auction_round_config.rent_payer, auction_config.creator);
+
+    check_rent_payer(rent_payer, ctx.accounts.rent_payer.key())?;
+
+    // Event
+    let event: CloseRoundEvent = CloseRoundEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        round_index: params.round_index.clone(),
+        rent_payer,
+        rent: auction_round_config.to_account_info().lamports(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
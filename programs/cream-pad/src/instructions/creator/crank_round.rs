@@ -0,0 +1,308 @@
+use crate::math::{calculate_boost, calculate_price};
+use crate::states::{
+    AuctionAccount, AuctionRoundAccount, AuctionRoundStatus, AuctionStatus, CreamPadAccount,
+    RoundSchedule, AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, AUCTION_ROUND_ACCOUNT_PREFIX,
+    AUCTION_ROUND_ACCOUNT_VERSION, AUCTION_VAULT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_exist, check_account_version, check_cream_pad_config, check_current_round,
+    check_is_auction_ended_or_sold_out, check_is_auction_round_ended,
+    check_is_auction_round_still_have_time, check_is_crank, check_is_program_working,
+    check_next_round, check_round_gap,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::Mint;
+
+use crate::events::CrankRoundEvent;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CrankRoundInputParams {
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    /// ignored when the cranked round is the last one
+    pub next_round_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub auction_round_config_bump: u8,
+
+    pub auction_vault_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CrankRoundInputParams)]
+pub struct CrankRoundInputAccounts<'info> {
+    /// anyone, pays the next round rent and receives the crank reward
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    /// CHECK: auction_vault_config, holds the crank reward lamports
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        ],
+        bump = params.auction_vault_config_bump,
+    )]
+    pub auction_vault_config: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        params.round_index.as_ref(),
+        ],
+        bump = params.auction_round_config_bump,
+    )]
+    pub auction_round_config: Box<Account<'info, AuctionRoundAccount>>,
+
+    /// not needed when the cranked round is the last one
+    #[account(
+        init,
+        payer = cranker,
+        space = AuctionRoundAccount::space(),
+        seeds = [
+        AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        params.next_round_index.as_ref(),
+        ],
+        bump,
+    )]
+    pub next_auction_round_config: Option<Box<Account<'info, AuctionRoundAccount>>>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_crank_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CrankRoundInputAccounts<'info>>,
+    params: &CrankRoundInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+    let auction_round_config: &Account<AuctionRoundAccount> = &ctx.accounts.auction_round_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_account_version(
+        ctx.accounts.auction_round_config.version,
+        AUCTION_ROUND_ACCOUNT_VERSION,
+    )?;
+
+    // permissionless, the back authority never co-signs so a round can't stall waiting on it
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    check_is_crank(auction_config.have_crank)?;
+
+    let current_round_index: u16 = params.round_index.clone().parse().unwrap();
+
+    check_current_round(auction_config.current_round, current_round_index)?;
+
+    check_is_auction_round_ended(auction_round_config.status.clone())?;
+
+    check_is_auction_ended_or_sold_out(auction_config.status.clone())?;
+
+    check_is_auction_round_still_have_time(auction_round_config.round_end_at, timestamp)?;
+
+    let is_last_round: bool = current_round_index == auction_config.tmax;
+
+    // the next round follows the schedule when there is one, otherwise the crank duration and
+    // the buy limit of the cranked round
+    let mut next_round: RoundSchedule = RoundSchedule {
+        duration: auction_config.crank_round_duration,
+        have_buy_limit: auction_round_config.have_buy_limit,
+        buy_limit: auction_round_config.buy_limit,
+        gap: 0,
+    };
+
+    if !is_last_round {
+        check_account_exist(ctx.accounts.next_auction_round_config.is_some())?;
+
+        let next_round_index: u16 = params.next_round_index.clone().parse().unwrap();
+
+        check_next_round(
+            auction_config.current_round.checked_add(1).unwrap(),
+            next_round_index,
+        )?;
+
+        if auction_config.have_round_schedule {
+            next_round =
+                auction_config.round_schedule[auction_config.current_round as usize].clone();
+
+            check_round_gap(
+                auction_round_config
+                    .round_end_at
+                    .checked_add(next_round.gap)
+                    .unwrap(),
+                timestamp,
+            )?;
+        };
+    };
+
+    let boost: i128 = calculate_boost(
+        auction_round_config.total_supply_sold,
+        auction_config
+            .total_supply
+            .checked_div(auction_config.tmax as u64)
+            .unwrap(),
+        auction_config.omega,
+        auction_config.alpha,
+        auction_config.time_shift_max,
+    );
+
+    // the vault stays rent exempt, the reward is skipped once the pool runs dry
+    let reward: u64 = auction_config.crank_reward.min(
+        ctx.accounts
+            .auction_vault_config
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(0)),
+    );
+
+    // Transfers
+
+    if reward > 0 {
+        // transfer crank reward to cranker
+        let auction_vault_config_bump_bytes = params.auction_vault_config_bump.to_le_bytes();
+        let auction_config_key: Pubkey = ctx.accounts.auction_config.key();
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            AUCTION_VAULT_PREFIX.as_ref(),
+            auction_config_key.as_ref(),
+            auction_vault_config_bump_bytes.as_ref(),
+        ]];
+
+        let transfer_reward_to_cranker_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.auction_vault_config.to_account_info(),
+                to: ctx.accounts.cranker.to_account_info(),
+            },
+            signer_seeds,
+        );
+
+        transfer(transfer_reward_to_cranker_cpi_ctx, reward)?;
+    };
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+    auction_config.boost_history.push(boost);
+    auction_config.total_crank_reward_paid = auction_config
+        .total_crank_reward_paid
+        .checked_add(reward)
+        .unwrap();
+
+    let auction_round_config: &mut Box<Account<AuctionRoundAccount>> =
+        &mut ctx.accounts.auction_round_config;
+    auction_round_config.last_block_timestamp = timestamp;
+    auction_round_config.status = AuctionRoundStatus::Ended;
+    auction_round_config.round_ended_at = timestamp;
+    auction_round_config.boost = boost;
+
+    // a scheduled pad nobody bought from is still marked as scheduled
+    if auction_config.status.eq(&AuctionStatus::Scheduled) {
+        auction_config.status = AuctionStatus::Started;
+    };
+
+    if is_last_round {
+        auction_config.status = AuctionStatus::Ended;
+
+        if auction_config.have_uniform_clearing_price {
+            auction_config.clearing_price = auction_config.current_price;
+        };
+
+        if auction_config.have_vesting {
+            auction_config.vesting_start_at = timestamp;
+        };
+    } else {
+        let current_price: u64 = calculate_price(
+            auction_config.p0,
+            auction_config.ptmax,
+            auction_config.tmax as u64,
+            auction_config.current_round as usize,
+            &auction_config.boost_history,
+            &auction_config.decay_model,
+            auction_config.time_shift_max,
+        );
+
+        auction_config.current_round = auction_config.current_round.checked_add(1).unwrap();
+        auction_config.current_price = current_price;
+
+        let next_auction_round_config: &mut Box<Account<AuctionRoundAccount>> =
+            ctx.accounts.next_auction_round_config.as_mut().unwrap();
+        next_auction_round_config.last_block_timestamp = timestamp;
+        next_auction_round_config.round_start_at = timestamp;
+        next_auction_round_config.round_end_at =
+            timestamp.checked_add(next_round.duration).unwrap();
+        next_auction_round_config.round = auction_config.current_round;
+        next_auction_round_config.price = current_price;
+        next_auction_round_config.boost = 0;
+        next_auction_round_config.have_buy_limit = next_round.have_buy_limit;
+        next_auction_round_config.buy_limit = next_round.buy_limit;
+        next_auction_round_config.rent_payer = ctx.accounts.cranker.key();
+        next_auction_round_config.version = AUCTION_ROUND_ACCOUNT_VERSION;
+    };
+
+    // Event
+    let event: CrankRoundEvent = CrankRoundEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        cranker: ctx.accounts.cranker.key(),
+        round_index: params.round_index.clone(),
+        boost,
+        next_round_index: if is_last_round {
+            String::new()
+        } else {
+            params.next_round_index.clone()
+        },
+        current_price: auction_config.current_price,
+        reward,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
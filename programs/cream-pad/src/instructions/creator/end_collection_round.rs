@@ -1,11 +1,15 @@
+use crate::math::calculate_boost;
 use crate::states::{
     AuctionRoundStatus, AuctionStatus, CollectionAuctionAccount, CollectionAuctionRoundAccount,
-    CreamPadAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    CreamPadAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ACCOUNT_VERSION,
+    COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
+    CREAM_PAD_ACCOUNT_PREFIX,
 };
 use crate::utils::{
-    calculate_boost, check_back_authority, check_current_round, check_is_auction_ended_or_sold_out,
-    check_is_auction_round_ended, check_is_auction_round_still_have_time, check_is_program_working,
-    check_program_id, check_round_ender, check_signer_exist, try_get_remaining_account_info,
+    check_account_version, check_back_authority, check_cream_pad_config, check_current_round,
+    check_is_auction_ended_or_sold_out, check_is_auction_round_ended,
+    check_is_auction_round_still_have_time, check_is_program_working, check_round_ender,
+    check_signer_exist,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -24,6 +28,8 @@ pub struct EndCollectionRoundInputParams {
     pub round_index: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub collection_auction_config_bump: u8,
 
     pub collection_auction_round_config_bump: u8,
@@ -34,6 +40,18 @@ pub struct EndCollectionRoundInputParams {
 pub struct EndCollectionRoundInputAccounts<'info> {
     pub ender: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -69,32 +87,36 @@ pub fn handle_end_collection_round<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
-
-    let collection_auction_config: &Box<Account<CollectionAuctionAccount>> =
+    let collection_auction_config: &Account<CollectionAuctionAccount> =
         &ctx.accounts.collection_auction_config;
-    let collection_auction_round_config: &Box<Account<CollectionAuctionRoundAccount>> =
+    let collection_auction_round_config: &Account<CollectionAuctionRoundAccount> =
         &ctx.accounts.collection_auction_round_config;
 
     // Checks
 
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_account_version(
+        ctx.accounts.collection_auction_round_config.version,
+        COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
-
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -103,7 +125,7 @@ pub fn handle_end_collection_round<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     check_round_ender(
@@ -125,7 +147,7 @@ pub fn handle_end_collection_round<'info>(
         timestamp,
     )?;
 
-    let boost: f64 = calculate_boost(
+    let boost: i128 = calculate_boost(
         collection_auction_round_config.total_supply_sold,
         collection_auction_config
             .total_supply
@@ -160,7 +182,7 @@ pub fn handle_end_collection_round<'info>(
         collection_mint: ctx.accounts.collection_mint_account.key(),
         pad_name: params.pad_name.clone(),
         round_index: params.round_index.clone(),
-        boost: boost,
+        boost,
     };
 
     emit!(event);
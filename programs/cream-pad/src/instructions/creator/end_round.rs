@@ -1,11 +1,14 @@
+use crate::math::calculate_boost;
 use crate::states::{
     AuctionAccount, AuctionRoundAccount, AuctionRoundStatus, AuctionStatus, CreamPadAccount,
-    AUCTION_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_PREFIX,
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, AUCTION_ROUND_ACCOUNT_PREFIX,
+    AUCTION_ROUND_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
 };
 use crate::utils::{
-    calculate_boost, check_back_authority, check_current_round, check_is_auction_ended_or_sold_out,
-    check_is_auction_round_ended, check_is_auction_round_still_have_time, check_is_program_working,
-    check_program_id, check_round_ender, check_signer_exist, try_get_remaining_account_info,
+    check_account_version, check_back_authority, check_cream_pad_config, check_current_round,
+    check_is_auction_ended_or_sold_out, check_is_auction_round_ended,
+    check_is_auction_round_still_have_time, check_is_program_working, check_round_ender,
+    check_signer_exist,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -24,6 +27,8 @@ pub struct EndRoundInputParams {
     pub round_index: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub auction_config_bump: u8,
 
     pub auction_round_config_bump: u8,
@@ -34,6 +39,18 @@ pub struct EndRoundInputParams {
 pub struct EndRoundInputAccounts<'info> {
     pub ender: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -69,31 +86,31 @@ pub fn handle_end_round<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let auction_config: &Box<Account<AuctionAccount>> = &ctx.accounts.auction_config;
-    let auction_round_config: &Box<Account<AuctionRoundAccount>> =
-        &ctx.accounts.auction_round_config;
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+    let auction_round_config: &Account<AuctionRoundAccount> = &ctx.accounts.auction_round_config;
 
     // Checks
 
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_account_version(
+        ctx.accounts.auction_round_config.version,
+        AUCTION_ROUND_ACCOUNT_VERSION,
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
-
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -102,7 +119,7 @@ pub fn handle_end_round<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     check_round_ender(
@@ -121,7 +138,7 @@ pub fn handle_end_round<'info>(
 
     check_is_auction_round_still_have_time(auction_round_config.round_end_at, timestamp)?;
 
-    let boost: f64 = calculate_boost(
+    let boost: i128 = calculate_boost(
         auction_round_config.total_supply_sold,
         auction_config
             .total_supply
@@ -136,8 +153,21 @@ pub fn handle_end_round<'info>(
     let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
     auction_config.last_block_timestamp = timestamp;
 
+    // a scheduled pad nobody bought from is still marked as scheduled
+    if auction_config.status.eq(&AuctionStatus::Scheduled) {
+        auction_config.status = AuctionStatus::Started;
+    };
+
     if current_round_index == auction_config.tmax {
         auction_config.status = AuctionStatus::Ended;
+
+        if auction_config.have_uniform_clearing_price {
+            auction_config.clearing_price = auction_config.current_price;
+        };
+
+        if auction_config.have_vesting {
+            auction_config.vesting_start_at = timestamp;
+        };
     };
 
     auction_config.boost_history.push(boost);
@@ -155,7 +185,7 @@ pub fn handle_end_round<'info>(
         mint: ctx.accounts.token_mint_account.key(),
         pad_name: params.pad_name.clone(),
         round_index: params.round_index.clone(),
-        boost: boost,
+        boost,
     };
 
     emit!(event);
@@ -1,10 +1,17 @@
-use crate::events::InitializeCollectionPadEvent;
+use crate::events::{CollectionRoundScheduleEvent, InitializeCollectionPadEvent};
+use crate::instructions::creator::FIRST_ROUND;
 use crate::states::{
-    AssetCreator, AuctionStatus, CollectionAuctionAccount,
-    CollectionAuctionRoundAccount, CreamPadAccount, DecayModelType, COLLECTION_AUCTION_ACCOUNT_PREFIX,
-    COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX,
+    AssetCreator, AuctionStatus, CollectionAuctionAccount, CollectionAuctionRoundAccount,
+    CreamPadAccount, DecayModelType, RoundSchedule, COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_VERSION, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_back_authority, check_creators_share, check_decay_model, check_is_program_working,
+    check_new_pads_not_paused, check_ptmax, check_referral, check_round_limit, check_round_schedule,
+    check_seller_fee_basis_points, check_supply_evenly_divisible, check_unique_creators,
+    check_value_is_zero, check_whitelist_rounds, get_payment_mint_fee_tiers,
 };
-use crate::utils::{check_back_authority, check_creators_share, check_is_program_working, check_ptmax, check_round_limit, check_seller_fee_basis_points, check_supply_evenly_divisible, check_unique_creators, check_value_is_zero, try_get_remaining_account_info};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::metadata::{
@@ -14,8 +21,6 @@ use anchor_spl::token_interface::{
     Mint, TokenInterface,
 };
 
-pub const FIRST_ROUND: &str = "1";
-
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct InitializeCollectionPadInputParams {
@@ -47,6 +52,23 @@ pub struct InitializeCollectionPadInputParams {
 
     pub buy_limit: u64,
 
+    pub have_whitelist: bool,
+
+    pub whitelist_merkle_root: [u8; 32],
+
+    pub whitelist_rounds: u16,
+
+    pub have_round_schedule: bool,
+
+    /// one entry per round, the first one matches `round_duration` and the buy limit
+    pub round_schedule: Vec<RoundSchedule>,
+
+    /// referrers earn `referral_base_point` of the creator's share of each referred buy, only on
+    /// pads paid in a single SPL mint without escrow
+    pub have_referral: bool,
+
+    pub referral_base_point: u16,
+
     pub seller_fee_basis_points: u16,
 
     pub asset_creators: Vec<AssetCreator>,
@@ -80,6 +102,7 @@ pub struct InitializeCollectionPadInputAccounts<'info> {
     #[account(
         seeds = [
         CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
         ],
         bump = params.cream_pad_config_bump,
     )]
@@ -113,6 +136,19 @@ pub struct InitializeCollectionPadInputAccounts<'info> {
 
     pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
 
+    /// CHECK: collection_metadata, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata: AccountInfo<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -130,14 +166,14 @@ pub fn handle_initialize_collection_pad<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let collection_metadata_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: &Box<Account<CreamPadAccount>> = &ctx.accounts.cream_pad_config;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
     // Checks
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
+    check_new_pads_not_paused(cream_pad_config.pause_flags.pause_new_pads)?;
+
     check_back_authority(
         cream_pad_config.back_authority,
         ctx.accounts.back_authority.key(),
@@ -160,8 +196,28 @@ pub fn handle_initialize_collection_pad<'info>(
 
     check_round_limit(cream_pad_config.round_limit, params.tmax)?;
 
+    if params.have_whitelist {
+        check_whitelist_rounds(params.whitelist_rounds, params.tmax)?;
+    };
+
+    if params.have_round_schedule {
+        check_round_schedule(
+            &params.round_schedule,
+            params.tmax,
+            params.round_duration,
+            params.have_buy_limit,
+            params.buy_limit,
+        )?;
+    };
+
+    if params.have_referral {
+        check_referral(params.referral_base_point, params.payment_mint, false, false)?;
+    };
+
     check_ptmax(params.p0, params.ptmax)?;
 
+    check_decay_model(&params.decay_model, params.p0, params.ptmax, params.tmax)?;
+
     if !params.asset_creators.is_empty() {
         check_unique_creators(&params.asset_creators)?;
 
@@ -175,7 +231,7 @@ pub fn handle_initialize_collection_pad<'info>(
     let update_collection_cpi_context = CpiContext::new(
         ctx.accounts.token_metadata_program.to_account_info(),
         UpdateMetadataAccountsV2 {
-            metadata: collection_metadata_account_info.to_account_info(),
+            metadata: ctx.accounts.collection_metadata.to_account_info(),
             update_authority: ctx.accounts.current_collection_update_authority.to_account_info(),
         }
     );
@@ -208,6 +264,24 @@ pub fn handle_initialize_collection_pad<'info>(
     collection_auction_config.current_round = 1;
     collection_auction_config.boost_history = Vec::with_capacity(params.tmax as usize);
     collection_auction_config.decay_model = params.decay_model.clone();
+    collection_auction_config.have_whitelist = params.have_whitelist;
+    collection_auction_config.whitelist_merkle_root = params.whitelist_merkle_root;
+    collection_auction_config.whitelist_rounds = params.whitelist_rounds;
+    collection_auction_config.have_round_schedule = params.have_round_schedule;
+    collection_auction_config.round_schedule = if params.have_round_schedule {
+        params.round_schedule.clone()
+    } else {
+        Vec::new()
+    };
+    collection_auction_config.have_referral = params.have_referral;
+    collection_auction_config.referral_base_point = params.referral_base_point;
+    // later config updates don't change the fees of a live pad
+    if cream_pad_config.is_fee_required {
+        collection_auction_config.fee_base_point = cream_pad_config.fee_base_point;
+        collection_auction_config.fee_tiers =
+            get_payment_mint_fee_tiers(&cream_pad_config.fee_tiers, params.payment_mint);
+    };
+    collection_auction_config.minting_fee = cream_pad_config.minting_fee;
     collection_auction_config.seller_fee_basis_points = params.seller_fee_basis_points;
     collection_auction_config.asset_creators = params.asset_creators.clone();
     collection_auction_config.starting_index = params.starting_index;
@@ -218,6 +292,8 @@ pub fn handle_initialize_collection_pad<'info>(
     collection_auction_config.asset_url = params.asset_url.clone();
     collection_auction_config.asset_url_suffix = params.asset_url_suffix.clone();
     collection_auction_config.have_collection_update_authority = true;
+    collection_auction_config.cream_pad_config = cream_pad_config.key();
+    collection_auction_config.version = COLLECTION_AUCTION_ACCOUNT_VERSION;
 
     let collection_auction_round_config: &mut Box<Account<CollectionAuctionRoundAccount>> = &mut ctx.accounts.collection_auction_round_config;
     collection_auction_round_config.last_block_timestamp = timestamp;
@@ -225,9 +301,10 @@ pub fn handle_initialize_collection_pad<'info>(
     collection_auction_round_config.round_end_at = timestamp.checked_add(params.round_duration).unwrap();
     collection_auction_round_config.round = 1;
     collection_auction_round_config.price = params.p0;
-    collection_auction_round_config.boost = 0.0;
+    collection_auction_round_config.boost = 0;
     collection_auction_round_config.have_buy_limit = params.have_buy_limit;
     collection_auction_round_config.buy_limit = params.buy_limit;
+    collection_auction_round_config.version = COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION;
 
     // // Event
     let event: InitializeCollectionPadEvent = InitializeCollectionPadEvent {
@@ -247,9 +324,27 @@ pub fn handle_initialize_collection_pad<'info>(
         buy_limit: params.buy_limit,
         starting_index: collection_auction_config.starting_index,
         ending_index: collection_auction_config.ending_index,
+        have_whitelist: params.have_whitelist,
+        whitelist_merkle_root: params.whitelist_merkle_root,
+        whitelist_rounds: params.whitelist_rounds,
+        have_referral: params.have_referral,
+        referral_base_point: params.referral_base_point,
+        fee_base_point: collection_auction_config.fee_base_point,
+        minting_fee: collection_auction_config.minting_fee,
     };
 
     emit!(event);
 
+    if params.have_round_schedule {
+        let round_schedule_event: CollectionRoundScheduleEvent = CollectionRoundScheduleEvent {
+            timestamp,
+            collection_mint: ctx.accounts.collection_mint_account.key(),
+            pad_name: params.pad_name.clone(),
+            round_schedule: params.round_schedule.clone(),
+        };
+
+        emit!(round_schedule_event);
+    };
+
     Ok(())
 }
@@ -1,11 +1,17 @@
-use crate::states::{AuctionAccount, AuctionRoundAccount, AuctionStatus, CreamPadAccount, DecayModelType, AUCTION_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX};
-use crate::utils::{adjust_amount, check_back_authority, check_is_program_working, check_ptmax, check_round_limit, check_value_is_zero};
+use crate::states::{AuctionAccount, AuctionRoundAccount, AuctionStatus, CreamPadAccount, DecayModelType, PaymentMint, RoundSchedule, AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, AUCTION_ROUND_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX};
+use crate::utils::{
+    adjust_amount, check_back_authority, check_decay_model, check_is_program_working,
+    check_native_payment_escrow, check_new_pads_not_paused, check_payment_mints, check_ptmax,
+    check_referral, check_round_limit, check_round_schedule, check_start_at,
+    check_uniform_clearing_price_escrow, check_value_is_zero, check_vesting_schedule,
+    check_whitelist_rounds, get_payment_mint_fee_tiers,
+};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token_interface::{
     transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
-use crate::events::InitializePadEvent;
+use crate::events::{InitializePadEvent, PaymentMintsEvent, RoundScheduleEvent};
 
 pub const FIRST_ROUND: &str = "1";
 
@@ -38,6 +44,53 @@ pub struct InitializePadInputParams {
 
     pub buy_limit: u64,
 
+    pub have_whitelist: bool,
+
+    pub whitelist_merkle_root: [u8; 32],
+
+    pub whitelist_rounds: u16,
+
+    pub have_payment_escrow: bool,
+
+    pub have_uniform_clearing_price: bool,
+
+    pub have_vesting: bool,
+
+    pub vesting_tge_base_point: u16,
+
+    pub vesting_cliff_duration: i64,
+
+    pub vesting_duration: i64,
+
+    pub have_crank: bool,
+
+    pub crank_round_duration: i64,
+
+    pub crank_reward: u64,
+
+    pub have_round_schedule: bool,
+
+    /// one entry per round, the first one matches `round_duration` and the buy limit
+    pub round_schedule: Vec<RoundSchedule>,
+
+    /// opens the first round at `start_at` instead of right away
+    pub have_start_at: bool,
+
+    pub start_at: i64,
+
+    pub payment_oracle_program: Pubkey,
+
+    pub payment_oracle_max_age: i64,
+
+    /// mints accepted besides `payment_mint`, their totals are ignored
+    pub payment_mints: Vec<PaymentMint>,
+
+    /// referrers earn `referral_base_point` of the creator's share of each referred buy, only on
+    /// pads paid in a single SPL mint without escrow
+    pub have_referral: bool,
+
+    pub referral_base_point: u16,
+
     pub pad_name: String,
 
     // Bumps
@@ -57,6 +110,7 @@ pub struct InitializePadInputAccounts<'info> {
     #[account(
         seeds = [
         CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
         ],
         bump = params.cream_pad_config_bump,
     )]
@@ -122,12 +176,14 @@ pub fn handle_initialize_pad(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config: &Box<Account<CreamPadAccount>> = &ctx.accounts.cream_pad_config;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
     // Checks
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
+    check_new_pads_not_paused(cream_pad_config.pause_flags.pause_new_pads)?;
+
     check_back_authority(
         cream_pad_config.back_authority,
         ctx.accounts.back_authority.key(),
@@ -148,8 +204,70 @@ pub fn handle_initialize_pad(
 
     check_round_limit(cream_pad_config.round_limit, params.tmax)?;
 
+    if params.have_whitelist {
+        check_whitelist_rounds(params.whitelist_rounds, params.tmax)?;
+    };
+
+    if params.have_round_schedule {
+        check_round_schedule(
+            &params.round_schedule,
+            params.tmax,
+            params.round_duration,
+            params.have_buy_limit,
+            params.buy_limit,
+        )?;
+    };
+
+    check_uniform_clearing_price_escrow(
+        params.have_uniform_clearing_price,
+        params.have_payment_escrow,
+    )?;
+
+    check_native_payment_escrow(params.payment_mint, params.have_payment_escrow)?;
+
+    check_payment_mints(
+        &params.payment_mints,
+        params.payment_mint,
+        params.have_payment_escrow,
+        params.payment_oracle_program,
+        params.payment_oracle_max_age,
+    )?;
+
+    if params.have_referral {
+        check_referral(
+            params.referral_base_point,
+            params.payment_mint,
+            params.have_payment_escrow,
+            !params.payment_mints.is_empty(),
+        )?;
+    };
+
+    if params.have_vesting {
+        check_vesting_schedule(
+            params.vesting_tge_base_point,
+            params.vesting_cliff_duration,
+            params.vesting_duration,
+        )?;
+    };
+
+    if params.have_crank {
+        check_value_is_zero(params.crank_round_duration as usize)?;
+    };
+
+    if params.have_start_at {
+        check_start_at(params.start_at, timestamp)?;
+    };
+
+    let start_at: i64 = if params.have_start_at {
+        params.start_at
+    } else {
+        timestamp
+    };
+
     check_ptmax(params.p0, params.ptmax)?;
 
+    check_decay_model(&params.decay_model, params.p0, params.ptmax, params.tmax)?;
+
     // Convert amount for transfer
     let adjusted_amount = adjust_amount(params.supply, 9, ctx.accounts.token_mint_account.decimals);
 
@@ -177,7 +295,11 @@ pub fn handle_initialize_pad(
     auction_config.mint = ctx.accounts.token_mint_account.key();
     auction_config.payment_mint = params.payment_mint;
     auction_config.payment_receiver = params.payment_receiver;
-    auction_config.status = AuctionStatus::Started;
+    auction_config.status = if params.have_start_at {
+        AuctionStatus::Scheduled
+    } else {
+        AuctionStatus::Started
+    };
     auction_config.p0 = params.p0;
     auction_config.ptmax = params.ptmax;
     auction_config.tmax = params.tmax;
@@ -189,16 +311,58 @@ pub fn handle_initialize_pad(
     auction_config.current_round = 1;
     auction_config.boost_history = Vec::with_capacity(params.tmax as usize);
     auction_config.decay_model = params.decay_model.clone();
+    auction_config.have_whitelist = params.have_whitelist;
+    auction_config.whitelist_merkle_root = params.whitelist_merkle_root;
+    auction_config.whitelist_rounds = params.whitelist_rounds;
+    auction_config.have_payment_escrow = params.have_payment_escrow;
+    auction_config.have_uniform_clearing_price = params.have_uniform_clearing_price;
+    auction_config.have_vesting = params.have_vesting;
+    auction_config.vesting_tge_base_point = params.vesting_tge_base_point;
+    auction_config.vesting_cliff_duration = params.vesting_cliff_duration;
+    auction_config.vesting_duration = params.vesting_duration;
+    auction_config.have_crank = params.have_crank;
+    auction_config.crank_round_duration = params.crank_round_duration;
+    auction_config.crank_reward = params.crank_reward;
+    auction_config.have_round_schedule = params.have_round_schedule;
+    auction_config.start_at = start_at;
+    auction_config.round_schedule = if params.have_round_schedule {
+        params.round_schedule.clone()
+    } else {
+        Vec::new()
+    };
+    auction_config.payment_oracle_program = params.payment_oracle_program;
+    auction_config.payment_oracle_max_age = params.payment_oracle_max_age;
+    auction_config.payment_mints = params
+        .payment_mints
+        .iter()
+        .map(|payment_mint| PaymentMint {
+            total_payment: 0,
+            total_fee: 0,
+            ..payment_mint.clone()
+        })
+        .collect();
+    auction_config.have_referral = params.have_referral;
+    auction_config.referral_base_point = params.referral_base_point;
+    // later config updates don't change the fee of a live pad
+    if cream_pad_config.is_fee_required {
+        auction_config.fee_base_point = cream_pad_config.fee_base_point;
+        auction_config.fee_tiers =
+            get_payment_mint_fee_tiers(&cream_pad_config.fee_tiers, params.payment_mint);
+    };
+    auction_config.cream_pad_config = cream_pad_config.key();
+    auction_config.version = AUCTION_ACCOUNT_VERSION;
 
     let auction_round_config: &mut Box<Account<AuctionRoundAccount>> = &mut ctx.accounts.auction_round_config;
     auction_round_config.last_block_timestamp = timestamp;
-    auction_round_config.round_start_at = timestamp;
-    auction_round_config.round_end_at = timestamp.checked_add(params.round_duration).unwrap();
+    auction_round_config.round_start_at = start_at;
+    auction_round_config.round_end_at = start_at.checked_add(params.round_duration).unwrap();
     auction_round_config.round = 1;
     auction_round_config.price = params.p0;
-    auction_round_config.boost = 0.0;
+    auction_round_config.boost = 0;
     auction_round_config.have_buy_limit = params.have_buy_limit;
     auction_round_config.buy_limit = params.buy_limit;
+    auction_round_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+    auction_round_config.version = AUCTION_ROUND_ACCOUNT_VERSION;
 
     // Event
     let event: InitializePadEvent = InitializePadEvent {
@@ -216,9 +380,49 @@ pub fn handle_initialize_pad(
         time_shift_max: params.time_shift_max,
         have_buy_limit: params.have_buy_limit,
         buy_limit: params.buy_limit,
+        have_whitelist: params.have_whitelist,
+        whitelist_merkle_root: params.whitelist_merkle_root,
+        whitelist_rounds: params.whitelist_rounds,
+        have_payment_escrow: params.have_payment_escrow,
+        have_uniform_clearing_price: params.have_uniform_clearing_price,
+        have_vesting: params.have_vesting,
+        vesting_tge_base_point: params.vesting_tge_base_point,
+        vesting_cliff_duration: params.vesting_cliff_duration,
+        vesting_duration: params.vesting_duration,
+        have_crank: params.have_crank,
+        crank_round_duration: params.crank_round_duration,
+        crank_reward: params.crank_reward,
+        start_at,
+        have_referral: params.have_referral,
+        referral_base_point: params.referral_base_point,
+        fee_base_point: auction_config.fee_base_point,
     };
 
     emit!(event);
 
+    if params.have_round_schedule {
+        let round_schedule_event: RoundScheduleEvent = RoundScheduleEvent {
+            timestamp,
+            mint: ctx.accounts.token_mint_account.key(),
+            pad_name: params.pad_name.clone(),
+            round_schedule: params.round_schedule.clone(),
+        };
+
+        emit!(round_schedule_event);
+    };
+
+    if !params.payment_mints.is_empty() {
+        let payment_mints_event: PaymentMintsEvent = PaymentMintsEvent {
+            timestamp,
+            mint: ctx.accounts.token_mint_account.key(),
+            pad_name: params.pad_name.clone(),
+            payment_oracle_program: params.payment_oracle_program,
+            payment_oracle_max_age: params.payment_oracle_max_age,
+            payment_mints: auction_config.payment_mints.clone(),
+        };
+
+        emit!(payment_mints_event);
+    };
+
     Ok(())
 }
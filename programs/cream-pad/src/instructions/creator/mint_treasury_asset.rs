@@ -1,24 +1,25 @@
 use crate::events::MintTreasuryAssetEvent;
-use crate::states::{CollectionAuctionAccount, CreamPadAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX};
+use crate::states::{
+    CollectionAuctionAccount, CreamPadAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
+};
 use crate::utils::{
-    check_back_authority, check_is_auction_is_locked, check_is_exceeding_end_index,
-    check_is_program_working, check_is_treasury_full, check_program_id, check_signer_exist,
-    check_treasury, try_get_remaining_account_info,
+    check_account_version, check_back_authority, check_collection_minting_not_paused,
+    check_cream_pad_config, check_is_auction_is_locked, check_is_exceeding_end_index,
+    check_is_program_working, check_is_treasury_full, check_signer_exist, check_treasury,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::sysvar::instructions::{
     load_current_index_checked, load_instruction_at_checked,
 };
-use anchor_spl::associated_token::{
-    create as associated_token_create, AssociatedToken, Create as AssociatedTokenCreate,
-};
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::metadata::{
     create_master_edition_v3, create_metadata_accounts_v3,
     mpl_token_metadata::types::{Collection, Creator, DataV2},
     verify_collection, CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata, VerifyCollection,
 };
-use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenInterface};
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
 
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -28,6 +29,8 @@ pub struct MintTreasuryAssetInputParams {
     pub asset_uuid: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub collection_auction_config_bump: u8,
 }
 
@@ -37,6 +40,18 @@ pub struct MintTreasuryAssetInputAccounts<'info> {
     #[account(mut)]
     pub fee_and_rent_payer: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -65,6 +80,72 @@ pub struct MintTreasuryAssetInputAccounts<'info> {
     )]
     pub asset_mint_account: Box<InterfaceAccount<'info, Mint>>,
 
+    /// CHECK: collection_metadata, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata: AccountInfo<'info>,
+
+    /// CHECK: collection_master_edition, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        collection_mint_account.key().as_ref(),
+        b"edition",
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_master_edition: AccountInfo<'info>,
+
+    /// CHECK: treasury, checked against the cream pad config
+    pub treasury: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = fee_and_rent_payer,
+        associated_token::mint = asset_mint_account,
+        associated_token::authority = treasury,
+        associated_token::token_program = token_program,
+    )]
+    pub treasury_asset_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: asset_metadata, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        asset_mint_account.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub asset_metadata: AccountInfo<'info>,
+
+    /// CHECK: asset_master_edition, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        asset_mint_account.key().as_ref(),
+        b"edition",
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub asset_master_edition: AccountInfo<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -86,29 +167,36 @@ pub fn handle_mint_treasury_asset<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let collection_auction_config: &Box<Account<CollectionAuctionAccount>> =
+    let collection_auction_config: &Account<CollectionAuctionAccount> =
         &ctx.accounts.collection_auction_config;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
     // Checks
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
-    if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
+    check_collection_minting_not_paused(
+        cream_pad_config.pause_flags.pause_collection_minting
+            || collection_auction_config
+                .pause_flags
+                .pause_collection_minting,
+    )?;
 
+    if cream_pad_config.is_back_authority_required {
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -117,26 +205,10 @@ pub fn handle_mint_treasury_asset<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
-    let collection_metadata_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 2)?;
-
-    let collection_master_edition_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 3)?;
-
-    let treasury_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 4)?;
-
-    let treasury_asset_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 5)?;
-
-    let asset_metadata_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 6)?;
-
-    let asset_master_edition_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 7)?;
-
-    check_treasury(cream_pad_config.treasury, treasury_account_info.key())?;
+    check_treasury(cream_pad_config.treasury, ctx.accounts.treasury.key())?;
 
     check_is_auction_is_locked(collection_auction_config.status.clone())?;
 
@@ -175,7 +247,7 @@ pub fn handle_mint_treasury_asset<'info>(
 
     let mut creators_option: Vec<Creator> = Vec::new();
 
-    if collection_auction_config.asset_creators.len() > 0 {
+    if !collection_auction_config.asset_creators.is_empty() {
         for creator in &collection_auction_config.asset_creators {
             creators_option.push(Creator {
                 verified: false,
@@ -192,7 +264,7 @@ pub fn handle_mint_treasury_asset<'info>(
         symbol: collection_auction_config.asset_symbol.clone(),
         uri: asset_url.clone(),
         seller_fee_basis_points: collection_auction_config.seller_fee_basis_points,
-        creators: creators,
+        creators,
         collection: Some(Collection {
             verified: false,
             key: ctx.accounts.collection_mint_account.key(),
@@ -200,23 +272,6 @@ pub fn handle_mint_treasury_asset<'info>(
         uses: None,
     };
 
-    // Create treasury asset token account
-    let create_treasury_asset_ata_cpi_accounts = AssociatedTokenCreate {
-        payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-        associated_token: treasury_asset_token_account_account_info.to_account_info(),
-        authority: treasury_account_info.to_account_info(),
-        mint: ctx.accounts.asset_mint_account.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-    };
-
-    let create_treasury_asset_ata_cpi_ctx = CpiContext::new(
-        ctx.accounts.associated_token_program.to_account_info(),
-        create_treasury_asset_ata_cpi_accounts,
-    );
-
-    associated_token_create(create_treasury_asset_ata_cpi_ctx)?;
-
     let collection_auction_config_bump_bytes = params.collection_auction_config_bump.to_le_bytes();
     let collection_mint_account_key = ctx.accounts.collection_mint_account.key();
 
@@ -232,7 +287,7 @@ pub fn handle_mint_treasury_asset<'info>(
         ctx.accounts.token_program.to_account_info(),
         MintTo {
             mint: ctx.accounts.asset_mint_account.to_account_info(),
-            to: treasury_asset_token_account_account_info.to_account_info(),
+            to: ctx.accounts.treasury_asset_token_account.to_account_info(),
             authority: ctx.accounts.collection_auction_config.to_account_info(),
         },
         signer_seeds,
@@ -243,7 +298,7 @@ pub fn handle_mint_treasury_asset<'info>(
     let create_metadata_accounts_v3_cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_metadata_program.to_account_info(),
         CreateMetadataAccountsV3 {
-            metadata: asset_metadata_account_info.to_account_info(), // the metadata account being created
+            metadata: ctx.accounts.asset_metadata.to_account_info(), // the metadata account being created
             mint: ctx.accounts.asset_mint_account.to_account_info(), // the mint account of the metadata account
             mint_authority: ctx.accounts.collection_auction_config.to_account_info(), // the mint authority of the mint account
             update_authority: ctx.accounts.collection_auction_config.to_account_info(), // the update authority of the metadata account
@@ -266,12 +321,12 @@ pub fn handle_mint_treasury_asset<'info>(
     let create_master_edition_v3_cpi_context = CpiContext::new_with_signer(
         ctx.accounts.token_metadata_program.to_account_info(),
         CreateMasterEditionV3 {
-            edition: asset_master_edition_account_info.to_account_info(),
+            edition: ctx.accounts.asset_master_edition.to_account_info(),
             mint: ctx.accounts.asset_mint_account.to_account_info(),
             update_authority: ctx.accounts.collection_auction_config.to_account_info(),
             mint_authority: ctx.accounts.collection_auction_config.to_account_info(),
             payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-            metadata: asset_metadata_account_info.to_account_info(),
+            metadata: ctx.accounts.asset_metadata.to_account_info(),
             token_program: ctx.accounts.token_program.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
             rent: ctx.accounts.rent.to_account_info(),
@@ -286,11 +341,11 @@ pub fn handle_mint_treasury_asset<'info>(
         ctx.accounts.token_metadata_program.to_account_info(),
         VerifyCollection {
             payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-            metadata: asset_metadata_account_info.to_account_info(),
+            metadata: ctx.accounts.asset_metadata.to_account_info(),
             collection_authority: ctx.accounts.collection_auction_config.to_account_info(),
             collection_mint: ctx.accounts.collection_mint_account.to_account_info(),
-            collection_metadata: collection_metadata_account_info.to_account_info(),
-            collection_master_edition: collection_master_edition_account_info.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
         },
         signer_seeds,
     );
@@ -12,6 +12,13 @@ pub use take_collection_update_authority::*;
 pub use give_collection_update_authority::*;
 pub use treasury_and_distribution::*;
 pub use mint_treasury_asset::*;
+pub use cancel_pad::*;
+pub use withdraw_cancelled_supply::*;
+pub use settle_pad::*;
+pub use crank_round::*;
+pub use fund_crank_reward::*;
+pub use withdraw_crank_reward::*;
+pub use close_round::*;
 
 pub mod initialize_pad;
 pub mod update_pad;
@@ -19,6 +26,13 @@ pub mod end_round;
 pub mod start_next_round;
 pub mod lock_and_distribute;
 pub mod unlock_unsold_supply;
+pub mod cancel_pad;
+pub mod withdraw_cancelled_supply;
+pub mod settle_pad;
+pub mod crank_round;
+pub mod fund_crank_reward;
+pub mod withdraw_crank_reward;
+pub mod close_round;
 
 pub mod initialize_collection_pad;
 pub mod update_collection_pad;
@@ -0,0 +1,360 @@
+use crate::states::{
+    AuctionAccount, AuctionRoundAccount, CreamPadAccount, AUCTION_ACCOUNT_PREFIX,
+    AUCTION_ACCOUNT_VERSION, AUCTION_ROUND_ACCOUNT_VERSION, AUCTION_VAULT_PREFIX,
+    CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    adjust_amount, calculate_clearing_fee_amount, calculate_round_rebate_reserve,
+    check_account_version, check_auction_round_account, check_auction_round_accounts_total,
+    check_back_authority, check_can_settle, check_cream_pad_config, check_is_payment_escrowed,
+    check_is_payment_settled, check_is_program_working, check_payment_fee_receiver,
+    check_payment_mint_account, check_payment_receiver, check_payment_settler,
+    check_rebate_reserve, check_signer_exist,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::events::SettlePadEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SettlePadInputParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub auction_vault_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SettlePadInputParams)]
+pub struct SettlePadInputAccounts<'info> {
+    pub settler: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    /// CHECK: auction_vault_config
+    #[account(
+        seeds = [
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        ],
+        bump = params.auction_vault_config_bump,
+    )]
+    pub auction_vault_config: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub payment_token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = auction_vault_config,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub auction_vault_config_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: payment_receiver, checked against the auction config
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = payment_receiver,
+        token::token_program = payment_token_program,
+    )]
+    pub payment_receiver_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: fee_receiver, checked against the cream pad config
+    pub fee_receiver: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = fee_receiver,
+        token::token_program = payment_token_program,
+    )]
+    pub fee_receiver_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_settle_pad<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SettlePadInputAccounts<'info>>,
+    params: &SettlePadInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_payment_settler(
+        auction_config.creator,
+        cream_pad_config.back_authority,
+        ctx.accounts.settler.key(),
+    )?;
+
+    check_is_payment_escrowed(auction_config.have_payment_escrow)?;
+
+    check_can_settle(auction_config.status.clone())?;
+
+    check_is_payment_settled(auction_config.is_payment_settled)?;
+
+    check_payment_mint_account(
+        auction_config.payment_mint,
+        ctx.accounts.payment_token_mint_account.key(),
+    )?;
+
+    check_payment_receiver(
+        auction_config.payment_receiver,
+        ctx.accounts.payment_receiver.key(),
+    )?;
+
+    check_payment_fee_receiver(
+        cream_pad_config.fee_receiver,
+        ctx.accounts.fee_receiver.key(),
+    )?;
+
+    // the vault balance is the source of truth, the fee is taken first and the rest goes to the receiver
+    let vault_amount: u64 = ctx
+        .accounts
+        .auction_vault_config_payment_token_account
+        .amount;
+
+    let fee_amount: u64;
+    let payment_amount: u64;
+    if auction_config.have_uniform_clearing_price {
+        // every round with a buy is passed in, what its buys paid above the clearing price stays
+        // in the vault for the rebates
+        let auction_config_key: Pubkey = auction_config.key();
+        let mut rebate_reserve: u64 = 0;
+        let mut rounds_supply_sold: u64 = 0;
+        let mut rounds_user_buy_count: u64 = 0;
+        let mut last_round: u16 = 0;
+
+        for account_info in ctx.remaining_accounts.iter() {
+            let auction_round_config: Account<AuctionRoundAccount> =
+                Account::try_from(account_info)?;
+
+            check_account_version(auction_round_config.version, AUCTION_ROUND_ACCOUNT_VERSION)?;
+
+            check_auction_round_account(
+                account_info.key(),
+                auction_config_key,
+                auction_round_config.round,
+                last_round,
+            )?;
+
+            rebate_reserve = rebate_reserve
+                .checked_add(calculate_round_rebate_reserve(
+                    auction_round_config.total_payment,
+                    auction_round_config.total_supply_sold,
+                    auction_round_config.total_user_buy_count,
+                    auction_round_config.price,
+                    auction_config.clearing_price,
+                ))
+                .unwrap();
+            rounds_supply_sold = rounds_supply_sold
+                .checked_add(auction_round_config.total_supply_sold)
+                .unwrap();
+            rounds_user_buy_count = rounds_user_buy_count
+                .checked_add(auction_round_config.total_user_buy_count)
+                .unwrap();
+            last_round = auction_round_config.round;
+        }
+
+        check_auction_round_accounts_total(
+            rounds_supply_sold,
+            auction_config.total_supply_sold,
+            rounds_user_buy_count,
+            auction_config.total_user_buy_count,
+        )?;
+
+        let rebate_reserve: u64 = adjust_amount(
+            rebate_reserve,
+            9,
+            ctx.accounts.payment_token_mint_account.decimals,
+        );
+
+        check_rebate_reserve(vault_amount, rebate_reserve)?;
+
+        let clearing_total_price: u64 = vault_amount.checked_sub(rebate_reserve).unwrap();
+
+        fee_amount = calculate_clearing_fee_amount(
+            clearing_total_price,
+            auction_config.total_fee,
+            auction_config.total_payment,
+        );
+
+        payment_amount = clearing_total_price.checked_sub(fee_amount).unwrap();
+    } else {
+        fee_amount = adjust_amount(
+            auction_config.total_fee,
+            9,
+            ctx.accounts.payment_token_mint_account.decimals,
+        )
+        .min(vault_amount);
+
+        payment_amount = vault_amount.checked_sub(fee_amount).unwrap();
+    };
+
+    // Transfers
+    let auction_vault_config_bump_bytes = params.auction_vault_config_bump.to_le_bytes();
+    let auction_config_key: Pubkey = ctx.accounts.auction_config.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config_key.as_ref(),
+        auction_vault_config_bump_bytes.as_ref(),
+    ]];
+
+    if fee_amount > 0 {
+        // transfer fee payment to fee receiver
+        let transfer_fee_payment_to_fee_receiver_cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .auction_vault_config_payment_token_account
+                .to_account_info(),
+            mint: ctx.accounts.payment_token_mint_account.to_account_info(),
+            to: ctx
+                .accounts
+                .fee_receiver_payment_token_account
+                .to_account_info(),
+            authority: ctx.accounts.auction_vault_config.to_account_info(),
+        };
+
+        let transfer_fee_payment_to_fee_receiver_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.payment_token_program.to_account_info(),
+            transfer_fee_payment_to_fee_receiver_cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(
+            transfer_fee_payment_to_fee_receiver_cpi_ctx,
+            fee_amount,
+            ctx.accounts.payment_token_mint_account.decimals,
+        )?;
+    };
+
+    if payment_amount > 0 {
+        // transfer payment to payment receiver
+        let transfer_payment_to_payment_receiver_cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .auction_vault_config_payment_token_account
+                .to_account_info(),
+            mint: ctx.accounts.payment_token_mint_account.to_account_info(),
+            to: ctx
+                .accounts
+                .payment_receiver_token_account
+                .to_account_info(),
+            authority: ctx.accounts.auction_vault_config.to_account_info(),
+        };
+
+        let transfer_payment_to_payment_receiver_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.payment_token_program.to_account_info(),
+            transfer_payment_to_payment_receiver_cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(
+            transfer_payment_to_payment_receiver_cpi_ctx,
+            payment_amount,
+            ctx.accounts.payment_token_mint_account.decimals,
+        )?;
+    };
+
+    let adjusted_back_payment_amount: u64 = adjust_amount(
+        payment_amount,
+        ctx.accounts.payment_token_mint_account.decimals,
+        9,
+    );
+    let adjusted_back_fee_amount: u64 = adjust_amount(
+        fee_amount,
+        ctx.accounts.payment_token_mint_account.decimals,
+        9,
+    );
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+    auction_config.is_payment_settled = true;
+    auction_config.payment_settled_at = timestamp;
+    auction_config.total_settled_payment = adjusted_back_payment_amount;
+    auction_config.total_settled_fee = adjusted_back_fee_amount;
+
+    // Event
+    let event: SettlePadEvent = SettlePadEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        payment: adjusted_back_payment_amount,
+        fee: adjusted_back_fee_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -1,12 +1,15 @@
+use crate::math::calculate_price;
 use crate::states::{
-    CollectionAuctionAccount, CollectionAuctionRoundAccount, CreamPadAccount,
-    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    CollectionAuctionAccount, CollectionAuctionRoundAccount, CreamPadAccount, RoundSchedule,
+    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ACCOUNT_VERSION,
+    COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
+    CREAM_PAD_ACCOUNT_PREFIX,
 };
 use crate::utils::{
-    calculate_price, check_back_authority, check_is_auction_ended_or_sold_out,
-    check_is_previous_auction_round_ended, check_is_program_working, check_next_round,
-    check_previous_round, check_program_id, check_round_starter, check_signer_exist,
-    check_value_is_zero, try_get_remaining_account_info,
+    check_account_version, check_back_authority, check_cream_pad_config,
+    check_is_auction_ended_or_sold_out, check_is_previous_auction_round_ended,
+    check_is_program_working, check_next_round, check_previous_round, check_round_gap,
+    check_round_starter, check_signer_exist, check_value_is_zero,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -33,6 +36,8 @@ pub struct StartNextCollectionRoundInputParams {
     pub next_buy_limit: u64,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub collection_auction_config_bump: u8,
 
     pub previous_collection_auction_round_config_bump: u8,
@@ -46,6 +51,18 @@ pub struct StartNextCollectionRoundInputAccounts<'info> {
 
     pub starter: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -98,31 +115,38 @@ pub fn handle_start_next_collection_round<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let collection_auction_config: &Box<Account<CollectionAuctionAccount>> =
+    let collection_auction_config: &Account<CollectionAuctionAccount> =
         &ctx.accounts.collection_auction_config;
-    let previous_collection_auction_round_config: &Box<Account<CollectionAuctionRoundAccount>> =
+    let previous_collection_auction_round_config: &Account<CollectionAuctionRoundAccount> =
         &ctx.accounts.previous_collection_auction_round_config;
 
     // Checks
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_account_version(
+        ctx.accounts
+            .previous_collection_auction_round_config
+            .version,
+        COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
-
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -131,7 +155,7 @@ pub fn handle_start_next_collection_round<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     check_round_starter(
@@ -161,8 +185,32 @@ pub fn handle_start_next_collection_round<'info>(
 
     check_is_auction_ended_or_sold_out(collection_auction_config.status.clone())?;
 
-    if params.next_have_buy_limit {
-        check_value_is_zero(params.next_buy_limit as usize)?;
+    // a scheduled pad ignores the round starter's parameters
+    let next_round: RoundSchedule = if collection_auction_config.have_round_schedule {
+        let next_round: RoundSchedule = collection_auction_config.round_schedule
+            [collection_auction_config.current_round as usize]
+            .clone();
+
+        check_round_gap(
+            previous_collection_auction_round_config
+                .round_end_at
+                .checked_add(next_round.gap)
+                .unwrap(),
+            timestamp,
+        )?;
+
+        next_round
+    } else {
+        if params.next_have_buy_limit {
+            check_value_is_zero(params.next_buy_limit as usize)?;
+        };
+
+        RoundSchedule {
+            duration: params.next_round_duration,
+            have_buy_limit: params.next_have_buy_limit,
+            buy_limit: params.next_buy_limit,
+            gap: 0,
+        }
     };
 
     let current_price = calculate_price(
@@ -171,7 +219,7 @@ pub fn handle_start_next_collection_round<'info>(
         collection_auction_config.tmax as u64,
         collection_auction_config.current_round as usize,
         &collection_auction_config.boost_history,
-        collection_auction_config.decay_model.clone(),
+        &collection_auction_config.decay_model,
         collection_auction_config.time_shift_max,
     );
 
@@ -190,12 +238,13 @@ pub fn handle_start_next_collection_round<'info>(
     next_collection_auction_round_config.last_block_timestamp = timestamp;
     next_collection_auction_round_config.round_start_at = timestamp;
     next_collection_auction_round_config.round_end_at =
-        timestamp.checked_add(params.next_round_duration).unwrap();
+        timestamp.checked_add(next_round.duration).unwrap();
     next_collection_auction_round_config.round = collection_auction_config.current_round;
     next_collection_auction_round_config.price = collection_auction_config.current_price;
-    next_collection_auction_round_config.boost = 0.0;
-    next_collection_auction_round_config.have_buy_limit = params.next_have_buy_limit;
-    next_collection_auction_round_config.buy_limit = params.next_buy_limit;
+    next_collection_auction_round_config.boost = 0;
+    next_collection_auction_round_config.have_buy_limit = next_round.have_buy_limit;
+    next_collection_auction_round_config.buy_limit = next_round.buy_limit;
+    next_collection_auction_round_config.version = COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION;
 
     // Event
     let event: StartCollectionRoundEvent = StartCollectionRoundEvent {
@@ -204,10 +253,10 @@ pub fn handle_start_next_collection_round<'info>(
         pad_name: params.pad_name.clone(),
         previous_round_index: params.previous_round_index.clone(),
         next_round_index: params.next_round_index.clone(),
-        next_round_duration: params.next_round_duration,
-        current_price: current_price,
-        next_have_buy_limit: params.next_have_buy_limit,
-        next_buy_limit: params.next_buy_limit,
+        next_round_duration: next_round.duration,
+        current_price,
+        next_have_buy_limit: next_round.have_buy_limit,
+        next_buy_limit: next_round.buy_limit,
     };
 
     emit!(event);
@@ -1,10 +1,10 @@
 use crate::events::TakeCollectionUpdateAuthorityEvent;
 use crate::states::{
     CollectionAuctionAccount, CreamPadAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX,
-    CREAM_PAD_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
 };
 use crate::utils::{
-    check_back_authority, check_is_program_working, try_get_remaining_account_info,
+    check_account_version, check_back_authority, check_cream_pad_config, check_is_program_working,
 };
 use anchor_lang::prelude::*;
 use anchor_spl::metadata::{update_metadata_accounts_v2, Metadata, UpdateMetadataAccountsV2};
@@ -31,6 +31,7 @@ pub struct TakeCollectionUpdateAuthorityInputAccounts<'info> {
     #[account(
         seeds = [
         CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
         ],
         bump = params.cream_pad_config_bump,
     )]
@@ -49,6 +50,19 @@ pub struct TakeCollectionUpdateAuthorityInputAccounts<'info> {
 
     pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
 
+    /// CHECK: collection_metadata, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata: AccountInfo<'info>,
+
     pub token_metadata_program: Program<'info, Metadata>,
 }
 
@@ -58,12 +72,19 @@ pub fn handle_take_collection_update_authority<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let collection_metadata_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: &Box<Account<CreamPadAccount>> = &ctx.accounts.cream_pad_config;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
     // Checks
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     check_back_authority(
@@ -76,7 +97,7 @@ pub fn handle_take_collection_update_authority<'info>(
     let update_collection_cpi_context = CpiContext::new(
         ctx.accounts.token_metadata_program.to_account_info(),
         UpdateMetadataAccountsV2 {
-            metadata: collection_metadata_account_info.to_account_info(),
+            metadata: ctx.accounts.collection_metadata.to_account_info(),
             update_authority: ctx
                 .accounts
                 .current_collection_update_authority
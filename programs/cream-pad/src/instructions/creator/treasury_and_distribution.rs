@@ -1,9 +1,10 @@
 use crate::states::{
     AuctionStatus, CollectionAuctionAccount, CreamPadAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
 };
 use crate::utils::{
-    check_back_authority, check_is_auction_ended, check_is_program_working, check_program_id,
-    check_signer_exist, check_supply_locker, try_get_remaining_account_info, BASE_POINT,
+    check_account_version, check_back_authority, check_cream_pad_config, check_is_auction_ended,
+    check_is_program_working, check_signer_exist, check_supply_locker, BASE_POINT,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -21,6 +22,8 @@ pub struct TreasuryAndDistributeInputParams {
     pub pad_name: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub collection_auction_config_bump: u8,
 }
 
@@ -29,6 +32,18 @@ pub struct TreasuryAndDistributeInputParams {
 pub struct TreasuryAndDistributeInputAccounts<'info> {
     pub supply_distributor: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -53,29 +68,29 @@ pub fn handle_treasury_and_distribute<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let collection_auction_config: &Box<Account<CollectionAuctionAccount>> =
+    let collection_auction_config: &Account<CollectionAuctionAccount> =
         &ctx.accounts.collection_auction_config;
 
     // Checks
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
-
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -84,7 +99,7 @@ pub fn handle_treasury_and_distribute<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     check_supply_locker(
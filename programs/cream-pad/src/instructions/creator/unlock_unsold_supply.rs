@@ -1,10 +1,11 @@
 use crate::states::{
-    AuctionAccount, AuctionStatus, CreamPadAccount, AUCTION_ACCOUNT_PREFIX, AUCTION_VAULT_PREFIX,
+    AuctionAccount, AuctionStatus, CreamPadAccount, AUCTION_ACCOUNT_PREFIX,
+    AUCTION_ACCOUNT_VERSION, AUCTION_VAULT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX,
 };
 use crate::utils::{
-    adjust_amount, check_back_authority, check_can_unlock, check_creator,
-    check_is_auction_is_locked, check_is_program_working, check_program_id, check_signer_exist,
-    try_get_remaining_account_info,
+    adjust_amount, check_account_version, check_back_authority, check_can_unlock,
+    check_cream_pad_config, check_creator, check_is_auction_is_locked, check_is_program_working,
+    check_signer_exist,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -24,6 +25,8 @@ pub struct UnlockUnsoldSupplyInputParams {
     pub pad_name: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub auction_config_bump: u8,
 
     pub auction_vault_config_bump: u8,
@@ -34,6 +37,18 @@ pub struct UnlockUnsoldSupplyInputParams {
 pub struct UnlockUnsoldSupplyInputAccounts<'info> {
     pub creator: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -88,28 +103,25 @@ pub fn handle_unlock_unsold_supply<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let auction_config: &Box<Account<AuctionAccount>> = &ctx.accounts.auction_config;
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
 
     // Checks
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
-
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -118,7 +130,7 @@ pub fn handle_unlock_unsold_supply<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     check_creator(auction_config.creator, ctx.accounts.creator.key())?;
@@ -1,7 +1,10 @@
-use crate::states::{AuctionAccount, CreamPadAccount, AUCTION_ACCOUNT_PREFIX};
+use crate::states::{
+    AuctionAccount, CreamPadAccount, AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION,
+    CREAM_PAD_ACCOUNT_PREFIX,
+};
 use crate::utils::{
-    check_back_authority, check_is_program_working, check_program_id, check_signer_exist,
-    try_get_remaining_account_info,
+    check_account_version, check_back_authority, check_cream_pad_config, check_is_program_working,
+    check_signer_exist,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -21,6 +24,8 @@ pub struct UpdatePadInputParams {
     pub pad_name: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub auction_config_bump: u8,
 }
 
@@ -29,6 +34,18 @@ pub struct UpdatePadInputParams {
 pub struct UpdatePadInputAccounts<'info> {
     pub creator: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -53,26 +70,22 @@ pub fn handle_update_pad<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
     // Checks
 
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
-
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -81,7 +94,7 @@ pub fn handle_update_pad<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     // Set Values
@@ -0,0 +1,178 @@
+use crate::states::{
+    AuctionAccount, CreamPadAccount, AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION,
+    CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    adjust_amount, check_account_version, check_back_authority, check_cream_pad_config,
+    check_creator, check_is_auction_cancelled, check_is_program_working, check_signer_exist,
+    check_value_is_zero,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::events::WithdrawCancelledSupplyEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawCancelledSupplyInputParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: WithdrawCancelledSupplyInputParams)]
+pub struct WithdrawCancelledSupplyInputAccounts<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = auction_config,
+        associated_token::token_program = token_program,
+    )]
+    pub auction_config_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = creator,
+        associated_token::token_program = token_program,
+    )]
+    pub creator_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_withdraw_cancelled_supply<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawCancelledSupplyInputAccounts<'info>>,
+    params: &WithdrawCancelledSupplyInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_creator(auction_config.creator, ctx.accounts.creator.key())?;
+
+    check_is_auction_cancelled(auction_config.status.clone())?;
+
+    // unsold supply plus whatever refunded buyers have returned so far
+    let withdraw_amount: u64 = ctx.accounts.auction_config_token_account.amount;
+
+    check_value_is_zero(withdraw_amount as usize)?;
+
+    // transfer token to creator
+    let auction_config_bump_bytes = params.auction_config_bump.to_le_bytes();
+    let token_mint_account_key = ctx.accounts.token_mint_account.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account_key.as_ref(),
+        auction_config_bump_bytes.as_ref(),
+    ]];
+
+    let transfer_token_to_creator_cpi_accounts = TransferChecked {
+        from: ctx.accounts.auction_config_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint_account.to_account_info(),
+        to: ctx.accounts.creator_token_account.to_account_info(),
+        authority: ctx.accounts.auction_config.to_account_info(),
+    };
+
+    let transfer_token_to_creator_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_token_to_creator_cpi_accounts,
+        signer_seeds,
+    );
+
+    transfer_checked(
+        transfer_token_to_creator_cpi_ctx,
+        withdraw_amount,
+        ctx.accounts.token_mint_account.decimals,
+    )?;
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+
+    // Event
+    let event: WithdrawCancelledSupplyEvent = WithdrawCancelledSupplyEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        amount: adjust_amount(withdraw_amount, ctx.accounts.token_mint_account.decimals, 9),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
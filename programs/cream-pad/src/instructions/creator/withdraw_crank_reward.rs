@@ -0,0 +1,172 @@
+use crate::states::{
+    AuctionAccount, CreamPadAccount, AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION,
+    AUCTION_VAULT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_version, check_back_authority, check_cream_pad_config, check_creator,
+    check_is_auction_running, check_is_crank, check_is_program_working, check_signer_exist,
+    check_value_is_zero,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::Mint;
+
+use crate::events::WithdrawCrankRewardEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WithdrawCrankRewardInputParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub auction_vault_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: WithdrawCrankRewardInputParams)]
+pub struct WithdrawCrankRewardInputAccounts<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    /// CHECK: auction_vault_config, holds the crank reward lamports
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        ],
+        bump = params.auction_vault_config_bump,
+    )]
+    pub auction_vault_config: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_withdraw_crank_reward<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawCrankRewardInputAccounts<'info>>,
+    params: &WithdrawCrankRewardInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_creator(auction_config.creator, ctx.accounts.creator.key())?;
+
+    check_is_crank(auction_config.have_crank)?;
+
+    // no round is left to crank once the pad ended, sold out or was cancelled
+    check_is_auction_running(auction_config.status.clone())?;
+
+    // the vault stays rent exempt, like after a crank
+    let amount: u64 = ctx
+        .accounts
+        .auction_vault_config
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(0));
+
+    check_value_is_zero(amount as usize)?;
+
+    // Transfers
+
+    // transfer the remaining reward lamports back to the creator
+    let auction_vault_config_bump_bytes = params.auction_vault_config_bump.to_le_bytes();
+    let auction_config_key: Pubkey = ctx.accounts.auction_config.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config_key.as_ref(),
+        auction_vault_config_bump_bytes.as_ref(),
+    ]];
+
+    let transfer_reward_to_creator_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.system_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.auction_vault_config.to_account_info(),
+            to: ctx.accounts.creator.to_account_info(),
+        },
+        signer_seeds,
+    );
+
+    transfer(transfer_reward_to_creator_cpi_ctx, amount)?;
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+
+    // Event
+    let event: WithdrawCrankRewardEvent = WithdrawCrankRewardEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
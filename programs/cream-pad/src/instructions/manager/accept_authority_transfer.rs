@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+
+use crate::events::AcceptAuthorityTransferEvent;
+use crate::states::{AuthorityType, CreamPadAccount, CREAM_PAD_ACCOUNT_PREFIX};
+use crate::utils::{check_is_not_governed, check_pending_authority};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AcceptAuthorityTransferInputParams {
+    pub authority_type: AuthorityType,
+
+    // Bump
+    pub cream_pad_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: AcceptAuthorityTransferInputParams)]
+pub struct AcceptAuthorityTransferInputAccounts<'info> {
+    pub new_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+}
+
+pub fn handle_accept_authority_transfer(
+    ctx: Context<AcceptAuthorityTransferInputAccounts>,
+    params: &AcceptAuthorityTransferInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    // Checks
+    check_is_not_governed(cream_pad_config.have_governance)?;
+
+    let pending_authority: Pubkey = match params.authority_type {
+        AuthorityType::SigningAuthority => cream_pad_config.pending_signing_authority,
+        AuthorityType::BackAuthority => cream_pad_config.pending_back_authority,
+    };
+
+    check_pending_authority(pending_authority, ctx.accounts.new_authority.key())?;
+
+    // Set Values
+    let cream_pad_config: &mut Box<Account<CreamPadAccount>> = &mut ctx.accounts.cream_pad_config;
+    cream_pad_config.last_block_timestamp = timestamp;
+
+    let previous_authority: Pubkey = match params.authority_type {
+        AuthorityType::SigningAuthority => {
+            let previous_authority: Pubkey = cream_pad_config.signing_authority;
+            cream_pad_config.signing_authority = pending_authority;
+            cream_pad_config.pending_signing_authority = Pubkey::default();
+            previous_authority
+        }
+        AuthorityType::BackAuthority => {
+            let previous_authority: Pubkey = cream_pad_config.back_authority;
+            cream_pad_config.back_authority = pending_authority;
+            cream_pad_config.pending_back_authority = Pubkey::default();
+            previous_authority
+        }
+    };
+
+    // Event
+    let event: AcceptAuthorityTransferEvent = AcceptAuthorityTransferEvent {
+        timestamp,
+        authority_type: params.authority_type.clone(),
+        previous_authority,
+        new_authority: pending_authority,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
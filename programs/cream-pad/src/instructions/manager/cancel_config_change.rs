@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::events::CancelConfigChangeEvent;
+use crate::states::{
+    ConfigChangeStatus, CreamPadAccount, PendingConfigChangeAccount, CREAM_PAD_ACCOUNT_PREFIX,
+    PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX,
+};
+use crate::utils::{check_governance_signer, check_is_config_change_pending, check_is_governed};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CancelConfigChangeInputParams {
+    pub config_change_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub pending_config_change_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CancelConfigChangeInputParams)]
+pub struct CancelConfigChangeInputAccounts<'info> {
+    pub governance_signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.key().as_ref(),
+        params.config_change_index.as_ref(),
+        ],
+        bump = params.pending_config_change_bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChangeAccount>>,
+}
+
+pub fn handle_cancel_config_change(
+    ctx: Context<CancelConfigChangeInputAccounts>,
+    _params: &CancelConfigChangeInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    // Checks
+    check_is_governed(cream_pad_config.have_governance)?;
+
+    check_governance_signer(
+        &cream_pad_config.governance_signers,
+        ctx.accounts.governance_signer.key(),
+    )?;
+
+    check_is_config_change_pending(ctx.accounts.pending_config_change.status.clone())?;
+
+    // Set Values
+    let pending_config_change: &mut Box<Account<PendingConfigChangeAccount>> =
+        &mut ctx.accounts.pending_config_change;
+    pending_config_change.last_block_timestamp = timestamp;
+    pending_config_change.status = ConfigChangeStatus::Cancelled;
+
+    // Event
+    let event: CancelConfigChangeEvent = CancelConfigChangeEvent {
+        timestamp,
+        governance_signer: ctx.accounts.governance_signer.key(),
+        config_change_index: pending_config_change.index,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
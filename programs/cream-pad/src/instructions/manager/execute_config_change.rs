@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ExecuteConfigChangeEvent;
+use crate::states::{
+    ConfigChangeStatus, CreamPadAccount, PendingConfigChangeAccount, CREAM_PAD_ACCOUNT_PREFIX,
+    PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    apply_config_change, check_config_change_approvals, check_config_change_timelock,
+    check_governance_signer, check_is_config_change_pending, check_is_governed,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ExecuteConfigChangeInputParams {
+    pub config_change_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub pending_config_change_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ExecuteConfigChangeInputParams)]
+pub struct ExecuteConfigChangeInputAccounts<'info> {
+    pub governance_signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.key().as_ref(),
+        params.config_change_index.as_ref(),
+        ],
+        bump = params.pending_config_change_bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChangeAccount>>,
+}
+
+pub fn handle_execute_config_change(
+    ctx: Context<ExecuteConfigChangeInputAccounts>,
+    _params: &ExecuteConfigChangeInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let pending_config_change: &Account<PendingConfigChangeAccount> =
+        &ctx.accounts.pending_config_change;
+
+    // Checks
+    check_is_governed(cream_pad_config.have_governance)?;
+
+    check_governance_signer(
+        &cream_pad_config.governance_signers,
+        ctx.accounts.governance_signer.key(),
+    )?;
+
+    check_is_config_change_pending(pending_config_change.status.clone())?;
+
+    // approvals of signers removed since the proposal don't count
+    let approval_count: usize = pending_config_change
+        .approvals
+        .iter()
+        .filter(|approval| cream_pad_config.governance_signers.contains(approval))
+        .count();
+
+    check_config_change_approvals(approval_count, cream_pad_config.governance_threshold)?;
+
+    check_config_change_timelock(pending_config_change.executable_at, timestamp)?;
+
+    // Set Values
+    let pending_config_change: &mut Box<Account<PendingConfigChangeAccount>> =
+        &mut ctx.accounts.pending_config_change;
+    pending_config_change.last_block_timestamp = timestamp;
+    pending_config_change.status = ConfigChangeStatus::Executed;
+
+    let cream_pad_config: &mut Box<Account<CreamPadAccount>> = &mut ctx.accounts.cream_pad_config;
+    cream_pad_config.last_block_timestamp = timestamp;
+    apply_config_change(cream_pad_config, &pending_config_change.change);
+
+    // Event
+    let event: ExecuteConfigChangeEvent = ExecuteConfigChangeEvent {
+        timestamp,
+        governance_signer: ctx.accounts.governance_signer.key(),
+        config_change_index: pending_config_change.index,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
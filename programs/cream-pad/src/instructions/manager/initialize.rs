@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::states::{CreamPadAccount, ProgramStatus, CREAM_PAD_ACCOUNT_PREFIX};
+use crate::states::{
+    CreamPadAccount, FeeTier, ProgramStatus, CREAM_PAD_ACCOUNT_PREFIX, CREAM_PAD_ACCOUNT_VERSION,
+};
 use crate::utils::{
-    check_distribution_and_lock_base_point, check_fee_base_point, check_value_is_zero,
+    check_distribution_and_lock_base_point, check_fee_base_point, check_fee_tiers, check_tenant_id,
+    check_value_is_zero,
 };
 
 #[repr(C)]
@@ -28,7 +31,13 @@ pub struct InitializeInputParams {
 
     pub minting_fee: u64,
 
-    pub treasury: Pubkey
+    pub treasury: Pubkey,
+
+    /// ascending by `min_volume`, replaces `fee_base_point` once a pad's volume reaches a tier
+    pub fee_tiers: Vec<FeeTier>,
+
+    /// namespaces the config PDA, empty for the default deployment
+    pub tenant_id: String,
 }
 
 #[derive(Accounts)]
@@ -45,6 +54,7 @@ pub struct InitializeInputAccounts<'info> {
         space = CreamPadAccount::space(),
         seeds = [
         CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        params.tenant_id.as_ref(),
         ],
         bump,
     )]
@@ -69,6 +79,8 @@ pub fn handle_initialize(
     check_value_is_zero(params.lock_duration as usize)?;
     check_value_is_zero(params.minting_fee as usize)?;
     check_fee_base_point(params.fee_base_point)?;
+    check_fee_tiers(&params.fee_tiers)?;
+    check_tenant_id(&params.tenant_id)?;
     check_distribution_and_lock_base_point(
         params
             .distribution_base_point
@@ -92,6 +104,9 @@ pub fn handle_initialize(
     cream_pad_config.lock_duration = params.lock_duration;
     cream_pad_config.minting_fee = params.minting_fee;
     cream_pad_config.treasury = params.treasury;
+    cream_pad_config.fee_tiers = params.fee_tiers.clone();
+    cream_pad_config.tenant_id = params.tenant_id.clone();
+    cream_pad_config.version = CREAM_PAD_ACCOUNT_VERSION;
 
     Ok(())
 }
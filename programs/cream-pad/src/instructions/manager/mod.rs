@@ -1,5 +1,27 @@
+pub use accept_authority_transfer::*;
+pub use approve_config_change::*;
+pub use cancel_config_change::*;
+pub use execute_config_change::*;
 pub use initialize::*;
+pub use propose_authority_transfer::*;
+pub use propose_config_change::*;
+pub use set_collection_pad_fee::*;
+pub use set_collection_pad_pause_flags::*;
+pub use set_pad_fee::*;
+pub use set_pad_pause_flags::*;
+pub use set_pause_flags::*;
 pub use update_config::*;
 
+pub mod accept_authority_transfer;
+pub mod approve_config_change;
+pub mod cancel_config_change;
+pub mod execute_config_change;
 pub mod initialize;
+pub mod propose_authority_transfer;
+pub mod propose_config_change;
+pub mod set_collection_pad_fee;
+pub mod set_collection_pad_pause_flags;
+pub mod set_pad_fee;
+pub mod set_pad_pause_flags;
+pub mod set_pause_flags;
 pub mod update_config;
\ No newline at end of file
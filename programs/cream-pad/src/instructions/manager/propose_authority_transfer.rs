@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ProposeAuthorityTransferEvent;
+use crate::states::{AuthorityType, CreamPadAccount, CREAM_PAD_ACCOUNT_PREFIX};
+use crate::utils::{check_is_not_governed, check_signing_authority};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposeAuthorityTransferInputParams {
+    pub authority_type: AuthorityType,
+
+    /// has to accept before taking over, the default pubkey withdraws a pending proposal
+    pub new_authority: Pubkey,
+
+    // Bump
+    pub cream_pad_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ProposeAuthorityTransferInputParams)]
+pub struct ProposeAuthorityTransferInputAccounts<'info> {
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+}
+
+pub fn handle_propose_authority_transfer(
+    ctx: Context<ProposeAuthorityTransferInputAccounts>,
+    params: &ProposeAuthorityTransferInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    // Checks
+    check_signing_authority(
+        cream_pad_config.signing_authority,
+        ctx.accounts.signing_authority.key(),
+    )?;
+
+    // a governed config rotates its authorities through the governance signers only
+    check_is_not_governed(cream_pad_config.have_governance)?;
+
+    // Set Values
+    let cream_pad_config: &mut Box<Account<CreamPadAccount>> = &mut ctx.accounts.cream_pad_config;
+    cream_pad_config.last_block_timestamp = timestamp;
+
+    let current_authority: Pubkey = match params.authority_type {
+        AuthorityType::SigningAuthority => {
+            cream_pad_config.pending_signing_authority = params.new_authority;
+            cream_pad_config.signing_authority
+        }
+        AuthorityType::BackAuthority => {
+            cream_pad_config.pending_back_authority = params.new_authority;
+            cream_pad_config.back_authority
+        }
+    };
+
+    // Event
+    let event: ProposeAuthorityTransferEvent = ProposeAuthorityTransferEvent {
+        timestamp,
+        authority_type: params.authority_type.clone(),
+        current_authority,
+        pending_authority: params.new_authority,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ProposeConfigChangeEvent;
+use crate::states::{
+    ConfigChange, ConfigChangeStatus, CreamPadAccount, PendingConfigChangeAccount,
+    CREAM_PAD_ACCOUNT_PREFIX, PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX,
+    PENDING_CONFIG_CHANGE_ACCOUNT_VERSION,
+};
+use crate::utils::{
+    check_config_change, check_config_change_index, check_governance_signer, check_is_governed,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProposeConfigChangeInputParams {
+    pub change: ConfigChange,
+
+    /// `total_config_change_count` + 1
+    pub config_change_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ProposeConfigChangeInputParams)]
+pub struct ProposeConfigChangeInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    pub proposer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    #[account(
+        init,
+        payer = fee_and_rent_payer,
+        space = PendingConfigChangeAccount::space(),
+        seeds = [
+        PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.key().as_ref(),
+        params.config_change_index.as_ref(),
+        ],
+        bump,
+    )]
+    pub pending_config_change: Box<Account<'info, PendingConfigChangeAccount>>,
+
+    pub system_program: Program<'info, System>,
+
+    pub rent: Sysvar<'info, Rent>,
+}
+
+pub fn handle_propose_config_change(
+    ctx: Context<ProposeConfigChangeInputAccounts>,
+    params: &ProposeConfigChangeInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    // Checks
+    check_is_governed(cream_pad_config.have_governance)?;
+
+    check_governance_signer(
+        &cream_pad_config.governance_signers,
+        ctx.accounts.proposer.key(),
+    )?;
+
+    let config_change_index: u64 = params.config_change_index.clone().parse().unwrap();
+
+    check_config_change_index(
+        cream_pad_config
+            .total_config_change_count
+            .checked_add(1)
+            .unwrap(),
+        config_change_index,
+    )?;
+
+    check_config_change(&params.change)?;
+
+    let executable_at: i64 = timestamp
+        .checked_add(cream_pad_config.governance_timelock)
+        .unwrap();
+
+    // Set Values
+    let pending_config_change: &mut Box<Account<PendingConfigChangeAccount>> =
+        &mut ctx.accounts.pending_config_change;
+    pending_config_change.last_block_timestamp = timestamp;
+    pending_config_change.index = config_change_index;
+    pending_config_change.version = PENDING_CONFIG_CHANGE_ACCOUNT_VERSION;
+    pending_config_change.proposer = ctx.accounts.proposer.key();
+    pending_config_change.change = params.change.clone();
+    pending_config_change.approvals = vec![ctx.accounts.proposer.key()];
+    pending_config_change.status = ConfigChangeStatus::Pending;
+    pending_config_change.proposed_at = timestamp;
+    pending_config_change.executable_at = executable_at;
+
+    let cream_pad_config: &mut Box<Account<CreamPadAccount>> = &mut ctx.accounts.cream_pad_config;
+    cream_pad_config.last_block_timestamp = timestamp;
+    cream_pad_config.total_config_change_count = config_change_index;
+
+    // Event
+    let event: ProposeConfigChangeEvent = ProposeConfigChangeEvent {
+        timestamp,
+        proposer: ctx.accounts.proposer.key(),
+        config_change_index,
+        change: params.change.clone(),
+        executable_at,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
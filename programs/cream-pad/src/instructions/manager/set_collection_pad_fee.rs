@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::events::SetCollectionPadFeeEvent;
+use crate::states::{
+    CollectionAuctionAccount, CreamPadAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_version, check_cream_pad_config, check_fee_base_point, check_signing_authority,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetCollectionPadFeeInputParams {
+    /// false goes back to the fees snapshotted at pad creation
+    pub have_fee_override: bool,
+
+    pub fee_override_base_point: u16,
+
+    pub fee_override_minting_fee: u64,
+
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub collection_auction_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetCollectionPadFeeInputParams)]
+pub struct SetCollectionPadFeeInputAccounts<'info> {
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        COLLECTION_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump = params.collection_auction_config_bump,
+    )]
+    pub collection_auction_config: Box<Account<'info, CollectionAuctionAccount>>,
+
+    pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn handle_set_collection_pad_fee(
+    ctx: Context<SetCollectionPadFeeInputAccounts>,
+    params: &SetCollectionPadFeeInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    // Checks
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_signing_authority(
+        cream_pad_config.signing_authority,
+        ctx.accounts.signing_authority.key(),
+    )?;
+
+    if params.have_fee_override {
+        check_fee_base_point(params.fee_override_base_point)?;
+    };
+
+    // Set Values
+    let collection_auction_config: &mut Box<Account<CollectionAuctionAccount>> =
+        &mut ctx.accounts.collection_auction_config;
+    collection_auction_config.last_block_timestamp = timestamp;
+    collection_auction_config.have_fee_override = params.have_fee_override;
+    collection_auction_config.fee_override_base_point = params.fee_override_base_point;
+    collection_auction_config.fee_override_minting_fee = params.fee_override_minting_fee;
+
+    // Event
+    let event: SetCollectionPadFeeEvent = SetCollectionPadFeeEvent {
+        timestamp,
+        collection_mint: ctx.accounts.collection_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        have_fee_override: params.have_fee_override,
+        fee_override_base_point: params.fee_override_base_point,
+        fee_override_minting_fee: params.fee_override_minting_fee,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
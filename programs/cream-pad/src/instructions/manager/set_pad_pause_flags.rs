@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::Mint;
+
+use crate::events::SetPadPauseFlagsEvent;
+use crate::states::{
+    AuctionAccount, CreamPadAccount, PauseFlags, AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION,
+    CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{check_account_version, check_cream_pad_config, check_signing_authority};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetPadPauseFlagsInputParams {
+    pub pause_flags: PauseFlags,
+
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetPadPauseFlagsInputParams)]
+pub struct SetPadPauseFlagsInputAccounts<'info> {
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+}
+
+pub fn handle_set_pad_pause_flags(
+    ctx: Context<SetPadPauseFlagsInputAccounts>,
+    params: &SetPadPauseFlagsInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    // Checks
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_signing_authority(
+        cream_pad_config.signing_authority,
+        ctx.accounts.signing_authority.key(),
+    )?;
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+    auction_config.pause_flags = params.pause_flags.clone();
+
+    // Event
+    let event: SetPadPauseFlagsEvent = SetPadPauseFlagsEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        pause_flags: params.pause_flags.clone(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::events::SetPauseFlagsEvent;
+use crate::states::{CreamPadAccount, PauseFlags, CREAM_PAD_ACCOUNT_PREFIX};
+use crate::utils::check_signing_authority;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetPauseFlagsInputParams {
+    pub pause_flags: PauseFlags,
+
+    // Bump
+    pub cream_pad_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: SetPauseFlagsInputParams)]
+pub struct SetPauseFlagsInputAccounts<'info> {
+    pub signing_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+}
+
+pub fn handle_set_pause_flags(
+    ctx: Context<SetPauseFlagsInputAccounts>,
+    params: &SetPauseFlagsInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    // Checks
+    check_signing_authority(
+        ctx.accounts.cream_pad_config.signing_authority,
+        ctx.accounts.signing_authority.key(),
+    )?;
+
+    // Set Values
+    let cream_pad_config: &mut Box<Account<CreamPadAccount>> = &mut ctx.accounts.cream_pad_config;
+    cream_pad_config.last_block_timestamp = timestamp;
+    cream_pad_config.pause_flags = params.pause_flags.clone();
+
+    // Event
+    let event: SetPauseFlagsEvent = SetPauseFlagsEvent {
+        timestamp,
+        pause_flags: params.pause_flags.clone(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -1,16 +1,15 @@
 use anchor_lang::prelude::*;
 
-use crate::states::{CreamPadAccount, ProgramStatus, CREAM_PAD_ACCOUNT_PREFIX};
+use crate::states::{
+    ConfigChange, CreamPadAccount, FeeTier, ProgramStatus, CREAM_PAD_ACCOUNT_PREFIX,
+};
 use crate::utils::{
-    check_distribution_and_lock_base_point, check_fee_base_point, check_signing_authority,
-    check_value_is_zero,
+    apply_config_change, check_config_change, check_is_not_governed, check_signing_authority,
 };
 
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct UpdateConfigInputParams {
-    pub back_authority: Pubkey,
-
     pub is_back_authority_required: bool,
 
     pub is_fee_required: bool,
@@ -33,6 +32,18 @@ pub struct UpdateConfigInputParams {
 
     pub treasury: Pubkey,
 
+    /// only pads created afterwards pick the new fees and tiers up
+    pub fee_tiers: Vec<FeeTier>,
+
+    /// once enabled, later changes go through `propose_config_change`
+    pub have_governance: bool,
+
+    pub governance_signers: Vec<Pubkey>,
+
+    pub governance_threshold: u8,
+
+    pub governance_timelock: i64,
+
     // Bump
     pub cream_pad_config_bump: u8,
 }
@@ -46,6 +57,7 @@ pub struct UpdateConfigInputAccounts<'info> {
         mut,
         seeds = [
         CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
         ],
         bump = params.cream_pad_config_bump,
     )]
@@ -58,7 +70,26 @@ pub fn handle_update_config(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config: &Box<Account<CreamPadAccount>> = &ctx.accounts.cream_pad_config;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let change: ConfigChange = ConfigChange {
+        is_back_authority_required: params.is_back_authority_required,
+        is_fee_required: params.is_fee_required,
+        fee_base_point: params.fee_base_point,
+        fee_receiver: params.fee_receiver,
+        round_limit: params.round_limit,
+        program_status: params.program_status.clone(),
+        distribution_base_point: params.distribution_base_point,
+        lock_base_point: params.lock_base_point,
+        lock_duration: params.lock_duration,
+        minting_fee: params.minting_fee,
+        treasury: params.treasury,
+        fee_tiers: params.fee_tiers.clone(),
+        have_governance: params.have_governance,
+        governance_signers: params.governance_signers.clone(),
+        governance_threshold: params.governance_threshold,
+        governance_timelock: params.governance_timelock,
+    };
 
     // Checks
     check_signing_authority(
@@ -66,34 +97,14 @@ pub fn handle_update_config(
         ctx.accounts.signing_authority.key(),
     )?;
 
-    check_value_is_zero(params.fee_base_point as usize)?;
-    check_value_is_zero(params.round_limit as usize)?;
-    check_value_is_zero(params.lock_base_point as usize)?;
-    check_value_is_zero(params.lock_duration as usize)?;
-    check_value_is_zero(params.minting_fee as usize)?;
-    check_fee_base_point(params.fee_base_point)?;
-    check_distribution_and_lock_base_point(
-        params
-            .distribution_base_point
-            .checked_add(params.lock_base_point)
-            .unwrap(),
-    )?;
+    check_is_not_governed(cream_pad_config.have_governance)?;
+
+    check_config_change(&change)?;
 
     // Set Values
     let cream_pad_config: &mut Box<Account<CreamPadAccount>> = &mut ctx.accounts.cream_pad_config;
     cream_pad_config.last_block_timestamp = timestamp;
-    cream_pad_config.back_authority = params.back_authority;
-    cream_pad_config.is_back_authority_required = params.is_back_authority_required;
-    cream_pad_config.program_status = params.program_status.clone();
-    cream_pad_config.is_fee_required = params.is_fee_required;
-    cream_pad_config.fee_receiver = params.fee_receiver;
-    cream_pad_config.fee_base_point = params.fee_base_point;
-    cream_pad_config.round_limit = params.round_limit;
-    cream_pad_config.distribution_base_point = params.distribution_base_point;
-    cream_pad_config.lock_base_point = params.lock_base_point;
-    cream_pad_config.lock_duration = params.lock_duration;
-    cream_pad_config.minting_fee = params.minting_fee;
-    cream_pad_config.treasury = params.treasury;
+    apply_config_change(cream_pad_config, &change);
 
     Ok(())
 }
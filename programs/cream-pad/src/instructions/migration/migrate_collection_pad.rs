@@ -0,0 +1,133 @@
+use crate::events::MigrateCollectionPadEvent;
+use crate::states::{
+    CollectionAuctionAccount, CollectionAuctionAccountV0, CreamPadAccount,
+    COLLECTION_AUCTION_ACCOUNT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id,
+    get_payment_mint_fee_tiers, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateCollectionPadInputParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub collection_auction_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateCollectionPadInputParams)]
+pub struct MigrateCollectionPadInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: collection_auction_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        COLLECTION_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump = params.collection_auction_config_bump,
+    )]
+    pub collection_auction_config: AccountInfo<'info>,
+
+    pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_collection_pad<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateCollectionPadInputAccounts<'info>>,
+    params: &MigrateCollectionPadInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let collection_auction_config_account_info: &AccountInfo =
+        &ctx.accounts.collection_auction_config;
+
+    // Checks
+    check_program_id(
+        collection_auction_config_account_info.owner.key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_collection_auction_config: CollectionAuctionAccountV0 = {
+        let data = collection_auction_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, CollectionAuctionAccount::discriminator())?;
+
+        let legacy_collection_auction_config =
+            CollectionAuctionAccountV0::deserialize(&mut &data[8..])?;
+
+        check_is_legacy_layout(
+            data.len(),
+            CollectionAuctionAccountV0::space(
+                legacy_collection_auction_config.tmax,
+                legacy_collection_auction_config.asset_creators.len(),
+            ),
+        )?;
+
+        legacy_collection_auction_config
+    };
+
+    // Migrate
+    let mut collection_auction_config: CollectionAuctionAccount =
+        legacy_collection_auction_config.migrate();
+    collection_auction_config.last_block_timestamp = timestamp;
+
+    // legacy pads all belong to the default config
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+    collection_auction_config.cream_pad_config = cream_pad_config.key();
+
+    // legacy pads keep paying the fees currently in effect
+    if cream_pad_config.is_fee_required {
+        collection_auction_config.fee_base_point = cream_pad_config.fee_base_point;
+        collection_auction_config.fee_tiers = get_payment_mint_fee_tiers(
+            &cream_pad_config.fee_tiers,
+            collection_auction_config.payment_mint,
+        );
+    };
+    collection_auction_config.minting_fee = cream_pad_config.minting_fee;
+
+    realloc_account(
+        collection_auction_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        CollectionAuctionAccount::space(
+            collection_auction_config.tmax,
+            collection_auction_config.asset_creators.len(),
+        ),
+    )?;
+
+    collection_auction_config.try_serialize(
+        &mut &mut collection_auction_config_account_info.try_borrow_mut_data()?[..],
+    )?;
+
+    // Event
+    let event: MigrateCollectionPadEvent = MigrateCollectionPadEvent {
+        timestamp,
+        collection_mint: ctx.accounts.collection_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        version: collection_auction_config.version,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,112 @@
+use crate::events::MigrateCollectionPadRoundEvent;
+use crate::states::{
+    CollectionAuctionRoundAccount, CollectionAuctionRoundAccountV0,
+    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateCollectionPadRoundInputParams {
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    // Bumps
+    pub collection_auction_config_bump: u8,
+
+    pub collection_auction_round_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateCollectionPadRoundInputParams)]
+pub struct MigrateCollectionPadRoundInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    /// CHECK: collection_auction_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        COLLECTION_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump = params.collection_auction_config_bump,
+    )]
+    pub collection_auction_config: AccountInfo<'info>,
+
+    /// CHECK: collection_auction_round_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        collection_auction_config.key().as_ref(),
+        params.round_index.as_ref(),
+        ],
+        bump = params.collection_auction_round_config_bump,
+    )]
+    pub collection_auction_round_config: AccountInfo<'info>,
+
+    pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_collection_pad_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateCollectionPadRoundInputAccounts<'info>>,
+    params: &MigrateCollectionPadRoundInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let collection_auction_round_config_account_info: &AccountInfo =
+        &ctx.accounts.collection_auction_round_config;
+
+    // Checks
+    check_program_id(
+        collection_auction_round_config_account_info.owner.key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_collection_auction_round_config: CollectionAuctionRoundAccountV0 = {
+        let data = collection_auction_round_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, CollectionAuctionRoundAccount::discriminator())?;
+
+        check_is_legacy_layout(data.len(), CollectionAuctionRoundAccountV0::space())?;
+
+        CollectionAuctionRoundAccountV0::deserialize(&mut &data[8..])?
+    };
+
+    // Migrate
+    let mut collection_auction_round_config: CollectionAuctionRoundAccount =
+        legacy_collection_auction_round_config.migrate();
+    collection_auction_round_config.last_block_timestamp = timestamp;
+
+    realloc_account(
+        collection_auction_round_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        CollectionAuctionRoundAccount::space(),
+    )?;
+
+    collection_auction_round_config.try_serialize(
+        &mut &mut collection_auction_round_config_account_info.try_borrow_mut_data()?[..],
+    )?;
+
+    // Event
+    let event: MigrateCollectionPadRoundEvent = MigrateCollectionPadRoundEvent {
+        timestamp,
+        collection_mint: ctx.accounts.collection_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        round_index: params.round_index.clone(),
+        version: collection_auction_round_config.version,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use crate::events::MigrateConfigEvent;
+use crate::states::{CreamPadAccount, CreamPadAccountV0, CREAM_PAD_ACCOUNT_PREFIX};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateConfigInputParams {
+    // Bumps
+    pub cream_pad_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateConfigInputParams)]
+pub struct MigrateConfigInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    /// CHECK: cream_pad_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_config<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateConfigInputAccounts<'info>>,
+    _params: &MigrateConfigInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config_account_info: &AccountInfo = &ctx.accounts.cream_pad_config;
+
+    // Checks
+    check_program_id(
+        cream_pad_config_account_info.owner.key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_cream_pad_config: CreamPadAccountV0 = {
+        let data = cream_pad_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, CreamPadAccount::discriminator())?;
+
+        check_is_legacy_layout(data.len(), CreamPadAccountV0::space())?;
+
+        CreamPadAccountV0::deserialize(&mut &data[8..])?
+    };
+
+    // Migrate
+    let mut cream_pad_config: CreamPadAccount = legacy_cream_pad_config.migrate();
+    cream_pad_config.last_block_timestamp = timestamp;
+
+    realloc_account(
+        cream_pad_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        CreamPadAccount::space(),
+    )?;
+
+    cream_pad_config
+        .try_serialize(&mut &mut cream_pad_config_account_info.try_borrow_mut_data()?[..])?;
+
+    // Event
+    let event: MigrateConfigEvent = MigrateConfigEvent {
+        timestamp,
+        signing_authority: cream_pad_config.signing_authority,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
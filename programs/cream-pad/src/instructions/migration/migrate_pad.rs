@@ -0,0 +1,120 @@
+use crate::events::MigratePadEvent;
+use crate::states::{
+    AuctionAccount, AuctionAccountV0, CreamPadAccount, AUCTION_ACCOUNT_PREFIX,
+    CREAM_PAD_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id,
+    get_payment_mint_fee_tiers, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigratePadInputParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigratePadInputParams)]
+pub struct MigratePadInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: auction_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_pad<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigratePadInputAccounts<'info>>,
+    params: &MigratePadInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let auction_config_account_info: &AccountInfo = &ctx.accounts.auction_config;
+
+    // Checks
+    check_program_id(
+        auction_config_account_info.owner.key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_auction_config: AuctionAccountV0 = {
+        let data = auction_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, AuctionAccount::discriminator())?;
+
+        let legacy_auction_config = AuctionAccountV0::deserialize(&mut &data[8..])?;
+
+        check_is_legacy_layout(
+            data.len(),
+            AuctionAccountV0::space(legacy_auction_config.tmax),
+        )?;
+
+        legacy_auction_config
+    };
+
+    // Migrate
+    let mut auction_config: AuctionAccount = legacy_auction_config.migrate();
+    auction_config.last_block_timestamp = timestamp;
+
+    // legacy pads all belong to the default config
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+    auction_config.cream_pad_config = cream_pad_config.key();
+
+    // legacy pads keep paying the fee currently in effect
+    if cream_pad_config.is_fee_required {
+        auction_config.fee_base_point = cream_pad_config.fee_base_point;
+        auction_config.fee_tiers =
+            get_payment_mint_fee_tiers(&cream_pad_config.fee_tiers, auction_config.payment_mint);
+    };
+
+    realloc_account(
+        auction_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        AuctionAccount::space(auction_config.tmax),
+    )?;
+
+    auction_config
+        .try_serialize(&mut &mut auction_config_account_info.try_borrow_mut_data()?[..])?;
+
+    // Event
+    let event: MigratePadEvent = MigratePadEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        version: auction_config.version,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
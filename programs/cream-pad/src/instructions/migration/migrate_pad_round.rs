@@ -0,0 +1,109 @@
+use crate::events::MigratePadRoundEvent;
+use crate::states::{
+    AuctionRoundAccount, AuctionRoundAccountV0, AUCTION_ACCOUNT_PREFIX,
+    AUCTION_ROUND_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigratePadRoundInputParams {
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    // Bumps
+    pub auction_config_bump: u8,
+
+    pub auction_round_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigratePadRoundInputParams)]
+pub struct MigratePadRoundInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    /// CHECK: auction_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: AccountInfo<'info>,
+
+    /// CHECK: auction_round_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        params.round_index.as_ref(),
+        ],
+        bump = params.auction_round_config_bump,
+    )]
+    pub auction_round_config: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_pad_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigratePadRoundInputAccounts<'info>>,
+    params: &MigratePadRoundInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let auction_round_config_account_info: &AccountInfo = &ctx.accounts.auction_round_config;
+
+    // Checks
+    check_program_id(
+        auction_round_config_account_info.owner.key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_auction_round_config: AuctionRoundAccountV0 = {
+        let data = auction_round_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, AuctionRoundAccount::discriminator())?;
+
+        check_is_legacy_layout(data.len(), AuctionRoundAccountV0::space())?;
+
+        AuctionRoundAccountV0::deserialize(&mut &data[8..])?
+    };
+
+    // Migrate
+    let mut auction_round_config: AuctionRoundAccount = legacy_auction_round_config.migrate();
+    auction_round_config.last_block_timestamp = timestamp;
+
+    realloc_account(
+        auction_round_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        AuctionRoundAccount::space(),
+    )?;
+
+    auction_round_config
+        .try_serialize(&mut &mut auction_round_config_account_info.try_borrow_mut_data()?[..])?;
+
+    // Event
+    let event: MigratePadRoundEvent = MigratePadRoundEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        round_index: params.round_index.clone(),
+        version: auction_round_config.version,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
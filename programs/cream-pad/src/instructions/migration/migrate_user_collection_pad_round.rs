@@ -0,0 +1,145 @@
+use crate::events::MigrateUserCollectionPadRoundEvent;
+use crate::states::{
+    UserCollectionAuctionRoundAccount, UserCollectionAuctionRoundAccountV0,
+    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_ACCOUNT_PREFIX, USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateUserCollectionPadRoundInputParams {
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    // Bumps
+    pub collection_auction_config_bump: u8,
+
+    pub collection_auction_round_config_bump: u8,
+
+    pub user_collection_auction_config_bump: u8,
+
+    pub user_collection_auction_round_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateUserCollectionPadRoundInputParams)]
+pub struct MigrateUserCollectionPadRoundInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    /// CHECK: collection_auction_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        COLLECTION_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump = params.collection_auction_config_bump,
+    )]
+    pub collection_auction_config: AccountInfo<'info>,
+
+    /// CHECK: collection_auction_round_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        collection_auction_config.key().as_ref(),
+        params.round_index.as_ref(),
+        ],
+        bump = params.collection_auction_round_config_bump,
+    )]
+    pub collection_auction_round_config: AccountInfo<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: user_collection_auction_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        USER_COLLECTION_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        collection_auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_collection_auction_config_bump,
+    )]
+    pub user_collection_auction_config: AccountInfo<'info>,
+
+    /// CHECK: user_collection_auction_round_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        collection_auction_round_config.key().as_ref(),
+        user_collection_auction_config.key().as_ref(),
+        ],
+        bump = params.user_collection_auction_round_config_bump,
+    )]
+    pub user_collection_auction_round_config: AccountInfo<'info>,
+
+    pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_user_collection_pad_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateUserCollectionPadRoundInputAccounts<'info>>,
+    params: &MigrateUserCollectionPadRoundInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let user_collection_auction_round_config_account_info: &AccountInfo =
+        &ctx.accounts.user_collection_auction_round_config;
+
+    // Checks
+    check_program_id(
+        user_collection_auction_round_config_account_info
+            .owner
+            .key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_user_collection_auction_round_config: UserCollectionAuctionRoundAccountV0 = {
+        let data = user_collection_auction_round_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, UserCollectionAuctionRoundAccount::discriminator())?;
+
+        check_is_legacy_layout(data.len(), UserCollectionAuctionRoundAccountV0::space())?;
+
+        UserCollectionAuctionRoundAccountV0::deserialize(&mut &data[8..])?
+    };
+
+    // Migrate
+    let mut user_collection_auction_round_config: UserCollectionAuctionRoundAccount =
+        legacy_user_collection_auction_round_config.migrate();
+    user_collection_auction_round_config.last_block_timestamp = timestamp;
+
+    realloc_account(
+        user_collection_auction_round_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        UserCollectionAuctionRoundAccount::space(),
+    )?;
+
+    user_collection_auction_round_config.try_serialize(
+        &mut &mut user_collection_auction_round_config_account_info.try_borrow_mut_data()?[..],
+    )?;
+
+    // Event
+    let event: MigrateUserCollectionPadRoundEvent = MigrateUserCollectionPadRoundEvent {
+        timestamp,
+        collection_mint: ctx.accounts.collection_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        round_index: params.round_index.clone(),
+        user: ctx.accounts.user.key(),
+        version: user_collection_auction_round_config.version,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
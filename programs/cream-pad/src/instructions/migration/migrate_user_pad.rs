@@ -0,0 +1,109 @@
+use crate::events::MigrateUserPadEvent;
+use crate::states::{
+    UserAuctionAccount, UserAuctionAccountV0, AUCTION_ACCOUNT_PREFIX, USER_AUCTION_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateUserPadInputParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub auction_config_bump: u8,
+
+    pub user_auction_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateUserPadInputParams)]
+pub struct MigrateUserPadInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    /// CHECK: auction_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: AccountInfo<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: user_auction_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_auction_config_bump,
+    )]
+    pub user_auction_config: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_user_pad<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateUserPadInputAccounts<'info>>,
+    params: &MigrateUserPadInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let user_auction_config_account_info: &AccountInfo = &ctx.accounts.user_auction_config;
+
+    // Checks
+    check_program_id(
+        user_auction_config_account_info.owner.key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_user_auction_config: UserAuctionAccountV0 = {
+        let data = user_auction_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, UserAuctionAccount::discriminator())?;
+
+        check_is_legacy_layout(data.len(), UserAuctionAccountV0::space())?;
+
+        UserAuctionAccountV0::deserialize(&mut &data[8..])?
+    };
+
+    // Migrate
+    let mut user_auction_config: UserAuctionAccount = legacy_user_auction_config.migrate();
+    user_auction_config.last_block_timestamp = timestamp;
+
+    realloc_account(
+        user_auction_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        UserAuctionAccount::space(),
+    )?;
+
+    user_auction_config
+        .try_serialize(&mut &mut user_auction_config_account_info.try_borrow_mut_data()?[..])?;
+
+    // Event
+    let event: MigrateUserPadEvent = MigrateUserPadEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        user: ctx.accounts.user.key(),
+        version: user_auction_config.version,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
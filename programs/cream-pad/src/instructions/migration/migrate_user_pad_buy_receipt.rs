@@ -0,0 +1,129 @@
+use crate::events::MigrateUserPadBuyReceiptEvent;
+use crate::states::{
+    UserAuctionBuyReceiptAccount, UserAuctionBuyReceiptAccountV0, AUCTION_ACCOUNT_PREFIX,
+    USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    check_account_discriminator, check_is_legacy_layout, check_program_id, realloc_account,
+};
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateUserPadBuyReceiptInputParams {
+    pub pad_name: String,
+
+    pub buy_index: String,
+
+    // Bumps
+    pub auction_config_bump: u8,
+
+    pub user_auction_config_bump: u8,
+
+    pub user_auction_buy_receipt_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: MigrateUserPadBuyReceiptInputParams)]
+pub struct MigrateUserPadBuyReceiptInputAccounts<'info> {
+    #[account(mut)]
+    pub fee_and_rent_payer: Signer<'info>,
+
+    /// CHECK: auction_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: AccountInfo<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    /// CHECK: user_auction_config, may still be in the legacy layout
+    #[account(
+        seeds = [
+        USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_auction_config_bump,
+    )]
+    pub user_auction_config: AccountInfo<'info>,
+
+    /// CHECK: user_auction_buy_receipt_config, still in the legacy layout
+    #[account(
+        mut,
+        seeds = [
+        USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_ref(),
+        user_auction_config.key().as_ref(),
+        params.buy_index.as_ref(),
+        ],
+        bump = params.user_auction_buy_receipt_config_bump,
+    )]
+    pub user_auction_buy_receipt_config: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_user_pad_buy_receipt<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MigrateUserPadBuyReceiptInputAccounts<'info>>,
+    params: &MigrateUserPadBuyReceiptInputParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let user_auction_buy_receipt_config_account_info: &AccountInfo =
+        &ctx.accounts.user_auction_buy_receipt_config;
+
+    // Checks
+    check_program_id(
+        user_auction_buy_receipt_config_account_info.owner.key(),
+        ctx.program_id.key(),
+    )?;
+
+    let legacy_user_auction_buy_receipt_config: UserAuctionBuyReceiptAccountV0 = {
+        let data = user_auction_buy_receipt_config_account_info.try_borrow_data()?;
+
+        check_account_discriminator(&data, UserAuctionBuyReceiptAccount::discriminator())?;
+
+        check_is_legacy_layout(data.len(), UserAuctionBuyReceiptAccountV0::space())?;
+
+        UserAuctionBuyReceiptAccountV0::deserialize(&mut &data[8..])?
+    };
+
+    // Migrate
+    let mut user_auction_buy_receipt_config: UserAuctionBuyReceiptAccount =
+        legacy_user_auction_buy_receipt_config.migrate();
+    user_auction_buy_receipt_config.last_block_timestamp = timestamp;
+
+    realloc_account(
+        user_auction_buy_receipt_config_account_info,
+        &ctx.accounts.fee_and_rent_payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        UserAuctionBuyReceiptAccount::space(),
+    )?;
+
+    user_auction_buy_receipt_config.try_serialize(
+        &mut &mut user_auction_buy_receipt_config_account_info.try_borrow_mut_data()?[..],
+    )?;
+
+    // Event
+    let event: MigrateUserPadBuyReceiptEvent = MigrateUserPadBuyReceiptEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        buy_index: params.buy_index.clone(),
+        user: ctx.accounts.user.key(),
+        version: user_auction_buy_receipt_config.version,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
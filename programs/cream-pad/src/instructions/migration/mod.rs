@@ -0,0 +1,27 @@
+pub use migrate_collection_pad::*;
+pub use migrate_collection_pad_round::*;
+pub use migrate_config::*;
+pub use migrate_pad::*;
+pub use migrate_pad_round::*;
+pub use migrate_user_collection_pad::*;
+pub use migrate_user_collection_pad_buy_receipt::*;
+pub use migrate_user_collection_pad_round::*;
+pub use migrate_user_collection_pad_unsold_distribution::*;
+pub use migrate_user_pad::*;
+pub use migrate_user_pad_buy_receipt::*;
+pub use migrate_user_pad_round::*;
+pub use migrate_user_pad_unsold_distribution::*;
+
+pub mod migrate_collection_pad;
+pub mod migrate_collection_pad_round;
+pub mod migrate_config;
+pub mod migrate_pad;
+pub mod migrate_pad_round;
+pub mod migrate_user_collection_pad;
+pub mod migrate_user_collection_pad_buy_receipt;
+pub mod migrate_user_collection_pad_round;
+pub mod migrate_user_collection_pad_unsold_distribution;
+pub mod migrate_user_pad;
+pub mod migrate_user_pad_buy_receipt;
+pub mod migrate_user_pad_round;
+pub mod migrate_user_pad_unsold_distribution;
@@ -3,4 +3,6 @@ pub mod manager;
 pub mod creator;
 
 pub mod user;
+
+pub mod migration;
 // pub mod views;
\ No newline at end of file
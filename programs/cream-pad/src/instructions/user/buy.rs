@@ -1,20 +1,36 @@
+use crate::math::calculate_boost;
 use crate::states::{
-    AuctionAccount, AuctionRoundAccount, AuctionRoundStatus, AuctionStatus, CreamPadAccount,
-    UserAuctionAccount, UserAuctionBuyReceiptAccount, UserAuctionRoundAccount, UserAuctionStatus,
-    AUCTION_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_PREFIX, USER_AUCTION_ACCOUNT_PREFIX,
-    USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX, USER_AUCTION_ROUND_ACCOUNT_PREFIX,
+    AuctionAccount, AuctionRoundAccount, AuctionRoundStatus, AuctionStatus, BuyIntent,
+    CreamPadAccount, PaymentMint, ReferrerAccount, UserAuctionAccount, UserAuctionBuyReceiptAccount,
+    UserAuctionRoundAccount, UserAuctionStatus, UserAuctionVestingAccount, AUCTION_ACCOUNT_PREFIX,
+    AUCTION_ACCOUNT_VERSION, AUCTION_ROUND_ACCOUNT_PREFIX, AUCTION_ROUND_ACCOUNT_VERSION,
+    AUCTION_VAULT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX, REFERRER_ACCOUNT_PREFIX,
+    REFERRER_ACCOUNT_VERSION, USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_ACCOUNT_VERSION,
+    USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX, USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+    USER_AUCTION_ROUND_ACCOUNT_PREFIX, USER_AUCTION_ROUND_ACCOUNT_VERSION,
+    USER_AUCTION_VESTING_ACCOUNT_PREFIX, USER_AUCTION_VESTING_ACCOUNT_VERSION,
 };
 use crate::utils::{
-    adjust_amount, calculate_boost, calculate_total_price, check_back_authority, check_buy_index,
-    check_current_round, check_is_auction_ended_or_sold_out, check_is_auction_round_ended,
-    check_is_auction_round_time_run_out, check_is_program_working, check_payment_fee_receiver,
-    check_payment_mint_account, check_payment_receiver, check_remaining_supply,
-    check_round_buy_limit, check_signer_exist, check_token_account_authority,
-    try_get_remaining_account_info, BASE_POINT,
+    adjust_amount, apply_payment_rate, calculate_total_price, check_account_exist,
+    check_account_version, check_back_authority, check_buy_index, check_buy_intent,
+    check_buy_intent_exist, check_buy_intent_expiry, check_buy_intent_max_price,
+    check_buy_intent_max_total_payment, check_buy_intent_payment, check_buy_intent_signature,
+    check_buys_not_paused, check_cream_pad_config, check_current_round,
+    check_is_auction_ended_or_sold_out, check_is_auction_round_ended,
+    check_is_auction_round_time_run_out, check_is_auction_started, check_is_program_working,
+    check_max_price_per_token, check_max_total_payment, check_payment_fee_receiver,
+    check_payment_mint_account, check_payment_receiver, check_referrer, check_remaining_supply,
+    check_round_buy_limit, check_signer_exist, check_whitelist_allocation, check_whitelist_proof,
+    get_fee_base_point, is_native_payment, read_oracle_rate, remove_payment_rate, BASE_POINT,
+    NATIVE_PAYMENT_DECIMALS, NATIVE_PAYMENT_MINT,
 };
 use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_lang::solana_program::instruction::Instruction;
-use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked};
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
 
 use crate::events::BuyEvent;
 use anchor_lang::solana_program::sysvar::instructions::{
@@ -26,16 +42,38 @@ use anchor_lang::solana_program::sysvar::instructions::{
 pub struct BuyParams {
     pub pad_name: String,
 
+    /// has to be the pad's current round, so a buy never lands in a later round
     pub current_round_index: String,
 
     pub buy_index: String,
 
     pub amount: u64,
 
+    /// allocation of the user's whitelist leaf, only read during whitelist rounds
+    pub whitelist_allocation: u64,
+
+    pub whitelist_proof: Vec<[u8; 32]>,
+
+    /// default pubkey for no referrer, only read on referral pads
+    pub referrer: Pubkey,
+
+    /// the user's signed intent, required when a relayer submits the buy without the user
+    pub buy_intent: Option<BuyIntent>,
+
+    /// highest `current_price` the user accepts, scaled to 9 decimals, none for no limit
+    pub max_price_per_token: Option<u64>,
+
+    /// most the user pays, fee included, in the smallest units of the paid mint, none for no limit
+    pub max_total_payment: Option<u64>,
+
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub auction_config_bump: u8,
 
     pub auction_round_config_bump: u8,
+
+    pub auction_vault_config_bump: u8,
 }
 
 #[derive(Accounts)]
@@ -44,7 +82,21 @@ pub struct BuyInputAccounts<'info> {
     #[account(mut)]
     pub fee_and_rent_payer: Signer<'info>,
 
-    pub user: Signer<'info>,
+    /// CHECK: user, signs the transaction or the buy intent
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
 
     #[account(
         mut,
@@ -97,7 +149,7 @@ pub struct BuyInputAccounts<'info> {
     #[account(
         init,
         payer = fee_and_rent_payer,
-        space = UserAuctionRoundAccount::space(),
+        space = UserAuctionBuyReceiptAccount::space(),
         seeds = [
         USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_ref(),
         user_auction_config.key().as_ref(),
@@ -107,9 +159,122 @@ pub struct BuyInputAccounts<'info> {
     )]
     pub user_auction_buy_receipt_config: Box<Account<'info, UserAuctionBuyReceiptAccount>>,
 
+    #[account(
+        init_if_needed,
+        payer = fee_and_rent_payer,
+        space = UserAuctionVestingAccount::space(),
+        seeds = [
+        USER_AUCTION_VESTING_ACCOUNT_PREFIX.as_ref(),
+        user_auction_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_auction_vesting_config: Option<Box<Account<'info, UserAuctionVestingAccount>>>,
+
     pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
 
-    pub payment_token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+    /// not needed when the pad is paid in native SOL
+    pub payment_token_mint_account: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = user,
+        token::token_program = payment_token_program,
+    )]
+    /// a relayed buy pays through the allowance the user approved to `auction_config` on this
+    /// account, at least the buy's total price, and the pad only spends it under a signed intent
+    pub user_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint_account,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = auction_config,
+        associated_token::token_program = token_program,
+    )]
+    pub auction_config_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: payment_receiver, checked against the auction config, not needed when escrowed
+    #[account(mut)]
+    pub payment_receiver: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = payment_receiver,
+        token::token_program = payment_token_program,
+    )]
+    pub payment_receiver_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: fee_receiver, checked against the cream pad config, not needed when escrowed
+    #[account(mut)]
+    pub fee_receiver: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = fee_receiver,
+        token::token_program = payment_token_program,
+    )]
+    pub fee_receiver_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: payment_oracle, checked against the paid payment mint, only needed for oracle priced mints
+    pub payment_oracle: Option<AccountInfo<'info>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_and_rent_payer,
+        space = ReferrerAccount::space(),
+        seeds = [
+        REFERRER_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        params.referrer.as_ref(),
+        ],
+        bump,
+    )]
+    pub referrer_config: Option<Box<Account<'info, ReferrerAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_and_rent_payer,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = referrer_config,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub referrer_config_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: auction_vault_config, only needed when the pad escrows payments
+    #[account(
+        seeds = [
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        ],
+        bump = params.auction_vault_config_bump,
+    )]
+    pub auction_vault_config: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = auction_vault_config,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub auction_vault_config_payment_token_account:
+        Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
 
     pub system_program: Program<'info, System>,
 
@@ -126,26 +291,35 @@ pub fn handle_buy<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
-
-    let auction_config: &Box<Account<AuctionAccount>> = &ctx.accounts.auction_config;
-    let auction_round_config: &Box<Account<AuctionRoundAccount>> =
-        &ctx.accounts.auction_round_config;
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+    let auction_round_config: &Account<AuctionRoundAccount> = &ctx.accounts.auction_round_config;
 
     // Checks
 
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_account_version(
+        ctx.accounts.auction_round_config.version,
+        AUCTION_ROUND_ACCOUNT_VERSION,
+    )?;
+
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
-    if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
+    check_buys_not_paused(
+        cream_pad_config.pause_flags.pause_buys || auction_config.pause_flags.pause_buys,
+    )?;
 
+    if cream_pad_config.is_back_authority_required {
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -155,12 +329,12 @@ pub fn handle_buy<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
-    let user_auction_config: &Box<Account<UserAuctionAccount>> = &ctx.accounts.user_auction_config;
+    let user_auction_config: &Account<UserAuctionAccount> = &ctx.accounts.user_auction_config;
 
-    let user_auction_round_config: &Box<Account<UserAuctionRoundAccount>> =
+    let user_auction_round_config: &Account<UserAuctionRoundAccount> =
         &ctx.accounts.user_auction_round_config;
 
     if auction_round_config.have_buy_limit {
@@ -180,34 +354,75 @@ pub fn handle_buy<'info>(
         user_auction_config.total_buy_count.checked_add(1).unwrap(),
     )?;
 
-    let token_program_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 2)?;
-    let payment_token_program_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 3)?;
+    let current_round_index: u16 = params.current_round_index.clone().parse().unwrap();
+
+    check_current_round(auction_config.current_round, current_round_index)?;
 
-    let user_payment_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 4)?;
-    let user_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 5)?;
+    check_max_price_per_token(auction_config.current_price, params.max_price_per_token)?;
 
-    let auction_config_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 6)?;
+    // a relayed buy carries the user's signed intent instead of the user's signature
+    let is_buy_intent: bool = !ctx.accounts.user.is_signer;
 
-    let payment_receiver_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 7)?;
-    let payment_receiver_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 8)?;
+    if is_buy_intent {
+        check_buy_intent_exist(params.buy_intent.is_some())?;
 
-    let fee_receiver_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 9)?;
-    let fee_receiver_payment_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 10)?;
+        let buy_intent: &BuyIntent = params.buy_intent.as_ref().unwrap();
 
-    let current_round_index: u16 = params.current_round_index.clone().parse().unwrap();
+        // the ed25519 program instruction goes right before the buy
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let ed25519_instruction: Instruction = load_instruction_at_checked(
+            instruction_index.saturating_sub(1),
+            &ctx.accounts.instructions_sysvar,
+        )?;
 
-    check_current_round(auction_config.current_round, current_round_index)?;
+        check_buy_intent_signature(&ed25519_instruction, ctx.accounts.user.key(), buy_intent)?;
+
+        check_buy_intent(
+            buy_intent,
+            auction_config.key(),
+            current_round_index,
+            params.amount,
+            ctx.accounts
+                .payment_token_mint_account
+                .as_ref()
+                .map(|payment_token_mint_account| payment_token_mint_account.key()),
+            buy_index,
+            params.referrer,
+        )?;
+
+        check_buy_intent_expiry(buy_intent.expires_at, timestamp)?;
+
+        check_buy_intent_max_price(auction_config.current_price, buy_intent.max_price)?;
+    };
+
+    let is_whitelist_round: bool = auction_config.have_whitelist
+        && current_round_index <= auction_config.whitelist_rounds;
+
+    if is_whitelist_round {
+        check_whitelist_proof(
+            &params.whitelist_proof,
+            auction_config.whitelist_merkle_root,
+            ctx.accounts.user.key(),
+            params.whitelist_allocation,
+        )?;
+
+        check_whitelist_allocation(
+            user_auction_config
+                .total_whitelist_buy_amount
+                .checked_add(params.amount)
+                .unwrap(),
+            params.whitelist_allocation,
+        )?;
+    };
 
     check_is_auction_round_ended(auction_round_config.status.clone())?;
 
     check_is_auction_ended_or_sold_out(auction_config.status.clone())?;
 
+    check_is_auction_started(auction_config.start_at, timestamp)?;
+
     check_is_auction_round_time_run_out(auction_round_config.round_end_at, timestamp)?;
 
     check_remaining_supply(
@@ -217,123 +432,180 @@ pub fn handle_buy<'info>(
         auction_config.total_supply,
     )?;
 
-    check_payment_mint_account(
-        auction_config.payment_mint,
-        ctx.accounts.payment_token_mint_account.key(),
-    )?;
-
-    check_payment_receiver(
-        auction_config.payment_receiver,
-        payment_receiver_account_info.key(),
-    )?;
-
-    check_payment_fee_receiver(
-        cream_pad_config.fee_receiver,
-        fee_receiver_account_info.key(),
-    )?;
-
-    // Convert amount for transfer
-    let adjusted_amount = adjust_amount(params.amount, 9, ctx.accounts.token_mint_account.decimals);
+    // index in `payment_mints` when the user pays with one of the extra payment mints
+    let payment_mint_index: Option<usize> = ctx
+        .accounts
+        .payment_token_mint_account
+        .as_ref()
+        .and_then(|payment_token_mint_account| {
+            auction_config
+                .payment_mints
+                .iter()
+                .position(|payment_mint| payment_mint.mint == payment_token_mint_account.key())
+        });
 
-    // Convert total price for transfer
-    let total_price = calculate_total_price(
-        params.amount,
-        auction_config.current_price,
-        9,                                                // From default 9 decimal
-        ctx.accounts.payment_token_mint_account.decimals, // To payment token decimals
-        ctx.accounts.payment_token_mint_account.decimals, // Output should match payment token decimals
-    );
+    let is_native_payment: bool =
+        payment_mint_index.is_none() && is_native_payment(auction_config.payment_mint);
 
-    // Transfers
+    if is_buy_intent {
+        check_buy_intent_payment(is_native_payment)?;
+    };
 
-    // handle fee transfer
-    let mut fee_price: u64 = 0;
-    if cream_pad_config.is_fee_required {
-        fee_price = total_price
-            .checked_mul(cream_pad_config.fee_base_point as u64)
-            .unwrap()
-            .checked_div(BASE_POINT as u64)
-            .unwrap();
+    if !is_native_payment {
+        check_account_exist(
+            ctx.accounts.payment_token_mint_account.is_some()
+                && ctx.accounts.user_payment_token_account.is_some(),
+        )?;
 
-        // Check fee receiver token account authority
-        let fee_receiver_token_account_unpacked: TokenAccount =
-            TokenAccount::try_deserialize_unchecked(
-                &mut &*fee_receiver_payment_token_account_account_info
-                    .data
-                    .borrow()
-                    .as_ref(),
+        if payment_mint_index.is_none() {
+            check_payment_mint_account(
+                auction_config.payment_mint,
+                ctx.accounts
+                    .payment_token_mint_account
+                    .as_ref()
+                    .unwrap()
+                    .key(),
             )?;
+        };
+    };
 
-        check_token_account_authority(
-            fee_receiver_token_account_unpacked.owner,
-            fee_receiver_account_info.key(),
+    let payment_rate: Option<u64> = match payment_mint_index {
+        Some(index) => {
+            let payment_mint: &PaymentMint = &auction_config.payment_mints[index];
+
+            if payment_mint.have_oracle {
+                check_account_exist(ctx.accounts.payment_oracle.is_some())?;
+
+                Some(read_oracle_rate(
+                    ctx.accounts.payment_oracle.as_ref().unwrap(),
+                    auction_config.payment_oracle_program,
+                    payment_mint.oracle,
+                    auction_config.payment_oracle_max_age,
+                    timestamp,
+                )?)
+            } else {
+                Some(payment_mint.rate)
+            }
+        }
+        None => None,
+    };
+
+    if auction_config.have_payment_escrow {
+        check_account_exist(
+            ctx.accounts
+                .auction_vault_config_payment_token_account
+                .is_some(),
+        )?;
+    } else {
+        check_account_exist(
+            ctx.accounts.payment_receiver.is_some() && ctx.accounts.fee_receiver.is_some(),
         )?;
 
-        // transfer fee payment to fee receiver
-        let transfer_fee_payment_to_fee_receiver_cpi_accounts = TransferChecked {
-            from: user_payment_token_account_account_info.to_account_info(),
-            mint: ctx.accounts.payment_token_mint_account.to_account_info(),
-            to: fee_receiver_payment_token_account_account_info.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
+        if !is_native_payment {
+            check_account_exist(
+                ctx.accounts.payment_receiver_token_account.is_some()
+                    && ctx.accounts.fee_receiver_payment_token_account.is_some(),
+            )?;
         };
 
-        let transfer_fee_payment_to_fee_receiver_cpi_ctx = CpiContext::new(
-            payment_token_program_account_info.clone(),
-            transfer_fee_payment_to_fee_receiver_cpi_accounts,
-        );
+        check_payment_receiver(
+            auction_config.payment_receiver,
+            ctx.accounts.payment_receiver.as_ref().unwrap().key(),
+        )?;
 
-        transfer_checked(
-            transfer_fee_payment_to_fee_receiver_cpi_ctx,
-            fee_price,
-            ctx.accounts.payment_token_mint_account.decimals,
+        check_payment_fee_receiver(
+            cream_pad_config.fee_receiver,
+            ctx.accounts.fee_receiver.as_ref().unwrap().key(),
         )?;
     };
 
-    // Handle payment transfer
+    if auction_config.have_vesting {
+        check_account_exist(ctx.accounts.user_auction_vesting_config.is_some())?;
+    };
+
+    let have_referrer: bool =
+        auction_config.have_referral && params.referrer != Pubkey::default();
 
-    // Check payment receiver token account authority
-    let payment_receiver_token_account_unpacked: TokenAccount =
-        TokenAccount::try_deserialize_unchecked(
-            &mut &*payment_receiver_token_account_account_info
-                .data
-                .borrow()
-                .as_ref(),
+    if have_referrer {
+        check_account_exist(
+            ctx.accounts.referrer_config.is_some()
+                && ctx.accounts.referrer_config_payment_token_account.is_some(),
         )?;
 
-    check_token_account_authority(
-        payment_receiver_token_account_unpacked.owner,
-        payment_receiver_account_info.key(),
-    )?;
+        check_referrer(params.referrer, ctx.accounts.user.key())?;
+    };
 
-    // transfer payment to payment receiver
-    let transfer_payment_to_payment_receiver_cpi_accounts = TransferChecked {
-        from: user_payment_token_account_account_info.to_account_info(),
-        mint: ctx.accounts.payment_token_mint_account.to_account_info(),
-        to: payment_receiver_token_account_account_info.to_account_info(),
-        authority: ctx.accounts.user.to_account_info(),
+    // Convert amount for transfer
+    let adjusted_amount = adjust_amount(params.amount, 9, ctx.accounts.token_mint_account.decimals);
+
+    let payment_decimals: u8 = if is_native_payment {
+        NATIVE_PAYMENT_DECIMALS
+    } else {
+        ctx.accounts
+            .payment_token_mint_account
+            .as_ref()
+            .unwrap()
+            .decimals
     };
 
-    let transfer_payment_to_payment_receiver_cpi_ctx = CpiContext::new(
-        payment_token_program_account_info.clone(),
-        transfer_payment_to_payment_receiver_cpi_accounts,
-    );
+    // Convert total price for transfer, extra payment mints pay it at their rate
+    let total_price = match payment_rate {
+        Some(rate) => adjust_amount(
+            apply_payment_rate(
+                calculate_total_price(params.amount, auction_config.current_price, 9, 9, 9),
+                rate,
+            )?,
+            9,
+            payment_decimals,
+        ),
+        None => calculate_total_price(
+            params.amount,
+            auction_config.current_price,
+            9,                // From default 9 decimal
+            payment_decimals, // To payment token decimals
+            payment_decimals, // Output should match payment token decimals
+        ),
+    };
 
-    transfer_checked(
-        transfer_payment_to_payment_receiver_cpi_ctx,
-        total_price.checked_sub(fee_price).unwrap(),
-        ctx.accounts.payment_token_mint_account.decimals,
-    )?;
+    check_max_total_payment(total_price, params.max_total_payment)?;
 
-    // handle token transfer to user
+    if is_buy_intent {
+        check_buy_intent_max_total_payment(
+            total_price,
+            params.buy_intent.as_ref().unwrap().max_total_payment,
+        )?;
+    };
 
-    // Check user token account authority
-    let user_token_account_unpacked: TokenAccount = TokenAccount::try_deserialize_unchecked(
-        &mut &*user_token_account_account_info.data.borrow().as_ref(),
-    )?;
+    // Transfers
+
+    // handle fee transfer
+    // the fee snapshotted at pad creation, at the tier the pad's volume reached
+    let fee_base_point: u16 = get_fee_base_point(
+        auction_config.fee_base_point,
+        &auction_config.fee_tiers,
+        auction_config.have_fee_override,
+        auction_config.fee_override_base_point,
+        auction_config.total_payment,
+    );
 
-    check_token_account_authority(user_token_account_unpacked.owner, ctx.accounts.user.key())?;
+    let fee_price: u64 = total_price
+        .checked_mul(fee_base_point as u64)
+        .unwrap()
+        .checked_div(BASE_POINT as u64)
+        .unwrap();
+
+    // the referral reward comes out of the creator's share
+    let mut referral_reward: u64 = 0;
+    if have_referrer {
+        referral_reward = total_price
+            .checked_sub(fee_price)
+            .unwrap()
+            .checked_mul(auction_config.referral_base_point as u64)
+            .unwrap()
+            .checked_div(BASE_POINT as u64)
+            .unwrap();
+    };
 
-    // transfer token to user
     let auction_config_bump_bytes = params.auction_config_bump.to_le_bytes();
     let token_mint_account_key = ctx.accounts.token_mint_account.key();
 
@@ -344,39 +616,260 @@ pub fn handle_buy<'info>(
         auction_config_bump_bytes.as_ref(),
     ]];
 
-    let transfer_token_to_user_cpi_accounts = TransferChecked {
-        from: auction_config_token_account_account_info.to_account_info(),
-        mint: ctx.accounts.token_mint_account.to_account_info(),
-        to: user_token_account_account_info.to_account_info(),
-        authority: ctx.accounts.auction_config.to_account_info(),
+    // a relayed buy spends what the user delegated to the pad on their payment token account
+    let payment_authority: AccountInfo<'info> = if is_buy_intent {
+        ctx.accounts.auction_config.to_account_info()
+    } else {
+        ctx.accounts.user.to_account_info()
     };
 
-    let transfer_token_to_user_cpi_ctx = CpiContext::new_with_signer(
-        token_program_account_info.clone(),
-        transfer_token_to_user_cpi_accounts,
-        signer_seeds,
-    );
+    if auction_config.have_payment_escrow {
+        // Handle escrow transfer
+        // transfer the whole payment, fee included, to the auction vault
+        let transfer_payment_to_auction_vault_cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .user_payment_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            mint: ctx
+                .accounts
+                .payment_token_mint_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            to: ctx
+                .accounts
+                .auction_vault_config_payment_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            authority: payment_authority.clone(),
+        };
 
-    transfer_checked(
-        transfer_token_to_user_cpi_ctx,
-        adjusted_amount,
-        ctx.accounts.token_mint_account.decimals,
-    )?;
+        let transfer_payment_to_auction_vault_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.payment_token_program.to_account_info(),
+            transfer_payment_to_auction_vault_cpi_accounts,
+            signer_seeds,
+        );
 
-    let adjusted_back_total_price = adjust_amount(
-        total_price,
-        ctx.accounts.payment_token_mint_account.decimals,
-        9,
-    );
-    let adjusted_back_fee_price = adjust_amount(
-        fee_price,
-        ctx.accounts.payment_token_mint_account.decimals,
-        9,
-    );
+        transfer_checked(
+            transfer_payment_to_auction_vault_cpi_ctx,
+            total_price,
+            payment_decimals,
+        )?;
+    } else if is_native_payment {
+        if fee_price > 0 {
+            // transfer fee lamports to fee receiver
+            let transfer_fee_payment_to_fee_receiver_cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx
+                        .accounts
+                        .fee_receiver
+                        .as_ref()
+                        .unwrap()
+                        .to_account_info(),
+                },
+            );
+
+            transfer(transfer_fee_payment_to_fee_receiver_cpi_ctx, fee_price)?;
+        };
+
+        // transfer payment lamports to payment receiver
+        let transfer_payment_to_payment_receiver_cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx
+                    .accounts
+                    .payment_receiver
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+            },
+        );
+
+        transfer(
+            transfer_payment_to_payment_receiver_cpi_ctx,
+            total_price.checked_sub(fee_price).unwrap(),
+        )?;
+    } else {
+        if fee_price > 0 {
+            // transfer fee payment to fee receiver
+            let transfer_fee_payment_to_fee_receiver_cpi_accounts = TransferChecked {
+                from: ctx
+                    .accounts
+                    .user_payment_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                mint: ctx
+                    .accounts
+                    .payment_token_mint_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                to: ctx
+                    .accounts
+                    .fee_receiver_payment_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                authority: payment_authority.clone(),
+            };
+
+            let transfer_fee_payment_to_fee_receiver_cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.payment_token_program.to_account_info(),
+                transfer_fee_payment_to_fee_receiver_cpi_accounts,
+                signer_seeds,
+            );
+
+            transfer_checked(
+                transfer_fee_payment_to_fee_receiver_cpi_ctx,
+                fee_price,
+                payment_decimals,
+            )?;
+        };
+
+        if referral_reward > 0 {
+            // transfer referral reward to the referrer config, claimed later by the referrer
+            let transfer_referral_reward_to_referrer_config_cpi_accounts = TransferChecked {
+                from: ctx
+                    .accounts
+                    .user_payment_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                mint: ctx
+                    .accounts
+                    .payment_token_mint_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                to: ctx
+                    .accounts
+                    .referrer_config_payment_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                authority: payment_authority.clone(),
+            };
+
+            let transfer_referral_reward_to_referrer_config_cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.payment_token_program.to_account_info(),
+                transfer_referral_reward_to_referrer_config_cpi_accounts,
+                signer_seeds,
+            );
+
+            transfer_checked(
+                transfer_referral_reward_to_referrer_config_cpi_ctx,
+                referral_reward,
+                payment_decimals,
+            )?;
+        };
+
+        // Handle payment transfer
+        // transfer payment to payment receiver
+        let transfer_payment_to_payment_receiver_cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .user_payment_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            mint: ctx
+                .accounts
+                .payment_token_mint_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            to: ctx
+                .accounts
+                .payment_receiver_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            authority: payment_authority.clone(),
+        };
+
+        let transfer_payment_to_payment_receiver_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.payment_token_program.to_account_info(),
+            transfer_payment_to_payment_receiver_cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(
+            transfer_payment_to_payment_receiver_cpi_ctx,
+            total_price
+                .checked_sub(fee_price)
+                .unwrap()
+                .checked_sub(referral_reward)
+                .unwrap(),
+            payment_decimals,
+        )?;
+    };
+
+    // handle token transfer to user, vested pads keep the tokens until claim_vested
+    if !auction_config.have_vesting {
+        // transfer token to user
+        let transfer_token_to_user_cpi_accounts = TransferChecked {
+            from: ctx.accounts.auction_config_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint_account.to_account_info(),
+            to: ctx.accounts.user_token_account.to_account_info(),
+            authority: ctx.accounts.auction_config.to_account_info(),
+        };
+
+        let transfer_token_to_user_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_token_to_user_cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(
+            transfer_token_to_user_cpi_ctx,
+            adjusted_amount,
+            ctx.accounts.token_mint_account.decimals,
+        )?;
+    };
+
+    let adjusted_back_payment_total_price = adjust_amount(total_price, payment_decimals, 9);
+    let adjusted_back_payment_fee_price = adjust_amount(fee_price, payment_decimals, 9);
+
+    // pad totals stay in `payment_mint` units
+    let (adjusted_back_total_price, adjusted_back_fee_price) = match payment_rate {
+        Some(rate) => (
+            remove_payment_rate(adjusted_back_payment_total_price, rate)?,
+            remove_payment_rate(adjusted_back_payment_fee_price, rate)?,
+        ),
+        None => (
+            adjusted_back_payment_total_price,
+            adjusted_back_payment_fee_price,
+        ),
+    };
+
+    let adjusted_back_referral_reward = adjust_amount(referral_reward, payment_decimals, 9);
+
+    let payment_mint_key: Pubkey = if is_native_payment {
+        NATIVE_PAYMENT_MINT
+    } else {
+        ctx.accounts
+            .payment_token_mint_account
+            .as_ref()
+            .unwrap()
+            .key()
+    };
     
     // Set Values
     let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
     auction_config.last_block_timestamp = timestamp;
+
+    if auction_config.status.eq(&AuctionStatus::Scheduled) {
+        auction_config.status = AuctionStatus::Started;
+    };
+
     auction_config.total_user_buy_count =
         auction_config.total_user_buy_count.checked_add(1).unwrap();
     auction_config.total_supply_sold = auction_config
@@ -391,6 +884,25 @@ pub fn handle_buy<'info>(
         .total_fee
         .checked_add(adjusted_back_fee_price)
         .unwrap();
+
+    if have_referrer {
+        auction_config.total_referral_reward = auction_config
+            .total_referral_reward
+            .checked_add(adjusted_back_referral_reward)
+            .unwrap();
+    };
+
+    if let Some(index) = payment_mint_index {
+        let payment_mint: &mut PaymentMint = &mut auction_config.payment_mints[index];
+        payment_mint.total_payment = payment_mint
+            .total_payment
+            .checked_add(adjusted_back_payment_total_price)
+            .unwrap();
+        payment_mint.total_fee = payment_mint
+            .total_fee
+            .checked_add(adjusted_back_payment_fee_price)
+            .unwrap();
+    };
     
     let auction_round_config: &mut Box<Account<AuctionRoundAccount>> =
         &mut ctx.accounts.auction_round_config;
@@ -414,7 +926,7 @@ pub fn handle_buy<'info>(
     
     // check is over sold
     if auction_config.total_supply_sold >= auction_config.total_supply {
-        let boost: f64 = calculate_boost(
+        let boost: i128 = calculate_boost(
             auction_round_config.total_supply_sold,
             auction_config
                 .total_supply
@@ -427,16 +939,32 @@ pub fn handle_buy<'info>(
     
         auction_config.boost_history.push(boost);
         auction_config.status = AuctionStatus::SoldOut;
+
+        if auction_config.have_uniform_clearing_price {
+            auction_config.clearing_price = auction_config.current_price;
+        };
+
+        if auction_config.have_vesting {
+            auction_config.vesting_start_at = timestamp;
+        };
     
         auction_round_config.status = AuctionRoundStatus::Ended;
         auction_round_config.boost = boost;
     };
     
+    // rent of every account created by this buy, for whoever sponsored it
+    let mut rent: u64 = 0;
+
     let user_auction_config: &mut Box<Account<UserAuctionAccount>> =
         &mut ctx.accounts.user_auction_config;
     if user_auction_config.last_block_timestamp == 0 {
         user_auction_config.user = ctx.accounts.user.key();
         user_auction_config.status = UserAuctionStatus::None;
+        user_auction_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+        user_auction_config.version = USER_AUCTION_ACCOUNT_VERSION;
+        rent = rent
+            .checked_add(user_auction_config.to_account_info().lamports())
+            .unwrap();
         auction_config.total_user_count = auction_config.total_user_count.checked_add(1).unwrap();
     };
     
@@ -451,11 +979,23 @@ pub fn handle_buy<'info>(
         .total_payment
         .checked_add(adjusted_back_total_price)
         .unwrap();
+
+    if is_whitelist_round {
+        user_auction_config.total_whitelist_buy_amount = user_auction_config
+            .total_whitelist_buy_amount
+            .checked_add(params.amount)
+            .unwrap();
+    };
     
     let user_auction_round_config: &mut Box<Account<UserAuctionRoundAccount>> =
         &mut ctx.accounts.user_auction_round_config;
     if user_auction_round_config.last_block_timestamp == 0 {
         user_auction_round_config.round = current_round_index;
+        user_auction_round_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+        user_auction_round_config.version = USER_AUCTION_ROUND_ACCOUNT_VERSION;
+        rent = rent
+            .checked_add(user_auction_round_config.to_account_info().lamports())
+            .unwrap();
         auction_round_config.total_user_count = auction_round_config
             .total_user_count
             .checked_add(1)
@@ -483,7 +1023,56 @@ pub fn handle_buy<'info>(
     user_auction_buy_receipt_config.payment = adjusted_back_total_price;
     user_auction_buy_receipt_config.round = current_round_index;
     user_auction_buy_receipt_config.index = buy_index;
+    user_auction_buy_receipt_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+    user_auction_buy_receipt_config.version = USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION;
+    rent = rent
+        .checked_add(user_auction_buy_receipt_config.to_account_info().lamports())
+        .unwrap();
+
+    if auction_config.have_vesting {
+        let user_auction_vesting_config: &mut Box<Account<UserAuctionVestingAccount>> =
+            ctx.accounts.user_auction_vesting_config.as_mut().unwrap();
+        if user_auction_vesting_config.last_block_timestamp == 0 {
+            user_auction_vesting_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+            rent = rent
+                .checked_add(user_auction_vesting_config.to_account_info().lamports())
+                .unwrap();
+        };
+
+        user_auction_vesting_config.last_block_timestamp = timestamp;
+        user_auction_vesting_config.version = USER_AUCTION_VESTING_ACCOUNT_VERSION;
+        user_auction_vesting_config.total_amount = user_auction_vesting_config
+            .total_amount
+            .checked_add(params.amount)
+            .unwrap();
+    };
     
+    if have_referrer {
+        let referrer_config: &mut Box<Account<ReferrerAccount>> =
+            ctx.accounts.referrer_config.as_mut().unwrap();
+        if referrer_config.last_block_timestamp == 0 {
+            referrer_config.referrer = params.referrer;
+            referrer_config.pad_config = auction_config.key();
+            referrer_config.cream_pad_config = auction_config.cream_pad_config;
+            referrer_config.payment_mint = auction_config.payment_mint;
+            referrer_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+            referrer_config.version = REFERRER_ACCOUNT_VERSION;
+            rent = rent
+                .checked_add(referrer_config.to_account_info().lamports())
+                .unwrap();
+        };
+
+        referrer_config.last_block_timestamp = timestamp;
+        referrer_config.total_referred_buy_count = referrer_config
+            .total_referred_buy_count
+            .checked_add(1)
+            .unwrap();
+        referrer_config.total_reward = referrer_config
+            .total_reward
+            .checked_add(adjusted_back_referral_reward)
+            .unwrap();
+    };
+
     // Event
     let event: BuyEvent = BuyEvent {
         timestamp,
@@ -497,6 +1086,18 @@ pub fn handle_buy<'info>(
         current_round: params.current_round_index.clone(),
         user_buy_index: params.buy_index.clone(),
         is_ended_and_sold_out: auction_config.status.eq(&AuctionStatus::SoldOut),
+        is_whitelist_round,
+        payment_mint: payment_mint_key,
+        payment_total_price: adjusted_back_payment_total_price,
+        referrer: if have_referrer {
+            params.referrer
+        } else {
+            Pubkey::default()
+        },
+        referral_reward: adjusted_back_referral_reward,
+        is_buy_intent,
+        rent_payer: ctx.accounts.fee_and_rent_payer.key(),
+        rent,
     };
     
     emit!(event);
@@ -1,25 +1,33 @@
+use crate::math::calculate_boost;
 use crate::states::{
     AuctionRoundStatus, AuctionStatus, CollectionAuctionAccount, CollectionAuctionRoundAccount,
-    CreamPadAccount, UserAuctionStatus, UserCollectionAuctionAccount,
+    CreamPadAccount, ReferrerAccount, UserAuctionStatus, UserCollectionAuctionAccount,
     UserCollectionAuctionBuyReceiptAccount, UserCollectionAuctionRoundAccount,
-    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
-    USER_COLLECTION_AUCTION_ACCOUNT_PREFIX, USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
-    USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_PREFIX, COLLECTION_AUCTION_ACCOUNT_VERSION,
+    COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX, COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
+    CREAM_PAD_ACCOUNT_PREFIX, REFERRER_ACCOUNT_PREFIX, REFERRER_ACCOUNT_VERSION,
+    USER_COLLECTION_AUCTION_ACCOUNT_PREFIX, USER_COLLECTION_AUCTION_ACCOUNT_VERSION,
+    USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+    USER_COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX, USER_COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
 };
 use crate::utils::{
-    adjust_amount, calculate_boost, check_back_authority, check_buy_index, check_current_round,
+    adjust_amount, check_account_exist, check_account_version, check_back_authority,
+    check_buy_index, check_buys_not_paused, check_cream_pad_config, check_current_round,
     check_is_auction_ended_or_sold_out, check_is_auction_round_ended,
-    check_is_auction_round_time_run_out, check_is_program_working, check_payment_fee_receiver,
-    check_payment_mint_account, check_payment_receiver, check_remaining_supply,
-    check_round_buy_limit, check_signer_exist, check_token_account_authority,
-    try_get_remaining_account_info, BASE_POINT,
+    check_is_auction_round_time_run_out, check_is_program_working, check_max_price_per_token,
+    check_max_total_payment, check_payment_fee_receiver, check_payment_mint_account,
+    check_payment_receiver, check_referrer, check_remaining_supply, check_round_buy_limit,
+    check_signer_exist, check_whitelist_allocation, check_whitelist_proof, get_fee_base_point,
+    is_native_payment, BASE_POINT, NATIVE_PAYMENT_DECIMALS,
 };
 use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program::{transfer, Transfer};
 use anchor_lang::{prelude::*, Discriminator};
-use anchor_spl::associated_token::{
-    create as associated_token_create, Create as AssociatedTokenCreate,
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
-use anchor_spl::token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked};
 
 use crate::events::BuyCollectionAssetEvent;
 use anchor_lang::solana_program::sysvar::instructions::{
@@ -31,13 +39,31 @@ use anchor_lang::solana_program::sysvar::instructions::{
 pub struct BuyCollectionAssetParams {
     pub pad_name: String,
 
+    /// has to be the pad's current round, so a buy never lands in a later round
     pub current_round_index: String,
 
     pub buy_index: String,
 
     pub amount: u64,
 
+    /// allocation of the user's whitelist leaf, only read during whitelist rounds
+    pub whitelist_allocation: u64,
+
+    pub whitelist_proof: Vec<[u8; 32]>,
+
+    /// default pubkey for no referrer, only read on referral pads
+    pub referrer: Pubkey,
+
+    /// highest `current_price` the user accepts, scaled to 9 decimals, none for no limit
+    pub max_price_per_token: Option<u64>,
+
+    /// most the user pays in the smallest units of the paid mint, protocol fee included, none for
+    /// no limit, the lamport minting fee is charged on top and not counted
+    pub max_total_payment: Option<u64>,
+
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub collection_auction_config_bump: u8,
 
     pub collection_auction_round_config_bump: u8,
@@ -54,6 +80,19 @@ pub struct BuyCollectionAssetInputAccounts<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, receives the minting fee and signs when the config requires it
+    #[account(mut)]
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -117,7 +156,68 @@ pub struct BuyCollectionAssetInputAccounts<'info> {
 
     pub collection_mint_account: Box<InterfaceAccount<'info, Mint>>,
 
-    pub payment_token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+    /// not needed when the pad is paid in native SOL
+    pub payment_token_mint_account: Option<Box<InterfaceAccount<'info, Mint>>>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = user,
+        token::token_program = payment_token_program,
+    )]
+    pub user_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: payment_receiver, checked against the collection auction config
+    #[account(mut)]
+    pub payment_receiver: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_and_rent_payer,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = payment_receiver,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub payment_receiver_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    /// CHECK: fee_receiver, checked against the cream pad config
+    #[account(mut)]
+    pub fee_receiver: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_and_rent_payer,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = fee_receiver,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub fee_receiver_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_and_rent_payer,
+        space = ReferrerAccount::space(),
+        seeds = [
+        REFERRER_ACCOUNT_PREFIX.as_ref(),
+        collection_auction_config.key().as_ref(),
+        params.referrer.as_ref(),
+        ],
+        bump,
+    )]
+    pub referrer_config: Option<Box<Account<'info, ReferrerAccount>>>,
+
+    #[account(
+        init_if_needed,
+        payer = fee_and_rent_payer,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = referrer_config,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub referrer_config_payment_token_account: Option<Box<InterfaceAccount<'info, TokenAccount>>>,
+
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    pub associated_token_program: Program<'info, AssociatedToken>,
 
     pub system_program: Program<'info, System>,
 
@@ -134,41 +234,55 @@ pub fn handle_buy_collection_asset<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
-
-    let collection_auction_config: &Box<Account<CollectionAuctionAccount>> =
+    let collection_auction_config: &Account<CollectionAuctionAccount> =
         &ctx.accounts.collection_auction_config;
-    let collection_auction_round_config: &Box<Account<CollectionAuctionRoundAccount>> =
+    let collection_auction_round_config: &Account<CollectionAuctionRoundAccount> =
         &ctx.accounts.collection_auction_round_config;
 
     // Checks
 
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_account_version(
+        ctx.accounts.collection_auction_round_config.version,
+        COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
+    )?;
+
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
-    let back_authority_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
+    check_buys_not_paused(
+        cream_pad_config.pause_flags.pause_buys || collection_auction_config.pause_flags.pause_buys,
+    )?;
 
-    if cream_pad_config.is_back_authority_required {
-        check_back_authority(
-            cream_pad_config.back_authority,
-            back_authority_account_info.key(),
-        )?;
+    check_back_authority(
+        cream_pad_config.back_authority,
+        ctx.accounts.back_authority.key(),
+    )?;
 
+    if cream_pad_config.is_back_authority_required {
         let instruction_index =
             load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
                 as usize;
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
-    let user_collection_auction_config: &Box<Account<UserCollectionAuctionAccount>> =
+    let user_collection_auction_config: &Account<UserCollectionAuctionAccount> =
         &ctx.accounts.user_collection_auction_config;
 
-    let user_collection_auction_round_config: &Box<Account<UserCollectionAuctionRoundAccount>> =
+    let user_collection_auction_round_config: &Account<UserCollectionAuctionRoundAccount> =
         &ctx.accounts.user_collection_auction_round_config;
 
     if collection_auction_round_config.have_buy_limit {
@@ -191,25 +305,34 @@ pub fn handle_buy_collection_asset<'info>(
             .unwrap(),
     )?;
 
-    let payment_token_program_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 2)?;
-    let associated_token_program_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 3)?;
+    let current_round_index: u16 = params.current_round_index.clone().parse().unwrap();
 
-    let user_payment_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 4)?;
+    check_current_round(collection_auction_config.current_round, current_round_index)?;
 
-    let payment_receiver_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 5)?;
-    let payment_receiver_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 6)?;
+    check_max_price_per_token(
+        collection_auction_config.current_price,
+        params.max_price_per_token,
+    )?;
 
-    let fee_receiver_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 7)?;
-    let fee_receiver_payment_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 8)?;
+    let is_whitelist_round: bool = collection_auction_config.have_whitelist
+        && current_round_index <= collection_auction_config.whitelist_rounds;
 
-    let current_round_index: u16 = params.current_round_index.clone().parse().unwrap();
+    if is_whitelist_round {
+        check_whitelist_proof(
+            &params.whitelist_proof,
+            collection_auction_config.whitelist_merkle_root,
+            ctx.accounts.user.key(),
+            params.whitelist_allocation,
+        )?;
 
-    check_current_round(collection_auction_config.current_round, current_round_index)?;
+        check_whitelist_allocation(
+            user_collection_auction_config
+                .total_whitelist_buy_amount
+                .checked_add(params.amount)
+                .unwrap(),
+            params.whitelist_allocation,
+        )?;
+    };
 
     check_is_auction_round_ended(collection_auction_round_config.status.clone())?;
 
@@ -224,39 +347,84 @@ pub fn handle_buy_collection_asset<'info>(
         collection_auction_config.total_supply,
     )?;
 
-    check_payment_mint_account(
-        collection_auction_config.payment_mint,
-        ctx.accounts.payment_token_mint_account.key(),
-    )?;
+    let is_native_payment: bool = is_native_payment(collection_auction_config.payment_mint);
+
+    if !is_native_payment {
+        check_account_exist(
+            ctx.accounts.payment_token_mint_account.is_some()
+                && ctx.accounts.user_payment_token_account.is_some()
+                && ctx.accounts.payment_receiver_token_account.is_some()
+                && ctx.accounts.fee_receiver_payment_token_account.is_some(),
+        )?;
+
+        check_payment_mint_account(
+            collection_auction_config.payment_mint,
+            ctx.accounts
+                .payment_token_mint_account
+                .as_ref()
+                .unwrap()
+                .key(),
+        )?;
+    };
 
     check_payment_receiver(
         collection_auction_config.payment_receiver,
-        payment_receiver_account_info.key(),
+        ctx.accounts.payment_receiver.key(),
     )?;
 
     check_payment_fee_receiver(
         cream_pad_config.fee_receiver,
-        fee_receiver_account_info.key(),
+        ctx.accounts.fee_receiver.key(),
     )?;
 
+    let have_referrer: bool =
+        collection_auction_config.have_referral && params.referrer != Pubkey::default();
+
+    if have_referrer {
+        check_account_exist(
+            ctx.accounts.referrer_config.is_some()
+                && ctx.accounts.referrer_config_payment_token_account.is_some(),
+        )?;
+
+        check_referrer(params.referrer, ctx.accounts.user.key())?;
+    };
+
     // Convert total price for transfer
     let total_price = collection_auction_config
         .current_price
         .checked_mul(params.amount)
         .unwrap();
 
+    let payment_decimals: u8 = if is_native_payment {
+        NATIVE_PAYMENT_DECIMALS
+    } else {
+        ctx.accounts
+            .payment_token_mint_account
+            .as_ref()
+            .unwrap()
+            .decimals
+    };
+
+    check_max_total_payment(
+        adjust_amount(total_price, 9, payment_decimals),
+        params.max_total_payment,
+    )?;
+
     // Transfers
 
     // transfer minting fee
 
-    let total_minting_fee: u64 = cream_pad_config
-        .minting_fee
-        .checked_mul(params.amount)
-        .unwrap();
+    let minting_fee: u64 = if collection_auction_config.have_fee_override {
+        collection_auction_config.fee_override_minting_fee
+    } else {
+        collection_auction_config.minting_fee
+    };
+
+    let total_minting_fee: u64 = minting_fee.checked_mul(params.amount).unwrap();
 
     let transfer_minting_fee_ix = anchor_lang::solana_program::system_instruction::transfer(
         &ctx.accounts.user.key(),
-        &back_authority_account_info.key(),
+        &ctx.accounts.back_authority.key(),
         total_minting_fee,
     );
 
@@ -264,137 +432,187 @@ pub fn handle_buy_collection_asset<'info>(
         &transfer_minting_fee_ix,
         &[
             ctx.accounts.user.to_account_info(),
-            back_authority_account_info.to_account_info(),
+            ctx.accounts.back_authority.to_account_info(),
         ],
     )?;
 
     // handle fee transfer
-    let mut fee_price: u64 = 0;
-    if cream_pad_config.is_fee_required {
-        fee_price = total_price
-            .checked_mul(cream_pad_config.fee_base_point as u64)
-            .unwrap()
-            .checked_div(BASE_POINT as u64)
-            .unwrap();
+    // the fee snapshotted at pad creation, at the tier the pad's volume reached
+    let fee_base_point: u16 = get_fee_base_point(
+        collection_auction_config.fee_base_point,
+        &collection_auction_config.fee_tiers,
+        collection_auction_config.have_fee_override,
+        collection_auction_config.fee_override_base_point,
+        collection_auction_config.total_payment,
+    );
 
-        let adjusted_fee_price = adjust_amount(
-            fee_price,
-            9,
-            ctx.accounts.payment_token_mint_account.decimals,
-        );
+    let fee_price: u64 = total_price
+        .checked_mul(fee_base_point as u64)
+        .unwrap()
+        .checked_div(BASE_POINT as u64)
+        .unwrap();
 
-        if fee_receiver_payment_token_account_account_info.data_is_empty() {
-            // Create fee receiver token account
-            let create_fee_receiver_ata_cpi_accounts = AssociatedTokenCreate {
-                payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-                associated_token: fee_receiver_payment_token_account_account_info.clone(),
-                authority: fee_receiver_account_info.to_account_info(),
-                mint: ctx.accounts.payment_token_mint_account.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                token_program: payment_token_program_account_info.clone(),
+    if fee_price > 0 {
+        let adjusted_fee_price = adjust_amount(fee_price, 9, payment_decimals);
+
+        if is_native_payment {
+            // transfer fee lamports to fee receiver
+            let transfer_fee_payment_to_fee_receiver_cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user.to_account_info(),
+                    to: ctx.accounts.fee_receiver.to_account_info(),
+                },
+            );
+
+            transfer(
+                transfer_fee_payment_to_fee_receiver_cpi_ctx,
+                adjusted_fee_price,
+            )?;
+        } else {
+            // transfer fee payment to fee receiver
+            let transfer_fee_payment_to_fee_receiver_cpi_accounts = TransferChecked {
+                from: ctx
+                    .accounts
+                    .user_payment_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                mint: ctx
+                    .accounts
+                    .payment_token_mint_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                to: ctx
+                    .accounts
+                    .fee_receiver_payment_token_account
+                    .as_ref()
+                    .unwrap()
+                    .to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
             };
 
-            let create_fee_receiver_ata_cpi_ctx = CpiContext::new(
-                associated_token_program_account_info.clone(),
-                create_fee_receiver_ata_cpi_accounts,
+            let transfer_fee_payment_to_fee_receiver_cpi_ctx = CpiContext::new(
+                ctx.accounts.payment_token_program.to_account_info(),
+                transfer_fee_payment_to_fee_receiver_cpi_accounts,
             );
 
-            associated_token_create(create_fee_receiver_ata_cpi_ctx)?;
-        };
-
-        // Check fee receiver token account authority
-        let fee_receiver_token_account_unpacked: TokenAccount =
-            TokenAccount::try_deserialize_unchecked(
-                &mut &*fee_receiver_payment_token_account_account_info
-                    .data
-                    .borrow()
-                    .as_ref(),
+            transfer_checked(
+                transfer_fee_payment_to_fee_receiver_cpi_ctx,
+                adjusted_fee_price,
+                payment_decimals,
             )?;
+        };
+    };
 
-        check_token_account_authority(
-            fee_receiver_token_account_unpacked.owner,
-            fee_receiver_account_info.key(),
-        )?;
+    // the referral reward comes out of the creator's share
+    let mut referral_reward: u64 = 0;
+    if have_referrer {
+        referral_reward = total_price
+            .checked_sub(fee_price)
+            .unwrap()
+            .checked_mul(collection_auction_config.referral_base_point as u64)
+            .unwrap()
+            .checked_div(BASE_POINT as u64)
+            .unwrap();
 
-        // transfer fee payment to fee receiver
-        let transfer_fee_payment_to_fee_receiver_cpi_accounts = TransferChecked {
-            from: user_payment_token_account_account_info.to_account_info(),
-            mint: ctx.accounts.payment_token_mint_account.to_account_info(),
-            to: fee_receiver_payment_token_account_account_info.to_account_info(),
+        // transfer referral reward to the referrer config, claimed later by the referrer
+        let transfer_referral_reward_to_referrer_config_cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .user_payment_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            mint: ctx
+                .accounts
+                .payment_token_mint_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            to: ctx
+                .accounts
+                .referrer_config_payment_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
             authority: ctx.accounts.user.to_account_info(),
         };
 
-        let transfer_fee_payment_to_fee_receiver_cpi_ctx = CpiContext::new(
-            payment_token_program_account_info.clone(),
-            transfer_fee_payment_to_fee_receiver_cpi_accounts,
+        let transfer_referral_reward_to_referrer_config_cpi_ctx = CpiContext::new(
+            ctx.accounts.payment_token_program.to_account_info(),
+            transfer_referral_reward_to_referrer_config_cpi_accounts,
         );
 
         transfer_checked(
-            transfer_fee_payment_to_fee_receiver_cpi_ctx,
-            adjusted_fee_price,
-            ctx.accounts.payment_token_mint_account.decimals,
+            transfer_referral_reward_to_referrer_config_cpi_ctx,
+            adjust_amount(referral_reward, 9, payment_decimals),
+            payment_decimals,
         )?;
     };
 
     // Handle payment transfer
-    if payment_receiver_token_account_account_info.data_is_empty() {
-        // Create payment receiver token account
-        let create_payment_receiver_ata_cpi_accounts = AssociatedTokenCreate {
-            payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-            associated_token: payment_receiver_token_account_account_info.clone(),
-            authority: payment_receiver_account_info.to_account_info(),
-            mint: ctx.accounts.payment_token_mint_account.to_account_info(),
-            system_program: ctx.accounts.system_program.to_account_info(),
-            token_program: payment_token_program_account_info.clone(),
-        };
+    let adjusted_total_price = adjust_amount(
+        total_price
+            .checked_sub(fee_price)
+            .unwrap()
+            .checked_sub(referral_reward)
+            .unwrap(),
+        9,
+        payment_decimals,
+    );
 
-        let create_payment_receiver_ata_cpi_ctx = CpiContext::new(
-            associated_token_program_account_info.clone(),
-            create_payment_receiver_ata_cpi_accounts,
+    if is_native_payment {
+        // transfer payment lamports to payment receiver
+        let transfer_payment_to_payment_receiver_cpi_ctx = CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: ctx.accounts.payment_receiver.to_account_info(),
+            },
         );
 
-        associated_token_create(create_payment_receiver_ata_cpi_ctx)?;
-    };
-
-    // Check payment receiver token account authority
-    let payment_receiver_token_account_unpacked: TokenAccount =
-        TokenAccount::try_deserialize_unchecked(
-            &mut &*payment_receiver_token_account_account_info
-                .data
-                .borrow()
-                .as_ref(),
+        transfer(
+            transfer_payment_to_payment_receiver_cpi_ctx,
+            adjusted_total_price,
         )?;
+    } else {
+        // transfer payment to payment receiver
+        let transfer_payment_to_payment_receiver_cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .user_payment_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            mint: ctx
+                .accounts
+                .payment_token_mint_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            to: ctx
+                .accounts
+                .payment_receiver_token_account
+                .as_ref()
+                .unwrap()
+                .to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
 
-    check_token_account_authority(
-        payment_receiver_token_account_unpacked.owner,
-        payment_receiver_account_info.key(),
-    )?;
+        let transfer_payment_to_payment_receiver_cpi_ctx = CpiContext::new(
+            ctx.accounts.payment_token_program.to_account_info(),
+            transfer_payment_to_payment_receiver_cpi_accounts,
+        );
 
-    // transfer payment to payment receiver
-    let transfer_payment_to_payment_receiver_cpi_accounts = TransferChecked {
-        from: user_payment_token_account_account_info.to_account_info(),
-        mint: ctx.accounts.payment_token_mint_account.to_account_info(),
-        to: payment_receiver_token_account_account_info.to_account_info(),
-        authority: ctx.accounts.user.to_account_info(),
+        transfer_checked(
+            transfer_payment_to_payment_receiver_cpi_ctx,
+            adjusted_total_price,
+            payment_decimals,
+        )?;
     };
 
-    let transfer_payment_to_payment_receiver_cpi_ctx = CpiContext::new(
-        payment_token_program_account_info.clone(),
-        transfer_payment_to_payment_receiver_cpi_accounts,
-    );
-
-    let adjusted_total_price = adjust_amount(
-        total_price.checked_sub(fee_price).unwrap(),
-        9,
-        ctx.accounts.payment_token_mint_account.decimals,
-    );
-
-    transfer_checked(
-        transfer_payment_to_payment_receiver_cpi_ctx,
-        adjusted_total_price,
-        ctx.accounts.payment_token_mint_account.decimals,
-    )?;
-
     // Set Values
     let collection_auction_config: &mut Box<Account<CollectionAuctionAccount>> =
         &mut ctx.accounts.collection_auction_config;
@@ -450,7 +668,7 @@ pub fn handle_buy_collection_asset<'info>(
 
     // check is over sold
     if collection_auction_config.total_supply_sold >= collection_auction_config.total_supply {
-        let boost: f64 = calculate_boost(
+        let boost: i128 = calculate_boost(
             collection_auction_round_config.total_supply_sold,
             collection_auction_config
                 .total_supply
@@ -468,11 +686,19 @@ pub fn handle_buy_collection_asset<'info>(
         collection_auction_round_config.boost = boost;
     };
 
+    // rent of every account created by this buy, for whoever sponsored it
+    let mut rent: u64 = 0;
+
     let user_collection_auction_config: &mut Box<Account<UserCollectionAuctionAccount>> =
         &mut ctx.accounts.user_collection_auction_config;
     if user_collection_auction_config.last_block_timestamp == 0 {
         user_collection_auction_config.user = ctx.accounts.user.key();
         user_collection_auction_config.status = UserAuctionStatus::None;
+        user_collection_auction_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+        user_collection_auction_config.version = USER_COLLECTION_AUCTION_ACCOUNT_VERSION;
+        rent = rent
+            .checked_add(user_collection_auction_config.to_account_info().lamports())
+            .unwrap();
         collection_auction_config.total_user_count = collection_auction_config
             .total_user_count
             .checked_add(1)
@@ -495,11 +721,28 @@ pub fn handle_buy_collection_asset<'info>(
         .checked_add(total_price)
         .unwrap();
 
+    if is_whitelist_round {
+        user_collection_auction_config.total_whitelist_buy_amount = user_collection_auction_config
+            .total_whitelist_buy_amount
+            .checked_add(params.amount)
+            .unwrap();
+    };
+
     let user_collection_auction_round_config: &mut Box<Account<UserCollectionAuctionRoundAccount>> =
         &mut ctx.accounts.user_collection_auction_round_config;
 
     if user_collection_auction_round_config.last_block_timestamp == 0 {
         user_collection_auction_round_config.round = current_round_index;
+        user_collection_auction_round_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+        user_collection_auction_round_config.version =
+            USER_COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION;
+        rent = rent
+            .checked_add(
+                user_collection_auction_round_config
+                    .to_account_info()
+                    .lamports(),
+            )
+            .unwrap();
 
         collection_auction_round_config.total_user_count = collection_auction_round_config
             .total_user_count
@@ -523,6 +766,37 @@ pub fn handle_buy_collection_asset<'info>(
         .checked_add(total_price)
         .unwrap();
 
+    if have_referrer {
+        collection_auction_config.total_referral_reward = collection_auction_config
+            .total_referral_reward
+            .checked_add(referral_reward)
+            .unwrap();
+
+        let referrer_config: &mut Box<Account<ReferrerAccount>> =
+            ctx.accounts.referrer_config.as_mut().unwrap();
+        if referrer_config.last_block_timestamp == 0 {
+            referrer_config.referrer = params.referrer;
+            referrer_config.pad_config = collection_auction_config.key();
+            referrer_config.cream_pad_config = collection_auction_config.cream_pad_config;
+            referrer_config.payment_mint = collection_auction_config.payment_mint;
+            referrer_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+            referrer_config.version = REFERRER_ACCOUNT_VERSION;
+            rent = rent
+                .checked_add(referrer_config.to_account_info().lamports())
+                .unwrap();
+        };
+
+        referrer_config.last_block_timestamp = timestamp;
+        referrer_config.total_referred_buy_count = referrer_config
+            .total_referred_buy_count
+            .checked_add(1)
+            .unwrap();
+        referrer_config.total_reward = referrer_config
+            .total_reward
+            .checked_add(referral_reward)
+            .unwrap();
+    };
+
     // create user_collection_auction_buy_receipt_config PDA
     let user_collection_auction_buy_receipt_config_space: usize =
         UserCollectionAuctionBuyReceiptAccount::space();
@@ -550,7 +824,7 @@ pub fn handle_buy_collection_asset<'info>(
                 .key(),
             user_collection_auction_buy_receipt_config_space_lamports,
             user_collection_auction_buy_receipt_config_space as u64,
-            &ctx.program_id,
+            ctx.program_id,
         );
 
     anchor_lang::solana_program::program::invoke_signed(
@@ -585,11 +859,18 @@ pub fn handle_buy_collection_asset<'info>(
         collection_mint: ctx.accounts.collection_mint_account.key(),
         user: ctx.accounts.user.key(),
         pad_name: params.pad_name.clone(),
+        rent_payer: ctx.accounts.fee_and_rent_payer.key(),
+        version: USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+        reserved: [0; 32],
     };
 
     create_user_collection_auction_buy_receipt
         .serialize(&mut &mut create_user_collection_auction_buy_receipt_data[8..])?;
 
+    rent = rent
+        .checked_add(user_collection_auction_buy_receipt_config_space_lamports)
+        .unwrap();
+
     // Event
     let event: BuyCollectionAssetEvent = BuyCollectionAssetEvent {
         timestamp,
@@ -600,10 +881,19 @@ pub fn handle_buy_collection_asset<'info>(
         price: collection_auction_config.current_price,
         fee: fee_price,
         minting_fee: total_minting_fee,
-        total_price: total_price,
+        total_price,
         current_round: params.current_round_index.clone(),
         user_buy_index: params.buy_index.clone(),
         is_ended_and_sold_out: collection_auction_config.status.eq(&AuctionStatus::SoldOut),
+        is_whitelist_round,
+        referrer: if have_referrer {
+            params.referrer
+        } else {
+            Pubkey::default()
+        },
+        referral_reward,
+        rent_payer: ctx.accounts.fee_and_rent_payer.key(),
+        rent,
     };
 
     emit!(event);
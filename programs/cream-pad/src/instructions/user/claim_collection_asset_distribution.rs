@@ -1,13 +1,15 @@
 use crate::states::{
     CollectionAuctionAccount, CreamPadAccount, UserCollectionAuctionAccount,
     UserCollectionAuctionUnsoldDistributionAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
     USER_COLLECTION_AUCTION_ACCOUNT_PREFIX,
     USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX,
+    USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION,
 };
 use crate::utils::{
-    check_back_authority, check_eligible_for_collection_distribution,
-    check_is_auction_is_distribution, check_is_program_working, check_remaining_supply,
-    check_signer_exist, try_get_remaining_account_info, BASE_POINT,
+    check_account_version, check_back_authority, check_claims_not_paused, check_cream_pad_config,
+    check_eligible_for_collection_distribution, check_is_auction_is_distribution,
+    check_is_program_working, check_remaining_supply, check_signer_exist, BASE_POINT,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -24,6 +26,8 @@ pub struct ClaimCollectionAssetDistributionParams {
     pub pad_name: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub collection_auction_config_bump: u8,
 
     pub user_collection_auction_config_bump: u8,
@@ -38,6 +42,18 @@ pub struct ClaimCollectionAssetDistributionInputAccounts<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -90,24 +106,34 @@ pub fn handle_claim_collection_asset_distribution<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let collection_auction_config: &Box<Account<CollectionAuctionAccount>> =
+    let collection_auction_config: &Account<CollectionAuctionAccount> =
         &ctx.accounts.collection_auction_config;
 
     // Checks
 
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
-    let back_authority_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
+    check_claims_not_paused(
+        cream_pad_config.pause_flags.pause_claims
+            || collection_auction_config.pause_flags.pause_claims,
+    )?;
 
     if cream_pad_config.is_back_authority_required {
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -116,12 +142,12 @@ pub fn handle_claim_collection_asset_distribution<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     check_is_auction_is_distribution(collection_auction_config.status.clone())?;
 
-    let user_collection_auction_config: &Box<Account<UserCollectionAuctionAccount>> =
+    let user_collection_auction_config: &Account<UserCollectionAuctionAccount> =
         &ctx.accounts.user_collection_auction_config;
 
     let user_share_base_point: u64 = user_collection_auction_config
@@ -150,14 +176,17 @@ pub fn handle_claim_collection_asset_distribution<'info>(
 
     // transfer minting fee
 
-    let total_minting_fee: u64 = cream_pad_config
-        .minting_fee
-        .checked_mul(user_share_amount)
-        .unwrap();
+    let minting_fee: u64 = if collection_auction_config.have_fee_override {
+        collection_auction_config.fee_override_minting_fee
+    } else {
+        collection_auction_config.minting_fee
+    };
+
+    let total_minting_fee: u64 = minting_fee.checked_mul(user_share_amount).unwrap();
 
     let transfer_minting_fee_ix = anchor_lang::solana_program::system_instruction::transfer(
         &ctx.accounts.user.key(),
-        &back_authority_account_info.key(),
+        &ctx.accounts.back_authority.key(),
         total_minting_fee,
     );
 
@@ -165,7 +194,7 @@ pub fn handle_claim_collection_asset_distribution<'info>(
         &transfer_minting_fee_ix,
         &[
             ctx.accounts.user.to_account_info(),
-            back_authority_account_info.to_account_info(),
+            ctx.accounts.back_authority.to_account_info(),
         ],
     )?;
 
@@ -197,6 +226,10 @@ pub fn handle_claim_collection_asset_distribution<'info>(
         .user_collection_auction_unsold_distribution_config;
     user_collection_auction_unsold_distribution_config.last_block_timestamp = timestamp;
     user_collection_auction_unsold_distribution_config.amount = user_share_amount;
+    user_collection_auction_unsold_distribution_config.rent_payer =
+        ctx.accounts.fee_and_rent_payer.key();
+    user_collection_auction_unsold_distribution_config.version =
+        USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION;
 
     // Event
     let event: CollectionClaimDistributionEvent = CollectionClaimDistributionEvent {
@@ -205,6 +238,10 @@ pub fn handle_claim_collection_asset_distribution<'info>(
         pad_name: params.pad_name.clone(),
         user: ctx.accounts.user.key(),
         amount: user_share_amount,
+        rent_payer: ctx.accounts.fee_and_rent_payer.key(),
+        rent: user_collection_auction_unsold_distribution_config
+            .to_account_info()
+            .lamports(),
     };
 
     emit!(event);
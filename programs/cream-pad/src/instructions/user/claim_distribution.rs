@@ -1,12 +1,13 @@
 use crate::states::{
     AuctionAccount, CreamPadAccount, UserAuctionAccount, UserAuctionUnsoldDistributionAccount,
-    AUCTION_ACCOUNT_PREFIX, USER_AUCTION_ACCOUNT_PREFIX,
-    USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX,
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
+    USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX,
+    USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION,
 };
 use crate::utils::{
-    adjust_amount, check_back_authority, check_is_auction_is_distribution,
-    check_is_program_working, check_remaining_supply, check_signer_exist,
-    try_get_remaining_account_info, BASE_POINT,
+    adjust_amount, check_account_version, check_back_authority, check_claims_not_paused,
+    check_cream_pad_config, check_is_auction_is_distribution, check_is_program_working,
+    check_remaining_supply, check_signer_exist, BASE_POINT,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
@@ -26,6 +27,8 @@ pub struct ClaimDistributionParams {
     pub pad_name: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub auction_config_bump: u8,
 
     pub user_auction_config_bump: u8,
@@ -39,6 +42,18 @@ pub struct ClaimDistributionInputAccounts<'info> {
 
     pub user: Signer<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -110,24 +125,29 @@ pub fn handle_claim_distribution<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
-
-    let auction_config: &Box<Account<AuctionAccount>> = &ctx.accounts.auction_config;
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
 
     // Checks
 
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
-    if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
+    check_claims_not_paused(
+        cream_pad_config.pause_flags.pause_claims || auction_config.pause_flags.pause_claims,
+    )?;
 
+    if cream_pad_config.is_back_authority_required {
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -136,12 +156,12 @@ pub fn handle_claim_distribution<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
     check_is_auction_is_distribution(auction_config.status.clone())?;
 
-    let user_auction_config: &Box<Account<UserAuctionAccount>> = &ctx.accounts.user_auction_config;
+    let user_auction_config: &Account<UserAuctionAccount> = &ctx.accounts.user_auction_config;
 
     let user_share_base_point: u64 = user_auction_config
         .total_buy_amount
@@ -222,6 +242,9 @@ pub fn handle_claim_distribution<'info>(
     > = &mut ctx.accounts.user_auction_unsold_distribution_config;
     user_auction_unsold_distribution_config.last_block_timestamp = timestamp;
     user_auction_unsold_distribution_config.amount = user_share_amount;
+    user_auction_unsold_distribution_config.rent_payer = ctx.accounts.fee_and_rent_payer.key();
+    user_auction_unsold_distribution_config.version =
+        USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION;
 
     // Event
     let event: ClaimDistributionEvent = ClaimDistributionEvent {
@@ -230,6 +253,10 @@ pub fn handle_claim_distribution<'info>(
         pad_name: params.pad_name.clone(),
         user: ctx.accounts.user.key(),
         amount: user_share_amount,
+        rent_payer: ctx.accounts.fee_and_rent_payer.key(),
+        rent: user_auction_unsold_distribution_config
+            .to_account_info()
+            .lamports(),
     };
 
     emit!(event);
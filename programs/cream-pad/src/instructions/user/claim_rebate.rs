@@ -0,0 +1,266 @@
+use crate::states::{
+    AuctionAccount, CreamPadAccount, UserAuctionAccount, UserAuctionBuyReceiptAccount,
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, AUCTION_VAULT_PREFIX,
+    CREAM_PAD_ACCOUNT_PREFIX, USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    adjust_amount, calculate_total_price, check_account_version, check_back_authority,
+    check_cream_pad_config, check_is_payment_not_settled, check_is_program_working,
+    check_is_rebate_claimed, check_is_uniform_clearing_price, check_payment_mint_account,
+    check_signer_exist,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::events::ClaimRebateEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimRebateParams {
+    pub pad_name: String,
+
+    pub buy_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub auction_vault_config_bump: u8,
+
+    pub user_auction_config_bump: u8,
+
+    pub user_auction_buy_receipt_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ClaimRebateParams)]
+pub struct ClaimRebateInputAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    /// CHECK: auction_vault_config
+    #[account(
+        seeds = [
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        ],
+        bump = params.auction_vault_config_bump,
+    )]
+    pub auction_vault_config: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_auction_config_bump,
+    )]
+    pub user_auction_config: Box<Account<'info, UserAuctionAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_ref(),
+        user_auction_config.key().as_ref(),
+        params.buy_index.as_ref(),
+        ],
+        bump = params.user_auction_buy_receipt_config_bump,
+    )]
+    pub user_auction_buy_receipt_config: Box<Account<'info, UserAuctionBuyReceiptAccount>>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub payment_token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = user,
+        token::token_program = payment_token_program,
+    )]
+    pub user_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = auction_vault_config,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub auction_vault_config_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_claim_rebate<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimRebateInputAccounts<'info>>,
+    params: &ClaimRebateParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    let user_auction_buy_receipt_config: &Account<UserAuctionBuyReceiptAccount> =
+        &ctx.accounts.user_auction_buy_receipt_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_is_uniform_clearing_price(auction_config.have_uniform_clearing_price)?;
+
+    // rebates open once the pad is settled, the settle keeps what they owe in the vault
+    check_is_payment_not_settled(auction_config.is_payment_settled)?;
+
+    check_payment_mint_account(
+        auction_config.payment_mint,
+        ctx.accounts.payment_token_mint_account.key(),
+    )?;
+
+    check_is_rebate_claimed(user_auction_buy_receipt_config.is_rebate_claimed)?;
+
+    let clearing_total_price: u64 = calculate_total_price(
+        user_auction_buy_receipt_config.buy_amount,
+        auction_config.clearing_price,
+        9,
+        9,
+        9,
+    );
+
+    // at 9 decimals, the way the settle reserved it
+    let rebate: u64 = adjust_amount(
+        user_auction_buy_receipt_config
+            .payment
+            .saturating_sub(clearing_total_price),
+        9,
+        ctx.accounts.payment_token_mint_account.decimals,
+    );
+
+    // Transfers
+
+    if rebate > 0 {
+        // transfer rebate to user
+        let auction_vault_config_bump_bytes = params.auction_vault_config_bump.to_le_bytes();
+        let auction_config_key: Pubkey = ctx.accounts.auction_config.key();
+
+        let signer_seeds: &[&[&[u8]]] = &[&[
+            AUCTION_VAULT_PREFIX.as_ref(),
+            auction_config_key.as_ref(),
+            auction_vault_config_bump_bytes.as_ref(),
+        ]];
+
+        let transfer_rebate_to_user_cpi_accounts = TransferChecked {
+            from: ctx
+                .accounts
+                .auction_vault_config_payment_token_account
+                .to_account_info(),
+            mint: ctx.accounts.payment_token_mint_account.to_account_info(),
+            to: ctx.accounts.user_payment_token_account.to_account_info(),
+            authority: ctx.accounts.auction_vault_config.to_account_info(),
+        };
+
+        let transfer_rebate_to_user_cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.payment_token_program.to_account_info(),
+            transfer_rebate_to_user_cpi_accounts,
+            signer_seeds,
+        );
+
+        transfer_checked(
+            transfer_rebate_to_user_cpi_ctx,
+            rebate,
+            ctx.accounts.payment_token_mint_account.decimals,
+        )?;
+    };
+
+    let adjusted_back_rebate: u64 =
+        adjust_amount(rebate, ctx.accounts.payment_token_mint_account.decimals, 9);
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+    auction_config.total_rebate = auction_config
+        .total_rebate
+        .checked_add(adjusted_back_rebate)
+        .unwrap();
+    auction_config.total_rebate_claimed_count = auction_config
+        .total_rebate_claimed_count
+        .checked_add(1)
+        .unwrap();
+
+    let user_auction_buy_receipt_config: &mut Box<Account<UserAuctionBuyReceiptAccount>> =
+        &mut ctx.accounts.user_auction_buy_receipt_config;
+    user_auction_buy_receipt_config.last_block_timestamp = timestamp;
+    user_auction_buy_receipt_config.is_rebate_claimed = true;
+
+    // Event
+    let event: ClaimRebateEvent = ClaimRebateEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        user: ctx.accounts.user.key(),
+        user_buy_index: params.buy_index.clone(),
+        clearing_price: auction_config.clearing_price,
+        rebate: adjusted_back_rebate,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,213 @@
+use crate::states::{
+    AuctionAccount, CollectionAuctionAccount, CreamPadAccount, PauseFlags, ReferrerAccount,
+    CREAM_PAD_ACCOUNT_PREFIX, REFERRER_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    adjust_amount, check_back_authority, check_claims_not_paused, check_cream_pad_config,
+    check_is_program_working, check_payment_mint_account, check_referral_rewards,
+    check_signer_exist,
+};
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::{prelude::*, Discriminator};
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::events::ClaimReferralRewardsEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimReferralRewardsParams {
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub referrer_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ClaimReferralRewardsParams)]
+pub struct ClaimReferralRewardsInputAccounts<'info> {
+    pub referrer: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        REFERRER_ACCOUNT_PREFIX.as_ref(),
+        referrer_config.pad_config.as_ref(),
+        referrer.key().as_ref(),
+        ],
+        bump = params.referrer_config_bump,
+    )]
+    pub referrer_config: Box<Account<'info, ReferrerAccount>>,
+
+    /// CHECK: pad_config, the auction config or collection auction config of the referrer config
+    #[account(address = referrer_config.pad_config)]
+    pub pad_config: AccountInfo<'info>,
+
+    pub payment_token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = referrer_config,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub referrer_config_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = referrer,
+        token::token_program = payment_token_program,
+    )]
+    pub referrer_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_claim_referral_rewards<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimReferralRewardsInputAccounts<'info>>,
+    params: &ClaimReferralRewardsParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let referrer_config: &Account<ReferrerAccount> = &ctx.accounts.referrer_config;
+
+    // Checks
+
+    check_cream_pad_config(
+        referrer_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    // referrer configs of token and collection pads share this instruction
+    let pad_pause_flags: PauseFlags = {
+        let data = ctx.accounts.pad_config.try_borrow_data()?;
+
+        if data.starts_with(&AuctionAccount::discriminator()) {
+            AuctionAccount::try_deserialize(&mut &data[..])?.pause_flags
+        } else {
+            CollectionAuctionAccount::try_deserialize(&mut &data[..])?.pause_flags
+        }
+    };
+
+    check_claims_not_paused(
+        cream_pad_config.pause_flags.pause_claims || pad_pause_flags.pause_claims,
+    )?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_payment_mint_account(
+        referrer_config.payment_mint,
+        ctx.accounts.payment_token_mint_account.key(),
+    )?;
+
+    let claimable_reward: u64 = referrer_config
+        .total_reward
+        .saturating_sub(referrer_config.total_claimed_reward);
+
+    check_referral_rewards(claimable_reward)?;
+
+    let adjusted_claimable_reward: u64 = adjust_amount(
+        claimable_reward,
+        9,
+        ctx.accounts.payment_token_mint_account.decimals,
+    )
+    .min(ctx.accounts.referrer_config_payment_token_account.amount);
+
+    // Transfers
+
+    // transfer referral rewards to referrer
+    let referrer_config_bump_bytes = params.referrer_config_bump.to_le_bytes();
+    let pad_config_key: Pubkey = referrer_config.pad_config;
+    let referrer_key: Pubkey = ctx.accounts.referrer.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        REFERRER_ACCOUNT_PREFIX.as_ref(),
+        pad_config_key.as_ref(),
+        referrer_key.as_ref(),
+        referrer_config_bump_bytes.as_ref(),
+    ]];
+
+    let transfer_referral_rewards_to_referrer_cpi_accounts = TransferChecked {
+        from: ctx
+            .accounts
+            .referrer_config_payment_token_account
+            .to_account_info(),
+        mint: ctx.accounts.payment_token_mint_account.to_account_info(),
+        to: ctx
+            .accounts
+            .referrer_payment_token_account
+            .to_account_info(),
+        authority: ctx.accounts.referrer_config.to_account_info(),
+    };
+
+    let transfer_referral_rewards_to_referrer_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.payment_token_program.to_account_info(),
+        transfer_referral_rewards_to_referrer_cpi_accounts,
+        signer_seeds,
+    );
+
+    transfer_checked(
+        transfer_referral_rewards_to_referrer_cpi_ctx,
+        adjusted_claimable_reward,
+        ctx.accounts.payment_token_mint_account.decimals,
+    )?;
+
+    // Set Values
+    let referrer_config: &mut Box<Account<ReferrerAccount>> = &mut ctx.accounts.referrer_config;
+    referrer_config.last_block_timestamp = timestamp;
+    referrer_config.total_claimed_reward = referrer_config
+        .total_claimed_reward
+        .checked_add(claimable_reward)
+        .unwrap();
+
+    // Event
+    let event: ClaimReferralRewardsEvent = ClaimReferralRewardsEvent {
+        timestamp,
+        referrer: ctx.accounts.referrer.key(),
+        pad_config: referrer_config.pad_config,
+        amount: claimable_reward,
+        total_claimed_reward: referrer_config.total_claimed_reward,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -0,0 +1,238 @@
+use crate::math::calculate_vested_amount;
+use crate::states::{
+    AuctionAccount, CreamPadAccount, UserAuctionAccount, UserAuctionVestingAccount,
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
+    USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_VESTING_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    adjust_amount, check_account_version, check_back_authority, check_cream_pad_config,
+    check_is_program_working, check_is_vesting, check_is_vesting_started, check_signer_exist,
+    check_vested_amount,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::events::ClaimVestedEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ClaimVestedParams {
+    pub pad_name: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub user_auction_config_bump: u8,
+
+    pub user_auction_vesting_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: ClaimVestedParams)]
+pub struct ClaimVestedInputAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    #[account(
+        seeds = [
+        USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_auction_config_bump,
+    )]
+    pub user_auction_config: Box<Account<'info, UserAuctionAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_AUCTION_VESTING_ACCOUNT_PREFIX.as_ref(),
+        user_auction_config.key().as_ref(),
+        ],
+        bump = params.user_auction_vesting_config_bump,
+    )]
+    pub user_auction_vesting_config: Box<Account<'info, UserAuctionVestingAccount>>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = auction_config,
+        associated_token::token_program = token_program,
+    )]
+    pub auction_config_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint_account,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_claim_vested<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimVestedInputAccounts<'info>>,
+    params: &ClaimVestedParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    let user_auction_vesting_config: &Account<UserAuctionVestingAccount> =
+        &ctx.accounts.user_auction_vesting_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_is_vesting(auction_config.have_vesting)?;
+
+    check_is_vesting_started(
+        auction_config.status.clone(),
+        auction_config.vesting_start_at,
+    )?;
+
+    let vested_amount: u64 = calculate_vested_amount(
+        user_auction_vesting_config.total_amount,
+        auction_config.vesting_tge_base_point,
+        auction_config.vesting_start_at,
+        auction_config.vesting_cliff_duration,
+        auction_config.vesting_duration,
+        timestamp,
+    );
+
+    let claimable_amount: u64 =
+        vested_amount.saturating_sub(user_auction_vesting_config.total_claimed_amount);
+
+    check_vested_amount(claimable_amount)?;
+
+    let adjusted_claimable_amount: u64 = adjust_amount(
+        claimable_amount,
+        9,
+        ctx.accounts.token_mint_account.decimals,
+    );
+
+    // Transfers
+
+    // transfer vested token to user
+    let auction_config_bump_bytes = params.auction_config_bump.to_le_bytes();
+    let token_mint_account_key = ctx.accounts.token_mint_account.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account_key.as_ref(),
+        auction_config_bump_bytes.as_ref(),
+    ]];
+
+    let transfer_token_to_user_cpi_accounts = TransferChecked {
+        from: ctx.accounts.auction_config_token_account.to_account_info(),
+        mint: ctx.accounts.token_mint_account.to_account_info(),
+        to: ctx.accounts.user_token_account.to_account_info(),
+        authority: ctx.accounts.auction_config.to_account_info(),
+    };
+
+    let transfer_token_to_user_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        transfer_token_to_user_cpi_accounts,
+        signer_seeds,
+    );
+
+    transfer_checked(
+        transfer_token_to_user_cpi_ctx,
+        adjusted_claimable_amount,
+        ctx.accounts.token_mint_account.decimals,
+    )?;
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+    auction_config.total_vesting_claimed = auction_config
+        .total_vesting_claimed
+        .checked_add(claimable_amount)
+        .unwrap();
+
+    let user_auction_vesting_config: &mut Box<Account<UserAuctionVestingAccount>> =
+        &mut ctx.accounts.user_auction_vesting_config;
+    user_auction_vesting_config.last_block_timestamp = timestamp;
+    user_auction_vesting_config.total_claimed_amount = user_auction_vesting_config
+        .total_claimed_amount
+        .checked_add(claimable_amount)
+        .unwrap();
+
+    // Event
+    let event: ClaimVestedEvent = ClaimVestedEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        user: ctx.accounts.user.key(),
+        amount: claimable_amount,
+        total_claimed_amount: user_auction_vesting_config.total_claimed_amount,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
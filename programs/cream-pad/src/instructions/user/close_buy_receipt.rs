@@ -0,0 +1,174 @@
+use crate::events::CloseBuyReceiptEvent;
+use crate::states::{
+    AuctionAccount, CreamPadAccount, UserAuctionAccount, UserAuctionBuyReceiptAccount,
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
+    USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
+    USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+};
+use crate::utils::{
+    check_account_version, check_back_authority, check_cream_pad_config, check_is_auction_finished,
+    check_is_program_working, check_is_rebate_not_claimed, check_rent_payer, check_signer_exist,
+    get_rent_payer,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CloseBuyReceiptParams {
+    pub pad_name: String,
+
+    pub buy_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub user_auction_config_bump: u8,
+
+    pub user_auction_buy_receipt_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CloseBuyReceiptParams)]
+pub struct CloseBuyReceiptInputAccounts<'info> {
+    /// anyone, the rent always goes back to the rent payer
+    pub closer: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_auction_config_bump,
+    )]
+    pub user_auction_config: Box<Account<'info, UserAuctionAccount>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+        USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_ref(),
+        user_auction_config.key().as_ref(),
+        params.buy_index.as_ref(),
+        ],
+        bump = params.user_auction_buy_receipt_config_bump,
+    )]
+    pub user_auction_buy_receipt_config: Box<Account<'info, UserAuctionBuyReceiptAccount>>,
+
+    /// CHECK: rent_payer, checked against the receipt
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_close_buy_receipt<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseBuyReceiptInputAccounts<'info>>,
+    params: &CloseBuyReceiptParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    let user_auction_buy_receipt_config: &Account<UserAuctionBuyReceiptAccount> =
+        &ctx.accounts.user_auction_buy_receipt_config;
+
+    // Checks
+
+    check_account_version(auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_account_version(
+        user_auction_buy_receipt_config.version,
+        USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(auction_config.cream_pad_config, cream_pad_config.key())?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_is_auction_finished(
+        auction_config.status.clone(),
+        auction_config.have_payment_escrow,
+        auction_config.is_payment_settled,
+    )?;
+
+    // the receipt is what proves the rebate, keep it until it is claimed
+    if auction_config.have_uniform_clearing_price {
+        check_is_rebate_not_claimed(user_auction_buy_receipt_config.is_rebate_claimed)?;
+    }
+
+    let rent_payer: Pubkey = get_rent_payer(
+        user_auction_buy_receipt_config.rent_payer,
+        ctx.accounts.user.key(),
+    );
+
+    check_rent_payer(rent_payer, ctx.accounts.rent_payer.key())?;
+
+    // Event
+    let event: CloseBuyReceiptEvent = CloseBuyReceiptEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        user: ctx.accounts.user.key(),
+        user_buy_index: params.buy_index.clone(),
+        rent_payer,
+        rent: user_auction_buy_receipt_config.to_account_info().lamports(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
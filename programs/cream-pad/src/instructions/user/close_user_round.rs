@@ -0,0 +1,181 @@
+use crate::events::CloseUserRoundEvent;
+use crate::states::{
+    AuctionAccount, CreamPadAccount, UserAuctionAccount, UserAuctionRoundAccount,
+    AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION, AUCTION_ROUND_ACCOUNT_PREFIX,
+    CREAM_PAD_ACCOUNT_PREFIX, USER_AUCTION_ACCOUNT_PREFIX, USER_AUCTION_ROUND_ACCOUNT_PREFIX,
+    USER_AUCTION_ROUND_ACCOUNT_VERSION,
+};
+use crate::utils::{
+    check_account_version, check_back_authority, check_cream_pad_config, check_is_auction_finished,
+    check_is_program_working, check_rent_payer, check_signer_exist, get_rent_payer,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_spl::token_interface::Mint;
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CloseUserRoundParams {
+    pub pad_name: String,
+
+    pub round_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub user_auction_config_bump: u8,
+
+    pub auction_round_config_bump: u8,
+
+    pub user_auction_round_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: CloseUserRoundParams)]
+pub struct CloseUserRoundInputAccounts<'info> {
+    /// anyone, the rent always goes back to the rent payer
+    pub closer: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    /// CHECK: auction_round_config, may already be closed
+    #[account(
+        seeds = [
+        AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        params.round_index.as_ref(),
+        ],
+        bump = params.auction_round_config_bump,
+    )]
+    pub auction_round_config: AccountInfo<'info>,
+
+    /// CHECK: user
+    pub user: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_auction_config_bump,
+    )]
+    pub user_auction_config: Box<Account<'info, UserAuctionAccount>>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [
+        USER_AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+        auction_round_config.key().as_ref(),
+        user_auction_config.key().as_ref(),
+        ],
+        bump = params.user_auction_round_config_bump,
+    )]
+    pub user_auction_round_config: Box<Account<'info, UserAuctionRoundAccount>>,
+
+    /// CHECK: rent_payer, checked against the user round
+    #[account(mut)]
+    pub rent_payer: AccountInfo<'info>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_close_user_round<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CloseUserRoundInputAccounts<'info>>,
+    params: &CloseUserRoundParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    let user_auction_round_config: &Account<UserAuctionRoundAccount> =
+        &ctx.accounts.user_auction_round_config;
+
+    // Checks
+
+    check_account_version(auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_account_version(
+        user_auction_round_config.version,
+        USER_AUCTION_ROUND_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(auction_config.cream_pad_config, cream_pad_config.key())?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_is_auction_finished(
+        auction_config.status.clone(),
+        auction_config.have_payment_escrow,
+        auction_config.is_payment_settled,
+    )?;
+
+    let rent_payer: Pubkey = get_rent_payer(
+        user_auction_round_config.rent_payer,
+        ctx.accounts.user.key(),
+    );
+
+    check_rent_payer(rent_payer, ctx.accounts.rent_payer.key())?;
+
+    // Event
+    let event: CloseUserRoundEvent = CloseUserRoundEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        user: ctx.accounts.user.key(),
+        round_index: params.round_index.clone(),
+        rent_payer,
+        rent: user_auction_round_config.to_account_info().lamports(),
+    };
+
+    emit!(event);
+
+    Ok(())
+}
@@ -2,26 +2,26 @@ use crate::events::FillBoughtCollectionAssetEvent;
 use crate::states::{
     CollectionAuctionAccount, CreamPadAccount, UserCollectionAuctionAccount,
     UserCollectionAuctionBuyReceiptAccount, COLLECTION_AUCTION_ACCOUNT_PREFIX,
+    COLLECTION_AUCTION_ACCOUNT_VERSION, CREAM_PAD_ACCOUNT_PREFIX,
     USER_COLLECTION_AUCTION_ACCOUNT_PREFIX, USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX,
 };
 use crate::utils::{
-    check_back_authority, check_is_exceeding_end_index, check_is_program_working,
-    check_is_receipt_full, check_program_id, check_signer_exist, try_get_remaining_account_info,
+    check_account_version, check_back_authority, check_cream_pad_config,
+    check_is_exceeding_end_index, check_is_program_working, check_is_receipt_full,
+    check_signer_exist,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
 use anchor_lang::solana_program::sysvar::instructions::{
     load_current_index_checked, load_instruction_at_checked,
 };
-use anchor_spl::associated_token::{
-    create as associated_token_create, AssociatedToken, Create as AssociatedTokenCreate,
-};
+use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::metadata::{
     create_master_edition_v3, create_metadata_accounts_v3,
     mpl_token_metadata::types::{Collection, Creator, DataV2},
     verify_collection, CreateMasterEditionV3, CreateMetadataAccountsV3, Metadata, VerifyCollection,
 };
-use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenInterface};
+use anchor_spl::token_interface::{mint_to, Mint, MintTo, TokenAccount, TokenInterface};
 
 #[repr(C)]
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -33,6 +33,8 @@ pub struct FillBoughtCollectionAssetInputParams {
     pub buy_index: String,
 
     // Bumps
+    pub cream_pad_config_bump: u8,
+
     pub collection_auction_config_bump: u8,
 
     pub user_collection_auction_config_bump: u8,
@@ -49,6 +51,18 @@ pub struct FillBoughtCollectionAssetInputAccounts<'info> {
     /// CHECK: user
     pub user: AccountInfo<'info>,
 
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
     #[account(
         mut,
         seeds = [
@@ -100,6 +114,69 @@ pub struct FillBoughtCollectionAssetInputAccounts<'info> {
     )]
     pub asset_mint_account: Box<InterfaceAccount<'info, Mint>>,
 
+    /// CHECK: collection_metadata, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        collection_mint_account.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_metadata: AccountInfo<'info>,
+
+    /// CHECK: collection_master_edition, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        collection_mint_account.key().as_ref(),
+        b"edition",
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub collection_master_edition: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = fee_and_rent_payer,
+        associated_token::mint = asset_mint_account,
+        associated_token::authority = user,
+        associated_token::token_program = token_program,
+    )]
+    pub user_asset_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: asset_metadata, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        asset_mint_account.key().as_ref(),
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub asset_metadata: AccountInfo<'info>,
+
+    /// CHECK: asset_master_edition, checked by the token metadata program
+    #[account(
+        mut,
+        seeds = [
+        b"metadata",
+        token_metadata_program.key().as_ref(),
+        asset_mint_account.key().as_ref(),
+        b"edition",
+        ],
+        bump,
+        seeds::program = token_metadata_program.key(),
+    )]
+    pub asset_master_edition: AccountInfo<'info>,
+
     pub token_program: Interface<'info, TokenInterface>,
 
     pub associated_token_program: Program<'info, AssociatedToken>,
@@ -121,33 +198,33 @@ pub fn handle_fill_bought_collection_asset<'info>(
 ) -> Result<()> {
     let timestamp = Clock::get().unwrap().unix_timestamp;
 
-    let collection_auction_config: &Box<Account<CollectionAuctionAccount>> =
+    let collection_auction_config: &Account<CollectionAuctionAccount> =
         &ctx.accounts.collection_auction_config;
 
-    let user_collection_auction_buy_receipt_config: &Box<
-        Account<UserCollectionAuctionBuyReceiptAccount>,
+    let user_collection_auction_buy_receipt_config: &Account<
+        UserCollectionAuctionBuyReceiptAccount,
     > = &ctx.accounts.user_collection_auction_buy_receipt_config;
 
-    let cream_pad_config_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 0)?;
-
-    let cream_pad_config: Account<CreamPadAccount> =
-        Account::try_from(cream_pad_config_account_info)?;
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
 
     // Checks
-    check_program_id(
-        cream_pad_config_account_info.owner.key(),
-        ctx.program_id.key(),
+
+    check_account_version(
+        ctx.accounts.collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION,
+    )?;
+
+    check_cream_pad_config(
+        ctx.accounts.collection_auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
     )?;
 
     check_is_program_working(cream_pad_config.program_status.clone())?;
 
     if cream_pad_config.is_back_authority_required {
-        let back_authority_account_info =
-            try_get_remaining_account_info(ctx.remaining_accounts, 1)?;
-
         check_back_authority(
             cream_pad_config.back_authority,
-            back_authority_account_info.key(),
+            ctx.accounts.back_authority.key(),
         )?;
 
         let instruction_index =
@@ -156,23 +233,9 @@ pub fn handle_fill_bought_collection_asset<'info>(
         let instruction: Instruction =
             load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
 
-        check_signer_exist(instruction, back_authority_account_info.key())?;
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
     };
 
-    let collection_metadata_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 2)?;
-
-    let collection_master_edition_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 3)?;
-
-    let user_asset_token_account_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 4)?;
-
-    let asset_metadata_account_info = try_get_remaining_account_info(ctx.remaining_accounts, 5)?;
-
-    let asset_master_edition_account_info =
-        try_get_remaining_account_info(ctx.remaining_accounts, 6)?;
-
     check_is_exceeding_end_index(
         collection_auction_config
             .current_index
@@ -208,7 +271,7 @@ pub fn handle_fill_bought_collection_asset<'info>(
 
     let mut creators_option: Vec<Creator> = Vec::new();
 
-    if collection_auction_config.asset_creators.len() > 0 {
+    if !collection_auction_config.asset_creators.is_empty() {
         for creator in &collection_auction_config.asset_creators {
             creators_option.push(Creator {
                 verified: false,
@@ -225,7 +288,7 @@ pub fn handle_fill_bought_collection_asset<'info>(
         symbol: collection_auction_config.asset_symbol.clone(),
         uri: asset_url.clone(),
         seller_fee_basis_points: collection_auction_config.seller_fee_basis_points,
-        creators: creators,
+        creators,
         collection: Some(Collection {
             verified: false,
             key: ctx.accounts.collection_mint_account.key(),
@@ -233,23 +296,6 @@ pub fn handle_fill_bought_collection_asset<'info>(
         uses: None,
     };
 
-    // Create treasury asset token account
-    let create_treasury_asset_ata_cpi_accounts = AssociatedTokenCreate {
-        payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-        associated_token: user_asset_token_account_account_info.to_account_info(),
-        authority: ctx.accounts.user.to_account_info(),
-        mint: ctx.accounts.asset_mint_account.to_account_info(),
-        system_program: ctx.accounts.system_program.to_account_info(),
-        token_program: ctx.accounts.token_program.to_account_info(),
-    };
-
-    let create_treasury_asset_ata_cpi_ctx = CpiContext::new(
-        ctx.accounts.associated_token_program.to_account_info(),
-        create_treasury_asset_ata_cpi_accounts,
-    );
-
-    associated_token_create(create_treasury_asset_ata_cpi_ctx)?;
-
     let collection_auction_config_bump_bytes = params.collection_auction_config_bump.to_le_bytes();
     let collection_mint_account_key = ctx.accounts.collection_mint_account.key();
 
@@ -265,7 +311,7 @@ pub fn handle_fill_bought_collection_asset<'info>(
         ctx.accounts.token_program.to_account_info(),
         MintTo {
             mint: ctx.accounts.asset_mint_account.to_account_info(),
-            to: user_asset_token_account_account_info.to_account_info(),
+            to: ctx.accounts.user_asset_token_account.to_account_info(),
             authority: ctx.accounts.collection_auction_config.to_account_info(),
         },
         signer_seeds,
@@ -276,7 +322,7 @@ pub fn handle_fill_bought_collection_asset<'info>(
     let create_metadata_accounts_v3_cpi_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_metadata_program.to_account_info(),
         CreateMetadataAccountsV3 {
-            metadata: asset_metadata_account_info.to_account_info(), // the metadata account being created
+            metadata: ctx.accounts.asset_metadata.to_account_info(), // the metadata account being created
             mint: ctx.accounts.asset_mint_account.to_account_info(), // the mint account of the metadata account
             mint_authority: ctx.accounts.collection_auction_config.to_account_info(), // the mint authority of the mint account
             update_authority: ctx.accounts.collection_auction_config.to_account_info(), // the update authority of the metadata account
@@ -299,12 +345,12 @@ pub fn handle_fill_bought_collection_asset<'info>(
     let create_master_edition_v3_cpi_context = CpiContext::new_with_signer(
         ctx.accounts.token_metadata_program.to_account_info(),
         CreateMasterEditionV3 {
-            edition: asset_master_edition_account_info.to_account_info(),
+            edition: ctx.accounts.asset_master_edition.to_account_info(),
             mint: ctx.accounts.asset_mint_account.to_account_info(),
             update_authority: ctx.accounts.collection_auction_config.to_account_info(),
             mint_authority: ctx.accounts.collection_auction_config.to_account_info(),
             payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-            metadata: asset_metadata_account_info.to_account_info(),
+            metadata: ctx.accounts.asset_metadata.to_account_info(),
             token_program: ctx.accounts.token_program.to_account_info(),
             system_program: ctx.accounts.system_program.to_account_info(),
             rent: ctx.accounts.rent.to_account_info(),
@@ -319,11 +365,11 @@ pub fn handle_fill_bought_collection_asset<'info>(
         ctx.accounts.token_metadata_program.to_account_info(),
         VerifyCollection {
             payer: ctx.accounts.fee_and_rent_payer.to_account_info(),
-            metadata: asset_metadata_account_info.to_account_info(),
+            metadata: ctx.accounts.asset_metadata.to_account_info(),
             collection_authority: ctx.accounts.collection_auction_config.to_account_info(),
             collection_mint: ctx.accounts.collection_mint_account.to_account_info(),
-            collection_metadata: collection_metadata_account_info.to_account_info(),
-            collection_master_edition: collection_master_edition_account_info.to_account_info(),
+            collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
+            collection_master_edition: ctx.accounts.collection_master_edition.to_account_info(),
         },
         signer_seeds,
     );
@@ -1,15 +1,32 @@
 pub use buy::*;
 pub use claim_distribution::*;
+pub use refund::*;
+pub use claim_rebate::*;
+pub use claim_vested::*;
+pub use claim_referral_rewards::*;
 pub use buy_collection_asset::*;
 pub use fill_bought_collection_asset::*;
 pub use claim_collection_asset_distribution::*;
 pub use fill_claimed_collection_asset_distribution::*;
+pub use close_buy_receipt::*;
+pub use close_user_round::*;
 
 pub mod buy;
 
 pub mod claim_distribution;
 
+pub mod refund;
+
+pub mod claim_rebate;
+
+pub mod claim_vested;
+
+pub mod claim_referral_rewards;
+
 pub mod buy_collection_asset;
 pub mod fill_bought_collection_asset;
 pub mod claim_collection_asset_distribution;
-pub mod fill_claimed_collection_asset_distribution;
\ No newline at end of file
+pub mod fill_claimed_collection_asset_distribution;
+
+pub mod close_buy_receipt;
+pub mod close_user_round;
\ No newline at end of file
@@ -0,0 +1,334 @@
+use crate::states::{
+    AuctionAccount, CreamPadAccount, UserAuctionAccount, UserAuctionBuyReceiptAccount,
+    UserAuctionVestingAccount, AUCTION_ACCOUNT_PREFIX, AUCTION_ACCOUNT_VERSION,
+    AUCTION_VAULT_PREFIX, CREAM_PAD_ACCOUNT_PREFIX, USER_AUCTION_ACCOUNT_PREFIX,
+    USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX, USER_AUCTION_VESTING_ACCOUNT_PREFIX,
+};
+use crate::utils::{
+    adjust_amount, check_account_exist, check_account_version, check_back_authority,
+    check_cream_pad_config, check_is_auction_cancelled, check_is_payment_escrowed,
+    check_is_program_working, check_is_receipt_refunded, check_payment_mint_account,
+    check_signer_exist,
+};
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::events::RefundEvent;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+#[repr(C)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RefundParams {
+    pub pad_name: String,
+
+    pub buy_index: String,
+
+    // Bumps
+    pub cream_pad_config_bump: u8,
+
+    pub auction_config_bump: u8,
+
+    pub auction_vault_config_bump: u8,
+
+    pub user_auction_config_bump: u8,
+
+    pub user_auction_buy_receipt_config_bump: u8,
+}
+
+#[derive(Accounts)]
+#[instruction(params: RefundParams)]
+pub struct RefundInputAccounts<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [
+        CREAM_PAD_ACCOUNT_PREFIX.as_ref(),
+        cream_pad_config.tenant_id.as_ref(),
+        ],
+        bump = params.cream_pad_config_bump,
+    )]
+    pub cream_pad_config: Box<Account<'info, CreamPadAccount>>,
+
+    /// CHECK: back_authority, has to sign when the cream pad config requires it
+    pub back_authority: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+        AUCTION_ACCOUNT_PREFIX.as_ref(),
+        params.pad_name.as_ref(),
+        token_mint_account.key().as_ref(),
+        ],
+        bump = params.auction_config_bump,
+    )]
+    pub auction_config: Box<Account<'info, AuctionAccount>>,
+
+    /// CHECK: auction_vault_config
+    #[account(
+        seeds = [
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        ],
+        bump = params.auction_vault_config_bump,
+    )]
+    pub auction_vault_config: AccountInfo<'info>,
+
+    #[account(
+        seeds = [
+        USER_AUCTION_ACCOUNT_PREFIX.as_ref(),
+        auction_config.key().as_ref(),
+        user.key().as_ref(),
+        ],
+        bump = params.user_auction_config_bump,
+    )]
+    pub user_auction_config: Box<Account<'info, UserAuctionAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX.as_ref(),
+        user_auction_config.key().as_ref(),
+        params.buy_index.as_ref(),
+        ],
+        bump = params.user_auction_buy_receipt_config_bump,
+    )]
+    pub user_auction_buy_receipt_config: Box<Account<'info, UserAuctionBuyReceiptAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+        USER_AUCTION_VESTING_ACCOUNT_PREFIX.as_ref(),
+        user_auction_config.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub user_auction_vesting_config: Option<Box<Account<'info, UserAuctionVestingAccount>>>,
+
+    pub token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    pub payment_token_mint_account: Box<InterfaceAccount<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = token_mint_account,
+        token::authority = user,
+        token::token_program = token_program,
+    )]
+    pub user_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = payment_token_mint_account,
+        token::authority = user,
+        token::token_program = payment_token_program,
+    )]
+    pub user_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = token_mint_account,
+        associated_token::authority = auction_config,
+        associated_token::token_program = token_program,
+    )]
+    pub auction_config_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = payment_token_mint_account,
+        associated_token::authority = auction_vault_config,
+        associated_token::token_program = payment_token_program,
+    )]
+    pub auction_vault_config_payment_token_account: Box<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub payment_token_program: Interface<'info, TokenInterface>,
+
+    /// CHECK: instructions_sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+pub fn handle_refund<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RefundInputAccounts<'info>>,
+    params: &RefundParams,
+) -> Result<()> {
+    let timestamp = Clock::get().unwrap().unix_timestamp;
+
+    let cream_pad_config: &Account<CreamPadAccount> = &ctx.accounts.cream_pad_config;
+
+    let auction_config: &Account<AuctionAccount> = &ctx.accounts.auction_config;
+
+    let user_auction_buy_receipt_config: &Account<UserAuctionBuyReceiptAccount> =
+        &ctx.accounts.user_auction_buy_receipt_config;
+
+    // Checks
+
+    check_account_version(ctx.accounts.auction_config.version, AUCTION_ACCOUNT_VERSION)?;
+
+    check_cream_pad_config(
+        ctx.accounts.auction_config.cream_pad_config,
+        ctx.accounts.cream_pad_config.key(),
+    )?;
+
+    check_is_program_working(cream_pad_config.program_status.clone())?;
+
+    if cream_pad_config.is_back_authority_required {
+        check_back_authority(
+            cream_pad_config.back_authority,
+            ctx.accounts.back_authority.key(),
+        )?;
+
+        let instruction_index =
+            load_current_index_checked(&ctx.accounts.instructions_sysvar.to_account_info())?
+                as usize;
+        let instruction: Instruction =
+            load_instruction_at_checked(instruction_index, &ctx.accounts.instructions_sysvar)?;
+
+        check_signer_exist(instruction, ctx.accounts.back_authority.key())?;
+    };
+
+    check_is_auction_cancelled(auction_config.status.clone())?;
+
+    check_is_payment_escrowed(auction_config.have_payment_escrow)?;
+
+    check_payment_mint_account(
+        auction_config.payment_mint,
+        ctx.accounts.payment_token_mint_account.key(),
+    )?;
+
+    check_is_receipt_refunded(user_auction_buy_receipt_config.is_refunded)?;
+
+    // tokens still locked in vesting never left the auction, only the claimed part is returned
+    let mut locked_amount: u64 = 0;
+    if auction_config.have_vesting {
+        check_account_exist(ctx.accounts.user_auction_vesting_config.is_some())?;
+
+        let user_auction_vesting_config: &Account<UserAuctionVestingAccount> =
+            ctx.accounts.user_auction_vesting_config.as_ref().unwrap();
+
+        locked_amount = user_auction_buy_receipt_config.buy_amount.min(
+            user_auction_vesting_config
+                .total_amount
+                .checked_sub(user_auction_vesting_config.total_claimed_amount)
+                .unwrap(),
+        );
+    };
+
+    let adjusted_amount: u64 = adjust_amount(
+        user_auction_buy_receipt_config
+            .buy_amount
+            .checked_sub(locked_amount)
+            .unwrap(),
+        9,
+        ctx.accounts.token_mint_account.decimals,
+    );
+
+    let adjusted_payment: u64 = adjust_amount(
+        user_auction_buy_receipt_config.payment,
+        9,
+        ctx.accounts.payment_token_mint_account.decimals,
+    );
+
+    // Transfers
+
+    // return bought token to the auction
+    if adjusted_amount > 0 {
+        let transfer_token_to_auction_cpi_accounts = TransferChecked {
+            from: ctx.accounts.user_token_account.to_account_info(),
+            mint: ctx.accounts.token_mint_account.to_account_info(),
+            to: ctx.accounts.auction_config_token_account.to_account_info(),
+            authority: ctx.accounts.user.to_account_info(),
+        };
+
+        let transfer_token_to_auction_cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            transfer_token_to_auction_cpi_accounts,
+        );
+
+        transfer_checked(
+            transfer_token_to_auction_cpi_ctx,
+            adjusted_amount,
+            ctx.accounts.token_mint_account.decimals,
+        )?;
+    };
+
+    // refund escrowed payment to user
+    let auction_vault_config_bump_bytes = params.auction_vault_config_bump.to_le_bytes();
+    let auction_config_key: Pubkey = ctx.accounts.auction_config.key();
+
+    let signer_seeds: &[&[&[u8]]] = &[&[
+        AUCTION_VAULT_PREFIX.as_ref(),
+        auction_config_key.as_ref(),
+        auction_vault_config_bump_bytes.as_ref(),
+    ]];
+
+    let transfer_payment_to_user_cpi_accounts = TransferChecked {
+        from: ctx
+            .accounts
+            .auction_vault_config_payment_token_account
+            .to_account_info(),
+        mint: ctx.accounts.payment_token_mint_account.to_account_info(),
+        to: ctx.accounts.user_payment_token_account.to_account_info(),
+        authority: ctx.accounts.auction_vault_config.to_account_info(),
+    };
+
+    let transfer_payment_to_user_cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.payment_token_program.to_account_info(),
+        transfer_payment_to_user_cpi_accounts,
+        signer_seeds,
+    );
+
+    transfer_checked(
+        transfer_payment_to_user_cpi_ctx,
+        adjusted_payment,
+        ctx.accounts.payment_token_mint_account.decimals,
+    )?;
+
+    // Set Values
+    let auction_config: &mut Box<Account<AuctionAccount>> = &mut ctx.accounts.auction_config;
+    auction_config.last_block_timestamp = timestamp;
+    auction_config.total_refunded_supply = auction_config
+        .total_refunded_supply
+        .checked_add(user_auction_buy_receipt_config.buy_amount)
+        .unwrap();
+    auction_config.total_refunded_payment = auction_config
+        .total_refunded_payment
+        .checked_add(user_auction_buy_receipt_config.payment)
+        .unwrap();
+
+    if locked_amount > 0 {
+        let user_auction_vesting_config: &mut Box<Account<UserAuctionVestingAccount>> =
+            ctx.accounts.user_auction_vesting_config.as_mut().unwrap();
+        user_auction_vesting_config.last_block_timestamp = timestamp;
+        user_auction_vesting_config.total_amount = user_auction_vesting_config
+            .total_amount
+            .checked_sub(locked_amount)
+            .unwrap();
+    };
+
+    let user_auction_buy_receipt_config: &mut Box<Account<UserAuctionBuyReceiptAccount>> =
+        &mut ctx.accounts.user_auction_buy_receipt_config;
+    user_auction_buy_receipt_config.last_block_timestamp = timestamp;
+    user_auction_buy_receipt_config.is_refunded = true;
+
+    // Event
+    let event: RefundEvent = RefundEvent {
+        timestamp,
+        mint: ctx.accounts.token_mint_account.key(),
+        pad_name: params.pad_name.clone(),
+        user: ctx.accounts.user.key(),
+        user_buy_index: params.buy_index.clone(),
+        amount: user_auction_buy_receipt_config.buy_amount,
+        payment: user_auction_buy_receipt_config.payment,
+    };
+
+    emit!(event);
+
+    Ok(())
+}
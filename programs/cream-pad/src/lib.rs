@@ -4,13 +4,17 @@ pub mod meta;
 
 use instructions::creator::*;
 use instructions::manager::*;
+use instructions::migration::*;
 use instructions::user::*;
 
+mod ed25519;
 mod error;
 
 mod events;
-mod instructions;
-mod states;
+pub mod instructions;
+mod math;
+mod merkle;
+pub mod states;
 
 mod utils;
 
@@ -34,6 +38,83 @@ pub mod cream_pad {
         handle_update_config(ctx, &params)
     }
 
+    pub fn propose_config_change(
+        ctx: Context<ProposeConfigChangeInputAccounts>,
+        params: ProposeConfigChangeInputParams,
+    ) -> Result<()> {
+        handle_propose_config_change(ctx, &params)
+    }
+
+    pub fn approve_config_change(
+        ctx: Context<ApproveConfigChangeInputAccounts>,
+        params: ApproveConfigChangeInputParams,
+    ) -> Result<()> {
+        handle_approve_config_change(ctx, &params)
+    }
+
+    pub fn execute_config_change(
+        ctx: Context<ExecuteConfigChangeInputAccounts>,
+        params: ExecuteConfigChangeInputParams,
+    ) -> Result<()> {
+        handle_execute_config_change(ctx, &params)
+    }
+
+    pub fn cancel_config_change(
+        ctx: Context<CancelConfigChangeInputAccounts>,
+        params: CancelConfigChangeInputParams,
+    ) -> Result<()> {
+        handle_cancel_config_change(ctx, &params)
+    }
+
+    pub fn propose_authority_transfer(
+        ctx: Context<ProposeAuthorityTransferInputAccounts>,
+        params: ProposeAuthorityTransferInputParams,
+    ) -> Result<()> {
+        handle_propose_authority_transfer(ctx, &params)
+    }
+
+    pub fn accept_authority_transfer(
+        ctx: Context<AcceptAuthorityTransferInputAccounts>,
+        params: AcceptAuthorityTransferInputParams,
+    ) -> Result<()> {
+        handle_accept_authority_transfer(ctx, &params)
+    }
+
+    pub fn set_pad_fee(
+        ctx: Context<SetPadFeeInputAccounts>,
+        params: SetPadFeeInputParams,
+    ) -> Result<()> {
+        handle_set_pad_fee(ctx, &params)
+    }
+
+    pub fn set_collection_pad_fee(
+        ctx: Context<SetCollectionPadFeeInputAccounts>,
+        params: SetCollectionPadFeeInputParams,
+    ) -> Result<()> {
+        handle_set_collection_pad_fee(ctx, &params)
+    }
+
+    pub fn set_pause_flags(
+        ctx: Context<SetPauseFlagsInputAccounts>,
+        params: SetPauseFlagsInputParams,
+    ) -> Result<()> {
+        handle_set_pause_flags(ctx, &params)
+    }
+
+    pub fn set_pad_pause_flags(
+        ctx: Context<SetPadPauseFlagsInputAccounts>,
+        params: SetPadPauseFlagsInputParams,
+    ) -> Result<()> {
+        handle_set_pad_pause_flags(ctx, &params)
+    }
+
+    pub fn set_collection_pad_pause_flags(
+        ctx: Context<SetCollectionPadPauseFlagsInputAccounts>,
+        params: SetCollectionPadPauseFlagsInputParams,
+    ) -> Result<()> {
+        handle_set_collection_pad_pause_flags(ctx, &params)
+    }
+
     pub fn initialize_pad(
         ctx: Context<InitializePadInputAccounts>,
         params: InitializePadInputParams,
@@ -90,6 +171,97 @@ pub mod cream_pad {
         handle_claim_distribution(ctx, &params)
     }
 
+    pub fn cancel_pad<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CancelPadInputAccounts<'info>>,
+        params: CancelPadInputParams,
+    ) -> Result<()> {
+        handle_cancel_pad(ctx, &params)
+    }
+
+    pub fn withdraw_cancelled_supply<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawCancelledSupplyInputAccounts<'info>>,
+        params: WithdrawCancelledSupplyInputParams,
+    ) -> Result<()> {
+        handle_withdraw_cancelled_supply(ctx, &params)
+    }
+
+    pub fn refund<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RefundInputAccounts<'info>>,
+        params: RefundParams,
+    ) -> Result<()> {
+        handle_refund(ctx, &params)
+    }
+
+    pub fn settle_pad<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettlePadInputAccounts<'info>>,
+        params: SettlePadInputParams,
+    ) -> Result<()> {
+        handle_settle_pad(ctx, &params)
+    }
+
+    pub fn crank_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankRoundInputAccounts<'info>>,
+        params: CrankRoundInputParams,
+    ) -> Result<()> {
+        handle_crank_round(ctx, &params)
+    }
+
+    pub fn fund_crank_reward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FundCrankRewardInputAccounts<'info>>,
+        params: FundCrankRewardInputParams,
+    ) -> Result<()> {
+        handle_fund_crank_reward(ctx, &params)
+    }
+
+    pub fn withdraw_crank_reward<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawCrankRewardInputAccounts<'info>>,
+        params: WithdrawCrankRewardInputParams,
+    ) -> Result<()> {
+        handle_withdraw_crank_reward(ctx, &params)
+    }
+
+    pub fn claim_rebate<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimRebateInputAccounts<'info>>,
+        params: ClaimRebateParams,
+    ) -> Result<()> {
+        handle_claim_rebate(ctx, &params)
+    }
+
+    pub fn claim_vested<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimVestedInputAccounts<'info>>,
+        params: ClaimVestedParams,
+    ) -> Result<()> {
+        handle_claim_vested(ctx, &params)
+    }
+
+    pub fn close_buy_receipt<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseBuyReceiptInputAccounts<'info>>,
+        params: CloseBuyReceiptParams,
+    ) -> Result<()> {
+        handle_close_buy_receipt(ctx, &params)
+    }
+
+    pub fn close_user_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseUserRoundInputAccounts<'info>>,
+        params: CloseUserRoundParams,
+    ) -> Result<()> {
+        handle_close_user_round(ctx, &params)
+    }
+
+    pub fn close_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CloseRoundInputAccounts<'info>>,
+        params: CloseRoundInputParams,
+    ) -> Result<()> {
+        handle_close_round(ctx, &params)
+    }
+
+    pub fn claim_referral_rewards<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimReferralRewardsInputAccounts<'info>>,
+        params: ClaimReferralRewardsParams,
+    ) -> Result<()> {
+        handle_claim_referral_rewards(ctx, &params)
+    }
+
     pub fn initialize_collection_pad<'info>(
         ctx: Context<'_, '_, 'info, 'info, InitializeCollectionPadInputAccounts<'info>>,
         params: InitializeCollectionPadInputParams,
@@ -179,4 +351,95 @@ pub mod cream_pad {
     ) -> Result<()> {
         handle_fill_claimed_collection_asset_distribution(ctx, &params)
     }
+
+    pub fn migrate_pad<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigratePadInputAccounts<'info>>,
+        params: MigratePadInputParams,
+    ) -> Result<()> {
+        handle_migrate_pad(ctx, &params)
+    }
+
+    pub fn migrate_pad_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigratePadRoundInputAccounts<'info>>,
+        params: MigratePadRoundInputParams,
+    ) -> Result<()> {
+        handle_migrate_pad_round(ctx, &params)
+    }
+
+    pub fn migrate_collection_pad<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateCollectionPadInputAccounts<'info>>,
+        params: MigrateCollectionPadInputParams,
+    ) -> Result<()> {
+        handle_migrate_collection_pad(ctx, &params)
+    }
+
+    pub fn migrate_collection_pad_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateCollectionPadRoundInputAccounts<'info>>,
+        params: MigrateCollectionPadRoundInputParams,
+    ) -> Result<()> {
+        handle_migrate_collection_pad_round(ctx, &params)
+    }
+
+    pub fn migrate_config<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateConfigInputAccounts<'info>>,
+        params: MigrateConfigInputParams,
+    ) -> Result<()> {
+        handle_migrate_config(ctx, &params)
+    }
+
+    pub fn migrate_user_pad<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserPadInputAccounts<'info>>,
+        params: MigrateUserPadInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_pad(ctx, &params)
+    }
+
+    pub fn migrate_user_pad_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserPadRoundInputAccounts<'info>>,
+        params: MigrateUserPadRoundInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_pad_round(ctx, &params)
+    }
+
+    pub fn migrate_user_pad_buy_receipt<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserPadBuyReceiptInputAccounts<'info>>,
+        params: MigrateUserPadBuyReceiptInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_pad_buy_receipt(ctx, &params)
+    }
+
+    pub fn migrate_user_pad_unsold_distribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserPadUnsoldDistributionInputAccounts<'info>>,
+        params: MigrateUserPadUnsoldDistributionInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_pad_unsold_distribution(ctx, &params)
+    }
+
+    pub fn migrate_user_collection_pad<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserCollectionPadInputAccounts<'info>>,
+        params: MigrateUserCollectionPadInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_collection_pad(ctx, &params)
+    }
+
+    pub fn migrate_user_collection_pad_round<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserCollectionPadRoundInputAccounts<'info>>,
+        params: MigrateUserCollectionPadRoundInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_collection_pad_round(ctx, &params)
+    }
+
+    pub fn migrate_user_collection_pad_buy_receipt<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserCollectionPadBuyReceiptInputAccounts<'info>>,
+        params: MigrateUserCollectionPadBuyReceiptInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_collection_pad_buy_receipt(ctx, &params)
+    }
+
+    pub fn migrate_user_collection_pad_unsold_distribution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MigrateUserCollectionPadUnsoldDistributionInputAccounts<'info>>,
+        params: MigrateUserCollectionPadUnsoldDistributionInputParams,
+    ) -> Result<()> {
+        handle_migrate_user_collection_pad_unsold_distribution(ctx, &params)
+    }
 }
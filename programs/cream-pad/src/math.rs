@@ -0,0 +1,557 @@
+use crate::states::DecayModelType;
+use crate::utils::BASE_POINT;
+
+/// Fixed point scale, every `_wad` value is multiplied by 1e18.
+pub const WAD: u128 = 1_000_000_000_000_000_000;
+
+pub const SIGNED_WAD: i128 = WAD as i128;
+
+/// ln(2) scaled by `WAD`
+pub const LN_2_WAD: i128 = 693_147_180_559_945_309;
+
+/// Below this exponent `exp_wad` rounds to zero
+pub const MIN_EXP_WAD: i128 = -42 * SIGNED_WAD;
+
+/// Steeper sigmoid curves saturate `exp_wad` at both ends
+pub const MAX_SIGMOID_STEEPNESS: u64 = 40;
+
+///////////// FIXED POINT ///////////////
+
+/// Natural logarithm of a positive `WAD` value.
+pub fn ln_wad(x: u128) -> i128 {
+    // Normalize x = m * 2^k with m in [1, 2)
+    let mut m: u128 = x;
+    let mut k: i128 = 0;
+
+    while m >= 2 * WAD {
+        m >>= 1;
+        k += 1;
+    }
+
+    while m < WAD {
+        m <<= 1;
+        k -= 1;
+    }
+
+    // ln(m) = 2 * atanh(z) with z = (m - 1) / (m + 1), z < 1/3
+    let z: i128 = ((m - WAD) * WAD / (m + WAD)) as i128;
+    let z_squared: i128 = z * z / SIGNED_WAD;
+
+    let mut sum: i128 = 0;
+    let mut term: i128 = z;
+    let mut n: i128 = 1;
+
+    while term != 0 {
+        sum += term / n;
+        term = term * z_squared / SIGNED_WAD;
+        n += 2;
+    }
+
+    k * LN_2_WAD + 2 * sum
+}
+
+/// e^x for a signed `WAD` exponent, `None` when the result does not fit in u128.
+pub fn exp_wad(x: i128) -> Option<u128> {
+    if x < MIN_EXP_WAD {
+        return Some(0);
+    }
+
+    // Reduce x = k * ln(2) + r with |r| < ln(2)
+    let k: i128 = x / LN_2_WAD;
+    let r: i128 = x - k * LN_2_WAD;
+
+    // Taylor series of e^r
+    let mut sum: i128 = SIGNED_WAD;
+    let mut term: i128 = SIGNED_WAD;
+    let mut n: i128 = 1;
+
+    while term != 0 {
+        term = term * r / SIGNED_WAD / n;
+        sum += term;
+        n += 1;
+    }
+
+    let sum: u128 = sum as u128;
+
+    if k >= 0 {
+        if k as u32 > sum.leading_zeros() {
+            return None;
+        }
+
+        Some(sum << k as u32)
+    } else {
+        Some(sum >> (-k).min(127) as u32)
+    }
+}
+
+/// Clamp a price to [ptmax, u64::MAX], saturating on overflow.
+fn clamp_price(price: Option<i128>, ptmax: u64) -> u64 {
+    match price {
+        Some(value) => value.max(ptmax as i128).min(u64::MAX as i128) as u64,
+        None => u64::MAX,
+    }
+}
+
+///////////// PRICING ///////////////
+
+/// Round boost as a signed `WAD`, `-WAD` (one round of decay) when sales are below target.
+pub fn calculate_boost(
+    actual_sales: u64,
+    expected_sales: u64,
+    omega: u64,
+    alpha: u64,
+    time_shift_max: u64,
+) -> i128 {
+    if actual_sales >= expected_sales {
+        let max_boost: u128 = (time_shift_max as u128) * WAD;
+
+        if expected_sales == 0 {
+            return max_boost as i128;
+        }
+
+        let ratio: u128 = (actual_sales as u128) * WAD / (expected_sales as u128);
+
+        let boost: u128 = (alpha as u128)
+            .checked_mul(omega as u128)
+            .and_then(|factor| factor.checked_mul(ratio))
+            .map_or(max_boost, |value| value.min(max_boost));
+
+        return boost as i128;
+    }
+
+    -SIGNED_WAD // No boost if sales are below target
+}
+
+pub fn calculate_price(
+    p0: u64,                // Initial price
+    ptmax: u64,             // Minimum price
+    t_max: u64,             // Total rounds (time)
+    current_round: usize,   // Current round index
+    boost_history: &[i128], // Boost applied per round, in WAD
+    decay_model: &DecayModelType,
+    time_shift_max: u64, // Maximum shift in time-based decay
+) -> u64 {
+    let max_boost: i128 = (time_shift_max as i128) * SIGNED_WAD;
+
+    let total_boost: i128 = boost_history
+        .iter()
+        .take(current_round)
+        .map(|&boost| -boost.min(max_boost))
+        .sum();
+
+    // Share of the decay curve already travelled, in WAD
+    let progress: i128 = total_boost / (t_max.saturating_sub(1).max(1) as i128);
+
+    match decay_model {
+        DecayModelType::Linear => {
+            let price: Option<i128> = (p0.saturating_sub(ptmax) as i128)
+                .checked_mul(progress)
+                .map(|decay| p0 as i128 - decay / SIGNED_WAD);
+
+            clamp_price(price, ptmax)
+        }
+        DecayModelType::Exponential => {
+            if p0 <= ptmax {
+                return ptmax;
+            }
+
+            // ln(0) has no value, a zero floor decays towards the smallest unit instead
+            let lambda: i128 = ln_wad((p0 as u128) * WAD / (ptmax.max(1) as u128));
+
+            let price: Option<i128> = lambda
+                .checked_mul(progress)
+                .and_then(|exponent| exp_wad(-exponent / SIGNED_WAD))
+                .and_then(|factor| scale_price(p0, factor));
+
+            clamp_price(price, ptmax)
+        }
+        DecayModelType::Step {
+            step_rounds,
+            step_base_point,
+        } => {
+            // Whole steps travelled, negative when boosts pushed the price back up
+            let steps: i128 = total_boost.div_euclid(((*step_rounds).max(1) as i128) * SIGNED_WAD);
+
+            let ln_step: i128 = ln_wad(
+                (BASE_POINT.saturating_sub(*step_base_point).max(1) as u128) * WAD
+                    / (BASE_POINT as u128),
+            );
+
+            let price: Option<i128> = ln_step
+                .checked_mul(steps)
+                .and_then(exp_wad)
+                .and_then(|factor| scale_price(p0, factor));
+
+            clamp_price(price, ptmax)
+        }
+        DecayModelType::Sigmoid { steepness } => {
+            let steepness: i128 = *steepness as i128;
+
+            // Logistic curve rescaled so progress 0 gives p0 and progress 1 gives ptmax
+            let low: i128 = sigmoid_wad(-steepness * SIGNED_WAD / 2);
+            let high: i128 = sigmoid_wad(steepness * SIGNED_WAD / 2);
+            let current: i128 = sigmoid_wad((progress - SIGNED_WAD / 2) * steepness);
+
+            let share: i128 = (current - low) * SIGNED_WAD / (high - low).max(1);
+
+            let price: Option<i128> = (p0.saturating_sub(ptmax) as i128)
+                .checked_mul(share)
+                .map(|decay| p0 as i128 - decay / SIGNED_WAD);
+
+            clamp_price(price, ptmax)
+        }
+        DecayModelType::Table { prices } => {
+            if prices.is_empty() {
+                return p0;
+            }
+
+            // Rounds travelled pick the entry, boosts move the index the same way as the curves
+            let index: usize =
+                (total_boost / SIGNED_WAD).clamp(0, prices.len() as i128 - 1) as usize;
+
+            prices[index].max(ptmax)
+        }
+    }
+}
+
+/// `value * factor / WAD`, `None` when it does not fit in i128.
+fn scale_price(value: u64, factor: u128) -> Option<i128> {
+    factor
+        .checked_mul(value as u128)
+        .map(|price| (price / WAD).min(i128::MAX as u128) as i128)
+}
+
+/// Logistic function 1 / (1 + e^-x) of a signed `WAD`, in WAD.
+fn sigmoid_wad(x: i128) -> i128 {
+    match x.checked_neg().and_then(exp_wad) {
+        Some(exp) => (WAD * WAD / WAD.saturating_add(exp)) as i128,
+        None => 0,
+    }
+}
+
+///////////// VESTING ///////////////
+
+/// Amount unlocked at `now`, the TGE share right away then linear over `duration`, nothing
+/// besides the TGE share before the cliff.
+pub fn calculate_vested_amount(
+    total_amount: u64,
+    tge_base_point: u16,
+    start_at: i64,
+    cliff_duration: i64,
+    duration: i64,
+    now: i64,
+) -> u64 {
+    if now < start_at {
+        return 0;
+    }
+
+    let tge_amount: u64 =
+        ((total_amount as u128) * (tge_base_point as u128) / (BASE_POINT as u128)) as u64;
+
+    let elapsed: i64 = now - start_at;
+
+    if elapsed >= duration {
+        return total_amount;
+    }
+
+    if elapsed < cliff_duration {
+        return tge_amount;
+    }
+
+    let linear_amount: u64 =
+        ((total_amount - tge_amount) as u128 * elapsed as u128 / duration as u128) as u64;
+
+    tge_amount + linear_amount
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P0: u64 = 4_000_000_000;
+
+    const PTMAX: u64 = 1_000_000_000;
+
+    /// One round below target, the price decays by one round
+    const DECAY: i128 = -SIGNED_WAD;
+
+    fn assert_close(value: i128, expected: i128, tolerance: i128) {
+        assert!(
+            (value - expected).abs() <= tolerance,
+            "{value} is not within {tolerance} of {expected}"
+        );
+    }
+
+    fn price(decay_model: &DecayModelType, ptmax: u64, boost_history: &[i128]) -> u64 {
+        calculate_price(
+            P0,
+            ptmax,
+            3,
+            boost_history.len(),
+            boost_history,
+            decay_model,
+            2,
+        )
+    }
+
+    #[test]
+    fn ln_wad_matches_known_values() {
+        assert_eq!(ln_wad(WAD), 0);
+        assert_close(ln_wad(2 * WAD), LN_2_WAD, 10);
+        assert_close(ln_wad(WAD / 2), -LN_2_WAD, 10);
+        assert_close(ln_wad(2_718_281_828_459_045_235), SIGNED_WAD, 100);
+        assert_close(ln_wad(1), -41_446_531_673_892_822_312, 100);
+    }
+
+    #[test]
+    fn exp_wad_matches_known_values() {
+        assert_eq!(exp_wad(0), Some(WAD));
+        assert_close(
+            exp_wad(SIGNED_WAD).unwrap() as i128,
+            2_718_281_828_459_045_235,
+            100,
+        );
+        assert_close(exp_wad(LN_2_WAD).unwrap() as i128, 2 * SIGNED_WAD, 100);
+        assert_close(exp_wad(-LN_2_WAD).unwrap() as i128, SIGNED_WAD / 2, 100);
+    }
+
+    #[test]
+    fn exp_wad_bounds() {
+        assert_eq!(exp_wad(MIN_EXP_WAD - 1), Some(0));
+        assert!(exp_wad(MIN_EXP_WAD).is_some());
+        // u128::MAX is about e^47.3 in WAD
+        assert!(exp_wad(47 * SIGNED_WAD).is_some());
+        assert_eq!(exp_wad(48 * SIGNED_WAD), None);
+    }
+
+    #[test]
+    fn exp_wad_inverts_ln_wad() {
+        for x in [WAD / 3, WAD, 7 * WAD, 1_000_000 * WAD] {
+            let value: i128 = exp_wad(ln_wad(x)).unwrap() as i128;
+
+            assert_close(value, x as i128, x as i128 / 1_000_000_000_000);
+        }
+    }
+
+    #[test]
+    fn calculate_boost_decays_below_target() {
+        assert_eq!(calculate_boost(49, 50, 1, 1, 2), DECAY);
+    }
+
+    #[test]
+    fn calculate_boost_scales_with_sales_up_to_the_max() {
+        assert_eq!(calculate_boost(50, 50, 1, 1, 2), SIGNED_WAD);
+        assert_eq!(calculate_boost(75, 50, 1, 1, 2), 3 * SIGNED_WAD / 2);
+        assert_eq!(calculate_boost(100, 50, 1, 1, 2), 2 * SIGNED_WAD);
+        assert_eq!(calculate_boost(150, 50, 1, 1, 2), 2 * SIGNED_WAD);
+    }
+
+    #[test]
+    fn calculate_boost_gives_the_max_without_target_or_on_overflow() {
+        assert_eq!(calculate_boost(0, 0, 1, 1, 2), 2 * SIGNED_WAD);
+        assert_eq!(
+            calculate_boost(50, 50, u64::MAX, u64::MAX, 2),
+            2 * SIGNED_WAD
+        );
+    }
+
+    #[test]
+    fn linear_price_decays_from_p0_to_ptmax() {
+        let decay_model: DecayModelType = DecayModelType::Linear;
+
+        assert_eq!(price(&decay_model, PTMAX, &[]), P0);
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY]), 2_500_000_000);
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY, DECAY]), PTMAX);
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY, DECAY, DECAY]), PTMAX);
+    }
+
+    #[test]
+    fn linear_price_rises_with_boosts() {
+        let decay_model: DecayModelType = DecayModelType::Linear;
+
+        assert_eq!(price(&decay_model, PTMAX, &[2 * SIGNED_WAD]), 7_000_000_000);
+        // boosts are capped at time_shift_max
+        assert_eq!(price(&decay_model, PTMAX, &[5 * SIGNED_WAD]), 7_000_000_000);
+    }
+
+    #[test]
+    fn linear_price_saturates_on_overflow() {
+        let price: u64 = calculate_price(
+            u64::MAX,
+            0,
+            2,
+            1,
+            &[i128::MAX],
+            &DecayModelType::Linear,
+            u64::MAX,
+        );
+
+        assert_eq!(price, u64::MAX);
+    }
+
+    #[test]
+    fn single_round_pad_price_does_not_divide_by_zero() {
+        let price: u64 = calculate_price(P0, PTMAX, 1, 1, &[DECAY], &DecayModelType::Linear, 2);
+
+        assert_eq!(price, PTMAX);
+    }
+
+    #[test]
+    fn exponential_price_decays_geometrically() {
+        let decay_model: DecayModelType = DecayModelType::Exponential;
+
+        assert_eq!(price(&decay_model, PTMAX, &[]), P0);
+        assert_close(
+            price(&decay_model, PTMAX, &[DECAY]) as i128,
+            2_000_000_000,
+            10,
+        );
+        assert_close(
+            price(&decay_model, PTMAX, &[DECAY, DECAY]) as i128,
+            PTMAX as i128,
+            10,
+        );
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY, DECAY, DECAY]), PTMAX);
+    }
+
+    #[test]
+    fn exponential_price_with_zero_ptmax_decays_to_the_smallest_unit() {
+        let decay_model: DecayModelType = DecayModelType::Exponential;
+
+        assert_eq!(price(&decay_model, 0, &[]), P0);
+        assert_close(price(&decay_model, 0, &[DECAY]) as i128, 63_245, 10);
+        assert_close(price(&decay_model, 0, &[DECAY, DECAY]) as i128, 1, 1);
+    }
+
+    #[test]
+    fn exponential_price_stays_at_ptmax_when_p0_is_below() {
+        let price: u64 = calculate_price(PTMAX, P0, 3, 0, &[], &DecayModelType::Exponential, 2);
+
+        assert_eq!(price, P0);
+    }
+
+    #[test]
+    fn exponential_price_saturates_on_overflow() {
+        let price: u64 = calculate_price(
+            u64::MAX,
+            1,
+            2,
+            1,
+            &[100 * SIGNED_WAD],
+            &DecayModelType::Exponential,
+            100,
+        );
+
+        assert_eq!(price, u64::MAX);
+    }
+
+    #[test]
+    fn step_price_drops_once_per_step() {
+        let decay_model: DecayModelType = DecayModelType::Step {
+            step_rounds: 1,
+            step_base_point: 5_000,
+        };
+
+        assert_eq!(price(&decay_model, PTMAX, &[]), P0);
+        assert_close(
+            price(&decay_model, PTMAX, &[DECAY]) as i128,
+            2_000_000_000,
+            10,
+        );
+        assert_close(
+            price(&decay_model, PTMAX, &[DECAY, DECAY]) as i128,
+            PTMAX as i128,
+            10,
+        );
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY, DECAY, DECAY]), PTMAX);
+    }
+
+    #[test]
+    fn step_price_waits_for_whole_steps() {
+        let decay_model: DecayModelType = DecayModelType::Step {
+            step_rounds: 2,
+            step_base_point: 5_000,
+        };
+
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY]), P0);
+        assert_close(
+            price(&decay_model, PTMAX, &[DECAY, DECAY]) as i128,
+            2_000_000_000,
+            10,
+        );
+    }
+
+    #[test]
+    fn sigmoid_price_runs_from_p0_to_ptmax() {
+        let decay_model: DecayModelType = DecayModelType::Sigmoid { steepness: 10 };
+
+        assert_eq!(price(&decay_model, PTMAX, &[]), P0);
+        assert_close(
+            price(&decay_model, PTMAX, &[DECAY]) as i128,
+            2_500_000_000,
+            10,
+        );
+        assert_close(
+            price(&decay_model, PTMAX, &[DECAY, DECAY]) as i128,
+            PTMAX as i128,
+            10,
+        );
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY, DECAY, DECAY]), PTMAX);
+    }
+
+    #[test]
+    fn sigmoid_price_holds_p0_without_steepness() {
+        let decay_model: DecayModelType = DecayModelType::Sigmoid { steepness: 0 };
+
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY]), P0);
+    }
+
+    #[test]
+    fn table_price_follows_the_rounds_travelled() {
+        let decay_model: DecayModelType = DecayModelType::Table {
+            prices: vec![P0, 3_000_000_000, 500_000_000],
+        };
+
+        assert_eq!(price(&decay_model, PTMAX, &[]), P0);
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY]), 3_000_000_000);
+        // entries below ptmax are floored
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY, DECAY]), PTMAX);
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY, DECAY, DECAY]), PTMAX);
+        // boosts never move before the first entry
+        assert_eq!(price(&decay_model, PTMAX, &[2 * SIGNED_WAD]), P0);
+    }
+
+    #[test]
+    fn table_price_without_entries_is_p0() {
+        let decay_model: DecayModelType = DecayModelType::Table { prices: vec![] };
+
+        assert_eq!(price(&decay_model, PTMAX, &[DECAY]), P0);
+    }
+
+    #[test]
+    fn calculate_vested_amount_unlocks_tge_then_linearly() {
+        // 10% at TGE, 10 cliff, 100 duration, starting at 1_000
+        let vested = |now: i64| calculate_vested_amount(1_000, 1_000, 1_000, 10, 100, now);
+
+        assert_eq!(vested(999), 0);
+        assert_eq!(vested(1_000), 100);
+        assert_eq!(vested(1_009), 100);
+        assert_eq!(vested(1_010), 190);
+        assert_eq!(vested(1_050), 550);
+        assert_eq!(vested(1_100), 1_000);
+        assert_eq!(vested(2_000), 1_000);
+    }
+
+    #[test]
+    fn calculate_vested_amount_bounds() {
+        // everything at TGE
+        assert_eq!(calculate_vested_amount(1_000, 10_000, 0, 10, 100, 0), 1_000);
+        // no duration unlocks everything at start
+        assert_eq!(calculate_vested_amount(1_000, 0, 0, 0, 0, 0), 1_000);
+        // no overflow at the largest amount
+        assert_eq!(
+            calculate_vested_amount(u64::MAX, 0, 0, 0, 4, 2),
+            u64::MAX / 2
+        );
+    }
+}
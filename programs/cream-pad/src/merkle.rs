@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak::hashv;
+
+/// Whitelist leaf, keccak256(user || allocation as little endian u64).
+pub fn whitelist_leaf(user: &Pubkey, allocation: u64) -> [u8; 32] {
+    hashv(&[user.as_ref(), &allocation.to_le_bytes()]).to_bytes()
+}
+
+/// Walk the proof up to the root, each pair is hashed in sorted order.
+pub fn verify_proof(proof: &[[u8; 32]], root: [u8; 32], leaf: [u8; 32]) -> bool {
+    let mut computed_hash: [u8; 32] = leaf;
+
+    for proof_element in proof {
+        computed_hash = if computed_hash <= *proof_element {
+            hashv(&[&computed_hash, proof_element]).to_bytes()
+        } else {
+            hashv(&[proof_element, &computed_hash]).to_bytes()
+        };
+    }
+
+    computed_hash == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const USER_A: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    const USER_B: Pubkey = Pubkey::new_from_array([2; 32]);
+
+    const USER_C: Pubkey = Pubkey::new_from_array([3; 32]);
+
+    const USER_D: Pubkey = Pubkey::new_from_array([4; 32]);
+
+    fn hash_pair(left: [u8; 32], right: [u8; 32]) -> [u8; 32] {
+        if left <= right {
+            hashv(&[&left, &right]).to_bytes()
+        } else {
+            hashv(&[&right, &left]).to_bytes()
+        }
+    }
+
+    /// Four leaf tree, returns the root and the proof of user A.
+    fn four_leaf_tree() -> ([u8; 32], Vec<[u8; 32]>) {
+        let leaf_a: [u8; 32] = whitelist_leaf(&USER_A, 100);
+        let leaf_b: [u8; 32] = whitelist_leaf(&USER_B, 200);
+        let leaf_c: [u8; 32] = whitelist_leaf(&USER_C, 300);
+        let leaf_d: [u8; 32] = whitelist_leaf(&USER_D, 400);
+
+        let node_ab: [u8; 32] = hash_pair(leaf_a, leaf_b);
+        let node_cd: [u8; 32] = hash_pair(leaf_c, leaf_d);
+
+        (hash_pair(node_ab, node_cd), vec![leaf_b, node_cd])
+    }
+
+    #[test]
+    fn whitelist_leaf_hashes_user_and_little_endian_allocation() {
+        let mut preimage: Vec<u8> = USER_A.to_bytes().to_vec();
+        preimage.extend_from_slice(&[100, 0, 0, 0, 0, 0, 0, 0]);
+
+        assert_eq!(whitelist_leaf(&USER_A, 100), hashv(&[&preimage]).to_bytes());
+    }
+
+    #[test]
+    fn verify_proof_accepts_multi_level_proof() {
+        let (root, proof) = four_leaf_tree();
+
+        assert!(verify_proof(&proof, root, whitelist_leaf(&USER_A, 100)));
+    }
+
+    #[test]
+    fn verify_proof_accepts_every_leaf_of_the_tree() {
+        let (root, _) = four_leaf_tree();
+
+        let leaf_a: [u8; 32] = whitelist_leaf(&USER_A, 100);
+        let leaf_b: [u8; 32] = whitelist_leaf(&USER_B, 200);
+        let leaf_c: [u8; 32] = whitelist_leaf(&USER_C, 300);
+        let leaf_d: [u8; 32] = whitelist_leaf(&USER_D, 400);
+
+        assert!(verify_proof(
+            &[leaf_a, hash_pair(leaf_c, leaf_d)],
+            root,
+            leaf_b
+        ));
+        assert!(verify_proof(
+            &[leaf_d, hash_pair(leaf_a, leaf_b)],
+            root,
+            leaf_c
+        ));
+        assert!(verify_proof(
+            &[leaf_c, hash_pair(leaf_a, leaf_b)],
+            root,
+            leaf_d
+        ));
+    }
+
+    #[test]
+    fn verify_proof_rejects_wrong_user() {
+        let (root, proof) = four_leaf_tree();
+
+        assert!(!verify_proof(&proof, root, whitelist_leaf(&USER_B, 100)));
+    }
+
+    #[test]
+    fn verify_proof_rejects_wrong_allocation() {
+        let (root, proof) = four_leaf_tree();
+
+        assert!(!verify_proof(&proof, root, whitelist_leaf(&USER_A, 101)));
+    }
+
+    #[test]
+    fn verify_proof_rejects_tampered_sibling() {
+        let (root, mut proof) = four_leaf_tree();
+        proof[1][0] ^= 1;
+
+        assert!(!verify_proof(&proof, root, whitelist_leaf(&USER_A, 100)));
+    }
+
+    #[test]
+    fn verify_proof_rejects_truncated_proof() {
+        let (root, proof) = four_leaf_tree();
+
+        assert!(!verify_proof(
+            &proof[..1],
+            root,
+            whitelist_leaf(&USER_A, 100)
+        ));
+    }
+
+    #[test]
+    fn verify_proof_accepts_empty_proof_against_single_leaf_root() {
+        let leaf: [u8; 32] = whitelist_leaf(&USER_A, 100);
+
+        assert!(verify_proof(&[], leaf, leaf));
+        assert!(!verify_proof(&[], leaf, whitelist_leaf(&USER_A, 99)));
+    }
+}
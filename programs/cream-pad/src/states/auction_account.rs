@@ -1,10 +1,16 @@
-use crate::states::{AuctionStatus, DecayModelType};
+use crate::states::{
+    AuctionStatus, DecayModelType, FeeTier, PauseFlags, PaymentMint, RoundSchedule, MAX_FEE_TIERS,
+    MAX_PAYMENT_MINTS,
+};
 use anchor_lang::prelude::*;
 
 pub const AUCTION_ACCOUNT_PREFIX: &str = "AAP";
 pub const AUCTION_VAULT_PREFIX: &str = "AVP";
 
+pub const AUCTION_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct AuctionAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -35,7 +41,9 @@ pub struct AuctionAccount {
 
     pub current_round: u16,
 
-    pub boost_history: Vec<f64>,
+    /// boost per ended round, scaled by `WAD`
+    #[max_len(0)]
+    pub boost_history: Vec<i128>,
 
     pub decay_model: DecayModelType,
 
@@ -64,67 +72,127 @@ pub struct AuctionAccount {
     pub total_payment: u64,
 
     pub total_fee: u64,
-}
 
-impl AuctionAccount {
-    pub fn space(limit: u16) -> usize {
-        8 // default
-            + 8 // last_block_timestamp
+    pub have_whitelist: bool,
+
+    /// root of the `(user, allocation)` leaves allowed to buy during whitelist rounds
+    pub whitelist_merkle_root: [u8; 32],
+
+    /// rounds, starting from round 1, that only whitelisted users can buy in
+    pub whitelist_rounds: u16,
+
+    /// payments are held by the auction vault instead of being sent to the payment receiver
+    pub have_payment_escrow: bool,
+
+    pub cancelled_at: i64,
+
+    pub total_refunded_supply: u64,
+
+    pub total_refunded_payment: u64,
+
+    pub is_payment_settled: bool,
 
-            + 32 // creator
+    pub payment_settled_at: i64,
 
-            + 32 // mint
+    /// escrowed payment sent to the payment receiver, fee excluded
+    pub total_settled_payment: u64,
 
-            + 32 // payment_mint
+    pub total_settled_fee: u64,
 
-            + 32 // payment_receiver
+    /// every buyer pays `clearing_price` in the end, the difference is claimed back as a rebate
+    pub have_uniform_clearing_price: bool,
 
-            + 1 // status
+    /// price of the final round, set once the auction ends or sells out
+    pub clearing_price: u64,
 
-            + 8 // p0
+    pub total_rebate: u64,
 
-            + 8 // ptmax
+    pub total_rebate_claimed_count: u64,
 
-            + 2 // tmax
+    /// bought tokens stay in the auction and unlock over the vesting schedule
+    pub have_vesting: bool,
 
-            + 8 // omega
+    /// share unlocked as soon as the auction ends
+    pub vesting_tge_base_point: u16,
 
-            + 8 // alpha
+    pub vesting_cliff_duration: i64,
 
-            + 8 // time_shift_max
+    pub vesting_duration: i64,
 
-            + 8 // current_price
+    /// set once the auction ends or sells out
+    pub vesting_start_at: i64,
 
-            + 2 // current_round
+    pub total_vesting_claimed: u64,
 
-            + (4 + (limit * 8) as usize) // boost_history
+    /// anyone can advance rounds with crank_round once a round runs out of time
+    pub have_crank: bool,
 
-            + 1 // decay_model
+    /// duration of every round opened by crank_round
+    pub crank_round_duration: i64,
 
-            + 8 // total_supply
+    /// lamports paid to the cranker from the auction vault, as long as it holds enough
+    pub crank_reward: u64,
 
-            + 8 // total_supply_sold
+    pub total_crank_reward_funded: u64,
 
-             + 8 // total_user_buy_count
+    pub total_crank_reward_paid: u64,
 
-             + 8 // total_user_count
+    /// rounds follow `round_schedule` instead of the round starter's parameters
+    pub have_round_schedule: bool,
 
-             + 8 // total_unsold_supply_locked
+    /// one entry per round, starting from round 1
+    #[max_len(0)]
+    pub round_schedule: Vec<RoundSchedule>,
 
-             + 8 // unsold_supply_locked_at
+    /// first round opens here, the pad stays `Scheduled` until then
+    pub start_at: i64,
 
-             + 8 // unsold_supply_can_unlock_at
+    /// owner of the price accounts read by oracle priced `payment_mints`
+    pub payment_oracle_program: Pubkey,
 
-             + 8 // unsold_supply_unlocked_at
+    /// oldest oracle price, in seconds, a buy accepts
+    pub payment_oracle_max_age: i64,
 
-             + 8 // total_unsold_supply_distribution
+    /// mints accepted besides `payment_mint`, each with its own totals
+    #[max_len(MAX_PAYMENT_MINTS)]
+    pub payment_mints: Vec<PaymentMint>,
 
-             + 8 // total_unsold_supply_distribution_claimed
+    /// buys can name a referrer, who earns a share of the creator's payment
+    pub have_referral: bool,
 
-             + 8 // total_unsold_supply_distribution_claimed_count
+    pub referral_base_point: u16,
 
-             + 8 // total_payment
+    pub total_referral_reward: u64,
 
-             + 8 // total_fee
+    /// protocol fee snapshotted from the cream pad config at pad creation, 0 when no fee was required
+    pub fee_base_point: u16,
+
+    #[max_len(MAX_FEE_TIERS)]
+    pub fee_tiers: Vec<FeeTier>,
+
+    /// set by the signing authority, replaces the snapshotted fee and tiers
+    pub have_fee_override: bool,
+
+    pub fee_override_base_point: u16,
+
+    /// set by the signing authority, checked along with the cream pad config's flags
+    pub pause_flags: PauseFlags,
+
+    /// cream pad config the pad was created under
+    pub cream_pad_config: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 64],
+}
+
+impl AuctionAccount {
+    pub fn space(limit: u16) -> usize {
+        8 // default
+            + Self::INIT_SPACE // the vectors sized by the pad count as empty
+            + (limit as usize * std::mem::size_of::<i128>()) // boost_history
+            + (limit as usize * std::mem::size_of::<u64>()) // decay_model table prices
+            + (limit as usize * RoundSchedule::INIT_SPACE) // round_schedule
     }
 }
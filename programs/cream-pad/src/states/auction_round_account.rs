@@ -3,7 +3,10 @@ use anchor_lang::prelude::*;
 
 pub const AUCTION_ROUND_ACCOUNT_PREFIX: &str = "ARAP";
 
+pub const AUCTION_ROUND_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct AuctionRoundAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -18,7 +21,8 @@ pub struct AuctionRoundAccount {
 
     pub total_user_count: u64,
 
-    pub boost: f64,
+    /// scaled by `WAD`
+    pub boost: i128,
 
     pub price: u64,
 
@@ -35,39 +39,19 @@ pub struct AuctionRoundAccount {
     pub have_buy_limit: bool,
 
     pub buy_limit: u64,
+
+    /// paid the rent and gets it back when the round is closed, default on migrated rounds
+    pub rent_payer: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 64],
 }
 
 impl AuctionRoundAccount {
     pub fn space() -> usize {
         8 // default
-            + 8 // last_block_timestamp
-
-            + 8 // round_start_at
-
-            + 8 // round_end_at
-
-            + 8 // total_supply_sold
-
-            + 8 // total_user_buy_count
-
-            + 8 // total_user_count
-
-            + 8 // boost
-
-            + 8 // price
-
-            + 1 // status
-
-            + 8 // total_payment
-
-            + 8 // total_fee
-
-            + 2 // round
-
-            + 8 // round_ended_at
-
-            + 1 // have_buy_limit
-
-            + 8 // buy_limit
+            + Self::INIT_SPACE
     }
 }
@@ -1,10 +1,12 @@
 use anchor_lang::prelude::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum AuctionStatus {
     Started,
     Ended,
     SoldOut,
     UnsoldLockedAndDistributionOpen,
     UnsoldUnlocked,
-}
\ No newline at end of file
+    Cancelled,
+    Scheduled,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+/// Signed off-chain by the user so a relayer can submit the buy, the signed message is the
+/// borsh encoding of the intent.
+#[repr(C)]
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+pub struct BuyIntent {
+    /// auction_config of the pad
+    pub pad: Pubkey,
+
+    pub round: u16,
+
+    pub amount: u64,
+
+    /// the mint the user pays with, the pad's `payment_mint` or one of its extra payment mints
+    pub payment_mint: Pubkey,
+
+    /// highest `current_price` the user accepts, scaled to 9 decimals
+    pub max_price: u64,
+
+    /// most the user pays, fee included, in the smallest units of `payment_mint`
+    pub max_total_payment: u64,
+
+    pub expires_at: i64,
+
+    /// the buy index, so every intent can only be used once
+    pub nonce: u64,
+
+    /// default pubkey for no referrer, signed so the relayer can't add its own
+    pub referrer: Pubkey,
+}
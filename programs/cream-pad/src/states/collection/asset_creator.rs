@@ -1,16 +1,9 @@
 use anchor_lang::prelude::*;
 
 #[repr(C)]
-#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize)]
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
 pub struct AssetCreator {
     pub address: Pubkey,
 
     pub share: u8,
 }
-
-impl AssetCreator {
-    pub fn space() -> usize {
-        32 // address
-            + 1 // share
-    }
-}
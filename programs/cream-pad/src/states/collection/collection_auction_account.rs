@@ -1,10 +1,24 @@
-use crate::states::{AuctionStatus, DecayModelType};
+use crate::states::{
+    AuctionStatus, DecayModelType, FeeTier, PauseFlags, RoundSchedule, MAX_FEE_TIERS,
+};
 use anchor_lang::prelude::*;
 use crate::states::collection::AssetCreator;
 
 pub const COLLECTION_AUCTION_ACCOUNT_PREFIX: &str = "CAAP";
 
+pub const COLLECTION_AUCTION_ACCOUNT_VERSION: u8 = 1;
+
+/// asset metadata strings keep the byte budgets of the original layout
+pub const MAX_ASSET_NAME_LENGTH: usize = 16;
+
+pub const MAX_ASSET_SYMBOL_LENGTH: usize = 16;
+
+pub const MAX_ASSET_URL_LENGTH: usize = 96;
+
+pub const MAX_ASSET_URL_SUFFIX_LENGTH: usize = 6;
+
 #[account]
+#[derive(InitSpace)]
 pub struct CollectionAuctionAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -37,12 +51,15 @@ pub struct CollectionAuctionAccount {
 
     pub current_round: u16,
 
-    pub boost_history: Vec<f64>,
+    /// boost per ended round, scaled by `WAD`
+    #[max_len(0)]
+    pub boost_history: Vec<i128>,
 
     pub decay_model: DecayModelType,
 
     pub seller_fee_basis_points: u16,
 
+    #[max_len(0)]
     pub asset_creators: Vec<AssetCreator>,
 
     pub total_supply: u64,
@@ -79,100 +96,76 @@ pub struct CollectionAuctionAccount {
 
     pub total_minting_fee: u64,
 
+    #[max_len(MAX_ASSET_NAME_LENGTH)]
     pub asset_name: String,
 
+    #[max_len(MAX_ASSET_SYMBOL_LENGTH)]
     pub asset_symbol: String,
 
+    #[max_len(MAX_ASSET_URL_LENGTH)]
     pub asset_url: String,
 
+    #[max_len(MAX_ASSET_URL_SUFFIX_LENGTH)]
     pub asset_url_suffix: String,
 
     pub have_collection_update_authority: bool,
-}
-
-impl CollectionAuctionAccount {
-    pub fn space(limit: u16, creator_len: usize) -> usize {
-        8 // default
-            + 8 // last_block_timestamp
-
-            + 32 // creator
-
-            + 32 // collection_mint
-
-            + 32 // collection_update_authority
-
-            + 32 // payment_mint
-
-            + 32 // payment_receiver
-
-            + 1 // status
-
-            + 8 // p0
-
-            + 8 // ptmax
 
-            + 2 // tmax
+    pub have_whitelist: bool,
 
-            + 8 // omega
+    /// root of the `(user, allocation)` leaves allowed to buy during whitelist rounds
+    pub whitelist_merkle_root: [u8; 32],
 
-            + 8 // alpha
+    /// rounds, starting from round 1, that only whitelisted users can buy in
+    pub whitelist_rounds: u16,
 
-            + 8 // time_shift_max
+    /// rounds follow `round_schedule` instead of the round starter's parameters
+    pub have_round_schedule: bool,
 
-            + 8 // current_price
+    /// one entry per round, starting from round 1
+    #[max_len(0)]
+    pub round_schedule: Vec<RoundSchedule>,
 
-            + 2 // current_round
+    /// buys can name a referrer, who earns a share of the creator's payment
+    pub have_referral: bool,
 
-            + (4 + (limit * 8) as usize) // boost_history
+    pub referral_base_point: u16,
 
-            + 1 // decay_model
+    pub total_referral_reward: u64,
 
-            + 2 // seller_fee_basis_points
+    /// protocol fee snapshotted from the cream pad config at pad creation, 0 when no fee was required
+    pub fee_base_point: u16,
 
-            + (4 + (AssetCreator::space() * creator_len)) // asset_creators
+    #[max_len(MAX_FEE_TIERS)]
+    pub fee_tiers: Vec<FeeTier>,
 
-            + 8 // total_supply
+    pub minting_fee: u64,
 
-            + 8 // total_supply_sold
+    /// set by the signing authority, replaces the snapshotted fees and tiers
+    pub have_fee_override: bool,
 
-            + 8 // total_supply_sold_filled
+    pub fee_override_base_point: u16,
 
-            + 8 // total_user_buy_count
+    pub fee_override_minting_fee: u64,
 
-            + 8 // total_user_count
+    /// set by the signing authority, checked along with the cream pad config's flags
+    pub pause_flags: PauseFlags,
 
-            + 8 // starting_index
+    /// cream pad config the pad was created under
+    pub cream_pad_config: Pubkey,
 
-            + 8 // ending_index
+    pub version: u8,
 
-            + 8 // current_index
-
-            + 8 // total_unsold_supply_to_treasury
-
-            + 8 // total_unsold_supply_to_treasury_filled
-
-            + 8 // total_unsold_supply_distribution
-
-            + 8 // total_unsold_supply_distribution_claimed
-
-            + 8 // total_unsold_supply_distribution_claimed_count
-
-            + 8 // total_unsold_supply_distribution_claimed_filled
-
-            + 8 // total_payment
-
-            + 8 // total_fee
-
-            + 8 // total_minting_fee
-
-            + 20 // asset_name
-
-            + 20 // asset_symbol
-
-            + 100 // asset_uri
-
-            + 10 // asset_uri_suffix
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 64],
+}
 
-            + 1 // have_collection_update_authority
+impl CollectionAuctionAccount {
+    pub fn space(limit: u16, creator_len: usize) -> usize {
+        8 // default
+            + Self::INIT_SPACE // the vectors sized by the pad count as empty
+            + (limit as usize * std::mem::size_of::<i128>()) // boost_history
+            + (limit as usize * std::mem::size_of::<u64>()) // decay_model table prices
+            + (creator_len * AssetCreator::INIT_SPACE) // asset_creators
+            + (limit as usize * RoundSchedule::INIT_SPACE) // round_schedule
     }
 }
@@ -3,7 +3,10 @@ use anchor_lang::prelude::*;
 
 pub const COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX: &str = "CARAP";
 
+pub const COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct CollectionAuctionRoundAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -18,7 +21,8 @@ pub struct CollectionAuctionRoundAccount {
 
     pub total_user_count: u64,
 
-    pub boost: f64,
+    /// scaled by `WAD`
+    pub boost: i128,
 
     pub price: u64,
 
@@ -35,39 +39,16 @@ pub struct CollectionAuctionRoundAccount {
     pub have_buy_limit: bool,
 
     pub buy_limit: u64,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 64],
 }
 
 impl CollectionAuctionRoundAccount {
     pub fn space() -> usize {
         8 // default
-            + 8 // last_block_timestamp
-
-            + 8 // round_start_at
-
-            + 8 // round_end_at
-
-            + 8 // total_supply_sold
-
-            + 8 // total_user_buy_count
-
-            + 8 // total_user_count
-
-            + 8 // boost
-
-            + 8 // price
-
-            + 1 // status
-
-            + 8 // total_payment
-
-            + 8 // total_fee
-
-            + 2 // round
-
-            + 8 // round_ended_at
-
-            + 1 // have_buy_limit
-
-            + 8 // buy_limit
+            + Self::INIT_SPACE
     }
 }
@@ -2,7 +2,13 @@ use anchor_lang::prelude::*;
 
 pub const USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX: &str = "UCABRAP";
 
+pub const USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION: u8 = 1;
+
+/// byte budget of the original layout
+pub const MAX_PAD_NAME_LENGTH: usize = 46;
+
 #[account]
+#[derive(InitSpace)]
 pub struct UserCollectionAuctionBuyReceiptAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -21,28 +27,21 @@ pub struct UserCollectionAuctionBuyReceiptAccount {
 
     pub user: Pubkey,
 
+    #[max_len(MAX_PAD_NAME_LENGTH)]
     pub pad_name: String,
+
+    /// paid the rent for the receipt, default on migrated receipts
+    pub rent_payer: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
 }
 
 impl UserCollectionAuctionBuyReceiptAccount {
     pub fn space() -> usize {
         8 // default
-            + 8 // last_block_timestamp
-
-            + 8 // buy_amount
-
-            + 8 // buy_amount_filled
-
-            + 8 // payment
-
-            + 2 // round
-
-            + 8 // index
-
-            + 32 // collection_mint
-
-            + 32 // user
-
-            + 50 // pad_name
+            + Self::INIT_SPACE
     }
 }
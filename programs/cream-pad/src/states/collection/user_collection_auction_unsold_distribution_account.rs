@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 
 pub const USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX: &str = "UCAUDAP";
 
+pub const USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct UserCollectionAuctionUnsoldDistributionAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -10,15 +13,19 @@ pub struct UserCollectionAuctionUnsoldDistributionAccount {
     pub amount: u64,
 
     pub amount_filled: u64,
+
+    /// paid the rent for the account, default on migrated accounts
+    pub rent_payer: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
 }
 
 impl UserCollectionAuctionUnsoldDistributionAccount {
     pub fn space() -> usize {
         8 // default
-            + 8 // last_block_timestamp
-
-            + 8 // amount
-
-            + 8 // amount_filled
+            + Self::INIT_SPACE
     }
 }
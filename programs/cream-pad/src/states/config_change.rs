@@ -0,0 +1,42 @@
+use crate::states::{FeeTier, ProgramStatus, MAX_FEE_TIERS, MAX_GOVERNANCE_SIGNERS};
+use anchor_lang::prelude::*;
+
+/// every value `update_config` sets, applied at once by `execute_config_change`. The authorities
+/// only rotate through `propose_authority_transfer` and `accept_authority_transfer`
+#[repr(C)]
+#[derive(Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct ConfigChange {
+    pub is_back_authority_required: bool,
+
+    pub is_fee_required: bool,
+
+    pub fee_base_point: u16,
+
+    pub fee_receiver: Pubkey,
+
+    pub round_limit: u16,
+
+    pub program_status: ProgramStatus,
+
+    pub distribution_base_point: u16,
+
+    pub lock_base_point: u16,
+
+    pub lock_duration: i64,
+
+    pub minting_fee: u64,
+
+    pub treasury: Pubkey,
+
+    #[max_len(MAX_FEE_TIERS)]
+    pub fee_tiers: Vec<FeeTier>,
+
+    pub have_governance: bool,
+
+    #[max_len(MAX_GOVERNANCE_SIGNERS)]
+    pub governance_signers: Vec<Pubkey>,
+
+    pub governance_threshold: u8,
+
+    pub governance_timelock: i64,
+}
@@ -0,0 +1,8 @@
+use anchor_lang::prelude::*;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
+pub enum ConfigChangeStatus {
+    Pending,
+    Executed,
+    Cancelled,
+}
@@ -1,9 +1,17 @@
 use anchor_lang::prelude::*;
-use crate::states::ProgramStatus;
+use crate::states::{FeeTier, PauseFlags, ProgramStatus, MAX_FEE_TIERS};
 
 pub const CREAM_PAD_ACCOUNT_PREFIX: &str = "CPAP";
 
+pub const CREAM_PAD_ACCOUNT_VERSION: u8 = 1;
+
+pub const MAX_GOVERNANCE_SIGNERS: usize = 5;
+
+/// configs live at `[CREAM_PAD_ACCOUNT_PREFIX, tenant_id]`, the empty tenant id is the original config
+pub const MAX_TENANT_ID_LENGTH: usize = 32;
+
 #[account]
+#[derive(InitSpace)]
 pub struct CreamPadAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -32,25 +40,43 @@ pub struct CreamPadAccount {
 
     pub minting_fee: u64,
 
-    pub treasury: Pubkey
+    pub treasury: Pubkey,
+
+    /// ascending by `min_volume`, a pad pays the highest tier its volume reached
+    #[max_len(MAX_FEE_TIERS)]
+    pub fee_tiers: Vec<FeeTier>,
+
+    /// config changes need `governance_threshold` approvals and wait `governance_timelock`
+    pub have_governance: bool,
+
+    #[max_len(MAX_GOVERNANCE_SIGNERS)]
+    pub governance_signers: Vec<Pubkey>,
+
+    pub governance_threshold: u8,
+
+    pub governance_timelock: i64,
+
+    pub total_config_change_count: u64,
+
+    /// proposed by the signing authority, takes over once it accepts, default when none
+    pub pending_signing_authority: Pubkey,
+
+    pub pending_back_authority: Pubkey,
+
+    pub pause_flags: PauseFlags,
+
+    #[max_len(MAX_TENANT_ID_LENGTH)]
+    pub tenant_id: String,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 64],
 }
 
 impl CreamPadAccount {
     pub fn space() -> usize {
         8 // default
-            + 8 // last_block_timestamp
-            + 32 // signing_authority
-            + 32 // back_authority
-            + 1 // is_back_authority_required
-            + 1 // program_status
-            + 1 // is_fee_required
-            + 2 // fee_base_point
-            + 32 // fee_receiver
-            + 2 // round_limit
-            + 2 // distribution_base_point
-            + 2 // lock_base_point
-            + 8 // lock_duration
-            + 8 // minting_fee
-            + 32 // treasury
+            + Self::INIT_SPACE
     }
 }
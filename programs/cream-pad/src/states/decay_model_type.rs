@@ -1,7 +1,22 @@
 use anchor_lang::prelude::*;
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, InitSpace)]
 pub enum DecayModelType {
     Linear,
-    Exponential
-}
\ No newline at end of file
+    Exponential,
+    /// drops `step_base_point` of the price every `step_rounds` rounds
+    Step {
+        step_rounds: u16,
+        step_base_point: u16,
+    },
+    /// slow, then fast, then slow, `steepness` shapes the middle of the curve
+    Sigmoid {
+        steepness: u64,
+    },
+    /// explicit price per round, `prices[0]` is p0 and the last one is ptmax, the pad's `space`
+    /// adds room for one price per round
+    Table {
+        #[max_len(0)]
+        prices: Vec<u64>,
+    },
+}
@@ -0,0 +1,16 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_FEE_TIERS: usize = 4;
+
+/// fee charged once the volume of a pad paid in `payment_mint` reaches `min_volume`
+#[repr(C)]
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct FeeTier {
+    /// volumes of different payment mints don't compare, pads only get the tiers of their mint
+    pub payment_mint: Pubkey,
+
+    /// pad `total_payment`, normalized to 9 decimals
+    pub min_volume: u64,
+
+    pub fee_base_point: u16,
+}
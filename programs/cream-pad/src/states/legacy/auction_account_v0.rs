@@ -0,0 +1,213 @@
+use crate::states::legacy::boost_to_wad;
+use crate::states::{
+    AuctionAccount, AuctionStatus, DecayModelType, PauseFlags, AUCTION_ACCOUNT_VERSION,
+};
+use anchor_lang::prelude::*;
+
+/// Layout of `AuctionAccount` before boost moved from f64 to fixed point
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AuctionAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub creator: Pubkey,
+
+    pub mint: Pubkey,
+
+    pub payment_mint: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub status: AuctionStatus,
+
+    pub p0: u64,
+
+    pub ptmax: u64,
+
+    pub tmax: u16,
+
+    pub omega: u64,
+
+    pub alpha: u64,
+
+    pub time_shift_max: u64,
+
+    pub current_price: u64,
+
+    pub current_round: u16,
+
+    pub boost_history: Vec<f64>,
+
+    pub decay_model: DecayModelType,
+
+    pub total_supply: u64,
+
+    pub total_supply_sold: u64,
+
+    pub total_user_buy_count: u64,
+
+    pub total_user_count: u64,
+
+    pub total_unsold_supply_locked: u64,
+
+    pub unsold_supply_locked_at: i64,
+
+    pub unsold_supply_can_unlock_at: i64,
+
+    pub unsold_supply_unlocked_at: i64,
+
+    pub total_unsold_supply_distribution: u64,
+
+    pub total_unsold_supply_distribution_claimed: u64,
+
+    pub total_unsold_supply_distribution_claimed_count: u64,
+
+    pub total_payment: u64,
+
+    pub total_fee: u64,
+}
+
+impl AuctionAccountV0 {
+    pub fn space(limit: u16) -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 32 // creator
+
+            + 32 // mint
+
+            + 32 // payment_mint
+
+            + 32 // payment_receiver
+
+            + 1 // status
+
+            + 8 // p0
+
+            + 8 // ptmax
+
+            + 2 // tmax
+
+            + 8 // omega
+
+            + 8 // alpha
+
+            + 8 // time_shift_max
+
+            + 8 // current_price
+
+            + 2 // current_round
+
+            + (4 + (limit * 8) as usize) // boost_history
+
+            + 1 // decay_model
+
+            + 8 // total_supply
+
+            + 8 // total_supply_sold
+
+             + 8 // total_user_buy_count
+
+             + 8 // total_user_count
+
+             + 8 // total_unsold_supply_locked
+
+             + 8 // unsold_supply_locked_at
+
+             + 8 // unsold_supply_can_unlock_at
+
+             + 8 // unsold_supply_unlocked_at
+
+             + 8 // total_unsold_supply_distribution
+
+             + 8 // total_unsold_supply_distribution_claimed
+
+             + 8 // total_unsold_supply_distribution_claimed_count
+
+             + 8 // total_payment
+
+             + 8 // total_fee
+    }
+
+    pub fn migrate(self) -> AuctionAccount {
+        AuctionAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            creator: self.creator,
+            mint: self.mint,
+            payment_mint: self.payment_mint,
+            payment_receiver: self.payment_receiver,
+            status: self.status,
+            p0: self.p0,
+            ptmax: self.ptmax,
+            tmax: self.tmax,
+            omega: self.omega,
+            alpha: self.alpha,
+            time_shift_max: self.time_shift_max,
+            current_price: self.current_price,
+            current_round: self.current_round,
+            boost_history: self
+                .boost_history
+                .iter()
+                .map(|&boost| boost_to_wad(boost))
+                .collect(),
+            decay_model: self.decay_model,
+            total_supply: self.total_supply,
+            total_supply_sold: self.total_supply_sold,
+            total_user_buy_count: self.total_user_buy_count,
+            total_user_count: self.total_user_count,
+            total_unsold_supply_locked: self.total_unsold_supply_locked,
+            unsold_supply_locked_at: self.unsold_supply_locked_at,
+            unsold_supply_can_unlock_at: self.unsold_supply_can_unlock_at,
+            unsold_supply_unlocked_at: self.unsold_supply_unlocked_at,
+            total_unsold_supply_distribution: self.total_unsold_supply_distribution,
+            total_unsold_supply_distribution_claimed: self.total_unsold_supply_distribution_claimed,
+            total_unsold_supply_distribution_claimed_count: self
+                .total_unsold_supply_distribution_claimed_count,
+            total_payment: self.total_payment,
+            total_fee: self.total_fee,
+            have_whitelist: false,
+            whitelist_merkle_root: [0; 32],
+            whitelist_rounds: 0,
+            have_payment_escrow: false,
+            cancelled_at: 0,
+            total_refunded_supply: 0,
+            total_refunded_payment: 0,
+            is_payment_settled: false,
+            payment_settled_at: 0,
+            total_settled_payment: 0,
+            total_settled_fee: 0,
+            have_uniform_clearing_price: false,
+            clearing_price: 0,
+            total_rebate: 0,
+            total_rebate_claimed_count: 0,
+            have_vesting: false,
+            vesting_tge_base_point: 0,
+            vesting_cliff_duration: 0,
+            vesting_duration: 0,
+            vesting_start_at: 0,
+            total_vesting_claimed: 0,
+            have_crank: false,
+            crank_round_duration: 0,
+            crank_reward: 0,
+            total_crank_reward_funded: 0,
+            total_crank_reward_paid: 0,
+            have_round_schedule: false,
+            round_schedule: Vec::new(),
+            start_at: 0,
+            payment_oracle_program: Pubkey::default(),
+            payment_oracle_max_age: 0,
+            payment_mints: Vec::new(),
+            have_referral: false,
+            referral_base_point: 0,
+            total_referral_reward: 0,
+            fee_base_point: 0,
+            fee_tiers: Vec::new(),
+            have_fee_override: false,
+            fee_override_base_point: 0,
+            pause_flags: PauseFlags::default(),
+            cream_pad_config: Pubkey::default(),
+            version: AUCTION_ACCOUNT_VERSION,
+            reserved: [0; 64],
+        }
+    }
+}
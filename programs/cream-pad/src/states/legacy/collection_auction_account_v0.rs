@@ -0,0 +1,250 @@
+use crate::states::legacy::boost_to_wad;
+use crate::states::{
+    AssetCreator, AuctionStatus, CollectionAuctionAccount, DecayModelType, PauseFlags,
+    COLLECTION_AUCTION_ACCOUNT_VERSION,
+};
+use anchor_lang::prelude::*;
+
+/// Layout of `CollectionAuctionAccount` before boost moved from f64 to fixed point
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollectionAuctionAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub creator: Pubkey,
+
+    pub collection_mint: Pubkey,
+
+    pub collection_update_authority: Pubkey,
+
+    pub payment_mint: Pubkey,
+
+    pub payment_receiver: Pubkey,
+
+    pub status: AuctionStatus,
+
+    pub p0: u64,
+
+    pub ptmax: u64,
+
+    pub tmax: u16,
+
+    pub omega: u64,
+
+    pub alpha: u64,
+
+    pub time_shift_max: u64,
+
+    pub current_price: u64,
+
+    pub current_round: u16,
+
+    pub boost_history: Vec<f64>,
+
+    pub decay_model: DecayModelType,
+
+    pub seller_fee_basis_points: u16,
+
+    pub asset_creators: Vec<AssetCreator>,
+
+    pub total_supply: u64,
+
+    pub total_supply_sold: u64,
+
+    pub total_supply_sold_filled: u64,
+
+    pub total_user_buy_count: u64,
+
+    pub total_user_count: u64,
+
+    pub starting_index: u64,
+
+    pub ending_index: u64,
+
+    pub current_index: u64,
+
+    pub total_unsold_supply_to_treasury: u64,
+
+    pub total_unsold_supply_to_treasury_filled: u64,
+
+    pub total_unsold_supply_distribution: u64,
+
+    pub total_unsold_supply_distribution_claimed: u64,
+
+    pub total_unsold_supply_distribution_claimed_count: u64,
+
+    pub total_unsold_supply_distribution_claimed_filled: u64,
+
+    pub total_payment: u64,
+
+    pub total_fee: u64,
+
+    pub total_minting_fee: u64,
+
+    pub asset_name: String,
+
+    pub asset_symbol: String,
+
+    pub asset_url: String,
+
+    pub asset_url_suffix: String,
+
+    pub have_collection_update_authority: bool,
+}
+
+impl CollectionAuctionAccountV0 {
+    pub fn space(limit: u16, creator_len: usize) -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 32 // creator
+
+            + 32 // collection_mint
+
+            + 32 // collection_update_authority
+
+            + 32 // payment_mint
+
+            + 32 // payment_receiver
+
+            + 1 // status
+
+            + 8 // p0
+
+            + 8 // ptmax
+
+            + 2 // tmax
+
+            + 8 // omega
+
+            + 8 // alpha
+
+            + 8 // time_shift_max
+
+            + 8 // current_price
+
+            + 2 // current_round
+
+            + (4 + (limit * 8) as usize) // boost_history
+
+            + 1 // decay_model
+
+            + 2 // seller_fee_basis_points
+
+            + (4 + (AssetCreator::INIT_SPACE * creator_len)) // asset_creators
+
+            + 8 // total_supply
+
+            + 8 // total_supply_sold
+
+            + 8 // total_supply_sold_filled
+
+            + 8 // total_user_buy_count
+
+            + 8 // total_user_count
+
+            + 8 // starting_index
+
+            + 8 // ending_index
+
+            + 8 // current_index
+
+            + 8 // total_unsold_supply_to_treasury
+
+            + 8 // total_unsold_supply_to_treasury_filled
+
+            + 8 // total_unsold_supply_distribution
+
+            + 8 // total_unsold_supply_distribution_claimed
+
+            + 8 // total_unsold_supply_distribution_claimed_count
+
+            + 8 // total_unsold_supply_distribution_claimed_filled
+
+            + 8 // total_payment
+
+            + 8 // total_fee
+
+            + 8 // total_minting_fee
+
+            + 20 // asset_name
+
+            + 20 // asset_symbol
+
+            + 100 // asset_uri
+
+            + 10 // asset_uri_suffix
+
+            + 1 // have_collection_update_authority
+    }
+
+    pub fn migrate(self) -> CollectionAuctionAccount {
+        CollectionAuctionAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            creator: self.creator,
+            collection_mint: self.collection_mint,
+            collection_update_authority: self.collection_update_authority,
+            payment_mint: self.payment_mint,
+            payment_receiver: self.payment_receiver,
+            status: self.status,
+            p0: self.p0,
+            ptmax: self.ptmax,
+            tmax: self.tmax,
+            omega: self.omega,
+            alpha: self.alpha,
+            time_shift_max: self.time_shift_max,
+            current_price: self.current_price,
+            current_round: self.current_round,
+            boost_history: self
+                .boost_history
+                .iter()
+                .map(|&boost| boost_to_wad(boost))
+                .collect(),
+            decay_model: self.decay_model,
+            seller_fee_basis_points: self.seller_fee_basis_points,
+            asset_creators: self.asset_creators,
+            total_supply: self.total_supply,
+            total_supply_sold: self.total_supply_sold,
+            total_supply_sold_filled: self.total_supply_sold_filled,
+            total_user_buy_count: self.total_user_buy_count,
+            total_user_count: self.total_user_count,
+            starting_index: self.starting_index,
+            ending_index: self.ending_index,
+            current_index: self.current_index,
+            total_unsold_supply_to_treasury: self.total_unsold_supply_to_treasury,
+            total_unsold_supply_to_treasury_filled: self.total_unsold_supply_to_treasury_filled,
+            total_unsold_supply_distribution: self.total_unsold_supply_distribution,
+            total_unsold_supply_distribution_claimed: self.total_unsold_supply_distribution_claimed,
+            total_unsold_supply_distribution_claimed_count: self
+                .total_unsold_supply_distribution_claimed_count,
+            total_unsold_supply_distribution_claimed_filled: self
+                .total_unsold_supply_distribution_claimed_filled,
+            total_payment: self.total_payment,
+            total_fee: self.total_fee,
+            total_minting_fee: self.total_minting_fee,
+            asset_name: self.asset_name,
+            asset_symbol: self.asset_symbol,
+            asset_url: self.asset_url,
+            asset_url_suffix: self.asset_url_suffix,
+            have_collection_update_authority: self.have_collection_update_authority,
+            have_whitelist: false,
+            whitelist_merkle_root: [0; 32],
+            whitelist_rounds: 0,
+            have_round_schedule: false,
+            round_schedule: Vec::new(),
+            have_referral: false,
+            referral_base_point: 0,
+            total_referral_reward: 0,
+            fee_base_point: 0,
+            fee_tiers: Vec::new(),
+            minting_fee: 0,
+            have_fee_override: false,
+            fee_override_base_point: 0,
+            fee_override_minting_fee: 0,
+            pause_flags: PauseFlags::default(),
+            cream_pad_config: Pubkey::default(),
+            version: COLLECTION_AUCTION_ACCOUNT_VERSION,
+            reserved: [0; 64],
+        }
+    }
+}
@@ -0,0 +1,97 @@
+use crate::states::legacy::boost_to_wad;
+use crate::states::{
+    AuctionRoundStatus, CollectionAuctionRoundAccount, COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
+};
+use anchor_lang::prelude::*;
+
+/// Layout of `CollectionAuctionRoundAccount` before boost moved from f64 to fixed point
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollectionAuctionRoundAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub round_start_at: i64,
+
+    pub round_end_at: i64,
+
+    pub total_supply_sold: u64,
+
+    pub total_user_buy_count: u64,
+
+    pub total_user_count: u64,
+
+    pub boost: f64,
+
+    pub price: u64,
+
+    pub status: AuctionRoundStatus,
+
+    pub total_payment: u64,
+
+    pub total_fee: u64,
+
+    pub round: u16,
+
+    pub round_ended_at: i64,
+
+    pub have_buy_limit: bool,
+
+    pub buy_limit: u64,
+}
+
+impl CollectionAuctionRoundAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 8 // round_start_at
+
+            + 8 // round_end_at
+
+            + 8 // total_supply_sold
+
+            + 8 // total_user_buy_count
+
+            + 8 // total_user_count
+
+            + 8 // boost
+
+            + 8 // price
+
+            + 1 // status
+
+            + 8 // total_payment
+
+            + 8 // total_fee
+
+            + 2 // round
+
+            + 8 // round_ended_at
+
+            + 1 // have_buy_limit
+
+            + 8 // buy_limit
+    }
+
+    pub fn migrate(self) -> CollectionAuctionRoundAccount {
+        CollectionAuctionRoundAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            round_start_at: self.round_start_at,
+            round_end_at: self.round_end_at,
+            total_supply_sold: self.total_supply_sold,
+            total_user_buy_count: self.total_user_buy_count,
+            total_user_count: self.total_user_count,
+            boost: boost_to_wad(self.boost),
+            price: self.price,
+            status: self.status,
+            total_payment: self.total_payment,
+            total_fee: self.total_fee,
+            round: self.round,
+            round_ended_at: self.round_ended_at,
+            have_buy_limit: self.have_buy_limit,
+            buy_limit: self.buy_limit,
+            version: COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION,
+            reserved: [0; 64],
+        }
+    }
+}
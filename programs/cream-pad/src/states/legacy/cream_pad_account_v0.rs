@@ -0,0 +1,86 @@
+use crate::states::{CreamPadAccount, PauseFlags, ProgramStatus, CREAM_PAD_ACCOUNT_VERSION};
+use anchor_lang::prelude::*;
+
+/// Unversioned layout of `CreamPadAccount`, before fee tiers, governance, authority transfers, pause flags and tenants
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreamPadAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub signing_authority: Pubkey,
+
+    pub back_authority: Pubkey,
+
+    pub is_back_authority_required: bool,
+
+    pub program_status: ProgramStatus,
+
+    pub is_fee_required: bool,
+
+    pub fee_base_point: u16,
+
+    pub fee_receiver: Pubkey,
+
+    pub round_limit: u16,
+
+    pub distribution_base_point: u16,
+
+    pub lock_base_point: u16,
+
+    pub lock_duration: i64,
+
+    pub minting_fee: u64,
+
+    pub treasury: Pubkey,
+}
+
+impl CreamPadAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+            + 32 // signing_authority
+            + 32 // back_authority
+            + 1 // is_back_authority_required
+            + 1 // program_status
+            + 1 // is_fee_required
+            + 2 // fee_base_point
+            + 32 // fee_receiver
+            + 2 // round_limit
+            + 2 // distribution_base_point
+            + 2 // lock_base_point
+            + 8 // lock_duration
+            + 8 // minting_fee
+            + 32 // treasury
+    }
+
+    pub fn migrate(self) -> CreamPadAccount {
+        CreamPadAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            signing_authority: self.signing_authority,
+            back_authority: self.back_authority,
+            is_back_authority_required: self.is_back_authority_required,
+            program_status: self.program_status,
+            is_fee_required: self.is_fee_required,
+            fee_base_point: self.fee_base_point,
+            fee_receiver: self.fee_receiver,
+            round_limit: self.round_limit,
+            distribution_base_point: self.distribution_base_point,
+            lock_base_point: self.lock_base_point,
+            lock_duration: self.lock_duration,
+            minting_fee: self.minting_fee,
+            treasury: self.treasury,
+            fee_tiers: Vec::new(),
+            have_governance: false,
+            governance_signers: Vec::new(),
+            governance_threshold: 0,
+            governance_timelock: 0,
+            total_config_change_count: 0,
+            pending_signing_authority: Pubkey::default(),
+            pending_back_authority: Pubkey::default(),
+            pause_flags: PauseFlags::default(),
+            tenant_id: String::new(),
+            version: CREAM_PAD_ACCOUNT_VERSION,
+            reserved: [0; 64],
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use crate::math::WAD;
+
+pub use auction_account_v0::*;
+pub use auction_round_account_v0::*;
+pub use collection_auction_account_v0::*;
+pub use collection_auction_round_account_v0::*;
+pub use cream_pad_account_v0::*;
+pub use user_auction_account_v0::*;
+pub use user_auction_buy_receipt_account_v0::*;
+pub use user_auction_round_account_v0::*;
+pub use user_auction_unsold_distribution_account_v0::*;
+pub use user_collection_auction_account_v0::*;
+pub use user_collection_auction_buy_receipt_account_v0::*;
+pub use user_collection_auction_round_account_v0::*;
+pub use user_collection_auction_unsold_distribution_account_v0::*;
+
+pub mod auction_account_v0;
+pub mod auction_round_account_v0;
+pub mod collection_auction_account_v0;
+pub mod collection_auction_round_account_v0;
+pub mod cream_pad_account_v0;
+pub mod user_auction_account_v0;
+pub mod user_auction_buy_receipt_account_v0;
+pub mod user_auction_round_account_v0;
+pub mod user_auction_unsold_distribution_account_v0;
+pub mod user_collection_auction_account_v0;
+pub mod user_collection_auction_buy_receipt_account_v0;
+pub mod user_collection_auction_round_account_v0;
+pub mod user_collection_auction_unsold_distribution_account_v0;
+
+// Legacy f64 boost to `WAD`, only used while migrating old layouts
+pub fn boost_to_wad(boost: f64) -> i128 {
+    (boost * WAD as f64) as i128
+}
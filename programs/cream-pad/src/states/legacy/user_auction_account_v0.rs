@@ -0,0 +1,51 @@
+use crate::states::{UserAuctionAccount, UserAuctionStatus, USER_AUCTION_ACCOUNT_VERSION};
+use anchor_lang::prelude::*;
+
+/// Unversioned layout of `UserAuctionAccount`, before whitelist buys were tracked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserAuctionAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub user: Pubkey,
+
+    pub total_buy_count: u64,
+
+    pub total_buy_amount: u64,
+
+    pub total_payment: u64,
+
+    pub status: UserAuctionStatus,
+}
+
+impl UserAuctionAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 32 // user
+
+            + 8 // total_buy_count
+
+            + 8 // total_buy_amount
+
+            + 8 // total_payment
+
+            + 1 // status
+    }
+
+    pub fn migrate(self) -> UserAuctionAccount {
+        UserAuctionAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            user: self.user,
+            total_buy_count: self.total_buy_count,
+            total_buy_amount: self.total_buy_amount,
+            total_payment: self.total_payment,
+            status: self.status,
+            total_whitelist_buy_amount: 0,
+            rent_payer: Pubkey::default(),
+            version: USER_AUCTION_ACCOUNT_VERSION,
+            reserved: [0; 32],
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use crate::states::{UserAuctionBuyReceiptAccount, USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION};
+use anchor_lang::prelude::*;
+
+/// Unversioned layout of `UserAuctionBuyReceiptAccount`, before refunds and rebates
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserAuctionBuyReceiptAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub buy_amount: u64,
+
+    pub payment: u64,
+
+    pub round: u16,
+
+    pub index: u64,
+}
+
+impl UserAuctionBuyReceiptAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 8 // buy_amount
+
+            + 8 // payment
+
+            + 2 // round
+
+            + 8 // index
+    }
+
+    pub fn migrate(self) -> UserAuctionBuyReceiptAccount {
+        UserAuctionBuyReceiptAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            buy_amount: self.buy_amount,
+            payment: self.payment,
+            round: self.round,
+            index: self.index,
+            is_refunded: false,
+            is_rebate_claimed: false,
+            rent_payer: Pubkey::default(),
+            version: USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+            reserved: [0; 32],
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use crate::states::{UserAuctionRoundAccount, USER_AUCTION_ROUND_ACCOUNT_VERSION};
+use anchor_lang::prelude::*;
+
+/// Unversioned layout of `UserAuctionRoundAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserAuctionRoundAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub total_buy_count: u64,
+
+    pub total_buy_amount: u64,
+
+    pub total_payment: u64,
+
+    pub round: u16,
+}
+
+impl UserAuctionRoundAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 8 // total_buy_count
+
+            + 8 // total_buy_amount
+
+            + 8 // total_payment
+
+            + 2 // round
+    }
+
+    pub fn migrate(self) -> UserAuctionRoundAccount {
+        UserAuctionRoundAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            total_buy_count: self.total_buy_count,
+            total_buy_amount: self.total_buy_amount,
+            total_payment: self.total_payment,
+            round: self.round,
+            rent_payer: Pubkey::default(),
+            version: USER_AUCTION_ROUND_ACCOUNT_VERSION,
+            reserved: [0; 32],
+        }
+    }
+}
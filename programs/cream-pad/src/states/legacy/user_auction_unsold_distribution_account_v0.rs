@@ -0,0 +1,32 @@
+use crate::states::{
+    UserAuctionUnsoldDistributionAccount, USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION,
+};
+use anchor_lang::prelude::*;
+
+/// Unversioned layout of `UserAuctionUnsoldDistributionAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserAuctionUnsoldDistributionAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub amount: u64,
+}
+
+impl UserAuctionUnsoldDistributionAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 8 // amount
+    }
+
+    pub fn migrate(self) -> UserAuctionUnsoldDistributionAccount {
+        UserAuctionUnsoldDistributionAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            amount: self.amount,
+            rent_payer: Pubkey::default(),
+            version: USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION,
+            reserved: [0; 32],
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use crate::states::{
+    UserCollectionAuctionBuyReceiptAccount, USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+};
+use anchor_lang::prelude::*;
+
+/// Unversioned layout of `UserCollectionAuctionBuyReceiptAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserCollectionAuctionBuyReceiptAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub buy_amount: u64,
+
+    pub buy_amount_filled: u64,
+
+    pub payment: u64,
+
+    pub round: u16,
+
+    pub index: u64,
+
+    pub collection_mint: Pubkey,
+
+    pub user: Pubkey,
+
+    pub pad_name: String,
+}
+
+impl UserCollectionAuctionBuyReceiptAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 8 // buy_amount
+
+            + 8 // buy_amount_filled
+
+            + 8 // payment
+
+            + 2 // round
+
+            + 8 // index
+
+            + 32 // collection_mint
+
+            + 32 // user
+
+            + 50 // pad_name
+    }
+
+    pub fn migrate(self) -> UserCollectionAuctionBuyReceiptAccount {
+        UserCollectionAuctionBuyReceiptAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            buy_amount: self.buy_amount,
+            buy_amount_filled: self.buy_amount_filled,
+            payment: self.payment,
+            round: self.round,
+            index: self.index,
+            collection_mint: self.collection_mint,
+            user: self.user,
+            pad_name: self.pad_name,
+            rent_payer: Pubkey::default(),
+            version: USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION,
+            reserved: [0; 32],
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use crate::states::{
+    UserCollectionAuctionUnsoldDistributionAccount,
+    USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION,
+};
+use anchor_lang::prelude::*;
+
+/// Unversioned layout of `UserCollectionAuctionUnsoldDistributionAccount`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UserCollectionAuctionUnsoldDistributionAccountV0 {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub amount: u64,
+
+    pub amount_filled: u64,
+}
+
+impl UserCollectionAuctionUnsoldDistributionAccountV0 {
+    pub fn space() -> usize {
+        8 // default
+            + 8 // last_block_timestamp
+
+            + 8 // amount
+
+            + 8 // amount_filled
+    }
+
+    pub fn migrate(self) -> UserCollectionAuctionUnsoldDistributionAccount {
+        UserCollectionAuctionUnsoldDistributionAccount {
+            last_block_timestamp: self.last_block_timestamp,
+            amount: self.amount,
+            amount_filled: self.amount_filled,
+            rent_payer: Pubkey::default(),
+            version: USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION,
+            reserved: [0; 32],
+        }
+    }
+}
@@ -1,13 +1,31 @@
 pub use program_status::*;
 
+pub use pause_flags::*;
+
 pub use cream_pad_account::*;
 
+pub use config_change::*;
+
+pub use config_change_status::*;
+
+pub use pending_config_change_account::*;
+
+pub use authority_type::*;
+
 pub use auction_account::*;
 
 pub use auction_status::*;
 
 pub use decay_model_type::*;
 
+pub use round_schedule::*;
+
+pub use payment_mint::*;
+
+pub use fee_tier::*;
+
+pub use buy_intent::*;
+
 pub use auction_round_account::*;
 
 pub use auction_round_status::*;
@@ -22,18 +40,42 @@ pub use user_auction_buy_receipt_account::*;
 
 pub use user_auction_unsold_distribution_account::*;
 
+pub use user_auction_vesting_account::*;
+
+pub use referrer_account::*;
+
 pub use collection::*;
 
+pub use legacy::*;
+
 pub mod program_status;
 
+pub mod pause_flags;
+
 pub mod cream_pad_account;
 
+pub mod config_change;
+
+pub mod config_change_status;
+
+pub mod pending_config_change_account;
+
+pub mod authority_type;
+
 pub mod auction_account;
 
 pub mod auction_status;
 
 pub mod decay_model_type;
 
+pub mod round_schedule;
+
+pub mod payment_mint;
+
+pub mod fee_tier;
+
+pub mod buy_intent;
+
 pub mod auction_round_account;
 
 pub mod auction_round_status;
@@ -48,4 +90,10 @@ pub mod user_auction_buy_receipt_account;
 
 pub mod user_auction_unsold_distribution_account;
 
+pub mod user_auction_vesting_account;
+
+pub mod referrer_account;
+
 pub mod collection;
+
+pub mod legacy;
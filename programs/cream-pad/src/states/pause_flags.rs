@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// operations paused on top of `ProgramStatus`, paid for claims are never paused
+#[repr(C)]
+#[derive(Debug, Clone, Default, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct PauseFlags {
+    pub pause_buys: bool,
+
+    /// only checked on the cream pad config
+    pub pause_new_pads: bool,
+
+    /// unsold supply distributions and referral rewards
+    pub pause_claims: bool,
+
+    /// treasury assets and claimed distribution assets, bought assets are still filled
+    pub pause_collection_minting: bool,
+}
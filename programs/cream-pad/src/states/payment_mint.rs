@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+pub const MAX_PAYMENT_MINTS: usize = 4;
+
+/// `rate` is scaled by 10^9
+pub const PAYMENT_RATE_SCALE: u64 = 1_000_000_000;
+
+/// extra mint a pad accepts besides `payment_mint`
+#[repr(C)]
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct PaymentMint {
+    pub mint: Pubkey,
+
+    /// units of this mint paid per unit of `payment_mint`, both normalized to 9 decimals
+    pub rate: u64,
+
+    /// the rate is read from `oracle` instead of `rate`
+    pub have_oracle: bool,
+
+    /// price account owned by the pad's `payment_oracle_program`
+    pub oracle: Pubkey,
+
+    /// paid in this mint, normalized to 9 decimals, fee included
+    pub total_payment: u64,
+
+    pub total_fee: u64,
+}
+
+/// price account layout expected from the oracle program, after its 8 byte discriminator
+pub struct OraclePrice {
+    /// same unit as `PaymentMint::rate`
+    pub rate: u64,
+
+    pub published_at: i64,
+}
+
+impl OraclePrice {
+    pub fn try_from_data(data: &[u8]) -> Option<Self> {
+        let rate: [u8; 8] = data.get(8..16)?.try_into().ok()?;
+        let published_at: [u8; 8] = data.get(16..24)?.try_into().ok()?;
+
+        Some(OraclePrice {
+            rate: u64::from_le_bytes(rate),
+            published_at: i64::from_le_bytes(published_at),
+        })
+    }
+}
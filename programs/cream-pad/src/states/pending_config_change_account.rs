@@ -0,0 +1,42 @@
+use crate::states::{ConfigChange, ConfigChangeStatus, MAX_GOVERNANCE_SIGNERS};
+use anchor_lang::prelude::*;
+
+pub const PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX: &str = "PCCP";
+
+pub const PENDING_CONFIG_CHANGE_ACCOUNT_VERSION: u8 = 1;
+
+#[account]
+#[derive(InitSpace)]
+pub struct PendingConfigChangeAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub index: u64,
+
+    pub proposer: Pubkey,
+
+    pub change: ConfigChange,
+
+    /// governance signers that approved, the proposer included
+    #[max_len(MAX_GOVERNANCE_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+
+    pub status: ConfigChangeStatus,
+
+    pub proposed_at: i64,
+
+    /// end of the timelock, snapshotted when proposed
+    pub executable_at: i64,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 64],
+}
+
+impl PendingConfigChangeAccount {
+    pub fn space() -> usize {
+        8 // default
+            + Self::INIT_SPACE
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+pub const REFERRER_ACCOUNT_PREFIX: &str = "RAP";
+
+pub const REFERRER_ACCOUNT_VERSION: u8 = 1;
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReferrerAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    pub referrer: Pubkey,
+
+    /// auction or collection auction config the rewards are earned on
+    pub pad_config: Pubkey,
+
+    /// cream pad config of the pad
+    pub cream_pad_config: Pubkey,
+
+    /// rewards are held by this account's token account of `payment_mint`
+    pub payment_mint: Pubkey,
+
+    pub total_referred_buy_count: u64,
+
+    pub total_reward: u64,
+
+    pub total_claimed_reward: u64,
+
+    /// paid the rent for the account, the first referred buy's payer
+    pub rent_payer: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
+}
+
+impl ReferrerAccount {
+    pub fn space() -> usize {
+        8 // default
+            + Self::INIT_SPACE
+    }
+}
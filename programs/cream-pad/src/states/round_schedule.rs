@@ -0,0 +1,14 @@
+use anchor_lang::prelude::*;
+
+#[repr(C)]
+#[derive(Debug, Clone, AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct RoundSchedule {
+    pub duration: i64,
+
+    pub have_buy_limit: bool,
+
+    pub buy_limit: u64,
+
+    /// wait after the previous round's `round_end_at` before this round can start
+    pub gap: i64,
+}
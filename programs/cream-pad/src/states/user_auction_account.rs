@@ -3,7 +3,10 @@ use anchor_lang::prelude::*;
 
 pub const USER_AUCTION_ACCOUNT_PREFIX: &str = "UAAP";
 
+pub const USER_AUCTION_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct UserAuctionAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -17,21 +20,22 @@ pub struct UserAuctionAccount {
     pub total_payment: u64,
 
     pub status: UserAuctionStatus,
-}
-
-impl UserAuctionAccount {
-    pub fn space() -> usize {
-        8 // default
-            + 8 // last_block_timestamp
 
-            + 32 // user
+    /// amount bought while the pad was in its whitelist rounds
+    pub total_whitelist_buy_amount: u64,
 
-            + 8 // total_buy_count
+    /// paid the rent for the account, default on migrated accounts
+    pub rent_payer: Pubkey,
 
-            + 8 // total_buy_amount
+    pub version: u8,
 
-            + 8 // total_payment
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
+}
 
-            + 1 // status
+impl UserAuctionAccount {
+    pub fn space() -> usize {
+        8 // default
+            + Self::INIT_SPACE
     }
 }
@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 
 pub const USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX: &str = "UABRAP";
 
+pub const USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct UserAuctionBuyReceiptAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -14,19 +17,23 @@ pub struct UserAuctionBuyReceiptAccount {
     pub round: u16,
 
     pub index: u64,
-}
 
-impl UserAuctionBuyReceiptAccount {
-    pub fn space() -> usize {
-        8 // default
-            + 8 // last_block_timestamp
+    pub is_refunded: bool,
 
-            + 8 // buy_amount
+    pub is_rebate_claimed: bool,
 
-            + 8 // payment
+    /// paid the rent and gets it back on close, default on migrated receipts
+    pub rent_payer: Pubkey,
 
-            + 2 // round
+    pub version: u8,
 
-            + 8 // index
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
+}
+
+impl UserAuctionBuyReceiptAccount {
+    pub fn space() -> usize {
+        8 // default
+            + Self::INIT_SPACE
     }
 }
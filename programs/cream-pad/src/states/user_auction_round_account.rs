@@ -2,7 +2,10 @@ use anchor_lang::prelude::*;
 
 pub const USER_AUCTION_ROUND_ACCOUNT_PREFIX: &str = "UARAP";
 
+pub const USER_AUCTION_ROUND_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct UserAuctionRoundAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
@@ -14,19 +17,19 @@ pub struct UserAuctionRoundAccount {
     pub total_payment: u64,
 
     pub round: u16,
+
+    /// paid the rent and gets it back on close, default on migrated accounts
+    pub rent_payer: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
 }
 
 impl UserAuctionRoundAccount {
     pub fn space() -> usize {
         8 // default
-            + 8 // last_block_timestamp
-
-            + 8 // total_buy_count
-
-            + 8 // total_buy_amount
-
-            + 8 // total_payment
-
-            + 2 // round
+            + Self::INIT_SPACE
     }
 }
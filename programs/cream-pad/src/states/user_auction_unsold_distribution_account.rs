@@ -2,19 +2,28 @@ use anchor_lang::prelude::*;
 
 pub const USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX: &str = "UAUDAP";
 
+pub const USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION: u8 = 1;
+
 #[account]
+#[derive(InitSpace)]
 pub struct UserAuctionUnsoldDistributionAccount {
     /// timestamp when account updated
     pub last_block_timestamp: i64,
 
     pub amount: u64,
+
+    /// paid the rent for the account, default on migrated accounts
+    pub rent_payer: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
 }
 
 impl UserAuctionUnsoldDistributionAccount {
     pub fn space() -> usize {
         8 // default
-            + 8 // last_block_timestamp
-
-            + 8 // amount
+            + Self::INIT_SPACE
     }
 }
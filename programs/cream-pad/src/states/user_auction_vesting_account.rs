@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+pub const USER_AUCTION_VESTING_ACCOUNT_PREFIX: &str = "UAVAP";
+
+pub const USER_AUCTION_VESTING_ACCOUNT_VERSION: u8 = 1;
+
+#[account]
+#[derive(InitSpace)]
+pub struct UserAuctionVestingAccount {
+    /// timestamp when account updated
+    pub last_block_timestamp: i64,
+
+    /// bought amount still owed by the auction, claimed or not
+    pub total_amount: u64,
+
+    pub total_claimed_amount: u64,
+
+    /// paid the rent for the account
+    pub rent_payer: Pubkey,
+
+    pub version: u8,
+
+    /// zeroed, room for new fields without a realloc
+    pub reserved: [u8; 32],
+}
+
+impl UserAuctionVestingAccount {
+    pub fn space() -> usize {
+        8 // default
+            + Self::INIT_SPACE
+    }
+}
@@ -1,13 +1,29 @@
+use crate::ed25519::ed25519_signed_message;
 use crate::error::CreamPadError;
+use crate::math::MAX_SIGMOID_STEEPNESS;
+use crate::merkle::{verify_proof, whitelist_leaf};
 use crate::states::{
-    AssetCreator, AuctionRoundStatus, AuctionStatus, DecayModelType, ProgramStatus,
+    AssetCreator, AuctionRoundStatus, AuctionStatus, BuyIntent, ConfigChange, ConfigChangeStatus,
+    CreamPadAccount, DecayModelType, FeeTier, OraclePrice, PaymentMint, ProgramStatus,
+    RoundSchedule, AUCTION_ROUND_ACCOUNT_PREFIX, MAX_FEE_TIERS, MAX_GOVERNANCE_SIGNERS,
+    MAX_PAYMENT_MINTS, MAX_TENANT_ID_LENGTH, PAYMENT_RATE_SCALE,
 };
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::system_program::{transfer, Transfer};
 use std::collections::HashSet;
 
 pub const BASE_POINT: u16 = 10000;
 
+/// `payment_mint` of pads paid in native SOL, payments move with system transfers
+pub const NATIVE_PAYMENT_MINT: Pubkey = anchor_lang::system_program::ID;
+
+pub const NATIVE_PAYMENT_DECIMALS: u8 = 9;
+
+pub fn is_native_payment(payment_mint: Pubkey) -> bool {
+    payment_mint == NATIVE_PAYMENT_MINT
+}
+
 pub fn check_signing_authority(
     signing_authority_from_account: Pubkey,
     signing_authority_from_input_accounts: Pubkey,
@@ -30,7 +46,7 @@ pub fn check_back_authority(
     Ok(())
 }
 pub fn check_value_is_zero(value: usize) -> Result<()> {
-    if value <= 0 {
+    if value == 0 {
         return Err(CreamPadError::ValueIsZero.into());
     }
 
@@ -69,6 +85,57 @@ pub fn check_is_program_working(value: ProgramStatus) -> Result<()> {
     Ok(())
 }
 
+pub fn check_buys_not_paused(paused: bool) -> Result<()> {
+    if paused {
+        return Err(CreamPadError::BuysPaused.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_new_pads_not_paused(paused: bool) -> Result<()> {
+    if paused {
+        return Err(CreamPadError::NewPadsPaused.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_claims_not_paused(paused: bool) -> Result<()> {
+    if paused {
+        return Err(CreamPadError::ClaimsPaused.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_collection_minting_not_paused(paused: bool) -> Result<()> {
+    if paused {
+        return Err(CreamPadError::CollectionMintingPaused.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_tenant_id(tenant_id: &str) -> Result<()> {
+    if tenant_id.len() > MAX_TENANT_ID_LENGTH {
+        return Err(CreamPadError::InvalidTenantId.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_cream_pad_config(
+    cream_pad_config_from_account: Pubkey,
+    cream_pad_config_from_input_accounts: Pubkey,
+) -> Result<()> {
+    if cream_pad_config_from_account != cream_pad_config_from_input_accounts {
+        return Err(CreamPadError::InvalidCreamPadConfig.into());
+    }
+
+    Ok(())
+}
+
 pub fn check_round_limit(from_config: u16, from_param: u16) -> Result<()> {
     if from_param > from_config {
         return Err(CreamPadError::ExceedRoundsLimit.into());
@@ -85,6 +152,42 @@ pub fn check_ptmax(p0: u64, ptmax: u64) -> Result<()> {
     Ok(())
 }
 
+pub fn check_decay_model(
+    decay_model: &DecayModelType,
+    p0: u64,
+    ptmax: u64,
+    tmax: u16,
+) -> Result<()> {
+    match decay_model {
+        DecayModelType::Linear | DecayModelType::Exponential => {}
+        DecayModelType::Step {
+            step_rounds,
+            step_base_point,
+        } => {
+            if *step_rounds == 0 || *step_base_point == 0 || *step_base_point >= BASE_POINT {
+                return Err(CreamPadError::InvalidStepDecay.into());
+            }
+        }
+        DecayModelType::Sigmoid { steepness } => {
+            if *steepness == 0 || *steepness > MAX_SIGMOID_STEEPNESS {
+                return Err(CreamPadError::InvalidSigmoidDecay.into());
+            }
+        }
+        DecayModelType::Table { prices } => {
+            // one price per round, from p0 down to ptmax without ever going back up
+            if prices.len() != tmax as usize
+                || prices.first() != Some(&p0)
+                || prices.last() != Some(&ptmax)
+                || prices.windows(2).any(|pair| pair[1] > pair[0])
+            {
+                return Err(CreamPadError::InvalidPriceTable.into());
+            }
+        }
+    };
+
+    Ok(())
+}
+
 pub fn check_signer_exist(instruction: Instruction, signer_account: Pubkey) -> Result<()> {
     if !instruction
         .accounts
@@ -97,14 +200,6 @@ pub fn check_signer_exist(instruction: Instruction, signer_account: Pubkey) -> R
     Ok(())
 }
 
-pub fn try_get_remaining_account_info<T>(remaining_accounts: &[T], index: usize) -> Result<&T> {
-    if index < remaining_accounts.len() {
-        Ok(&remaining_accounts[index])
-    } else {
-        Err(CreamPadError::MissingAccount.into())
-    }
-}
-
 pub fn check_round_ender(creator: Pubkey, back_authority: Pubkey, ender: Pubkey) -> Result<()> {
     if ender != creator && ender != back_authority {
         return Err(CreamPadError::InvalidRoundEnder.into());
@@ -154,13 +249,21 @@ pub fn check_is_previous_auction_round_ended(status: AuctionRoundStatus) -> Resu
 }
 
 pub fn check_is_auction_ended_or_sold_out(status: AuctionStatus) -> Result<()> {
-    if !status.eq(&AuctionStatus::Started) {
+    if !status.eq(&AuctionStatus::Started) && !status.eq(&AuctionStatus::Scheduled) {
         return Err(CreamPadError::AuctionIsEndedOrSoldOut.into());
     }
 
     Ok(())
 }
 
+pub fn check_is_auction_running(status: AuctionStatus) -> Result<()> {
+    if status.eq(&AuctionStatus::Started) || status.eq(&AuctionStatus::Scheduled) {
+        return Err(CreamPadError::AuctionNotFinished.into());
+    }
+
+    Ok(())
+}
+
 pub fn check_current_round(value_a: u16, value_b: u16) -> Result<()> {
     if value_a != value_b {
         return Err(CreamPadError::InvalidCurrentRound.into());
@@ -169,6 +272,25 @@ pub fn check_current_round(value_a: u16, value_b: u16) -> Result<()> {
     Ok(())
 }
 
+pub fn check_max_price_per_token(
+    current_price: u64,
+    max_price_per_token: Option<u64>,
+) -> Result<()> {
+    if max_price_per_token.is_some_and(|max_price_per_token| current_price > max_price_per_token) {
+        return Err(CreamPadError::MaxPricePerTokenExceeded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_max_total_payment(total_payment: u64, max_total_payment: Option<u64>) -> Result<()> {
+    if max_total_payment.is_some_and(|max_total_payment| total_payment > max_total_payment) {
+        return Err(CreamPadError::MaxTotalPaymentExceeded.into());
+    }
+
+    Ok(())
+}
+
 pub fn check_previous_round(value_a: u16, value_b: u16) -> Result<()> {
     if value_a != value_b {
         return Err(CreamPadError::InvalidPreviousRound.into());
@@ -233,14 +355,6 @@ pub fn check_payment_mint_account(value_a: Pubkey, value_b: Pubkey) -> Result<()
     Ok(())
 }
 
-pub fn check_token_account_authority(value_a: Pubkey, value_b: Pubkey) -> Result<()> {
-    if value_a != value_b {
-        return Err(CreamPadError::InvalidTokenAccountAuthority.into());
-    }
-
-    Ok(())
-}
-
 pub fn check_buy_index(value_a: u64, value_b: u64) -> Result<()> {
     if value_a != value_b {
         return Err(CreamPadError::InvalidBuyIndex.into());
@@ -273,6 +387,27 @@ pub fn check_can_unlock(unlock_at: i64, current_at: i64) -> Result<()> {
     Ok(())
 }
 
+// Sold out, or past lock and distribute
+// An escrowed pad can still be cancelled and refunded until its payment is settled
+pub fn check_is_auction_finished(
+    status: AuctionStatus,
+    have_payment_escrow: bool,
+    is_payment_settled: bool,
+) -> Result<()> {
+    if !status.eq(&AuctionStatus::SoldOut)
+        && !status.eq(&AuctionStatus::UnsoldLockedAndDistributionOpen)
+        && !status.eq(&AuctionStatus::UnsoldUnlocked)
+    {
+        return Err(CreamPadError::AuctionNotFinished.into());
+    }
+
+    if have_payment_escrow && !is_payment_settled {
+        return Err(CreamPadError::AuctionNotFinished.into());
+    }
+
+    Ok(())
+}
+
 pub fn check_is_auction_is_distribution(status: AuctionStatus) -> Result<()> {
     if !status.eq(&AuctionStatus::UnsoldLockedAndDistributionOpen)
         && !status.eq(&AuctionStatus::UnsoldUnlocked)
@@ -319,6 +454,8 @@ pub fn check_seller_fee_basis_points(seller_fee_basis_points: u16) -> Result<()>
     Ok(())
 }
 
+// `is_multiple_of` is newer than the toolchain programs build with
+#[allow(clippy::manual_is_multiple_of)]
 pub fn check_supply_evenly_divisible(supply: u64, t_max: u64) -> Result<()> {
     if supply % t_max != 0 {
         return Err(CreamPadError::SupplyNotEvenlyDivisible.into());
@@ -371,85 +508,1346 @@ pub fn check_treasury(
 }
 
 pub fn check_eligible_for_collection_distribution(share: u64) -> Result<()> {
-    if share <= 0 {
+    if share == 0 {
         return Err(CreamPadError::NotEligibleForCollectionDistribution.into());
     }
 
     Ok(())
 }
 
-///////////// MATH ///////////////
+pub fn check_account_version(version: u8, expected_version: u8) -> Result<()> {
+    if version != expected_version {
+        return Err(CreamPadError::AccountNotMigrated.into());
+    }
+
+    Ok(())
+}
 
-pub fn calculate_boost(
-    actual_sales: u64,
-    expected_sales: u64,
-    omega: u64,
-    alpha: u64,
-    time_shift_max: u64,
-) -> f64 {
-    if actual_sales >= expected_sales {
-        let ratio = actual_sales as f64 / expected_sales as f64; //   Use f64 for precision
+pub fn check_account_discriminator(data: &[u8], discriminator: [u8; 8]) -> Result<()> {
+    if data.len() < 8 || data[..8] != discriminator {
+        return Err(CreamPadError::InvalidAccountDiscriminator.into());
+    }
 
-        let boost = (alpha as f64 * omega as f64 * ratio).min(time_shift_max as f64);
+    Ok(())
+}
 
-        return boost;
+pub fn check_is_legacy_layout(data_len: usize, legacy_space: usize) -> Result<()> {
+    if data_len != legacy_space {
+        return Err(CreamPadError::AccountAlreadyMigrated.into());
     }
-    -1.0 // No boost if sales are below target
+
+    Ok(())
 }
 
-pub fn calculate_price(
-    p0: u64,               // Initial price
-    ptmax: u64,            // Minimum price
-    t_max: u64,            // Total rounds (time)
-    current_round: usize,  // Current round index
-    boost_history: &[f64], // Boost applied per round
-    decay_model: DecayModelType,
-    time_shift_max: u64, // Maximum shift in time-based decay
-) -> u64 {
-    let mut total_boost: f64 = 0.0; // Use f64 for precision
+pub fn check_whitelist_rounds(whitelist_rounds: u16, tmax: u16) -> Result<()> {
+    if whitelist_rounds == 0 || whitelist_rounds > tmax {
+        return Err(CreamPadError::InvalidWhitelistRounds.into());
+    }
 
-    for &boost in boost_history.iter().take(current_round) {
-        total_boost += -boost.min(time_shift_max as f64); // Fix boost impact
+    Ok(())
+}
+
+pub fn check_whitelist_proof(
+    proof: &[[u8; 32]],
+    root: [u8; 32],
+    user: Pubkey,
+    allocation: u64,
+) -> Result<()> {
+    if !verify_proof(proof, root, whitelist_leaf(&user, allocation)) {
+        return Err(CreamPadError::InvalidWhitelistProof.into());
     }
 
-    if decay_model == DecayModelType::Linear {
-        let k0 = (p0 as f64 - ptmax as f64) / (t_max - 1) as f64;
-        let new_price = (p0 as f64 - k0 * total_boost).max(ptmax as f64);
-        return new_price as u64;
-    } else {
-        if p0 <= ptmax {
-            return ptmax;
-        }
+    Ok(())
+}
 
-        let lambda0 = ((p0 as f64).ln() - (ptmax as f64).ln()) / (t_max - 1) as f64;
-        let new_price = (p0 as f64 * (-lambda0 * total_boost).exp()).max(ptmax as f64);
-        return new_price as u64;
+pub fn check_buy_intent_exist(is_exist: bool) -> Result<()> {
+    if !is_exist {
+        return Err(CreamPadError::MissingBuyIntent.into());
     }
+
+    Ok(())
 }
 
-// Utility to adjust amount based on mint decimals
-pub fn adjust_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> u64 {
-    if to_decimals > from_decimals {
-        amount * 10u64.pow((to_decimals - from_decimals) as u32)
-    } else {
-        amount / 10u64.pow((from_decimals - to_decimals) as u32)
+// The ed25519 program already failed the transaction if the signature itself is bad
+pub fn check_buy_intent_signature(
+    instruction: &Instruction,
+    user: Pubkey,
+    buy_intent: &BuyIntent,
+) -> Result<()> {
+    let message: Vec<u8> = buy_intent.try_to_vec()?;
+
+    match ed25519_signed_message(instruction) {
+        Some((signer, signed_message)) if signer == user && signed_message == message => Ok(()),
+        _ => Err(CreamPadError::InvalidBuyIntentSignature.into()),
     }
 }
 
-// Calculate total price dynamically based on mint decimals
-pub fn calculate_total_price(
+pub fn check_buy_intent(
+    buy_intent: &BuyIntent,
+    pad: Pubkey,
+    round: u16,
     amount: u64,
-    price: u64,
-    from_decimals: u8,
-    to_decimals: u8,
-    output_decimals: u8,
-) -> u64 {
-    let adjusted_amount = adjust_amount(amount, from_decimals, to_decimals);
-    let adjusted_price = adjust_amount(price, from_decimals, output_decimals);
+    payment_mint: Option<Pubkey>,
+    buy_index: u64,
+    referrer: Pubkey,
+) -> Result<()> {
+    if buy_intent.pad != pad
+        || buy_intent.round != round
+        || buy_intent.amount != amount
+        || Some(buy_intent.payment_mint) != payment_mint
+        || buy_intent.nonce != buy_index
+        || buy_intent.referrer != referrer
+    {
+        return Err(CreamPadError::InvalidBuyIntent.into());
+    }
 
-    let amount_in_point: u128 = adjusted_amount as u128;
-    let price_in_point: u128 = adjusted_price as u128;
+    Ok(())
+}
 
-    let total_price: u128 = (amount_in_point * price_in_point) / 10u128.pow(output_decimals as u32);
-    total_price as u64
+pub fn check_buy_intent_expiry(expires_at: i64, timestamp: i64) -> Result<()> {
+    if timestamp > expires_at {
+        return Err(CreamPadError::BuyIntentExpired.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_buy_intent_max_price(current_price: u64, max_price: u64) -> Result<()> {
+    if current_price > max_price {
+        return Err(CreamPadError::BuyIntentMaxPriceExceeded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_buy_intent_max_total_payment(
+    total_payment: u64,
+    max_total_payment: u64,
+) -> Result<()> {
+    if total_payment > max_total_payment {
+        return Err(CreamPadError::BuyIntentMaxTotalPaymentExceeded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_buy_intent_payment(is_native_payment: bool) -> Result<()> {
+    if is_native_payment {
+        return Err(CreamPadError::BuyIntentNativePayment.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_whitelist_allocation(current_amount: u64, allocation: u64) -> Result<()> {
+    if current_amount > allocation {
+        return Err(CreamPadError::WhitelistAllocationExceeded.into());
+    }
+
+    Ok(())
+}
+
+// Pads without escrow can only be cancelled before the first buy, there is nothing to refund
+pub fn check_can_cancel(
+    status: AuctionStatus,
+    have_payment_escrow: bool,
+    is_payment_settled: bool,
+    total_user_buy_count: u64,
+    total_rebate_claimed_count: u64,
+) -> Result<()> {
+    if is_payment_settled {
+        return Err(CreamPadError::AuctionCannotBeCancelled.into());
+    }
+
+    // a cancel refunds the whole payment, rebates already paid would be paid twice
+    if total_rebate_claimed_count > 0 {
+        return Err(CreamPadError::AuctionCannotBeCancelled.into());
+    }
+
+    if !status.eq(&AuctionStatus::Started)
+        && !status.eq(&AuctionStatus::Scheduled)
+        && !status.eq(&AuctionStatus::Ended)
+        && !status.eq(&AuctionStatus::SoldOut)
+    {
+        return Err(CreamPadError::AuctionCannotBeCancelled.into());
+    }
+
+    if !have_payment_escrow && total_user_buy_count > 0 {
+        return Err(CreamPadError::AuctionCannotBeCancelled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_auction_cancelled(status: AuctionStatus) -> Result<()> {
+    if !status.eq(&AuctionStatus::Cancelled) {
+        return Err(CreamPadError::AuctionNotCancelled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_payment_escrowed(have_payment_escrow: bool) -> Result<()> {
+    if !have_payment_escrow {
+        return Err(CreamPadError::AuctionPaymentNotEscrowed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_account_exist(is_exist: bool) -> Result<()> {
+    if !is_exist {
+        return Err(CreamPadError::MissingAccount.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_receipt_refunded(is_refunded: bool) -> Result<()> {
+    if is_refunded {
+        return Err(CreamPadError::ReceiptAlreadyRefunded.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_payment_settler(
+    creator: Pubkey,
+    back_authority: Pubkey,
+    settler: Pubkey,
+) -> Result<()> {
+    if settler != creator && settler != back_authority {
+        return Err(CreamPadError::InvalidPaymentSettler.into());
+    }
+
+    Ok(())
+}
+
+// Escrowed payment can be settled once no more buys can happen and the pad was not cancelled
+pub fn check_can_settle(status: AuctionStatus) -> Result<()> {
+    if status.eq(&AuctionStatus::Started)
+        || status.eq(&AuctionStatus::Scheduled)
+        || status.eq(&AuctionStatus::Cancelled)
+    {
+        return Err(CreamPadError::AuctionPaymentCannotBeSettled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_payment_settled(is_payment_settled: bool) -> Result<()> {
+    if is_payment_settled {
+        return Err(CreamPadError::AuctionPaymentAlreadySettled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_native_payment_escrow(payment_mint: Pubkey, have_payment_escrow: bool) -> Result<()> {
+    if is_native_payment(payment_mint) && have_payment_escrow {
+        return Err(CreamPadError::NativePaymentCannotBeEscrowed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_payment_mints(
+    payment_mints: &[PaymentMint],
+    payment_mint: Pubkey,
+    have_payment_escrow: bool,
+    payment_oracle_program: Pubkey,
+    payment_oracle_max_age: i64,
+) -> Result<()> {
+    if payment_mints.is_empty() {
+        return Ok(());
+    }
+
+    if have_payment_escrow {
+        return Err(CreamPadError::PaymentMintsCannotBeEscrowed.into());
+    }
+
+    if payment_mints.len() > MAX_PAYMENT_MINTS {
+        return Err(CreamPadError::InvalidPaymentMints.into());
+    }
+
+    let mut mints: HashSet<Pubkey> = HashSet::new();
+    for entry in payment_mints.iter() {
+        if entry.mint == payment_mint || is_native_payment(entry.mint) || !mints.insert(entry.mint)
+        {
+            return Err(CreamPadError::InvalidPaymentMints.into());
+        }
+
+        if entry.have_oracle {
+            if entry.oracle == Pubkey::default()
+                || payment_oracle_program == Pubkey::default()
+                || payment_oracle_max_age <= 0
+            {
+                return Err(CreamPadError::InvalidPaymentMints.into());
+            }
+        } else if entry.rate == 0 {
+            return Err(CreamPadError::InvalidPaymentMints.into());
+        }
+    }
+
+    Ok(())
+}
+
+// Read the rate of an oracle priced payment mint
+pub fn read_oracle_rate(
+    oracle: &AccountInfo,
+    payment_oracle_program: Pubkey,
+    expected_oracle: Pubkey,
+    max_age: i64,
+    current_time: i64,
+) -> Result<u64> {
+    if oracle.key() != expected_oracle || *oracle.owner != payment_oracle_program {
+        return Err(CreamPadError::InvalidOraclePrice.into());
+    }
+
+    let oracle_price: OraclePrice = OraclePrice::try_from_data(&oracle.try_borrow_data()?)
+        .ok_or(CreamPadError::InvalidOraclePrice)?;
+
+    if oracle_price.rate == 0
+        || oracle_price.published_at > current_time
+        || current_time.saturating_sub(oracle_price.published_at) > max_age
+    {
+        return Err(CreamPadError::InvalidOraclePrice.into());
+    }
+
+    Ok(oracle_price.rate)
+}
+
+// Referral rewards only apply to single SPL mint pads paid directly to the receiver. The reward is
+// split off the creator's share at buy time into the referrer's token account of the payment mint:
+// native SOL has no token account, escrowed payments can still be refunded or rebated, and extra
+// payment mints would spread one referrer's rewards over several mints
+pub fn check_referral(
+    referral_base_point: u16,
+    payment_mint: Pubkey,
+    have_payment_escrow: bool,
+    have_payment_mints: bool,
+) -> Result<()> {
+    if referral_base_point == 0
+        || referral_base_point >= BASE_POINT
+        || is_native_payment(payment_mint)
+        || have_payment_escrow
+        || have_payment_mints
+    {
+        return Err(CreamPadError::InvalidReferral.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_referrer(referrer: Pubkey, user: Pubkey) -> Result<()> {
+    if referrer == user {
+        return Err(CreamPadError::InvalidReferrer.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_referral_rewards(amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Err(CreamPadError::NoReferralRewardsToClaim.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_fee_tiers(fee_tiers: &[FeeTier]) -> Result<()> {
+    if fee_tiers.len() > MAX_FEE_TIERS {
+        return Err(CreamPadError::InvalidFeeTiers.into());
+    }
+
+    // increasing volumes within each payment mint
+    for (index, fee_tier) in fee_tiers.iter().enumerate() {
+        let last_min_volume: u64 = fee_tiers[..index]
+            .iter()
+            .rev()
+            .find(|last_fee_tier| last_fee_tier.payment_mint == fee_tier.payment_mint)
+            .map_or(0, |last_fee_tier| last_fee_tier.min_volume);
+
+        if fee_tier.min_volume <= last_min_volume {
+            return Err(CreamPadError::InvalidFeeTiers.into());
+        }
+
+        check_fee_base_point(fee_tier.fee_base_point)?;
+    }
+
+    Ok(())
+}
+
+pub fn check_config_change(change: &ConfigChange) -> Result<()> {
+    check_value_is_zero(change.fee_base_point as usize)?;
+    check_value_is_zero(change.round_limit as usize)?;
+    check_value_is_zero(change.lock_base_point as usize)?;
+    check_value_is_zero(change.lock_duration as usize)?;
+    check_value_is_zero(change.minting_fee as usize)?;
+    check_fee_base_point(change.fee_base_point)?;
+    check_fee_tiers(&change.fee_tiers)?;
+    check_distribution_and_lock_base_point(
+        change
+            .distribution_base_point
+            .checked_add(change.lock_base_point)
+            .unwrap(),
+    )?;
+
+    if change.have_governance {
+        check_governance(
+            &change.governance_signers,
+            change.governance_threshold,
+            change.governance_timelock,
+        )?;
+    };
+
+    Ok(())
+}
+
+pub fn check_governance(signers: &[Pubkey], threshold: u8, timelock: i64) -> Result<()> {
+    if signers.is_empty()
+        || signers.len() > MAX_GOVERNANCE_SIGNERS
+        || threshold == 0
+        || threshold as usize > signers.len()
+        || timelock < 0
+    {
+        return Err(CreamPadError::InvalidGovernance.into());
+    }
+
+    let mut unique_signers = HashSet::new();
+    for signer in signers {
+        if !unique_signers.insert(signer) {
+            return Err(CreamPadError::InvalidGovernance.into());
+        }
+    }
+
+    Ok(())
+}
+
+pub fn check_is_not_governed(have_governance: bool) -> Result<()> {
+    if have_governance {
+        return Err(CreamPadError::GovernanceRequired.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_governed(have_governance: bool) -> Result<()> {
+    if !have_governance {
+        return Err(CreamPadError::GovernanceNotEnabled.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_governance_signer(signers: &[Pubkey], signer: Pubkey) -> Result<()> {
+    if !signers.contains(&signer) {
+        return Err(CreamPadError::InvalidGovernanceSigner.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_config_change_index(value_a: u64, value_b: u64) -> Result<()> {
+    if value_a != value_b {
+        return Err(CreamPadError::InvalidConfigChangeIndex.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_config_change_pending(status: ConfigChangeStatus) -> Result<()> {
+    if !status.eq(&ConfigChangeStatus::Pending) {
+        return Err(CreamPadError::ConfigChangeNotPending.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_config_change_not_approved(approvals: &[Pubkey], signer: Pubkey) -> Result<()> {
+    if approvals.contains(&signer) {
+        return Err(CreamPadError::ConfigChangeAlreadyApproved.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_config_change_approvals(approval_count: usize, threshold: u8) -> Result<()> {
+    if approval_count < threshold as usize {
+        return Err(CreamPadError::NotEnoughConfigChangeApprovals.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_config_change_timelock(executable_at: i64, timestamp: i64) -> Result<()> {
+    if timestamp < executable_at {
+        return Err(CreamPadError::ConfigChangeTimelocked.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_pending_authority(pending_authority: Pubkey, authority: Pubkey) -> Result<()> {
+    if pending_authority == Pubkey::default() || pending_authority != authority {
+        return Err(CreamPadError::InvalidPendingAuthority.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_uniform_clearing_price_escrow(
+    have_uniform_clearing_price: bool,
+    have_payment_escrow: bool,
+) -> Result<()> {
+    if have_uniform_clearing_price && !have_payment_escrow {
+        return Err(CreamPadError::UniformClearingPriceRequiresEscrow.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_uniform_clearing_price(have_uniform_clearing_price: bool) -> Result<()> {
+    if !have_uniform_clearing_price {
+        return Err(CreamPadError::AuctionNotUniformClearingPrice.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_rebate_claimed(is_rebate_claimed: bool) -> Result<()> {
+    if is_rebate_claimed {
+        return Err(CreamPadError::RebateAlreadyClaimed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_rebate_not_claimed(is_rebate_claimed: bool) -> Result<()> {
+    if !is_rebate_claimed {
+        return Err(CreamPadError::RebateNotClaimed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_payment_not_settled(is_payment_settled: bool) -> Result<()> {
+    if !is_payment_settled {
+        return Err(CreamPadError::AuctionPaymentNotSettled.into());
+    }
+
+    Ok(())
+}
+
+// Round accounts come in increasing round order, so none is counted twice
+pub fn check_auction_round_account(
+    auction_round_config: Pubkey,
+    auction_config: Pubkey,
+    round: u16,
+    last_round: u16,
+) -> Result<()> {
+    if round <= last_round {
+        return Err(CreamPadError::InvalidAuctionRoundAccounts.into());
+    }
+
+    let round_index: String = round.to_string();
+    let (expected_auction_round_config, _) = Pubkey::find_program_address(
+        &[
+            AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+            auction_config.as_ref(),
+            round_index.as_ref(),
+        ],
+        &crate::ID,
+    );
+
+    if auction_round_config != expected_auction_round_config {
+        return Err(CreamPadError::InvalidAuctionRoundAccounts.into());
+    }
+
+    Ok(())
+}
+
+// Every round with a buy has to be passed in, their totals add up to the pad's
+pub fn check_auction_round_accounts_total(
+    rounds_supply_sold: u64,
+    total_supply_sold: u64,
+    rounds_user_buy_count: u64,
+    total_user_buy_count: u64,
+) -> Result<()> {
+    if rounds_supply_sold != total_supply_sold || rounds_user_buy_count != total_user_buy_count {
+        return Err(CreamPadError::InvalidAuctionRoundAccounts.into());
+    }
+
+    Ok(())
+}
+
+// Rebates a round owes at the clearing price, in 9 decimals. Every buy of a round paid the round
+// price, one unit per buy covers the rounding of each receipt
+pub fn calculate_round_rebate_reserve(
+    total_payment: u64,
+    total_supply_sold: u64,
+    total_user_buy_count: u64,
+    price: u64,
+    clearing_price: u64,
+) -> u64 {
+    if price <= clearing_price {
+        return 0;
+    }
+
+    calculate_total_price(total_supply_sold, price - clearing_price, 9, 9, 9)
+        .checked_add(total_user_buy_count)
+        .unwrap()
+        .min(total_payment)
+}
+
+// The fee on what the vault keeps at the clearing price, pro-rated from the fee taken on the round prices
+pub fn calculate_clearing_fee_amount(
+    clearing_total_price: u64,
+    total_fee: u64,
+    total_payment: u64,
+) -> u64 {
+    if total_payment == 0 {
+        return 0;
+    }
+
+    (clearing_total_price as u128)
+        .checked_mul(total_fee as u128)
+        .unwrap()
+        .checked_div(total_payment as u128)
+        .unwrap() as u64
+}
+
+pub fn check_rebate_reserve(vault_amount: u64, rebate_reserve: u64) -> Result<()> {
+    if vault_amount < rebate_reserve {
+        return Err(CreamPadError::InsufficientRebateReserve.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_vesting_schedule(
+    tge_base_point: u16,
+    cliff_duration: i64,
+    duration: i64,
+) -> Result<()> {
+    if tge_base_point > BASE_POINT
+        || cliff_duration < 0
+        || duration < cliff_duration
+        || (tge_base_point < BASE_POINT && duration == 0)
+    {
+        return Err(CreamPadError::InvalidVestingSchedule.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_vesting(have_vesting: bool) -> Result<()> {
+    if !have_vesting {
+        return Err(CreamPadError::AuctionHasNoVesting.into());
+    }
+
+    Ok(())
+}
+
+// Vesting starts once the auction ends or sells out, a cancelled pad refunds instead
+pub fn check_is_vesting_started(status: AuctionStatus, vesting_start_at: i64) -> Result<()> {
+    if status.eq(&AuctionStatus::Cancelled) || vesting_start_at == 0 {
+        return Err(CreamPadError::VestingNotStarted.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_vested_amount(amount: u64) -> Result<()> {
+    if amount == 0 {
+        return Err(CreamPadError::NothingVestedToClaim.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_crank(have_crank: bool) -> Result<()> {
+    if !have_crank {
+        return Err(CreamPadError::AuctionHasNoCrank.into());
+    }
+
+    Ok(())
+}
+
+// Round 1 is opened by the pad creation, its entry has to match the creation parameters
+pub fn check_round_schedule(
+    round_schedule: &[RoundSchedule],
+    tmax: u16,
+    round_duration: i64,
+    have_buy_limit: bool,
+    buy_limit: u64,
+) -> Result<()> {
+    let is_invalid_round: bool = round_schedule.iter().any(|round| {
+        round.duration <= 0 || round.gap < 0 || (round.have_buy_limit && round.buy_limit == 0)
+    });
+
+    let is_valid_first_round: bool = round_schedule.first().is_some_and(|round| {
+        round.duration == round_duration
+            && round.have_buy_limit == have_buy_limit
+            && (!have_buy_limit || round.buy_limit == buy_limit)
+            && round.gap == 0
+    });
+
+    if round_schedule.len() != tmax as usize || is_invalid_round || !is_valid_first_round {
+        return Err(CreamPadError::InvalidRoundSchedule.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_round_gap(can_start_at: i64, current_time: i64) -> Result<()> {
+    if can_start_at > current_time {
+        return Err(CreamPadError::RoundGapNotElapsed.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_start_at(start_at: i64, current_time: i64) -> Result<()> {
+    if start_at <= current_time {
+        return Err(CreamPadError::InvalidStartAt.into());
+    }
+
+    Ok(())
+}
+
+pub fn check_is_auction_started(start_at: i64, current_time: i64) -> Result<()> {
+    if start_at > current_time {
+        return Err(CreamPadError::AuctionNotStarted.into());
+    }
+
+    Ok(())
+}
+
+// Grow a program owned account and top up its rent from the payer
+pub fn realloc_account<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_space: usize,
+) -> Result<()> {
+    let rent_lamports: u64 = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(account_info.lamports());
+
+    if rent_lamports > 0 {
+        let transfer_rent_cpi_ctx = CpiContext::new(
+            system_program.clone(),
+            Transfer {
+                from: payer.clone(),
+                to: account_info.clone(),
+            },
+        );
+
+        transfer(transfer_rent_cpi_ctx, rent_lamports)?;
+    };
+
+    account_info.realloc(new_space, true)?;
+
+    Ok(())
+}
+
+// Write every value of a config change to the cream pad config
+pub fn apply_config_change(cream_pad_config: &mut CreamPadAccount, change: &ConfigChange) {
+    cream_pad_config.is_back_authority_required = change.is_back_authority_required;
+    cream_pad_config.program_status = change.program_status.clone();
+    cream_pad_config.is_fee_required = change.is_fee_required;
+    cream_pad_config.fee_receiver = change.fee_receiver;
+    cream_pad_config.fee_base_point = change.fee_base_point;
+    cream_pad_config.round_limit = change.round_limit;
+    cream_pad_config.distribution_base_point = change.distribution_base_point;
+    cream_pad_config.lock_base_point = change.lock_base_point;
+    cream_pad_config.lock_duration = change.lock_duration;
+    cream_pad_config.minting_fee = change.minting_fee;
+    cream_pad_config.treasury = change.treasury;
+    cream_pad_config.fee_tiers = change.fee_tiers.clone();
+    cream_pad_config.have_governance = change.have_governance;
+    cream_pad_config.governance_signers = change.governance_signers.clone();
+    cream_pad_config.governance_threshold = change.governance_threshold;
+    cream_pad_config.governance_timelock = change.governance_timelock;
+}
+
+///////////// MATH ///////////////
+
+// Utility to adjust amount based on mint decimals
+pub fn adjust_amount(amount: u64, from_decimals: u8, to_decimals: u8) -> u64 {
+    if to_decimals > from_decimals {
+        amount * 10u64.pow((to_decimals - from_decimals) as u32)
+    } else {
+        amount / 10u64.pow((from_decimals - to_decimals) as u32)
+    }
+}
+
+// Convert a `payment_mint` amount to a payment mint amount, both normalized to 9 decimals
+pub fn apply_payment_rate(amount: u64, rate: u64) -> Result<u64> {
+    u64::try_from((amount as u128) * (rate as u128) / (PAYMENT_RATE_SCALE as u128))
+        .map_err(|_| CreamPadError::PaymentRateOverflow.into())
+}
+
+// Convert a payment mint amount back to a `payment_mint` amount
+pub fn remove_payment_rate(amount: u64, rate: u64) -> Result<u64> {
+    u64::try_from((amount as u128) * (PAYMENT_RATE_SCALE as u128) / (rate as u128))
+        .map_err(|_| CreamPadError::PaymentRateOverflow.into())
+}
+
+// Tiers a pad paid in `payment_mint` snapshots, the others count volume in another unit
+pub fn get_payment_mint_fee_tiers(fee_tiers: &[FeeTier], payment_mint: Pubkey) -> Vec<FeeTier> {
+    fee_tiers
+        .iter()
+        .filter(|fee_tier| fee_tier.payment_mint == payment_mint)
+        .cloned()
+        .collect()
+}
+
+// Fee base point of a pad at `volume`, the override wins over the highest reached tier
+pub fn get_fee_base_point(
+    fee_base_point: u16,
+    fee_tiers: &[FeeTier],
+    have_fee_override: bool,
+    fee_override_base_point: u16,
+    volume: u64,
+) -> u16 {
+    if have_fee_override {
+        return fee_override_base_point;
+    }
+
+    fee_tiers
+        .iter()
+        .rev()
+        .find(|fee_tier| volume >= fee_tier.min_volume)
+        .map_or(fee_base_point, |fee_tier| fee_tier.fee_base_point)
+}
+
+// Calculate total price dynamically based on mint decimals
+pub fn calculate_total_price(
+    amount: u64,
+    price: u64,
+    from_decimals: u8,
+    to_decimals: u8,
+    output_decimals: u8,
+) -> u64 {
+    let adjusted_amount = adjust_amount(amount, from_decimals, to_decimals);
+    let adjusted_price = adjust_amount(price, from_decimals, output_decimals);
+
+    let amount_in_point: u128 = adjusted_amount as u128;
+    let price_in_point: u128 = adjusted_price as u128;
+
+    let total_price: u128 = (amount_in_point * price_in_point) / 10u128.pow(output_decimals as u32);
+    total_price as u64
+}
+
+// Migrated accounts never recorded who paid their rent
+pub fn get_rent_payer(rent_payer: Pubkey, legacy_rent_payer: Pubkey) -> Pubkey {
+    if rent_payer == Pubkey::default() {
+        legacy_rent_payer
+    } else {
+        rent_payer
+    }
+}
+
+pub fn check_rent_payer(rent_payer: Pubkey, rent_payer_from_input_accounts: Pubkey) -> Result<()> {
+    if rent_payer != rent_payer_from_input_accounts {
+        return Err(CreamPadError::InvalidRentPayer.into());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ed25519::ed25519_test_instruction;
+
+    const PAYMENT_MINT: Pubkey = Pubkey::new_from_array([1; 32]);
+
+    const PAD: Pubkey = Pubkey::new_from_array([2; 32]);
+
+    const USER: Pubkey = Pubkey::new_from_array([3; 32]);
+
+    fn buy_intent() -> BuyIntent {
+        BuyIntent {
+            pad: PAD,
+            round: 2,
+            amount: 10,
+            payment_mint: PAYMENT_MINT,
+            max_price: 1_000,
+            max_total_payment: 10_000,
+            expires_at: 100,
+            nonce: 4,
+            referrer: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn check_referral_accepts_single_spl_mint_pads() {
+        assert_eq!(check_referral(500, PAYMENT_MINT, false, false), Ok(()));
+    }
+
+    #[test]
+    fn check_referral_rejects_base_point_out_of_range() {
+        assert_eq!(
+            check_referral(0, PAYMENT_MINT, false, false),
+            Err(CreamPadError::InvalidReferral.into())
+        );
+        assert_eq!(
+            check_referral(BASE_POINT, PAYMENT_MINT, false, false),
+            Err(CreamPadError::InvalidReferral.into())
+        );
+    }
+
+    #[test]
+    fn check_referral_rejects_native_payment() {
+        assert_eq!(
+            check_referral(500, NATIVE_PAYMENT_MINT, false, false),
+            Err(CreamPadError::InvalidReferral.into())
+        );
+    }
+
+    #[test]
+    fn check_referral_rejects_payment_escrow() {
+        assert_eq!(
+            check_referral(500, PAYMENT_MINT, true, false),
+            Err(CreamPadError::InvalidReferral.into())
+        );
+    }
+
+    #[test]
+    fn check_referral_rejects_extra_payment_mints() {
+        assert_eq!(
+            check_referral(500, PAYMENT_MINT, false, true),
+            Err(CreamPadError::InvalidReferral.into())
+        );
+    }
+
+    #[test]
+    fn check_max_price_per_token_accepts_no_limit_and_equal_price() {
+        assert_eq!(check_max_price_per_token(1_000, None), Ok(()));
+        assert_eq!(check_max_price_per_token(1_000, Some(1_000)), Ok(()));
+    }
+
+    #[test]
+    fn check_max_price_per_token_rejects_higher_price() {
+        assert_eq!(
+            check_max_price_per_token(1_001, Some(1_000)),
+            Err(CreamPadError::MaxPricePerTokenExceeded.into())
+        );
+    }
+
+    #[test]
+    fn check_max_total_payment_accepts_no_limit_and_equal_payment() {
+        assert_eq!(check_max_total_payment(5_000, None), Ok(()));
+        assert_eq!(check_max_total_payment(5_000, Some(5_000)), Ok(()));
+    }
+
+    #[test]
+    fn check_max_total_payment_rejects_higher_payment() {
+        assert_eq!(
+            check_max_total_payment(5_001, Some(5_000)),
+            Err(CreamPadError::MaxTotalPaymentExceeded.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_signature_accepts_the_users_intent() {
+        let instruction: Instruction =
+            ed25519_test_instruction(&USER, &buy_intent().try_to_vec().unwrap());
+
+        assert_eq!(
+            check_buy_intent_signature(&instruction, USER, &buy_intent()),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_signature_rejects_wrong_signer() {
+        let instruction: Instruction =
+            ed25519_test_instruction(&PAD, &buy_intent().try_to_vec().unwrap());
+
+        assert_eq!(
+            check_buy_intent_signature(&instruction, USER, &buy_intent()),
+            Err(CreamPadError::InvalidBuyIntentSignature.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_signature_rejects_tampered_intent() {
+        let instruction: Instruction =
+            ed25519_test_instruction(&USER, &buy_intent().try_to_vec().unwrap());
+
+        let mut tampered: BuyIntent = buy_intent();
+        tampered.amount = 11;
+
+        assert_eq!(
+            check_buy_intent_signature(&instruction, USER, &tampered),
+            Err(CreamPadError::InvalidBuyIntentSignature.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_signature_rejects_instruction_of_another_program() {
+        // what the buy finds when the ed25519 instruction isn't right before it
+        let mut instruction: Instruction =
+            ed25519_test_instruction(&USER, &buy_intent().try_to_vec().unwrap());
+        instruction.program_id = crate::ID;
+
+        assert_eq!(
+            check_buy_intent_signature(&instruction, USER, &buy_intent()),
+            Err(CreamPadError::InvalidBuyIntentSignature.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_accepts_matching_buy() {
+        assert_eq!(
+            check_buy_intent(
+                &buy_intent(),
+                PAD,
+                2,
+                10,
+                Some(PAYMENT_MINT),
+                4,
+                Pubkey::default()
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_rejects_replayed_nonce() {
+        // the buy index moved on once the intent was used
+        assert_eq!(
+            check_buy_intent(
+                &buy_intent(),
+                PAD,
+                2,
+                10,
+                Some(PAYMENT_MINT),
+                5,
+                Pubkey::default()
+            ),
+            Err(CreamPadError::InvalidBuyIntent.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_rejects_other_round_and_referrer() {
+        assert_eq!(
+            check_buy_intent(
+                &buy_intent(),
+                PAD,
+                3,
+                10,
+                Some(PAYMENT_MINT),
+                4,
+                Pubkey::default()
+            ),
+            Err(CreamPadError::InvalidBuyIntent.into())
+        );
+        assert_eq!(
+            check_buy_intent(&buy_intent(), PAD, 2, 10, Some(PAYMENT_MINT), 4, USER),
+            Err(CreamPadError::InvalidBuyIntent.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_rejects_other_payment_mint() {
+        // the relayer can't switch the user to another of the pad's payment mints
+        assert_eq!(
+            check_buy_intent(&buy_intent(), PAD, 2, 10, Some(PAD), 4, Pubkey::default()),
+            Err(CreamPadError::InvalidBuyIntent.into())
+        );
+        assert_eq!(
+            check_buy_intent(&buy_intent(), PAD, 2, 10, None, 4, Pubkey::default()),
+            Err(CreamPadError::InvalidBuyIntent.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_max_total_payment_rejects_higher_payment() {
+        assert_eq!(check_buy_intent_max_total_payment(10_000, 10_000), Ok(()));
+        assert_eq!(
+            check_buy_intent_max_total_payment(10_001, 10_000),
+            Err(CreamPadError::BuyIntentMaxTotalPaymentExceeded.into())
+        );
+    }
+
+    #[test]
+    fn check_buy_intent_expiry_rejects_expired_intent() {
+        assert_eq!(check_buy_intent_expiry(100, 100), Ok(()));
+        assert_eq!(
+            check_buy_intent_expiry(100, 101),
+            Err(CreamPadError::BuyIntentExpired.into())
+        );
+    }
+
+    const AUCTION: Pubkey = Pubkey::new_from_array([5; 32]);
+
+    fn auction_round_config(auction_config: Pubkey, round: u16) -> Pubkey {
+        Pubkey::find_program_address(
+            &[
+                AUCTION_ROUND_ACCOUNT_PREFIX.as_ref(),
+                auction_config.as_ref(),
+                round.to_string().as_ref(),
+            ],
+            &crate::ID,
+        )
+        .0
+    }
+
+    // What claim_rebate pays the buyers of a round, one receipt per buy amount
+    fn round_rebates(amounts: &[u64], price: u64, clearing_price: u64) -> (u64, u64) {
+        let mut total_payment: u64 = 0;
+        let mut rebates: u64 = 0;
+
+        for amount in amounts {
+            let payment: u64 = calculate_total_price(*amount, price, 9, 9, 9);
+
+            total_payment += payment;
+            rebates +=
+                payment.saturating_sub(calculate_total_price(*amount, clearing_price, 9, 9, 9));
+        }
+
+        (total_payment, rebates)
+    }
+
+    #[test]
+    fn calculate_round_rebate_reserve_is_zero_at_or_below_clearing_price() {
+        assert_eq!(
+            calculate_round_rebate_reserve(
+                40_000_000_000,
+                10_000_000_000,
+                2,
+                4_000_000_000,
+                4_000_000_000
+            ),
+            0
+        );
+        assert_eq!(
+            calculate_round_rebate_reserve(
+                30_000_000_000,
+                10_000_000_000,
+                2,
+                3_000_000_000,
+                4_000_000_000
+            ),
+            0
+        );
+    }
+
+    #[test]
+    fn calculate_round_rebate_reserve_covers_round_rebates() {
+        let (total_payment, rebates) = round_rebates(
+            &[3_000_000_000, 7_000_000_000],
+            5_000_000_000,
+            4_000_000_000,
+        );
+
+        assert_eq!(rebates, 10_000_000_000);
+        assert_eq!(
+            calculate_round_rebate_reserve(
+                total_payment,
+                10_000_000_000,
+                2,
+                5_000_000_000,
+                4_000_000_000
+            ),
+            10_000_000_002
+        );
+    }
+
+    #[test]
+    fn calculate_round_rebate_reserve_covers_rounded_receipts() {
+        let amounts: [u64; 3] = [333_333_333, 666_666_667, 1];
+        let (total_payment, rebates) = round_rebates(&amounts, 3_000_000_001, 1_000_000_000);
+
+        let reserve: u64 = calculate_round_rebate_reserve(
+            total_payment,
+            amounts.iter().sum(),
+            amounts.len() as u64,
+            3_000_000_001,
+            1_000_000_000,
+        );
+
+        // each receipt rounds its payment and its clearing price down, the reserve only its sum
+        assert!(reserve >= rebates);
+        assert!(reserve - rebates <= 2 * amounts.len() as u64);
+    }
+
+    #[test]
+    fn calculate_round_rebate_reserve_adds_up_over_rounds() {
+        let clearing_price: u64 = 4_000_000_000;
+
+        // round 1 and 2 sold above the clearing price, round 3 at it and round 4 had no buy
+        let rounds: [(&[u64], u64); 4] = [
+            (&[10_000_000_000], 5_000_000_000),
+            (&[1_000_000_000, 1_000_000_000], 4_500_000_000),
+            (&[4_000_000_000], clearing_price),
+            (&[], 4_800_000_000),
+        ];
+
+        let mut reserve: u64 = 0;
+        let mut rebates: u64 = 0;
+
+        for (amounts, price) in rounds {
+            let (round_payment, round_rebates) = round_rebates(amounts, price, clearing_price);
+
+            reserve += calculate_round_rebate_reserve(
+                round_payment,
+                amounts.iter().sum(),
+                amounts.len() as u64,
+                price,
+                clearing_price,
+            );
+            rebates += round_rebates;
+        }
+
+        assert_eq!(rebates, 11_000_000_000);
+        assert_eq!(reserve, 11_000_000_003);
+    }
+
+    #[test]
+    fn calculate_round_rebate_reserve_is_zero_for_round_without_buys() {
+        assert_eq!(
+            calculate_round_rebate_reserve(0, 0, 0, 5_000_000_000, 4_000_000_000),
+            0
+        );
+    }
+
+    #[test]
+    fn calculate_round_rebate_reserve_never_exceeds_round_payment() {
+        assert_eq!(
+            calculate_round_rebate_reserve(5, 10_000_000_000, 1, 5_000_000_000, 4_000_000_000),
+            5
+        );
+    }
+
+    #[test]
+    fn calculate_clearing_fee_amount_pro_rates_the_fee() {
+        // 25% fee on 50 paid, 40 kept at the clearing price
+        assert_eq!(
+            calculate_clearing_fee_amount(40_000_000_000, 12_500_000_000, 50_000_000_000),
+            10_000_000_000
+        );
+        assert_eq!(
+            calculate_clearing_fee_amount(40_000_000_000, 0, 50_000_000_000),
+            0
+        );
+    }
+
+    #[test]
+    fn calculate_clearing_fee_amount_is_zero_without_payment() {
+        assert_eq!(calculate_clearing_fee_amount(0, 0, 0), 0);
+    }
+
+    #[test]
+    fn calculate_clearing_fee_amount_handles_large_totals() {
+        assert_eq!(
+            calculate_clearing_fee_amount(u64::MAX, u64::MAX / 4, u64::MAX),
+            u64::MAX / 4
+        );
+    }
+
+    #[test]
+    fn check_auction_round_account_accepts_increasing_rounds() {
+        // rounds without buys can be left out
+        assert_eq!(
+            check_auction_round_account(auction_round_config(AUCTION, 1), AUCTION, 1, 0),
+            Ok(())
+        );
+        assert_eq!(
+            check_auction_round_account(auction_round_config(AUCTION, 3), AUCTION, 3, 1),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn check_auction_round_account_rejects_out_of_order_rounds() {
+        assert_eq!(
+            check_auction_round_account(auction_round_config(AUCTION, 1), AUCTION, 1, 2),
+            Err(CreamPadError::InvalidAuctionRoundAccounts.into())
+        );
+    }
+
+    #[test]
+    fn check_auction_round_account_rejects_repeated_round() {
+        assert_eq!(
+            check_auction_round_account(auction_round_config(AUCTION, 2), AUCTION, 2, 2),
+            Err(CreamPadError::InvalidAuctionRoundAccounts.into())
+        );
+    }
+
+    #[test]
+    fn check_auction_round_account_rejects_round_of_another_pad() {
+        assert_eq!(
+            check_auction_round_account(auction_round_config(PAD, 2), AUCTION, 2, 1),
+            Err(CreamPadError::InvalidAuctionRoundAccounts.into())
+        );
+        assert_eq!(
+            check_auction_round_account(auction_round_config(AUCTION, 3), AUCTION, 2, 1),
+            Err(CreamPadError::InvalidAuctionRoundAccounts.into())
+        );
+    }
+
+    #[test]
+    fn check_auction_round_accounts_total_rejects_missing_round() {
+        assert_eq!(check_auction_round_accounts_total(12, 12, 3, 3), Ok(()));
+        assert_eq!(
+            check_auction_round_accounts_total(10, 12, 2, 3),
+            Err(CreamPadError::InvalidAuctionRoundAccounts.into())
+        );
+        assert_eq!(
+            check_auction_round_accounts_total(12, 12, 2, 3),
+            Err(CreamPadError::InvalidAuctionRoundAccounts.into())
+        );
+    }
+
+    #[test]
+    fn apply_payment_rate_converts_at_the_rate() {
+        assert_eq!(
+            apply_payment_rate(4_000_000_000, 2 * PAYMENT_RATE_SCALE),
+            Ok(8_000_000_000)
+        );
+        assert_eq!(
+            remove_payment_rate(8_000_000_000, 2 * PAYMENT_RATE_SCALE),
+            Ok(4_000_000_000)
+        );
+    }
+
+    #[test]
+    fn apply_payment_rate_accepts_u64_max() {
+        assert_eq!(
+            apply_payment_rate(u64::MAX, PAYMENT_RATE_SCALE),
+            Ok(u64::MAX)
+        );
+        assert_eq!(
+            remove_payment_rate(u64::MAX, PAYMENT_RATE_SCALE),
+            Ok(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn apply_payment_rate_rejects_overflow() {
+        // one past u64::MAX once scaled, used to wrap to a small payment
+        assert_eq!(
+            apply_payment_rate(u64::MAX, PAYMENT_RATE_SCALE + 1),
+            Err(CreamPadError::PaymentRateOverflow.into())
+        );
+        assert_eq!(
+            apply_payment_rate(u64::MAX / 2 + 1, 2 * PAYMENT_RATE_SCALE),
+            Err(CreamPadError::PaymentRateOverflow.into())
+        );
+    }
+
+    #[test]
+    fn remove_payment_rate_rejects_overflow() {
+        assert_eq!(
+            remove_payment_rate(u64::MAX, PAYMENT_RATE_SCALE - 1),
+            Err(CreamPadError::PaymentRateOverflow.into())
+        );
+    }
 }
@@ -0,0 +1,170 @@
+//! Checks every `space()` against the serialized size of the account it allocates, with each
+//! vector and string filled to the most the program ever writes.
+
+use anchor_lang::prelude::*;
+use anchor_lang::AccountSerialize;
+use cream_pad::states::*;
+
+// Every field zeroed, vectors and strings empty, enums at their first variant
+fn zeroed<T: AnchorDeserialize>() -> T {
+    T::deserialize(&mut [0u8; 4096].as_slice()).unwrap()
+}
+
+fn serialized_len<T: AccountSerialize>(account: &T) -> usize {
+    let mut data: Vec<u8> = Vec::new();
+    account.try_serialize(&mut data).unwrap();
+
+    data.len()
+}
+
+fn full<T: AnchorDeserialize>(len: usize) -> Vec<T> {
+    (0..len).map(|_| zeroed()).collect()
+}
+
+#[test]
+fn fixed_size_accounts_match_their_space() {
+    assert_eq!(
+        serialized_len(&zeroed::<AuctionRoundAccount>()),
+        AuctionRoundAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserAuctionAccount>()),
+        UserAuctionAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserAuctionRoundAccount>()),
+        UserAuctionRoundAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserAuctionBuyReceiptAccount>()),
+        UserAuctionBuyReceiptAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserAuctionUnsoldDistributionAccount>()),
+        UserAuctionUnsoldDistributionAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserAuctionVestingAccount>()),
+        UserAuctionVestingAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<ReferrerAccount>()),
+        ReferrerAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<CollectionAuctionRoundAccount>()),
+        CollectionAuctionRoundAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserCollectionAuctionAccount>()),
+        UserCollectionAuctionAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserCollectionAuctionRoundAccount>()),
+        UserCollectionAuctionRoundAccount::space()
+    );
+    assert_eq!(
+        serialized_len(&zeroed::<UserCollectionAuctionUnsoldDistributionAccount>()),
+        UserCollectionAuctionUnsoldDistributionAccount::space()
+    );
+}
+
+#[test]
+fn collection_buy_receipt_fits_the_longest_pad_name() {
+    let mut receipt: UserCollectionAuctionBuyReceiptAccount = zeroed();
+    receipt.pad_name = "p".repeat(MAX_PAD_NAME_LENGTH);
+
+    assert_eq!(
+        serialized_len(&receipt),
+        UserCollectionAuctionBuyReceiptAccount::space()
+    );
+}
+
+#[test]
+fn cream_pad_config_fits_full_tiers_signers_and_tenant_id() {
+    let mut cream_pad_config: CreamPadAccount = zeroed();
+    cream_pad_config.fee_tiers = full(MAX_FEE_TIERS);
+    cream_pad_config.governance_signers = vec![Pubkey::default(); MAX_GOVERNANCE_SIGNERS];
+    cream_pad_config.tenant_id = "t".repeat(MAX_TENANT_ID_LENGTH);
+
+    assert_eq!(serialized_len(&cream_pad_config), CreamPadAccount::space());
+}
+
+#[test]
+fn pending_config_change_fits_a_full_change_and_every_approval() {
+    let mut pending_config_change: PendingConfigChangeAccount = zeroed();
+    pending_config_change.change.fee_tiers = full(MAX_FEE_TIERS);
+    pending_config_change.change.governance_signers =
+        vec![Pubkey::default(); MAX_GOVERNANCE_SIGNERS];
+    pending_config_change.approvals = vec![Pubkey::default(); MAX_GOVERNANCE_SIGNERS];
+
+    assert_eq!(
+        serialized_len(&pending_config_change),
+        PendingConfigChangeAccount::space()
+    );
+}
+
+#[test]
+fn pad_space_without_rounds_matches_the_largest_fixed_decay_model() {
+    let mut auction_config: AuctionAccount = zeroed();
+    auction_config.decay_model = DecayModelType::Sigmoid { steepness: 0 };
+    auction_config.payment_mints = full(MAX_PAYMENT_MINTS);
+    auction_config.fee_tiers = full(MAX_FEE_TIERS);
+
+    assert_eq!(serialized_len(&auction_config), AuctionAccount::space(0));
+}
+
+#[test]
+fn pad_space_fits_every_round() {
+    let tmax: u16 = 100;
+
+    let mut auction_config: AuctionAccount = zeroed();
+    auction_config.boost_history = vec![0; tmax as usize];
+    auction_config.decay_model = DecayModelType::Table {
+        prices: vec![0; tmax as usize],
+    };
+    auction_config.round_schedule = full(tmax as usize);
+    auction_config.payment_mints = full(MAX_PAYMENT_MINTS);
+    auction_config.fee_tiers = full(MAX_FEE_TIERS);
+
+    assert!(serialized_len(&auction_config) <= AuctionAccount::space(tmax));
+}
+
+#[test]
+fn collection_pad_space_without_rounds_matches_the_largest_fixed_decay_model() {
+    let mut collection_auction_config: CollectionAuctionAccount = zeroed();
+    collection_auction_config.decay_model = DecayModelType::Sigmoid { steepness: 0 };
+    collection_auction_config.asset_creators = full(3);
+    collection_auction_config.asset_name = "n".repeat(MAX_ASSET_NAME_LENGTH);
+    collection_auction_config.asset_symbol = "s".repeat(MAX_ASSET_SYMBOL_LENGTH);
+    collection_auction_config.asset_url = "u".repeat(MAX_ASSET_URL_LENGTH);
+    collection_auction_config.asset_url_suffix = "x".repeat(MAX_ASSET_URL_SUFFIX_LENGTH);
+    collection_auction_config.fee_tiers = full(MAX_FEE_TIERS);
+
+    assert_eq!(
+        serialized_len(&collection_auction_config),
+        CollectionAuctionAccount::space(0, 3)
+    );
+}
+
+#[test]
+fn collection_pad_space_fits_every_round() {
+    let tmax: u16 = 100;
+
+    let mut collection_auction_config: CollectionAuctionAccount = zeroed();
+    collection_auction_config.boost_history = vec![0; tmax as usize];
+    collection_auction_config.decay_model = DecayModelType::Table {
+        prices: vec![0; tmax as usize],
+    };
+    collection_auction_config.asset_creators = full(3);
+    collection_auction_config.asset_name = "n".repeat(MAX_ASSET_NAME_LENGTH);
+    collection_auction_config.asset_symbol = "s".repeat(MAX_ASSET_SYMBOL_LENGTH);
+    collection_auction_config.asset_url = "u".repeat(MAX_ASSET_URL_LENGTH);
+    collection_auction_config.asset_url_suffix = "x".repeat(MAX_ASSET_URL_SUFFIX_LENGTH);
+    collection_auction_config.round_schedule = full(tmax as usize);
+    collection_auction_config.fee_tiers = full(MAX_FEE_TIERS);
+
+    assert!(
+        serialized_len(&collection_auction_config) <= CollectionAuctionAccount::space(tmax, 3)
+    );
+}
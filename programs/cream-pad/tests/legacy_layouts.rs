@@ -0,0 +1,364 @@
+//! Upgrades accounts written by the unversioned program, the fixtures under `tests/fixtures` are
+//! in the `solana account --output json` format so they can also be loaded into a test validator.
+
+use anchor_lang::prelude::*;
+use anchor_lang::{AccountDeserialize, AccountSerialize, Discriminator};
+use base64::Engine;
+use cream_pad::states::*;
+
+fn load_fixture(name: &str) -> Vec<u8> {
+    let path: String = format!("{}/tests/fixtures/{name}.json", env!("CARGO_MANIFEST_DIR"));
+    let fixture: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+    base64::engine::general_purpose::STANDARD
+        .decode(fixture["account"]["data"][0].as_str().unwrap())
+        .unwrap()
+}
+
+fn load_legacy<T: Discriminator, V: AnchorDeserialize>(data: &[u8], legacy_space: usize) -> V {
+    assert_eq!(data[..8], T::discriminator());
+    assert_eq!(data.len(), legacy_space);
+
+    V::deserialize(&mut &data[8..]).unwrap()
+}
+
+// Writes the migrated account into a buffer of the new size and reads it back
+fn realloc_and_reload<T: AccountSerialize + AccountDeserialize>(account: &T, space: usize) -> T {
+    let mut data: Vec<u8> = Vec::with_capacity(space);
+    account.try_serialize(&mut data).unwrap();
+    assert!(data.len() <= space);
+    data.resize(space, 0);
+
+    T::try_deserialize(&mut data.as_slice()).unwrap()
+}
+
+fn key(byte: u8) -> Pubkey {
+    Pubkey::new_from_array([byte; 32])
+}
+
+#[test]
+fn migrates_cream_pad_config() {
+    let data: Vec<u8> = load_fixture("cream_pad_config");
+    assert!(CreamPadAccount::try_deserialize(&mut data.as_slice()).is_err());
+
+    let legacy: CreamPadAccountV0 =
+        load_legacy::<CreamPadAccount, _>(&data, CreamPadAccountV0::space());
+    let cream_pad_config: CreamPadAccount =
+        realloc_and_reload(&legacy.migrate(), CreamPadAccount::space());
+
+    assert_eq!(cream_pad_config.signing_authority, key(1));
+    assert_eq!(cream_pad_config.back_authority, key(2));
+    assert!(cream_pad_config.program_status == ProgramStatus::Normal);
+    assert_eq!(cream_pad_config.fee_base_point, 100);
+    assert_eq!(cream_pad_config.minting_fee, 1_000_000);
+    assert_eq!(cream_pad_config.treasury, key(4));
+    assert!(cream_pad_config.fee_tiers.is_empty());
+    assert!(!cream_pad_config.have_governance);
+    assert!(cream_pad_config.tenant_id.is_empty());
+    assert_eq!(cream_pad_config.version, CREAM_PAD_ACCOUNT_VERSION);
+    assert_eq!(cream_pad_config.reserved, [0; 64]);
+}
+
+#[test]
+fn migrates_pad() {
+    let data: Vec<u8> = load_fixture("auction_config");
+    assert!(AuctionAccount::try_deserialize(&mut data.as_slice()).is_err());
+
+    let legacy: AuctionAccountV0 =
+        load_legacy::<AuctionAccount, _>(&data, AuctionAccountV0::space(5));
+    let auction_config: AuctionAccount =
+        realloc_and_reload(&legacy.migrate(), AuctionAccount::space(5));
+
+    assert_eq!(auction_config.mint, key(7));
+    assert!(auction_config.status == AuctionStatus::Started);
+    assert_eq!(auction_config.tmax, 5);
+    assert_eq!(auction_config.current_round, 2);
+    assert_eq!(auction_config.boost_history, vec![0, boost_to_wad(0.25)]);
+    assert!(auction_config.decay_model == DecayModelType::Exponential);
+    assert_eq!(auction_config.total_supply_sold, 250_000);
+    assert_eq!(auction_config.total_fee, 2_250_000);
+    assert!(!auction_config.have_whitelist);
+    assert!(auction_config.payment_mints.is_empty());
+    assert_eq!(auction_config.cream_pad_config, Pubkey::default());
+    assert_eq!(auction_config.version, AUCTION_ACCOUNT_VERSION);
+    assert_eq!(auction_config.reserved, [0; 64]);
+}
+
+#[test]
+fn migrates_pad_round() {
+    let data: Vec<u8> = load_fixture("auction_round_config");
+
+    let legacy: AuctionRoundAccountV0 =
+        load_legacy::<AuctionRoundAccount, _>(&data, AuctionRoundAccountV0::space());
+    let auction_round_config: AuctionRoundAccount =
+        realloc_and_reload(&legacy.migrate(), AuctionRoundAccount::space());
+
+    assert_eq!(auction_round_config.round, 2);
+    assert_eq!(auction_round_config.boost, boost_to_wad(0.25));
+    assert_eq!(auction_round_config.price, 900_000);
+    assert!(auction_round_config.have_buy_limit);
+    assert_eq!(auction_round_config.buy_limit, 10_000);
+    assert_eq!(auction_round_config.rent_payer, Pubkey::default());
+    assert_eq!(auction_round_config.version, AUCTION_ROUND_ACCOUNT_VERSION);
+}
+
+#[test]
+fn migrates_user_pad() {
+    let data: Vec<u8> = load_fixture("user_auction_config");
+    assert!(UserAuctionAccount::try_deserialize(&mut data.as_slice()).is_err());
+
+    let legacy: UserAuctionAccountV0 =
+        load_legacy::<UserAuctionAccount, _>(&data, UserAuctionAccountV0::space());
+    let user_auction_config: UserAuctionAccount =
+        realloc_and_reload(&legacy.migrate(), UserAuctionAccount::space());
+
+    assert_eq!(user_auction_config.user, key(9));
+    assert_eq!(user_auction_config.total_buy_count, 3);
+    assert_eq!(user_auction_config.total_buy_amount, 250_000);
+    assert_eq!(user_auction_config.total_payment, 225_000_000);
+    assert_eq!(user_auction_config.total_whitelist_buy_amount, 0);
+    assert_eq!(user_auction_config.rent_payer, Pubkey::default());
+    assert_eq!(user_auction_config.version, USER_AUCTION_ACCOUNT_VERSION);
+    assert_eq!(user_auction_config.reserved, [0; 32]);
+}
+
+#[test]
+fn migrates_user_pad_round() {
+    let data: Vec<u8> = load_fixture("user_auction_round_config");
+
+    let legacy: UserAuctionRoundAccountV0 =
+        load_legacy::<UserAuctionRoundAccount, _>(&data, UserAuctionRoundAccountV0::space());
+    let user_auction_round_config: UserAuctionRoundAccount =
+        realloc_and_reload(&legacy.migrate(), UserAuctionRoundAccount::space());
+
+    assert_eq!(user_auction_round_config.total_buy_amount, 50_000);
+    assert_eq!(user_auction_round_config.round, 2);
+    assert_eq!(user_auction_round_config.rent_payer, Pubkey::default());
+    assert_eq!(
+        user_auction_round_config.version,
+        USER_AUCTION_ROUND_ACCOUNT_VERSION
+    );
+}
+
+#[test]
+fn migrates_user_pad_buy_receipt() {
+    let data: Vec<u8> = load_fixture("user_auction_buy_receipt_config");
+
+    let legacy: UserAuctionBuyReceiptAccountV0 = load_legacy::<UserAuctionBuyReceiptAccount, _>(
+        &data,
+        UserAuctionBuyReceiptAccountV0::space(),
+    );
+    let user_auction_buy_receipt_config: UserAuctionBuyReceiptAccount =
+        realloc_and_reload(&legacy.migrate(), UserAuctionBuyReceiptAccount::space());
+
+    assert_eq!(user_auction_buy_receipt_config.buy_amount, 50_000);
+    assert_eq!(user_auction_buy_receipt_config.payment, 45_000_000);
+    assert_eq!(user_auction_buy_receipt_config.index, 3);
+    assert!(!user_auction_buy_receipt_config.is_refunded);
+    assert!(!user_auction_buy_receipt_config.is_rebate_claimed);
+    assert_eq!(
+        user_auction_buy_receipt_config.rent_payer,
+        Pubkey::default()
+    );
+    assert_eq!(
+        user_auction_buy_receipt_config.version,
+        USER_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION
+    );
+}
+
+#[test]
+fn migrates_user_pad_unsold_distribution() {
+    let data: Vec<u8> = load_fixture("user_auction_unsold_distribution_config");
+
+    let legacy: UserAuctionUnsoldDistributionAccountV0 =
+        load_legacy::<UserAuctionUnsoldDistributionAccount, _>(
+            &data,
+            UserAuctionUnsoldDistributionAccountV0::space(),
+        );
+    let user_auction_unsold_distribution_config: UserAuctionUnsoldDistributionAccount =
+        realloc_and_reload(
+            &legacy.migrate(),
+            UserAuctionUnsoldDistributionAccount::space(),
+        );
+
+    assert_eq!(user_auction_unsold_distribution_config.amount, 12_345);
+    assert_eq!(
+        user_auction_unsold_distribution_config.rent_payer,
+        Pubkey::default()
+    );
+    assert_eq!(
+        user_auction_unsold_distribution_config.version,
+        USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION
+    );
+}
+
+#[test]
+fn migrates_collection_pad() {
+    let data: Vec<u8> = load_fixture("collection_auction_config");
+    assert!(CollectionAuctionAccount::try_deserialize(&mut data.as_slice()).is_err());
+
+    let legacy: CollectionAuctionAccountV0 =
+        load_legacy::<CollectionAuctionAccount, _>(&data, CollectionAuctionAccountV0::space(5, 1));
+    let collection_auction_config: CollectionAuctionAccount =
+        realloc_and_reload(&legacy.migrate(), CollectionAuctionAccount::space(5, 1));
+
+    assert_eq!(collection_auction_config.collection_mint, key(8));
+    assert_eq!(
+        collection_auction_config.boost_history,
+        vec![0, boost_to_wad(0.25)]
+    );
+    assert!(collection_auction_config.decay_model == DecayModelType::Linear);
+    assert_eq!(collection_auction_config.asset_creators.len(), 1);
+    assert_eq!(collection_auction_config.asset_creators[0].share, 100);
+    assert_eq!(collection_auction_config.current_index, 4);
+    assert_eq!(collection_auction_config.asset_name, "Legacy");
+    assert_eq!(collection_auction_config.asset_url_suffix, ".json");
+    assert!(collection_auction_config.have_collection_update_authority);
+    assert_eq!(
+        collection_auction_config.version,
+        COLLECTION_AUCTION_ACCOUNT_VERSION
+    );
+    assert_eq!(collection_auction_config.reserved, [0; 64]);
+}
+
+#[test]
+fn migrates_collection_pad_round() {
+    let data: Vec<u8> = load_fixture("collection_auction_round_config");
+
+    let legacy: CollectionAuctionRoundAccountV0 = load_legacy::<CollectionAuctionRoundAccount, _>(
+        &data,
+        CollectionAuctionRoundAccountV0::space(),
+    );
+    let collection_auction_round_config: CollectionAuctionRoundAccount =
+        realloc_and_reload(&legacy.migrate(), CollectionAuctionRoundAccount::space());
+
+    assert_eq!(collection_auction_round_config.round, 2);
+    assert_eq!(collection_auction_round_config.boost, boost_to_wad(0.25));
+    assert!(!collection_auction_round_config.have_buy_limit);
+    assert_eq!(
+        collection_auction_round_config.version,
+        COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION
+    );
+}
+
+#[test]
+fn migrates_user_collection_pad() {
+    let data: Vec<u8> = load_fixture("user_collection_auction_config");
+
+    let legacy: UserCollectionAuctionAccountV0 = load_legacy::<UserCollectionAuctionAccount, _>(
+        &data,
+        UserCollectionAuctionAccountV0::space(),
+    );
+    let user_collection_auction_config: UserCollectionAuctionAccount =
+        realloc_and_reload(&legacy.migrate(), UserCollectionAuctionAccount::space());
+
+    assert_eq!(user_collection_auction_config.user, key(9));
+    assert_eq!(user_collection_auction_config.total_buy_amount, 3);
+    assert_eq!(user_collection_auction_config.total_buy_amount_filled, 1);
+    assert_eq!(user_collection_auction_config.total_whitelist_buy_amount, 0);
+    assert_eq!(user_collection_auction_config.rent_payer, Pubkey::default());
+    assert_eq!(
+        user_collection_auction_config.version,
+        USER_COLLECTION_AUCTION_ACCOUNT_VERSION
+    );
+}
+
+#[test]
+fn migrates_user_collection_pad_round() {
+    let data: Vec<u8> = load_fixture("user_collection_auction_round_config");
+
+    let legacy: UserCollectionAuctionRoundAccountV0 =
+        load_legacy::<UserCollectionAuctionRoundAccount, _>(
+            &data,
+            UserCollectionAuctionRoundAccountV0::space(),
+        );
+    let user_collection_auction_round_config: UserCollectionAuctionRoundAccount =
+        realloc_and_reload(
+            &legacy.migrate(),
+            UserCollectionAuctionRoundAccount::space(),
+        );
+
+    assert_eq!(
+        user_collection_auction_round_config.total_payment,
+        2_700_000
+    );
+    assert_eq!(user_collection_auction_round_config.round, 2);
+    assert_eq!(
+        user_collection_auction_round_config.rent_payer,
+        Pubkey::default()
+    );
+    assert_eq!(
+        user_collection_auction_round_config.version,
+        USER_COLLECTION_AUCTION_ROUND_ACCOUNT_VERSION
+    );
+}
+
+#[test]
+fn migrates_user_collection_pad_buy_receipt() {
+    let data: Vec<u8> = load_fixture("user_collection_auction_buy_receipt_config");
+
+    let legacy: UserCollectionAuctionBuyReceiptAccountV0 =
+        load_legacy::<UserCollectionAuctionBuyReceiptAccount, _>(
+            &data,
+            UserCollectionAuctionBuyReceiptAccountV0::space(),
+        );
+    let user_collection_auction_buy_receipt_config: UserCollectionAuctionBuyReceiptAccount =
+        realloc_and_reload(
+            &legacy.migrate(),
+            UserCollectionAuctionBuyReceiptAccount::space(),
+        );
+
+    assert_eq!(user_collection_auction_buy_receipt_config.buy_amount, 3);
+    assert_eq!(
+        user_collection_auction_buy_receipt_config.buy_amount_filled,
+        1
+    );
+    assert_eq!(
+        user_collection_auction_buy_receipt_config.collection_mint,
+        key(8)
+    );
+    assert_eq!(user_collection_auction_buy_receipt_config.user, key(9));
+    assert_eq!(
+        user_collection_auction_buy_receipt_config.pad_name,
+        "legacy"
+    );
+    assert_eq!(
+        user_collection_auction_buy_receipt_config.rent_payer,
+        Pubkey::default()
+    );
+    assert_eq!(
+        user_collection_auction_buy_receipt_config.version,
+        USER_COLLECTION_AUCTION_BUY_RECEIPT_ACCOUNT_VERSION
+    );
+}
+
+#[test]
+fn migrates_user_collection_pad_unsold_distribution() {
+    let data: Vec<u8> = load_fixture("user_collection_auction_unsold_distribution_config");
+
+    let legacy: UserCollectionAuctionUnsoldDistributionAccountV0 =
+        load_legacy::<UserCollectionAuctionUnsoldDistributionAccount, _>(
+            &data,
+            UserCollectionAuctionUnsoldDistributionAccountV0::space(),
+        );
+    let user_collection_auction_unsold_distribution_config: UserCollectionAuctionUnsoldDistributionAccount =
+        realloc_and_reload(
+            &legacy.migrate(),
+            UserCollectionAuctionUnsoldDistributionAccount::space(),
+        );
+
+    assert_eq!(user_collection_auction_unsold_distribution_config.amount, 2);
+    assert_eq!(
+        user_collection_auction_unsold_distribution_config.amount_filled,
+        1
+    );
+    assert_eq!(
+        user_collection_auction_unsold_distribution_config.rent_payer,
+        Pubkey::default()
+    );
+    assert_eq!(
+        user_collection_auction_unsold_distribution_config.version,
+        USER_COLLECTION_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_VERSION
+    );
+}
@@ -1,7 +1,7 @@
 ///// Assert
 import {Connection, PublicKey} from "@solana/web3.js";
 import {assert} from "chai";
-import {BN, Program} from "@coral-xyz/anchor";
+import {AnchorError, BN, Program} from "@coral-xyz/anchor";
 import {CreamPad} from "../target/types/cream_pad";
 import {
     AuctionRoundStatusType,
@@ -10,6 +10,7 @@ import {
     ProgramStatusType,
     UserAuctionStatusType
 } from "./cream-pad-enum";
+import {toWad} from "./cream-pad-math";
 import {lamportsToTokens} from "./cream-pad-math";
 
 interface LocalCreator {
@@ -27,6 +28,28 @@ export async function assertTokenBalance(connection: Connection, ata: PublicKey,
     assert(ataBalance.value.uiAmount === balance, assertMessage);
 }
 
+export async function assertAnchorError(transaction: Promise<unknown>, errorCode: string, assertMessage: string) {
+    try {
+        await transaction;
+    } catch (error) {
+        console.log(error);
+
+        assert(error instanceof AnchorError && error.error.errorCode.code === errorCode, assertMessage);
+
+        return;
+    }
+
+    assert.fail(assertMessage);
+}
+
+export async function assertAuctionStatus(program: Program<CreamPad>, pdaAddress: PublicKey, status: AuctionStatusType) {
+    const data = await program.account.auctionAccount.fetch(pdaAddress);
+
+    console.log("Auction Status: ", data.status);
+
+    assert(JSON.stringify(data.status) === JSON.stringify(status), "Auction -> status");
+}
+
 export async function assertCreamPadAccount(program: Program<CreamPad>, pdaAddress: PublicKey, signingAuthority: PublicKey, backAuthority: PublicKey, isBackAuthorityRequired: boolean, programStatus: ProgramStatusType, isFeeRequired: boolean, feeBasePoint: number, feeReceiver: PublicKey, roundLimit: number, distributionBasePoint: number, lockBasePoint: number, lockDuration: BN, mintingFee: BN, treasury: PublicKey) {
     const data = await program.account.creamPadAccount.fetch(pdaAddress);
 
@@ -98,7 +121,7 @@ export async function assertAuctionAccount(
     assert(data.currentRound === currentRound, "Auction -> currentRound");
 
     for (let i = 0; i < data.boostHistory.length; i++) {
-        assert(data.boostHistory[i] === boostHistory[i], "Auction -> boostHistory");
+        assert(data.boostHistory[i].eq(toWad(boostHistory[i])), "Auction -> boostHistory");
     }
 
     assert(JSON.stringify(data.decayModel) === JSON.stringify(decayModel), "Auction -> decayModel");
@@ -145,7 +168,7 @@ export async function assertAuctionRoundAccount(
     assert(data.totalSupplySold.toNumber() === totalSupplySold.toNumber(), "Auction Round -> totalSupplySold");
     assert(data.totalUserBuyCount.toNumber() === totalUserBuyCount.toNumber(), "Auction Round -> totalUserBuyCount");
     assert(data.totalUserCount.toNumber() === totalUserCount.toNumber(), "Auction Round -> totalUserCount");
-    assert(data.boost === boost, "Auction Round -> boost");
+    assert(data.boost.eq(toWad(boost)), "Auction Round -> boost");
     assert(data.price.toNumber() === price.toNumber(), "Auction Round -> price");
     assert(JSON.stringify(data.status) === JSON.stringify(status), "Auction Round -> status");
     assert(data.totalPayment.toNumber() === totalPayment.toNumber(), "Auction Round -> totalPayment");
@@ -289,7 +312,7 @@ export async function assertCollectionAuctionAccount(
     assert(data.boostHistory.length === boostHistory.length, "Auction -> boostHistory length");
 
     for (let i = 0; i < data.boostHistory.length; i++) {
-        assert(data.boostHistory[i] === boostHistory[i], "Auction -> boostHistory");
+        assert(data.boostHistory[i].eq(toWad(boostHistory[i])), "Auction -> boostHistory");
     }
 
     assert(JSON.stringify(data.decayModel) === JSON.stringify(decayModel), "Auction -> decayModel");
@@ -359,7 +382,7 @@ export async function assertCollectionAuctionRoundAccount(
     assert(data.totalSupplySold.toNumber() === totalSupplySold.toNumber(), "Auction Round -> totalSupplySold");
     assert(data.totalUserBuyCount.toNumber() === totalUserBuyCount.toNumber(), "Auction Round -> totalUserBuyCount");
     assert(data.totalUserCount.toNumber() === totalUserCount.toNumber(), "Auction Round -> totalUserCount");
-    assert(data.boost === boost, "Auction Round -> boost");
+    assert(data.boost.eq(toWad(boost)), "Auction Round -> boost");
     assert(data.price.toNumber() === price.toNumber(), "Auction Round -> price");
     assert(JSON.stringify(data.status) === JSON.stringify(status), "Auction Round -> status");
     assert(data.totalPayment.toNumber() === totalPayment.toNumber(), "Auction Round -> totalPayment");
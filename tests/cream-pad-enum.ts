@@ -1,4 +1,6 @@
 /// ENUMS
+import {BN} from "@coral-xyz/anchor";
+
 export type ProgramStatusType =
     | { normal: {} }
     | { halted: {} };
@@ -8,12 +10,23 @@ export class ProgramStatus {
     static readonly Halted: ProgramStatusType = {halted: {}};
 }
 
+export type AuthorityTypeType =
+    | { signingAuthority: {} }
+    | { backAuthority: {} };
+
+export class AuthorityType {
+    static readonly SigningAuthority: AuthorityTypeType = {signingAuthority: {}};
+    static readonly BackAuthority: AuthorityTypeType = {backAuthority: {}};
+}
+
 export type AuctionStatusType =
     | { started: {} }
     | { ended: {} }
     | { soldOut: {} }
     | { unsoldLockedAndDistributionOpen: {} }
-    | { unsoldUnlocked: {} };
+    | { unsoldUnlocked: {} }
+    | { cancelled: {} }
+    | { scheduled: {} };
 
 export class AuctionStatus {
     static readonly Started: AuctionStatusType = {started: {}};
@@ -21,6 +34,8 @@ export class AuctionStatus {
     static readonly SoldOut: AuctionStatusType = {soldOut: {}};
     static readonly UnsoldLockedAndDistributionOpen: AuctionStatusType = {unsoldLockedAndDistributionOpen: {}};
     static readonly UnsoldUnlocked: AuctionStatusType = {unsoldUnlocked: {}};
+    static readonly Cancelled: AuctionStatusType = {cancelled: {}};
+    static readonly Scheduled: AuctionStatusType = {scheduled: {}};
 }
 
 export type AuctionRoundStatusType =
@@ -34,15 +49,40 @@ export class AuctionRoundStatus {
 
 export type DecayModelType =
     | { linear: {} }
-    | { exponential: {} };
+    | { exponential: {} }
+    | { step: { stepRounds: number, stepBasePoint: number } }
+    | { sigmoid: { steepness: BN } }
+    | { table: { prices: BN[] } };
 
 export class DecayModel {
     static readonly Linear: DecayModelType = {linear: {}};
     static readonly Exponential: DecayModelType = {exponential: {}};
+
+    static step(stepRounds: number, stepBasePoint: number): DecayModelType {
+        return {step: {stepRounds, stepBasePoint}};
+    }
+
+    static sigmoid(steepness: BN): DecayModelType {
+        return {sigmoid: {steepness}};
+    }
+
+    static table(prices: BN[]): DecayModelType {
+        return {table: {prices}};
+    }
 }
 
 export type UserAuctionStatusType = { none: {} };
 
 export class UserAuctionStatus {
     static readonly None: UserAuctionStatusType = {none: {}};
-}
\ No newline at end of file
+}
+export type ConfigChangeStatusType =
+    | { pending: {} }
+    | { executed: {} }
+    | { cancelled: {} };
+
+export class ConfigChangeStatus {
+    static readonly Pending: ConfigChangeStatusType = {pending: {}};
+    static readonly Executed: ConfigChangeStatusType = {executed: {}};
+    static readonly Cancelled: ConfigChangeStatusType = {cancelled: {}};
+}
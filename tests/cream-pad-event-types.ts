@@ -2,6 +2,83 @@
 
 import {PublicKey} from "@solana/web3.js";
 import {BN} from "@coral-xyz/anchor";
+import {AuthorityTypeType, ProgramStatusType} from "./cream-pad-enum";
+
+export interface RoundSchedule {
+    duration: BN,
+
+    haveBuyLimit: boolean,
+
+    buyLimit: BN,
+
+    gap: BN,
+}
+
+export interface PaymentMint {
+    mint: PublicKey,
+
+    rate: BN,
+
+    haveOracle: boolean,
+
+    oracle: PublicKey,
+
+    totalPayment: BN,
+
+    totalFee: BN,
+}
+
+export interface PauseFlags {
+    pauseBuys: boolean,
+
+    pauseNewPads: boolean,
+
+    pauseClaims: boolean,
+
+    pauseCollectionMinting: boolean,
+}
+
+export interface FeeTier {
+    paymentMint: PublicKey,
+
+    minVolume: BN,
+
+    feeBasePoint: number,
+}
+
+export interface ConfigChange {
+    isBackAuthorityRequired: boolean,
+
+    isFeeRequired: boolean,
+
+    feeBasePoint: number,
+
+    feeReceiver: PublicKey,
+
+    roundLimit: number,
+
+    programStatus: ProgramStatusType,
+
+    distributionBasePoint: number,
+
+    lockBasePoint: number,
+
+    lockDuration: BN,
+
+    mintingFee: BN,
+
+    treasury: PublicKey,
+
+    feeTiers: FeeTier[],
+
+    haveGovernance: boolean,
+
+    governanceSigners: PublicKey[],
+
+    governanceThreshold: number,
+
+    governanceTimelock: BN,
+}
 
 export const InitializePadEventName = "InitializePadEvent";
 
@@ -33,6 +110,38 @@ export interface InitializePadEvent {
     haveBuyLimit: boolean,
 
     buyLimit: BN,
+
+    haveWhitelist: boolean,
+
+    whitelistMerkleRoot: number[],
+
+    whitelistRounds: number,
+
+    havePaymentEscrow: boolean,
+
+    haveUniformClearingPrice: boolean,
+
+    haveVesting: boolean,
+
+    vestingTgeBasePoint: number,
+
+    vestingCliffDuration: BN,
+
+    vestingDuration: BN,
+
+    haveCrank: boolean,
+
+    crankRoundDuration: BN,
+
+    crankReward: BN,
+
+    startAt: BN,
+
+    haveReferral: boolean,
+
+    referralBasePoint: number,
+
+    feeBasePoint: number,
 }
 
 export const UpdatePadEventName = "UpdatePadEvent";
@@ -58,7 +167,7 @@ export interface EndRoundEvent {
 
     roundIndex: string,
 
-    boost: number,
+    boost: BN,
 }
 
 export const StartRoundEventName = "StartRoundEvent";
@@ -134,6 +243,22 @@ export interface BuyEvent {
     totalPrice: BN,
 
     isEndedAndSoldOut: boolean,
+
+    isWhitelistRound: boolean,
+
+    paymentMint: PublicKey,
+
+    paymentTotalPrice: BN,
+
+    referrer: PublicKey,
+
+    referralReward: BN,
+
+    isBuyIntent: boolean,
+
+    rentPayer: PublicKey,
+
+    rent: BN,
 }
 
 export const ClaimDistributionEventName = "ClaimDistributionEvent";
@@ -148,6 +273,10 @@ export interface ClaimDistributionEvent {
     user: PublicKey,
 
     amount: BN,
+
+    rentPayer: PublicKey,
+
+    rent: BN,
 }
 
 // Collection
@@ -186,6 +315,20 @@ export interface InitializeCollectionPadEvent {
     startingIndex: BN,
 
     endingIndex: BN,
+
+    haveWhitelist: boolean,
+
+    whitelistMerkleRoot: number[],
+
+    whitelistRounds: number,
+
+    haveReferral: boolean,
+
+    referralBasePoint: number,
+
+    feeBasePoint: number,
+
+    mintingFee: BN,
 }
 
 export const UpdateCollectionPadEventName = "UpdateCollectionPadEvent";
@@ -211,7 +354,7 @@ export interface EndCollectionRoundEvent {
 
     roundIndex: string,
 
-    boost: number,
+    boost: BN,
 }
 
 export const StartCollectionRoundEventName = "StartCollectionRoundEvent";
@@ -314,6 +457,16 @@ export interface BuyCollectionAssetEvent {
     totalPrice: BN,
 
     isEndedAndSoldOut: boolean,
+
+    isWhitelistRound: boolean,
+
+    referrer: PublicKey,
+
+    referralReward: BN,
+
+    rentPayer: PublicKey,
+
+    rent: BN,
 }
 
 export const FillBoughtCollectionAssetEventName = "FillBoughtCollectionAssetEvent";
@@ -348,6 +501,10 @@ export interface CollectionClaimDistributionEvent {
     user: PublicKey,
 
     amount: BN,
+
+    rentPayer: PublicKey,
+
+    rent: BN,
 }
 
 export const FillClaimedCollectionAssetDistributionEventName = "FillClaimedCollectionAssetDistributionEvent";
@@ -368,6 +525,510 @@ export interface FillClaimedCollectionAssetDistributionEvent {
     assetMintAccount: PublicKey,
 }
 
+export const CancelPadEventName = "CancelPadEvent";
+
+export interface CancelPadEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    totalSupplySold: BN,
+
+    totalPayment: BN,
+}
+
+export const WithdrawCancelledSupplyEventName = "WithdrawCancelledSupplyEvent";
+
+export interface WithdrawCancelledSupplyEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    amount: BN,
+}
+
+export const RefundEventName = "RefundEvent";
+
+export interface RefundEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    userBuyIndex: string,
+
+    amount: BN,
+
+    payment: BN,
+}
+
+export const SettlePadEventName = "SettlePadEvent";
+
+export interface SettlePadEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    payment: BN,
+
+    fee: BN,
+}
+
+export const ClaimRebateEventName = "ClaimRebateEvent";
+
+export interface ClaimRebateEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    userBuyIndex: string,
+
+    clearingPrice: BN,
+
+    rebate: BN,
+}
+
+export const ClaimVestedEventName = "ClaimVestedEvent";
+
+export interface ClaimVestedEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    amount: BN,
+
+    totalClaimedAmount: BN,
+}
+
+export const CrankRoundEventName = "CrankRoundEvent";
+
+export interface CrankRoundEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    cranker: PublicKey,
+
+    roundIndex: string,
+
+    boost: BN,
+
+    nextRoundIndex: string,
+
+    currentPrice: BN,
+
+    reward: BN,
+}
+
+export const FundCrankRewardEventName = "FundCrankRewardEvent";
+
+export interface FundCrankRewardEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    amount: BN,
+}
+
+export const RoundScheduleEventName = "RoundScheduleEvent";
+
+export interface RoundScheduleEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    roundSchedule: RoundSchedule[],
+}
+
+export const CollectionRoundScheduleEventName = "CollectionRoundScheduleEvent";
+
+export interface CollectionRoundScheduleEvent {
+    timestamp: BN,
+
+    collectionMint: PublicKey,
+
+    padName: string,
+
+    roundSchedule: RoundSchedule[],
+}
+
+export const PaymentMintsEventName = "PaymentMintsEvent";
+
+export interface PaymentMintsEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    paymentOracleProgram: PublicKey,
+
+    paymentOracleMaxAge: BN,
+
+    paymentMints: PaymentMint[],
+}
+
+export const ClaimReferralRewardsEventName = "ClaimReferralRewardsEvent";
+
+export interface ClaimReferralRewardsEvent {
+    timestamp: BN,
+
+    referrer: PublicKey,
+
+    padConfig: PublicKey,
+
+    amount: BN,
+
+    totalClaimedReward: BN,
+}
+
+export const SetPadFeeEventName = "SetPadFeeEvent";
+
+export interface SetPadFeeEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    haveFeeOverride: boolean,
+
+    feeOverrideBasePoint: number,
+}
+
+export const SetCollectionPadFeeEventName = "SetCollectionPadFeeEvent";
+
+export interface SetCollectionPadFeeEvent {
+    timestamp: BN,
+
+    collectionMint: PublicKey,
+
+    padName: string,
+
+    haveFeeOverride: boolean,
+
+    feeOverrideBasePoint: number,
+
+    feeOverrideMintingFee: BN,
+}
+
+export const MigrateConfigEventName = "MigrateConfigEvent";
+
+export interface MigrateConfigEvent {
+    timestamp: BN,
+
+    signingAuthority: PublicKey,
+}
+
+export const ProposeConfigChangeEventName = "ProposeConfigChangeEvent";
+
+export interface ProposeConfigChangeEvent {
+    timestamp: BN,
+
+    proposer: PublicKey,
+
+    configChangeIndex: BN,
+
+    change: ConfigChange,
+
+    executableAt: BN,
+}
+
+export const ApproveConfigChangeEventName = "ApproveConfigChangeEvent";
+
+export interface ApproveConfigChangeEvent {
+    timestamp: BN,
+
+    governanceSigner: PublicKey,
+
+    configChangeIndex: BN,
+
+    approvalCount: number,
+}
+
+export const ExecuteConfigChangeEventName = "ExecuteConfigChangeEvent";
+
+export interface ExecuteConfigChangeEvent {
+    timestamp: BN,
+
+    governanceSigner: PublicKey,
+
+    configChangeIndex: BN,
+}
+
+export const CancelConfigChangeEventName = "CancelConfigChangeEvent";
+
+export interface CancelConfigChangeEvent {
+    timestamp: BN,
+
+    governanceSigner: PublicKey,
+
+    configChangeIndex: BN,
+}
+
+export const ProposeAuthorityTransferEventName = "ProposeAuthorityTransferEvent";
+
+export interface ProposeAuthorityTransferEvent {
+    timestamp: BN,
+
+    authorityType: AuthorityTypeType,
+
+    currentAuthority: PublicKey,
+
+    pendingAuthority: PublicKey,
+}
+
+export const AcceptAuthorityTransferEventName = "AcceptAuthorityTransferEvent";
+
+export interface AcceptAuthorityTransferEvent {
+    timestamp: BN,
+
+    authorityType: AuthorityTypeType,
+
+    previousAuthority: PublicKey,
+
+    newAuthority: PublicKey,
+}
+
+export const SetPauseFlagsEventName = "SetPauseFlagsEvent";
+
+export interface SetPauseFlagsEvent {
+    timestamp: BN,
+
+    pauseFlags: PauseFlags,
+}
+
+export const SetPadPauseFlagsEventName = "SetPadPauseFlagsEvent";
+
+export interface SetPadPauseFlagsEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    pauseFlags: PauseFlags,
+}
+
+export const SetCollectionPadPauseFlagsEventName = "SetCollectionPadPauseFlagsEvent";
+
+export interface SetCollectionPadPauseFlagsEvent {
+    timestamp: BN,
+
+    collectionMint: PublicKey,
+
+    padName: string,
+
+    pauseFlags: PauseFlags,
+}
+
+export const MigrateUserPadEventName = "MigrateUserPadEvent";
+
+export interface MigrateUserPadEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const MigrateUserPadRoundEventName = "MigrateUserPadRoundEvent";
+
+export interface MigrateUserPadRoundEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    roundIndex: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const MigrateUserPadBuyReceiptEventName = "MigrateUserPadBuyReceiptEvent";
+
+export interface MigrateUserPadBuyReceiptEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    buyIndex: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const MigrateUserPadUnsoldDistributionEventName = "MigrateUserPadUnsoldDistributionEvent";
+
+export interface MigrateUserPadUnsoldDistributionEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const MigrateUserCollectionPadEventName = "MigrateUserCollectionPadEvent";
+
+export interface MigrateUserCollectionPadEvent {
+    timestamp: BN,
+
+    collectionMint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const MigrateUserCollectionPadRoundEventName = "MigrateUserCollectionPadRoundEvent";
+
+export interface MigrateUserCollectionPadRoundEvent {
+    timestamp: BN,
+
+    collectionMint: PublicKey,
+
+    padName: string,
+
+    roundIndex: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const MigrateUserCollectionPadBuyReceiptEventName = "MigrateUserCollectionPadBuyReceiptEvent";
+
+export interface MigrateUserCollectionPadBuyReceiptEvent {
+    timestamp: BN,
+
+    collectionMint: PublicKey,
+
+    padName: string,
+
+    buyIndex: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const MigrateUserCollectionPadUnsoldDistributionEventName = "MigrateUserCollectionPadUnsoldDistributionEvent";
+
+export interface MigrateUserCollectionPadUnsoldDistributionEvent {
+    timestamp: BN,
+
+    collectionMint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    version: number,
+}
+
+export const CloseBuyReceiptEventName = "CloseBuyReceiptEvent";
+
+export interface CloseBuyReceiptEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    userBuyIndex: string,
+
+    rentPayer: PublicKey,
+
+    rent: BN,
+}
+
+export const CloseUserRoundEventName = "CloseUserRoundEvent";
+
+export interface CloseUserRoundEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    user: PublicKey,
+
+    roundIndex: string,
+
+    rentPayer: PublicKey,
+
+    rent: BN,
+}
+
+export const CloseRoundEventName = "CloseRoundEvent";
+
+export interface CloseRoundEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    roundIndex: string,
+
+    rentPayer: PublicKey,
+
+    rent: BN,
+}
+
+export const WithdrawCrankRewardEventName = "WithdrawCrankRewardEvent";
+
+export interface WithdrawCrankRewardEvent {
+    timestamp: BN,
+
+    mint: PublicKey,
+
+    padName: string,
+
+    amount: BN,
+}
+
 export const handleInitializePadEvent = (ev: InitializePadEvent) =>
     console.log(`${InitializePadEventName} ==> `, ev);
 
@@ -432,4 +1093,112 @@ export const handleCollectionClaimDistributionEvent = (ev: CollectionClaimDistri
     console.log(`${CollectionClaimDistributionEventName} ==> `, ev);
 
 export const handleFillClaimedCollectionAssetDistributionEvent = (ev: FillClaimedCollectionAssetDistributionEvent) =>
-    console.log(`${FillClaimedCollectionAssetDistributionEventName} ==> `, ev);
\ No newline at end of file
+    console.log(`${FillClaimedCollectionAssetDistributionEventName} ==> `, ev);
+
+export const handleCancelPadEvent = (ev: CancelPadEvent) =>
+    console.log(`${CancelPadEventName} ==> `, ev);
+
+export const handleWithdrawCancelledSupplyEvent = (ev: WithdrawCancelledSupplyEvent) =>
+    console.log(`${WithdrawCancelledSupplyEventName} ==> `, ev);
+
+export const handleRefundEvent = (ev: RefundEvent) =>
+    console.log(`${RefundEventName} ==> `, ev);
+
+export const handleSettlePadEvent = (ev: SettlePadEvent) =>
+    console.log(`${SettlePadEventName} ==> `, ev);
+
+export const handleClaimRebateEvent = (ev: ClaimRebateEvent) =>
+    console.log(`${ClaimRebateEventName} ==> `, ev);
+
+export const handleClaimVestedEvent = (ev: ClaimVestedEvent) =>
+    console.log(`${ClaimVestedEventName} ==> `, ev);
+
+export const handleCrankRoundEvent = (ev: CrankRoundEvent) =>
+    console.log(`${CrankRoundEventName} ==> `, ev);
+
+export const handleFundCrankRewardEvent = (ev: FundCrankRewardEvent) =>
+    console.log(`${FundCrankRewardEventName} ==> `, ev);
+
+export const handleRoundScheduleEvent = (ev: RoundScheduleEvent) =>
+    console.log(`${RoundScheduleEventName} ==> `, ev);
+
+export const handleCollectionRoundScheduleEvent = (ev: CollectionRoundScheduleEvent) =>
+    console.log(`${CollectionRoundScheduleEventName} ==> `, ev);
+
+export const handlePaymentMintsEvent = (ev: PaymentMintsEvent) =>
+    console.log(`${PaymentMintsEventName} ==> `, ev);
+
+export const handleClaimReferralRewardsEvent = (ev: ClaimReferralRewardsEvent) =>
+    console.log(`${ClaimReferralRewardsEventName} ==> `, ev);
+
+export const handleSetPadFeeEvent = (ev: SetPadFeeEvent) =>
+    console.log(`${SetPadFeeEventName} ==> `, ev);
+
+export const handleSetCollectionPadFeeEvent = (ev: SetCollectionPadFeeEvent) =>
+    console.log(`${SetCollectionPadFeeEventName} ==> `, ev);
+
+export const handleMigrateConfigEvent = (ev: MigrateConfigEvent) =>
+    console.log(`${MigrateConfigEventName} ==> `, ev);
+
+export const handleProposeConfigChangeEvent = (ev: ProposeConfigChangeEvent) =>
+    console.log(`${ProposeConfigChangeEventName} ==> `, ev);
+
+export const handleApproveConfigChangeEvent = (ev: ApproveConfigChangeEvent) =>
+    console.log(`${ApproveConfigChangeEventName} ==> `, ev);
+
+export const handleExecuteConfigChangeEvent = (ev: ExecuteConfigChangeEvent) =>
+    console.log(`${ExecuteConfigChangeEventName} ==> `, ev);
+
+export const handleCancelConfigChangeEvent = (ev: CancelConfigChangeEvent) =>
+    console.log(`${CancelConfigChangeEventName} ==> `, ev);
+
+export const handleProposeAuthorityTransferEvent = (ev: ProposeAuthorityTransferEvent) =>
+    console.log(`${ProposeAuthorityTransferEventName} ==> `, ev);
+
+export const handleAcceptAuthorityTransferEvent = (ev: AcceptAuthorityTransferEvent) =>
+    console.log(`${AcceptAuthorityTransferEventName} ==> `, ev);
+
+export const handleSetPauseFlagsEvent = (ev: SetPauseFlagsEvent) =>
+    console.log(`${SetPauseFlagsEventName} ==> `, ev);
+
+export const handleSetPadPauseFlagsEvent = (ev: SetPadPauseFlagsEvent) =>
+    console.log(`${SetPadPauseFlagsEventName} ==> `, ev);
+
+export const handleSetCollectionPadPauseFlagsEvent = (ev: SetCollectionPadPauseFlagsEvent) =>
+    console.log(`${SetCollectionPadPauseFlagsEventName} ==> `, ev);
+
+export const handleMigrateUserPadEvent = (ev: MigrateUserPadEvent) =>
+    console.log(`${MigrateUserPadEventName} ==> `, ev);
+
+export const handleMigrateUserPadRoundEvent = (ev: MigrateUserPadRoundEvent) =>
+    console.log(`${MigrateUserPadRoundEventName} ==> `, ev);
+
+export const handleMigrateUserPadBuyReceiptEvent = (ev: MigrateUserPadBuyReceiptEvent) =>
+    console.log(`${MigrateUserPadBuyReceiptEventName} ==> `, ev);
+
+export const handleMigrateUserPadUnsoldDistributionEvent = (ev: MigrateUserPadUnsoldDistributionEvent) =>
+    console.log(`${MigrateUserPadUnsoldDistributionEventName} ==> `, ev);
+
+export const handleMigrateUserCollectionPadEvent = (ev: MigrateUserCollectionPadEvent) =>
+    console.log(`${MigrateUserCollectionPadEventName} ==> `, ev);
+
+export const handleMigrateUserCollectionPadRoundEvent = (ev: MigrateUserCollectionPadRoundEvent) =>
+    console.log(`${MigrateUserCollectionPadRoundEventName} ==> `, ev);
+
+export const handleMigrateUserCollectionPadBuyReceiptEvent = (ev: MigrateUserCollectionPadBuyReceiptEvent) =>
+    console.log(`${MigrateUserCollectionPadBuyReceiptEventName} ==> `, ev);
+
+export const handleMigrateUserCollectionPadUnsoldDistributionEvent = (ev: MigrateUserCollectionPadUnsoldDistributionEvent) =>
+    console.log(`${MigrateUserCollectionPadUnsoldDistributionEventName} ==> `, ev);
+
+export const handleCloseBuyReceiptEvent = (ev: CloseBuyReceiptEvent) =>
+    console.log(`${CloseBuyReceiptEventName} ==> `, ev);
+
+export const handleCloseUserRoundEvent = (ev: CloseUserRoundEvent) =>
+    console.log(`${CloseUserRoundEventName} ==> `, ev);
+
+export const handleCloseRoundEvent = (ev: CloseRoundEvent) =>
+    console.log(`${CloseRoundEventName} ==> `, ev);
+
+export const handleWithdrawCrankRewardEvent = (ev: WithdrawCrankRewardEvent) =>
+    console.log(`${WithdrawCrankRewardEventName} ==> `, ev);
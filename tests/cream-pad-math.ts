@@ -1,43 +1,123 @@
+import {BN} from "@coral-xyz/anchor";
 
 ///// MATH
 
+export const WAD = 1_000_000_000_000_000_000n;
+
+export const LN_2_WAD = 693_147_180_559_945_309n;
+
+export const MIN_EXP_WAD = -42n * WAD;
+
+// Boost or price factor as a fixed point value, mirrors the program `WAD` scale
+export function toWad(value: number): BN {
+    const scaled = BigInt(Math.round(value * 1_000_000)) * (WAD / 1_000_000n);
+
+    return new BN(scaled.toString());
+}
+
+export function lnWad(x: bigint): bigint {
+    let m = x;
+    let k = 0n;
+
+    while (m >= 2n * WAD) {
+        m >>= 1n;
+        k += 1n;
+    }
+
+    while (m < WAD) {
+        m <<= 1n;
+        k -= 1n;
+    }
+
+    const z = (m - WAD) * WAD / (m + WAD);
+    const zSquared = z * z / WAD;
+
+    let sum = 0n;
+    let term = z;
+    let n = 1n;
+
+    while (term !== 0n) {
+        sum += term / n;
+        term = term * zSquared / WAD;
+        n += 2n;
+    }
+
+    return k * LN_2_WAD + 2n * sum;
+}
+
+export function expWad(x: bigint): bigint {
+    if (x < MIN_EXP_WAD) return 0n;
+
+    const k = x / LN_2_WAD;
+    const r = x - k * LN_2_WAD;
+
+    let sum = WAD;
+    let term = WAD;
+    let n = 1n;
+
+    while (term !== 0n) {
+        term = term * r / WAD / n;
+        sum += term;
+        n += 1n;
+    }
+
+    return k >= 0n ? sum << k : sum >> -k;
+}
+
 export function calculateBoost(
-    actualSales: number,
-    expectedSales: number,
-    omega: number,
-    alpha: number,
-    timeShiftMax: number
-): number {
-    if (expectedSales === 0) return 0; // Prevent division by zero
-    const ratio = Math.floor((actualSales * omega) / expectedSales);
-    return Math.min(alpha * ratio, timeShiftMax);
+    actualSales: bigint,
+    expectedSales: bigint,
+    omega: bigint,
+    alpha: bigint,
+    timeShiftMax: bigint
+): bigint {
+    if (actualSales >= expectedSales) {
+        const maxBoost = timeShiftMax * WAD;
+
+        if (expectedSales === 0n) return maxBoost;
+
+        const ratio = actualSales * WAD / expectedSales;
+        const boost = alpha * omega * ratio;
+
+        return boost < maxBoost ? boost : maxBoost;
+    }
+
+    return -WAD; // No boost if sales are below target
 }
 
 export function calculatePrice(
-    p0: number,
-    ptmax: number,
-    tMax: number,
+    p0: bigint,
+    ptmax: bigint,
+    tMax: bigint,
     currentRound: number,
-    boostHistory: number[],
+    boostHistory: bigint[],
     decayModel: number,
-    timeShiftMax: number
-): number {
-    let totalBoost = 0;
+    timeShiftMax: bigint
+): bigint {
+    const maxBoost = timeShiftMax * WAD;
 
-    // ✅ Fix: Correctly apply boost by shifting the time effect
-    for (let i = 0; i < currentRound; i++) {
-        totalBoost += 1 - Math.min(boostHistory[i] || 0, timeShiftMax);
+    let totalBoost = 0n;
+
+    for (let i = 0; i < currentRound && i < boostHistory.length; i++) {
+        totalBoost += -(boostHistory[i] < maxBoost ? boostHistory[i] : maxBoost);
     }
 
+    const rounds = tMax > 1n ? tMax - 1n : 1n;
+    const progress = totalBoost / rounds;
+
+    let price: bigint;
+
     if (decayModel === 0) { // Linear decay
-        const k0 = (p0 - ptmax) / (tMax - 1); // ✅ Fix: Use `tMax - 1` instead of `tMax`
-        return Math.max(p0 - k0 * totalBoost, ptmax);
+        const range = p0 > ptmax ? p0 - ptmax : 0n;
+        price = p0 - range * progress / WAD;
     } else { // Exponential decay
-        if (p0 <= ptmax) return ptmax; // Prevent log errors
+        if (p0 <= ptmax) return ptmax;
 
-        const lambda0 = (Math.log(p0) - Math.log(ptmax)) / (tMax - 1);
-        return Math.max(p0 * Math.exp(-lambda0 * totalBoost), ptmax);
+        const lambda = lnWad(p0 * WAD / (ptmax > 0n ? ptmax : 1n));
+        price = p0 * expWad(-(lambda * progress) / WAD) / WAD;
     }
+
+    return price > ptmax ? price : ptmax;
 }
 
 export function adjustAmount(amount: number, fromDecimals: number, toDecimals: number): number {
@@ -0,0 +1,60 @@
+import {BN} from "@coral-xyz/anchor";
+import {PublicKey} from "@solana/web3.js";
+import {keccak_256} from "@noble/hashes/sha3";
+
+///// MERKLE
+
+export interface WhitelistEntry {
+    user: PublicKey;
+    allocation: BN;
+}
+
+export interface WhitelistTree {
+    root: number[];
+    proofs: number[][][];
+}
+
+// keccak256(user || allocation as little endian u64), mirrors the program `whitelist_leaf`
+export function whitelistLeaf(user: PublicKey, allocation: BN): Uint8Array {
+    return keccak_256(Buffer.concat([user.toBuffer(), allocation.toArrayLike(Buffer, "le", 8)]));
+}
+
+// Pairs are hashed in sorted order, mirrors the program `verify_proof`
+export function hashPair(left: Uint8Array, right: Uint8Array): Uint8Array {
+    return Buffer.compare(Buffer.from(left), Buffer.from(right)) <= 0
+        ? keccak_256(Buffer.concat([left, right]))
+        : keccak_256(Buffer.concat([right, left]));
+}
+
+// Root and one proof per entry, an odd node is carried up to the next level as is
+export function getWhitelistTree(entries: WhitelistEntry[]): WhitelistTree {
+    let level: Uint8Array[] = entries.map((entry) => whitelistLeaf(entry.user, entry.allocation));
+    let positions: number[] = entries.map((_, index) => index);
+
+    const proofs: Uint8Array[][] = entries.map(() => []);
+
+    while (level.length > 1) {
+        for (let entry = 0; entry < entries.length; entry++) {
+            const sibling = positions[entry] ^ 1;
+
+            if (sibling < level.length) {
+                proofs[entry].push(level[sibling]);
+            }
+
+            positions[entry] = positions[entry] >> 1;
+        }
+
+        const nextLevel: Uint8Array[] = [];
+
+        for (let index = 0; index < level.length; index += 2) {
+            nextLevel.push(index + 1 < level.length ? hashPair(level[index], level[index + 1]) : level[index]);
+        }
+
+        level = nextLevel;
+    }
+
+    return {
+        root: Array.from(level[0]),
+        proofs: proofs.map((proof) => proof.map((node) => Array.from(node)))
+    };
+}
@@ -2,6 +2,7 @@
 import {PublicKey} from "@solana/web3.js";
 
 const CREAM_PAD_ACCOUNT_PREFIX: string = "CPAP";
+const PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX: string = "PCCP";
 
 const AUCTION_ACCOUNT_PREFIX: string = "AAP";
 const AUCTION_VAULT_PREFIX: string = "AVP";
@@ -11,6 +12,9 @@ const USER_AUCTION_ACCOUNT_PREFIX: string = "UAAP";
 const USER_AUCTION_ROUND_ACCOUNT_PREFIX: string = "UARAP";
 const USER_AUCTION_BUY_RECEIPT_ACCOUNT_PREFIX: string = "UABRAP";
 const USER_AUCTION_UNSOLD_DISTRIBUTION_ACCOUNT_PREFIX: string = "UAUDAP";
+const USER_AUCTION_VESTING_ACCOUNT_PREFIX: string = "UAVAP";
+
+const REFERRER_ACCOUNT_PREFIX: string = "RAP";
 
 const COLLECTION_AUCTION_ACCOUNT_PREFIX: string = "CAAP";
 const COLLECTION_AUCTION_ROUND_ACCOUNT_PREFIX: string = "CARAP";
@@ -51,9 +55,24 @@ export function getMasterEditionPda(programAddress: PublicKey, mint: PublicKey):
     return masterEditionPda;
 }
 
-export function getCreamPadAccountPdaAndBump(programAddress: PublicKey): [PublicKey, number] {
+// The empty tenant id is the default deployment's config
+export function getCreamPadAccountPdaAndBump(programAddress: PublicKey, tenantId: string = ""): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
-        [Buffer.from(CREAM_PAD_ACCOUNT_PREFIX)],
+        [
+            Buffer.from(CREAM_PAD_ACCOUNT_PREFIX),
+            Buffer.from(tenantId),
+        ],
+        programAddress
+    )
+}
+
+export function getPendingConfigChangeAccountPdaAndBump(programAddress: PublicKey, creamPadConfig: PublicKey, configChangeIndex: string): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(PENDING_CONFIG_CHANGE_ACCOUNT_PREFIX),
+            creamPadConfig.toBuffer(),
+            Buffer.from(configChangeIndex),
+        ],
         programAddress
     )
 }
@@ -134,6 +153,27 @@ export function getUserAuctionUnsoldDistributionAccountPdaAndBump(programAddress
     )
 }
 
+export function getUserAuctionVestingAccountPdaAndBump(programAddress: PublicKey, userAuctionConfig: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(USER_AUCTION_VESTING_ACCOUNT_PREFIX),
+            userAuctionConfig.toBuffer(),
+        ],
+        programAddress
+    )
+}
+
+export function getReferrerAccountPdaAndBump(programAddress: PublicKey, padConfig: PublicKey, referrer: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [
+            Buffer.from(REFERRER_ACCOUNT_PREFIX),
+            padConfig.toBuffer(),
+            referrer.toBuffer(),
+        ],
+        programAddress
+    )
+}
+
 export function getCollectionAuctionAccountPdaAndBump(programAddress: PublicKey, padName: string, collectionMintAccount: PublicKey): [PublicKey, number] {
     return PublicKey.findProgramAddressSync(
         [
@@ -9,8 +9,10 @@ import {
     SYSVAR_INSTRUCTIONS_PUBKEY,
     Transaction,
     ComputeBudgetProgram,
-    TransactionInstruction
+    TransactionInstruction,
+    Ed25519Program
 } from "@solana/web3.js";
+import nacl from "tweetnacl";
 import {nanoid} from "nanoid";
 import {
     ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -23,12 +25,15 @@ import {
     createInitializeMintInstruction,
     createAssociatedTokenAccountInstruction,
     createMintToCheckedInstruction,
-    getMinimumBalanceForRentExemptMint, MINT_SIZE
+    getMinimumBalanceForRentExemptMint, MINT_SIZE,
+    approveChecked
 } from '@solana/spl-token';
 import {
     PROGRAM_ID as MPL_TOKEN_METADATA_PROGRAM_ID, DataV2, createCreateMetadataAccountV3Instruction,
     createCreateMasterEditionV3Instruction, createVerifyCollectionInstruction
 } from "@metaplex-foundation/mpl-token-metadata";
+import {assert} from "chai";
+import {getWhitelistTree, WhitelistTree} from "./cream-pad-merkle";
 import {CreamPad} from "../target/types/cream_pad";
 import {
     BuyCollectionAssetEventName,
@@ -68,16 +73,91 @@ import {
     TreasuryAndDistributionEventName,
     UnlockUnsoldSupplyEventName,
     UpdateCollectionPadEventName,
-    UpdatePadEventName
+    UpdatePadEventName,
+    handleCancelPadEvent,
+    CancelPadEventName,
+    handleWithdrawCancelledSupplyEvent,
+    WithdrawCancelledSupplyEventName,
+    handleRefundEvent,
+    RefundEventName,
+    handleSettlePadEvent,
+    SettlePadEventName,
+    handleClaimRebateEvent,
+    ClaimRebateEventName,
+    handleClaimVestedEvent,
+    ClaimVestedEventName,
+    handleCrankRoundEvent,
+    CrankRoundEventName,
+    handleFundCrankRewardEvent,
+    FundCrankRewardEventName,
+    handleRoundScheduleEvent,
+    RoundScheduleEventName,
+    handleCollectionRoundScheduleEvent,
+    CollectionRoundScheduleEventName,
+    handlePaymentMintsEvent,
+    PaymentMintsEventName,
+    handleClaimReferralRewardsEvent,
+    ClaimReferralRewardsEventName,
+    handleSetPadFeeEvent,
+    SetPadFeeEventName,
+    handleSetCollectionPadFeeEvent,
+    SetCollectionPadFeeEventName,
+    handleMigrateConfigEvent,
+    MigrateConfigEventName,
+    handleProposeConfigChangeEvent,
+    ProposeConfigChangeEventName,
+    handleApproveConfigChangeEvent,
+    ApproveConfigChangeEventName,
+    handleExecuteConfigChangeEvent,
+    ExecuteConfigChangeEventName,
+    handleCancelConfigChangeEvent,
+    CancelConfigChangeEventName,
+    handleProposeAuthorityTransferEvent,
+    ProposeAuthorityTransferEventName,
+    handleAcceptAuthorityTransferEvent,
+    AcceptAuthorityTransferEventName,
+    handleSetPauseFlagsEvent,
+    SetPauseFlagsEventName,
+    handleSetPadPauseFlagsEvent,
+    SetPadPauseFlagsEventName,
+    handleSetCollectionPadPauseFlagsEvent,
+    SetCollectionPadPauseFlagsEventName,
+    handleMigrateUserPadEvent,
+    MigrateUserPadEventName,
+    handleMigrateUserPadRoundEvent,
+    MigrateUserPadRoundEventName,
+    handleMigrateUserPadBuyReceiptEvent,
+    MigrateUserPadBuyReceiptEventName,
+    handleMigrateUserPadUnsoldDistributionEvent,
+    MigrateUserPadUnsoldDistributionEventName,
+    handleMigrateUserCollectionPadEvent,
+    MigrateUserCollectionPadEventName,
+    handleMigrateUserCollectionPadRoundEvent,
+    MigrateUserCollectionPadRoundEventName,
+    handleMigrateUserCollectionPadBuyReceiptEvent,
+    MigrateUserCollectionPadBuyReceiptEventName,
+    handleMigrateUserCollectionPadUnsoldDistributionEvent,
+    MigrateUserCollectionPadUnsoldDistributionEventName,
+    handleCloseBuyReceiptEvent,
+    CloseBuyReceiptEventName,
+    handleCloseUserRoundEvent,
+    CloseUserRoundEventName,
+    handleCloseRoundEvent,
+    CloseRoundEventName,
+    handleWithdrawCrankRewardEvent,
+    WithdrawCrankRewardEventName
 } from "./cream-pad-event-types";
 import {
     AuctionRoundStatus,
     AuctionStatus,
+    AuthorityType,
+    AuthorityTypeType,
+    ConfigChangeStatus,
     DecayModel,
     ProgramStatus,
     UserAuctionStatus,
 } from "./cream-pad-enum";
-import {tokensToLamports} from "./cream-pad-math";
+import {calculateBoost, tokensToLamports} from "./cream-pad-math";
 import {
     getAuctionAccountPdaAndBump,
     getAuctionRoundAccountPdaAndBump,
@@ -85,12 +165,15 @@ import {
     getCollectionAuctionAccountPdaAndBump,
     getCollectionAuctionRoundAccountPdaAndBump,
     getCreamPadAccountPdaAndBump,
+    getPendingConfigChangeAccountPdaAndBump,
     getMasterEditionPda,
     getMetadataPda,
     getUserAuctionAccountPdaAndBump,
     getUserAuctionBuyReceiptAccountPdaAndBump,
     getUserAuctionRoundAccountPdaAndBump,
     getUserAuctionUnsoldDistributionAccountPdaAndBump,
+    getUserAuctionVestingAccountPdaAndBump,
+    getReferrerAccountPdaAndBump,
     getUserCollectionAuctionAccountPdaAndBump, getUserCollectionAuctionBuyReceiptAccountPdaAndBump,
     getUserCollectionAuctionRoundAccountPdaAndBump, getUserCollectionAuctionUnsoldDistributionAccountPdaAndBump,
 } from "./cream-pad-pda";
@@ -99,6 +182,8 @@ import {
     assertAuctionRoundAccount,
     assertCollectionAuctionAccount,
     assertCollectionAuctionRoundAccount,
+    assertAnchorError,
+    assertAuctionStatus,
     assertCreamPadAccount,
     assertTokenBalance,
     assertUserAuctionAccount,
@@ -116,6 +201,8 @@ import {
 const mainSigningAuthorityPubKey: PublicKey = anchor.AnchorProvider.env().wallet.publicKey;
 const feeAndRentPayerKeypair: Keypair = Keypair.generate();
 const signingAuthorityKeypair: Keypair = Keypair.generate();
+const nextSigningAuthorityKeypair: Keypair = Keypair.generate();
+const tenantSigningAuthorityKeypair: Keypair = Keypair.generate();
 const backAuthorityKeypair: Keypair = Keypair.generate();
 const mintAuthorityKeypair: Keypair = Keypair.generate();
 const creatorKeypair: Keypair = Keypair.generate();
@@ -132,13 +219,36 @@ const paymentTokenDecimal = 6;
 
 let sellingTokenMintAccount: PublicKey;
 let paymentTokenMintAccount: PublicKey;
+let extraPaymentTokenMintAccount: PublicKey;
+let whitelistTree: WhitelistTree;
+const whitelistAllocationA: BN = new BN(tokensToLamports(5, 9).toString());
+const whitelistAllocationB: BN = new BN(tokensToLamports(3, 9).toString());
 let collectionMintAccount: PublicKey;
 
 const collectionTokenProgramAccount: PublicKey = TOKEN_PROGRAM_ID;
 const sellingTokenProgramAccount: PublicKey = TOKEN_PROGRAM_ID;
 const paymentTokenProgramAccount: PublicKey = TOKEN_2022_PROGRAM_ID;
+const extraPaymentTokenProgramAccount: PublicKey = TOKEN_PROGRAM_ID;
 
 const padName = "one";
+const relayPadName = "relay";
+const cancelPadName = "cancel";
+const settlePadName = "settle";
+const vestingPadName = "vesting";
+const schedulePadName = "schedule";
+const scheduledStartPadName = "start";
+const nativePadName = "native";
+const paymentMintsPadName = "mints";
+const referralPadName = "referral";
+const feePadName = "fee";
+const pausedPadName = "paused";
+const tenantPadName = "tenant";
+const whitelistPadName = "whitelist";
+const rebatePadName = "rebate";
+const crankPadName = "crank";
+const closePadName = "close";
+
+const tenantId: string = "brand";
 const collectionPadName = "collection";
 
 const collectionName: string = "Cream Pad Asset Collection";
@@ -152,6 +262,9 @@ const assetUrlSuffix: string = ".json";
 
 const mintingFee: number = tokensToLamports(0.1, 9);
 
+const governanceTimelock: number = 10;
+const crankReward: number = 2_000_000;
+
 describe("cream-pad", () => {
 
     // const boost = calculateBoost(
@@ -197,6 +310,42 @@ describe("cream-pad", () => {
     const unlockUnsoldSupplyEventListener = program.addEventListener(UnlockUnsoldSupplyEventName, handleUnlockUnsoldSupplyEvent);
     const buyEventListener = program.addEventListener(BuyEventName, handleBuyEvent);
     const claimDistributionEventListener = program.addEventListener(ClaimDistributionEventName, handleClaimDistributionEvent);
+    const cancelPadEventListener = program.addEventListener(CancelPadEventName, handleCancelPadEvent);
+    const withdrawCancelledSupplyEventListener = program.addEventListener(WithdrawCancelledSupplyEventName, handleWithdrawCancelledSupplyEvent);
+    const refundEventListener = program.addEventListener(RefundEventName, handleRefundEvent);
+    const settlePadEventListener = program.addEventListener(SettlePadEventName, handleSettlePadEvent);
+    const claimRebateEventListener = program.addEventListener(ClaimRebateEventName, handleClaimRebateEvent);
+    const claimVestedEventListener = program.addEventListener(ClaimVestedEventName, handleClaimVestedEvent);
+    const crankRoundEventListener = program.addEventListener(CrankRoundEventName, handleCrankRoundEvent);
+    const fundCrankRewardEventListener = program.addEventListener(FundCrankRewardEventName, handleFundCrankRewardEvent);
+    const roundScheduleEventListener = program.addEventListener(RoundScheduleEventName, handleRoundScheduleEvent);
+    const collectionRoundScheduleEventListener = program.addEventListener(CollectionRoundScheduleEventName, handleCollectionRoundScheduleEvent);
+    const paymentMintsEventListener = program.addEventListener(PaymentMintsEventName, handlePaymentMintsEvent);
+    const claimReferralRewardsEventListener = program.addEventListener(ClaimReferralRewardsEventName, handleClaimReferralRewardsEvent);
+    const setPadFeeEventListener = program.addEventListener(SetPadFeeEventName, handleSetPadFeeEvent);
+    const setCollectionPadFeeEventListener = program.addEventListener(SetCollectionPadFeeEventName, handleSetCollectionPadFeeEvent);
+    const migrateConfigEventListener = program.addEventListener(MigrateConfigEventName, handleMigrateConfigEvent);
+    const proposeConfigChangeEventListener = program.addEventListener(ProposeConfigChangeEventName, handleProposeConfigChangeEvent);
+    const approveConfigChangeEventListener = program.addEventListener(ApproveConfigChangeEventName, handleApproveConfigChangeEvent);
+    const executeConfigChangeEventListener = program.addEventListener(ExecuteConfigChangeEventName, handleExecuteConfigChangeEvent);
+    const cancelConfigChangeEventListener = program.addEventListener(CancelConfigChangeEventName, handleCancelConfigChangeEvent);
+    const proposeAuthorityTransferEventListener = program.addEventListener(ProposeAuthorityTransferEventName, handleProposeAuthorityTransferEvent);
+    const acceptAuthorityTransferEventListener = program.addEventListener(AcceptAuthorityTransferEventName, handleAcceptAuthorityTransferEvent);
+    const setPauseFlagsEventListener = program.addEventListener(SetPauseFlagsEventName, handleSetPauseFlagsEvent);
+    const setPadPauseFlagsEventListener = program.addEventListener(SetPadPauseFlagsEventName, handleSetPadPauseFlagsEvent);
+    const setCollectionPadPauseFlagsEventListener = program.addEventListener(SetCollectionPadPauseFlagsEventName, handleSetCollectionPadPauseFlagsEvent);
+    const migrateUserPadEventListener = program.addEventListener(MigrateUserPadEventName, handleMigrateUserPadEvent);
+    const migrateUserPadRoundEventListener = program.addEventListener(MigrateUserPadRoundEventName, handleMigrateUserPadRoundEvent);
+    const migrateUserPadBuyReceiptEventListener = program.addEventListener(MigrateUserPadBuyReceiptEventName, handleMigrateUserPadBuyReceiptEvent);
+    const migrateUserPadUnsoldDistributionEventListener = program.addEventListener(MigrateUserPadUnsoldDistributionEventName, handleMigrateUserPadUnsoldDistributionEvent);
+    const migrateUserCollectionPadEventListener = program.addEventListener(MigrateUserCollectionPadEventName, handleMigrateUserCollectionPadEvent);
+    const migrateUserCollectionPadRoundEventListener = program.addEventListener(MigrateUserCollectionPadRoundEventName, handleMigrateUserCollectionPadRoundEvent);
+    const migrateUserCollectionPadBuyReceiptEventListener = program.addEventListener(MigrateUserCollectionPadBuyReceiptEventName, handleMigrateUserCollectionPadBuyReceiptEvent);
+    const migrateUserCollectionPadUnsoldDistributionEventListener = program.addEventListener(MigrateUserCollectionPadUnsoldDistributionEventName, handleMigrateUserCollectionPadUnsoldDistributionEvent);
+    const closeBuyReceiptEventListener = program.addEventListener(CloseBuyReceiptEventName, handleCloseBuyReceiptEvent);
+    const closeUserRoundEventListener = program.addEventListener(CloseUserRoundEventName, handleCloseUserRoundEvent);
+    const closeRoundEventListener = program.addEventListener(CloseRoundEventName, handleCloseRoundEvent);
+    const withdrawCrankRewardEventListener = program.addEventListener(WithdrawCrankRewardEventName, handleWithdrawCrankRewardEvent);
 
     // collection
     const initializeCollectionPadEventListener = program.addEventListener(InitializeCollectionPadEventName, handleInitializeCollectionPadEvent);
@@ -598,6 +747,8 @@ describe("cream-pad", () => {
             lockDuration: new BN(5),
             mintingFee: new BN(mintingFee),
             treasury: treasuryKeypair.publicKey,
+            feeTiers: [],
+            tenantId: "",
         })
             .accounts({
                 feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
@@ -644,7 +795,6 @@ describe("cream-pad", () => {
 
 
         const tx = await program.methods.updateConfig({
-            backAuthority: backAuthorityKeypair.publicKey,
             isBackAuthorityRequired: true,
             isFeeRequired: true,
             feeBasePoint: 2500,
@@ -656,6 +806,11 @@ describe("cream-pad", () => {
             lockDuration: new BN(5),
             mintingFee: new BN(mintingFee),
             treasury: treasuryKeypair.publicKey,
+            feeTiers: [],
+            haveGovernance: false,
+            governanceSigners: [],
+            governanceThreshold: 0,
+            governanceTimelock: new BN(0),
             creamPadConfigBump: creamPadConfigBump,
         })
             .accounts({
@@ -723,6 +878,27 @@ describe("cream-pad", () => {
             decayModel: DecayModel.Linear,
             haveBuyLimit: true,
             buyLimit: new BN(tokensToLamports(100, 9).toString()),
+            haveWhitelist: false,
+            whitelistMerkleRoot: Array(32).fill(0),
+            whitelistRounds: 0,
+            havePaymentEscrow: false,
+            haveUniformClearingPrice: false,
+            haveVesting: false,
+            vestingTgeBasePoint: 0,
+            vestingCliffDuration: new BN(0),
+            vestingDuration: new BN(0),
+            haveCrank: false,
+            crankRoundDuration: new BN(0),
+            crankReward: new BN(0),
+            haveRoundSchedule: false,
+            roundSchedule: [],
+            haveStartAt: false,
+            startAt: new BN(0),
+            paymentOracleProgram: PublicKey.default,
+            paymentOracleMaxAge: new BN(0),
+            paymentMints: [],
+            haveReferral: false,
+            referralBasePoint: 0,
             padName: padName,
             creamPadConfigBump: creamPadConfigBump
         })
@@ -809,7 +985,7 @@ describe("cream-pad", () => {
     });
 
     it("update Pad Config", async () => {
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -819,28 +995,17 @@ describe("cream-pad", () => {
         const tx = await program.methods.updatePad({
             paymentReceiver: paymentReceiverKeypair.publicKey,
             padName: padName,
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump
         })
             .accounts({
                 creator: creatorKeypair.publicKey,
                 auctionConfig: auctionConfigPda,
                 tokenMintAccount: sellingTokenMintAccount,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 0: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([creatorKeypair, backAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -888,7 +1053,7 @@ describe("cream-pad", () => {
         const roundIndex = "1";
         const userBuyIndex = "1";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -897,6 +1062,8 @@ describe("cream-pad", () => {
         const [auctionRoundConfigPda, auctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex);
         console.log("auctionRoundConfigPda: ", auctionRoundConfigPda.toBase58());
 
+        const [, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
         const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
         console.log("auctionConfigSellingTokenAccount: ", auctionConfigSellingTokenAccount.toBase58());
 
@@ -967,8 +1134,16 @@ describe("cream-pad", () => {
             currentRoundIndex: roundIndex,
             buyIndex: userBuyIndex,
             amount: new BN(tokensToLamports(75, 9)),
+            whitelistAllocation: new BN(0),
+            whitelistProof: [],
+            referrer: PublicKey.default,
+            maxPricePerToken: null,
+            maxTotalPayment: null,
+            buyIntent: null,
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump,
-            auctionRoundConfigBump: auctionRoundConfigBump
+            auctionRoundConfigBump: auctionRoundConfigBump,
+            auctionVaultConfigBump: auctionVaultConfigBump
         })
             .accounts({
                 feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
@@ -978,80 +1153,30 @@ describe("cream-pad", () => {
                 userAuctionConfig: userAuctionConfigPda,
                 userAuctionRoundConfig: userAuctionRoundConfigPda,
                 userAuctionBuyReceiptConfig: userAuctionBuyReceiptConfigPda,
+                userAuctionVestingConfig: null,
                 tokenMintAccount: sellingTokenMintAccount,
                 paymentTokenMintAccount: paymentTokenMintAccount,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                tokenProgram: sellingTokenProgramAccount,
+                paymentTokenProgram: paymentTokenProgramAccount,
+                userPaymentTokenAccount: userPaymentTokenAccount,
+                userTokenAccount: userSellingTokenAccount,
+                auctionConfigTokenAccount: auctionConfigSellingTokenAccount,
+                paymentReceiver: paymentReceiverKeypair.publicKey,
+                paymentReceiverTokenAccount: paymentReceiverPaymentTokenAccount,
+                feeReceiver: feeReceiverKeypair.publicKey,
+                feeReceiverPaymentTokenAccount: feeReceiverPaymentTokenAccount,
+                paymentOracle: null,
+                referrerConfig: null,
+                referrerConfigPaymentTokenAccount: null,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                auctionVaultConfig: null,
+                auctionVaultConfigPaymentTokenAccount: null
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: Back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                },
-                // index 2: token program
-                {
-                    pubkey: sellingTokenProgramAccount,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 3: payment token program
-                {
-                    pubkey: paymentTokenProgramAccount,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 4: user payment token program
-                {
-                    pubkey: userPaymentTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 5: user token program
-                {
-                    pubkey: userSellingTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 6: auction config token program
-                {
-                    pubkey: auctionConfigSellingTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 7: payment receiver
-                {
-                    pubkey: paymentReceiverKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 8: payment receiver token account
-                {
-                    pubkey: paymentReceiverPaymentTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 9: fee receiver
-                {
-                    pubkey: feeReceiverKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 10: fee receiver token account
-                {
-                    pubkey: feeReceiverPaymentTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
 
             .preInstructions([...ixs])
             .signers([feeAndRentPayerKeypair, backAuthorityKeypair, userAKeypair])
@@ -1163,7 +1288,7 @@ describe("cream-pad", () => {
 
         const roundIndex = "1";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -1176,6 +1301,7 @@ describe("cream-pad", () => {
         const tx = await program.methods.endRound({
             padName: padName,
             roundIndex: roundIndex,
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump,
             auctionRoundConfigBump: auctionRoundConfigBump
         })
@@ -1184,22 +1310,10 @@ describe("cream-pad", () => {
                 auctionConfig: auctionConfigPda,
                 auctionRoundConfig: auctionRoundConfigPda,
                 tokenMintAccount: sellingTokenMintAccount,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 0: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([backAuthorityKeypair, creatorKeypair])
             .rpc({
                 skipPreflight: true
@@ -1271,7 +1385,7 @@ describe("cream-pad", () => {
         const previousRoundIndex = "1";
         const nextRoundIndex = "2";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -1290,6 +1404,7 @@ describe("cream-pad", () => {
             nextRoundDuration: new BN(5),
             nextHaveBuyLimit: true,
             nextBuyLimit: new BN(tokensToLamports(100, 9).toString()),
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump,
             previousAuctionRoundConfigBump: previousAuctionRoundConfigBump
         })
@@ -1302,22 +1417,10 @@ describe("cream-pad", () => {
                 tokenMintAccount: sellingTokenMintAccount,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 0: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([feeAndRentPayerKeypair, backAuthorityKeypair, creatorKeypair])
             .rpc({
                 skipPreflight: true
@@ -1390,7 +1493,7 @@ describe("cream-pad", () => {
 
         const roundIndex = "2";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -1403,6 +1506,7 @@ describe("cream-pad", () => {
         const tx = await program.methods.endRound({
             padName: padName,
             roundIndex: roundIndex,
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump,
             auctionRoundConfigBump: auctionRoundConfigBump
         })
@@ -1411,22 +1515,10 @@ describe("cream-pad", () => {
                 auctionConfig: auctionConfigPda,
                 auctionRoundConfig: auctionRoundConfigPda,
                 tokenMintAccount: sellingTokenMintAccount,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 0: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([backAuthorityKeypair, creatorKeypair])
             .rpc({
                 skipPreflight: true
@@ -1496,7 +1588,7 @@ describe("cream-pad", () => {
     });
 
     it("lock and distribute", async () => {
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -1513,6 +1605,7 @@ describe("cream-pad", () => {
 
         const tx = await program.methods.lockAndDistribute({
             padName: padName,
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump,
             auctionVaultConfigBump: auctionVaultConfigBump,
         })
@@ -1528,22 +1621,10 @@ describe("cream-pad", () => {
                 associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 0: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([feeAndRentPayerKeypair, backAuthorityKeypair, creatorKeypair])
             .rpc({
                 skipPreflight: true
@@ -1599,7 +1680,7 @@ describe("cream-pad", () => {
     it("unlock unsold supply", async () => {
         await delay(5000);
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -1617,6 +1698,7 @@ describe("cream-pad", () => {
 
         const tx = await program.methods.unlockUnsoldSupply({
             padName: padName,
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump,
             auctionVaultConfigBump: auctionVaultConfigBump,
         })
@@ -1629,22 +1711,10 @@ describe("cream-pad", () => {
                 creatorTokenAccount: creatorSellingTokenAccount,
                 tokenProgram: sellingTokenProgramAccount,
                 associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 0: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([backAuthorityKeypair, creatorKeypair])
             .rpc({
                 skipPreflight: true
@@ -1700,7 +1770,7 @@ describe("cream-pad", () => {
 
     it("claim distribution", async () => {
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, padName, sellingTokenMintAccount);
@@ -1721,6 +1791,7 @@ describe("cream-pad", () => {
 
         const tx = await program.methods.claimDistribution({
             padName: padName,
+            creamPadConfigBump: creamPadConfigBump,
             auctionConfigBump: auctionConfigBump,
             userAuctionConfigBump: userAuctionConfigBump,
         })
@@ -1737,22 +1808,10 @@ describe("cream-pad", () => {
                 associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 0: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([feeAndRentPayerKeypair, backAuthorityKeypair, userAKeypair])
             .rpc({
                 skipPreflight: true
@@ -1842,6 +1901,13 @@ describe("cream-pad", () => {
             startingIndex: new BN(1),
             haveBuyLimit: true,
             buyLimit: new BN(6),
+            haveWhitelist: false,
+            whitelistMerkleRoot: Array(32).fill(0),
+            whitelistRounds: 0,
+            haveRoundSchedule: false,
+            roundSchedule: [],
+            haveReferral: false,
+            referralBasePoint: 0,
             sellerFeeBasisPoints: 500,
             assetCreators: [{
                 address: creatorKeypair.publicKey,
@@ -1870,16 +1936,9 @@ describe("cream-pad", () => {
                 associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                collectionMetadata: collectionMetadataPda
             })
-            .remainingAccounts([
-                // 0: collection metadata pda
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                }
-            ])
             .signers([feeAndRentPayerKeypair, backAuthorityKeypair, creatorKeypair, collectionUpdateAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -1960,7 +2019,7 @@ describe("cream-pad", () => {
 
     it("Update Collection Pad Config", async () => {
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -1969,28 +2028,17 @@ describe("cream-pad", () => {
         const tx = await program.methods.updateCollectionPad({
             paymentReceiver: paymentReceiverKeypair.publicKey,
             padName: collectionPadName,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump
         })
             .accounts({
                 creator: creatorKeypair.publicKey,
                 collectionAuctionConfig: collectionAuctionConfigPda,
                 collectionMintAccount: collectionMintAccount,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([backAuthorityKeypair, creatorKeypair])
             .rpc({
                 skipPreflight: true
@@ -2070,16 +2118,9 @@ describe("cream-pad", () => {
                 creamPadConfig: creamPadConfigPda,
                 collectionAuctionConfig: collectionAuctionConfigPda,
                 collectionMintAccount: collectionMintAccount,
-                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                collectionMetadata: collectionMetadataPda
             })
-            .remainingAccounts([
-                // index 0: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .signers([backAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -2158,16 +2199,9 @@ describe("cream-pad", () => {
                 creamPadConfig: creamPadConfigPda,
                 collectionAuctionConfig: collectionAuctionConfigPda,
                 collectionMintAccount: collectionMintAccount,
-                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID
+                tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
+                collectionMetadata: collectionMetadataPda
             })
-            .remainingAccounts([
-                // index 0: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .signers([backAuthorityKeypair, collectionUpdateAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -2229,7 +2263,7 @@ describe("cream-pad", () => {
         const roundIndex = "1";
         const userBuyIndex = "1";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -2261,6 +2295,12 @@ describe("cream-pad", () => {
             currentRoundIndex: roundIndex,
             buyIndex: userBuyIndex,
             amount: new BN(3),
+            whitelistAllocation: new BN(0),
+            whitelistProof: [],
+            referrer: PublicKey.default,
+            maxPricePerToken: null,
+            maxTotalPayment: null,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             collectionAuctionRoundConfigBump: collectionAuctionRoundConfigBump,
             userCollectionAuctionBuyReceiptConfigBump: userCollectionAuctionBuyReceiptConfigBump
@@ -2277,64 +2317,19 @@ describe("cream-pad", () => {
                 paymentTokenMintAccount: paymentTokenMintAccount,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                paymentTokenProgram: paymentTokenProgramAccount,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                userPaymentTokenAccount: userPaymentTokenAccount,
+                paymentReceiver: paymentReceiverKeypair.publicKey,
+                paymentReceiverTokenAccount: paymentReceiverPaymentTokenAccount,
+                feeReceiver: feeReceiverKeypair.publicKey,
+                feeReceiverPaymentTokenAccount: feeReceiverPaymentTokenAccount,
+                referrerConfig: null,
+                referrerConfigPaymentTokenAccount: null
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: true,
-                    isSigner: true
-                },
-                // index 2: payment token program
-                {
-                    pubkey: paymentTokenProgramAccount,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 3: associated token program
-                {
-                    pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 4: user payment token account
-                {
-                    pubkey: userPaymentTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 5: payment receiver
-                {
-                    pubkey: paymentReceiverKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 6: payment receiver
-                {
-                    pubkey: paymentReceiverPaymentTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 7: payment receiver
-                {
-                    pubkey: feeReceiverKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 8: payment receiver
-                {
-                    pubkey: feeReceiverPaymentTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .signers([feeAndRentPayerKeypair, backAuthorityKeypair, userAKeypair])
             .rpc({
                 skipPreflight: true
@@ -2456,7 +2451,7 @@ describe("cream-pad", () => {
         const assetUuid = nanoid();
         console.log("assetUuid: ", assetUuid);
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -2490,6 +2485,7 @@ describe("cream-pad", () => {
             padName: collectionPadName,
             assetUuid: assetUuid,
             buyIndex: userBuyIndex,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             userCollectionAuctionConfigBump: userCollectionAuctionConfigBump,
             userCollectionAuctionBuyReceiptConfigBump: userCollectionAuctionBuyReceiptConfigBump
@@ -2507,52 +2503,15 @@ describe("cream-pad", () => {
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
                 tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                collectionMetadata: collectionMetadataPda,
+                collectionMasterEdition: collectionMasterEditionPda,
+                userAssetTokenAccount: userAssetTokenAccount,
+                assetMetadata: assetMetadataPda,
+                assetMasterEdition: assetMasterEditionPda
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                },
-                // index 2: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index: 3: collection master edition
-                {
-                    pubkey: collectionMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 4: user asset token account
-                {
-                    pubkey: userAssetTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 5: asset metadata
-                {
-                    pubkey: assetMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 6: payment receiver
-                {
-                    pubkey: assetMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .postInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({
                     units: 400_000  // Increased limit
@@ -2647,7 +2606,7 @@ describe("cream-pad", () => {
         const assetUuid = nanoid();
         console.log("assetUuid: ", assetUuid);
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -2681,6 +2640,7 @@ describe("cream-pad", () => {
             padName: collectionPadName,
             assetUuid: assetUuid,
             buyIndex: userBuyIndex,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             userCollectionAuctionConfigBump: userCollectionAuctionConfigBump,
             userCollectionAuctionBuyReceiptConfigBump: userCollectionAuctionBuyReceiptConfigBump
@@ -2698,52 +2658,15 @@ describe("cream-pad", () => {
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
                 tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                collectionMetadata: collectionMetadataPda,
+                collectionMasterEdition: collectionMasterEditionPda,
+                userAssetTokenAccount: userAssetTokenAccount,
+                assetMetadata: assetMetadataPda,
+                assetMasterEdition: assetMasterEditionPda
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                },
-                // index 2: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index: 3: collection master edition
-                {
-                    pubkey: collectionMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 4: user asset token account
-                {
-                    pubkey: userAssetTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 5: asset metadata
-                {
-                    pubkey: assetMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 6: payment receiver
-                {
-                    pubkey: assetMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .postInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({
                     units: 400_000  // Increased limit
@@ -2838,7 +2761,7 @@ describe("cream-pad", () => {
         const assetUuid = nanoid();
         console.log("assetUuid: ", assetUuid);
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -2872,6 +2795,7 @@ describe("cream-pad", () => {
             padName: collectionPadName,
             assetUuid: assetUuid,
             buyIndex: userBuyIndex,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             userCollectionAuctionConfigBump: userCollectionAuctionConfigBump,
             userCollectionAuctionBuyReceiptConfigBump: userCollectionAuctionBuyReceiptConfigBump
@@ -2889,52 +2813,15 @@ describe("cream-pad", () => {
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
                 tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                collectionMetadata: collectionMetadataPda,
+                collectionMasterEdition: collectionMasterEditionPda,
+                userAssetTokenAccount: userAssetTokenAccount,
+                assetMetadata: assetMetadataPda,
+                assetMasterEdition: assetMasterEditionPda
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                },
-                // index 2: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index: 3: collection master edition
-                {
-                    pubkey: collectionMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 4: user asset token account
-                {
-                    pubkey: userAssetTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 5: asset metadata
-                {
-                    pubkey: assetMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 6: payment receiver
-                {
-                    pubkey: assetMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .postInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({
                     units: 400_000  // Increased limit
@@ -3026,7 +2913,7 @@ describe("cream-pad", () => {
     it("End Collection Round - 1", async () => {
         const roundIndex = "1";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3038,6 +2925,7 @@ describe("cream-pad", () => {
         const tx = await program.methods.endCollectionRound({
             padName: collectionPadName,
             roundIndex: roundIndex,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             collectionAuctionRoundConfigBump: collectionAuctionRoundConfigBump,
         })
@@ -3046,22 +2934,10 @@ describe("cream-pad", () => {
                 collectionAuctionConfig: collectionAuctionConfigPda,
                 collectionAuctionRoundConfig: collectionAuctionRoundConfigPda,
                 collectionMintAccount: collectionMintAccount,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([backAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -3146,7 +3022,7 @@ describe("cream-pad", () => {
         const previousRoundIndex = "1";
         const nextRoundIndex = "2";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3165,6 +3041,7 @@ describe("cream-pad", () => {
             nextRoundDuration: new BN(5),
             nextHaveBuyLimit: true,
             nextBuyLimit: new BN(6),
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             previousCollectionAuctionRoundConfigBump: previousCollectionAuctionRoundConfigBump,
         })
@@ -3177,22 +3054,10 @@ describe("cream-pad", () => {
                 collectionMintAccount: collectionMintAccount,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([feeAndRentPayerKeypair, backAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -3278,7 +3143,7 @@ describe("cream-pad", () => {
 
         const roundIndex = "2";
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3290,6 +3155,7 @@ describe("cream-pad", () => {
         const tx = await program.methods.endCollectionRound({
             padName: collectionPadName,
             roundIndex: roundIndex,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             collectionAuctionRoundConfigBump: collectionAuctionRoundConfigBump,
         })
@@ -3298,22 +3164,10 @@ describe("cream-pad", () => {
                 collectionAuctionConfig: collectionAuctionConfigPda,
                 collectionAuctionRoundConfig: collectionAuctionRoundConfigPda,
                 collectionMintAccount: collectionMintAccount,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([backAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -3396,7 +3250,7 @@ describe("cream-pad", () => {
     });
 
     it("Treasury and distribute", async () => {
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3404,28 +3258,17 @@ describe("cream-pad", () => {
 
         const tx = await program.methods.treasuryAndDistribute({
             padName: collectionPadName,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
         })
             .accounts({
                 supplyDistributor: backAuthorityKeypair.publicKey,
                 collectionAuctionConfig: collectionAuctionConfigPda,
                 collectionMintAccount: collectionMintAccount,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                }
-            ])
             .signers([backAuthorityKeypair])
             .rpc({
                 skipPreflight: true
@@ -3490,7 +3333,7 @@ describe("cream-pad", () => {
         const assetUuid = nanoid();
         console.log("assetUuid: ", assetUuid);
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3517,6 +3360,7 @@ describe("cream-pad", () => {
         const tx = await program.methods.mintTreasuryAsset({
             padName: collectionPadName,
             assetUuid: assetUuid,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
         })
             .accounts({
@@ -3529,58 +3373,16 @@ describe("cream-pad", () => {
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
                 tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                collectionMetadata: collectionMetadataPda,
+                collectionMasterEdition: collectionMasterEditionPda,
+                treasury: treasuryKeypair.publicKey,
+                treasuryAssetTokenAccount: treasuryAssetTokenAccount,
+                assetMetadata: assetMetadataPda,
+                assetMasterEdition: assetMasterEditionPda
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                },
-                // index 2: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index: 3: collection master edition
-                {
-                    pubkey: collectionMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 4: treasury
-                {
-                    pubkey: treasuryKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 5: treasury asset token account
-                {
-                    pubkey: treasuryAssetTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 6: asset metadata
-                {
-                    pubkey: assetMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 7: payment receiver
-                {
-                    pubkey: assetMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .postInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({
                     units: 400_000  // Increased limit
@@ -3652,7 +3454,7 @@ describe("cream-pad", () => {
         const assetUuid = nanoid();
         console.log("assetUuid: ", assetUuid);
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3679,6 +3481,7 @@ describe("cream-pad", () => {
         const tx = await program.methods.mintTreasuryAsset({
             padName: collectionPadName,
             assetUuid: assetUuid,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
         })
             .accounts({
@@ -3691,58 +3494,16 @@ describe("cream-pad", () => {
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
                 tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                collectionMetadata: collectionMetadataPda,
+                collectionMasterEdition: collectionMasterEditionPda,
+                treasury: treasuryKeypair.publicKey,
+                treasuryAssetTokenAccount: treasuryAssetTokenAccount,
+                assetMetadata: assetMetadataPda,
+                assetMasterEdition: assetMasterEditionPda
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                },
-                // index 2: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index: 3: collection master edition
-                {
-                    pubkey: collectionMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 4: treasury
-                {
-                    pubkey: treasuryKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 5: treasury asset token account
-                {
-                    pubkey: treasuryAssetTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 6: asset metadata
-                {
-                    pubkey: assetMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 7: payment receiver
-                {
-                    pubkey: assetMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .postInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({
                     units: 400_000  // Increased limit
@@ -3811,7 +3572,7 @@ describe("cream-pad", () => {
     });
 
     it("Claim collection asset distribution", async () => {
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3825,6 +3586,7 @@ describe("cream-pad", () => {
 
         const tx = await program.methods.claimCollectionAssetDistribution({
             padName: collectionPadName,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             userCollectionAuctionConfigBump: userCollectionAuctionConfigBump
         })
@@ -3837,22 +3599,10 @@ describe("cream-pad", () => {
                 collectionMintAccount: collectionMintAccount,
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: true,
-                    isSigner: true
-                },
-            ])
             .postInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({
                     units: 400_000  // Increased limit
@@ -3929,7 +3679,7 @@ describe("cream-pad", () => {
         const assetUuid = nanoid();
         console.log("assetUuid: ", assetUuid);
 
-        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(program.programId);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
         console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
         const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
@@ -3963,6 +3713,7 @@ describe("cream-pad", () => {
         const tx = await program.methods.fillClaimedCollectionAssetDistribution({
             padName: collectionPadName,
             assetUuid: assetUuid,
+            creamPadConfigBump: creamPadConfigBump,
             collectionAuctionConfigBump: collectionAuctionConfigBump,
             userCollectionAuctionConfigBump: userCollectionAuctionConfigBump,
             userCollectionAuctionUnsoldDistributionConfigBump: userCollectionAuctionUnsoldDistributionConfigBump
@@ -3980,52 +3731,15 @@ describe("cream-pad", () => {
                 systemProgram: SystemProgram.programId,
                 rent: SYSVAR_RENT_PUBKEY,
                 tokenMetadataProgram: MPL_TOKEN_METADATA_PROGRAM_ID,
-                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+                instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+                creamPadConfig: creamPadConfigPda,
+                backAuthority: backAuthorityKeypair.publicKey,
+                collectionMetadata: collectionMetadataPda,
+                collectionMasterEdition: collectionMasterEditionPda,
+                userAssetTokenAccount: userAssetTokenAccount,
+                assetMetadata: assetMetadataPda,
+                assetMasterEdition: assetMasterEditionPda
             })
-            .remainingAccounts([
-                // index 0: Cream pad config
-                {
-                    pubkey: creamPadConfigPda,
-                    isWritable: false,
-                    isSigner: false
-                },
-                // index 1: back authority
-                {
-                    pubkey: backAuthorityKeypair.publicKey,
-                    isWritable: false,
-                    isSigner: true
-                },
-                // index 2: collection metadata
-                {
-                    pubkey: collectionMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index: 3: collection master edition
-                {
-                    pubkey: collectionMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 4: user asset token account
-                {
-                    pubkey: userAssetTokenAccount,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 5: asset metadata
-                {
-                    pubkey: assetMetadataPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-                // index 6: payment receiver
-                {
-                    pubkey: assetMasterEditionPda,
-                    isWritable: true,
-                    isSigner: false
-                },
-            ])
             .postInstructions([
                 ComputeBudgetProgram.setComputeUnitLimit({
                     units: 400_000  // Increased limit
@@ -4100,37 +3814,3231 @@ describe("cream-pad", () => {
         await assertTokenBalance(connection, userAssetTokenAccount, 1, "userAssetTokenAccount", "userAssetTokenAccount");
     });
 
-    it("Remove Events", async () => {
-        await delay(delayTimeCount);
+    it("Initialize Relay Pad Config", async () => {
+        const roundIndex = "1";
 
-        await program.removeEventListener(initializePadEventListener);
-        await program.removeEventListener(updatePadEventListener);
-        await program.removeEventListener(endRoundEventListener);
-        await program.removeEventListener(startRoundEventListener);
-        await program.removeEventListener(lockAndDistributionEventListener);
-        await program.removeEventListener(unlockUnsoldSupplyEventListener);
-        await program.removeEventListener(buyEventListener);
-        await program.removeEventListener(claimDistributionEventListener);
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+        console.log("creamPadConfigPda: ", creamPadConfigPda.toBase58());
 
-        // Collection
-        await program.removeEventListener(initializeCollectionPadEventListener);
-        await program.removeEventListener(updateCollectionPadEventListener);
-        await program.removeEventListener(endCollectionRoundEventListener);
-        await program.removeEventListener(startCollectionRoundEventListener);
-        await program.removeEventListener(takeCollectionUpdateAuthorityEventListener);
-        await program.removeEventListener(giveCollectionUpdateAuthorityEventListener);
-        await program.removeEventListener(treasuryAndDistributionEventListener);
-        await program.removeEventListener(mintTreasuryAssetEventListener);
-        await program.removeEventListener(buyCollectionAssetEventListener);
-        await program.removeEventListener(fillBoughtCollectionAssetEventListener);
-        await program.removeEventListener(collectionClaimDistributionEventListener);
-        await program.removeEventListener(fillClaimedCollectionAssetDistributionEventListener);
-    });
-});
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, relayPadName, sellingTokenMintAccount);
+        console.log("auctionConfigPda: ", auctionConfigPda.toBase58());
 
-/// Helper
-function delay(ms: number) {
-    return new Promise(resolve => setTimeout(resolve, ms));
-}
+        const [auctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex);
+        console.log("auctionRoundConfigPda: ", auctionRoundConfigPda.toBase58());
+
+        const creatorSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, creatorKeypair.publicKey, true, sellingTokenProgramAccount);
+        console.log("creatorSellingTokenAccount: ", creatorSellingTokenAccount.toBase58());
 
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+        console.log("auctionConfigSellingTokenAccount: ", auctionConfigSellingTokenAccount.toBase58());
 
+        const tx = await program.methods.initializePad({
+            paymentMint: paymentTokenMintAccount,
+            paymentReceiver: paymentReceiverKeypair.publicKey,
+            p0: new BN(tokensToLamports(4, 9).toString()),
+            ptmax: new BN(tokensToLamports(1.2, 9).toString()),
+            tmax: 2,
+            omega: new BN(tokensToLamports(2, 9).toString()),
+            alpha: new BN(tokensToLamports(2, 9).toString()),
+            timeShiftMax: new BN(2),
+            roundDuration: new BN(1000),
+            supply: new BN(tokensToLamports(100, 9).toString()),
+            decayModel: DecayModel.Linear,
+            haveBuyLimit: false,
+            buyLimit: new BN(0),
+            haveWhitelist: false,
+            whitelistMerkleRoot: Array(32).fill(0),
+            whitelistRounds: 0,
+            havePaymentEscrow: false,
+            haveUniformClearingPrice: false,
+            haveVesting: false,
+            vestingTgeBasePoint: 0,
+            vestingCliffDuration: new BN(0),
+            vestingDuration: new BN(0),
+            haveCrank: false,
+            crankRoundDuration: new BN(0),
+            crankReward: new BN(0),
+            haveRoundSchedule: false,
+            roundSchedule: [],
+            haveStartAt: false,
+            startAt: new BN(0),
+            paymentOracleProgram: PublicKey.default,
+            paymentOracleMaxAge: new BN(0),
+            paymentMints: [],
+            haveReferral: false,
+            referralBasePoint: 0,
+            padName: relayPadName,
+            creamPadConfigBump: creamPadConfigBump
+        })
+            .accounts({
+                feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
+                backAuthority: backAuthorityKeypair.publicKey,
+                creator: creatorKeypair.publicKey,
+                creamPadConfig: creamPadConfigPda,
+                auctionConfig: auctionConfigPda,
+                auctionRoundConfig: auctionRoundConfigPda,
+                tokenMintAccount: sellingTokenMintAccount,
+                auctionConfigTokenAccount: auctionConfigSellingTokenAccount,
+                creatorTokenAccount: creatorSellingTokenAccount,
+                tokenProgram: sellingTokenProgramAccount,
+                associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([feeAndRentPayerKeypair, backAuthorityKeypair, creatorKeypair])
+            .rpc({
+                skipPreflight: true
+            });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 100, "Auction Config Selling token account", "Auction Config Selling token account");
+    });
+
+    it("Approve relay pad to spend user b payment", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, relayPadName, sellingTokenMintAccount);
+
+        const userPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, userBKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        // a relayed buy pulls the payment through this allowance instead of the user's signature
+        const tx = await approveChecked(
+            connection,
+            feeAndRentPayerKeypair,
+            paymentTokenMintAccount,
+            userPaymentTokenAccount,
+            auctionConfigPda,
+            userBKeypair,
+            tokensToLamports(40, paymentTokenDecimal),
+            paymentTokenDecimal,
+            [],
+            undefined,
+            paymentTokenProgramAccount
+        );
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Relayed buy user b rejects an intent signed by another user", async () => {
+        const buyIntent = relayBuyIntent(program, "1", 10, 3600);
+
+        await assertAnchorError(
+            relayedBuy(program, buyIntent, signBuyIntent(program, buyIntent, userAKeypair), "1"),
+            "InvalidBuyIntentSignature",
+            "Relayed buy -> signed by another user"
+        );
+    });
+
+    it("Relayed buy user b rejects a tampered intent", async () => {
+        const buyIntent = relayBuyIntent(program, "1", 10, 3600);
+        const ed25519Ix = signBuyIntent(program, buyIntent, userBKeypair);
+
+        // the relayer raises the amount after the user signed
+        buyIntent.amount = new BN(tokensToLamports(20, 9));
+
+        await assertAnchorError(
+            relayedBuy(program, buyIntent, ed25519Ix, "1"),
+            "InvalidBuyIntentSignature",
+            "Relayed buy -> tampered intent"
+        );
+    });
+
+    it("Relayed buy user b rejects the signature at the wrong instruction index", async () => {
+        const buyIntent = relayBuyIntent(program, "1", 10, 3600);
+
+        await assertAnchorError(
+            relayedBuy(
+                program,
+                buyIntent,
+                signBuyIntent(program, buyIntent, userBKeypair),
+                "1",
+                [ComputeBudgetProgram.setComputeUnitLimit({units: 400_000})]
+            ),
+            "InvalidBuyIntentSignature",
+            "Relayed buy -> ed25519 instruction not right before the buy"
+        );
+    });
+
+    it("Relayed buy user b rejects an expired intent", async () => {
+        const buyIntent = relayBuyIntent(program, "1", 10, -60);
+
+        await assertAnchorError(
+            relayedBuy(program, buyIntent, signBuyIntent(program, buyIntent, userBKeypair), "1"),
+            "BuyIntentExpired",
+            "Relayed buy -> expired intent"
+        );
+    });
+
+    it("Relayed buy user b rejects an intent for another payment mint", async () => {
+        const buyIntent = relayBuyIntent(program, "1", 10, 3600);
+
+        // signed for a mint the relayer doesn't pass in
+        buyIntent.paymentMint = sellingTokenMintAccount;
+
+        await assertAnchorError(
+            relayedBuy(program, buyIntent, signBuyIntent(program, buyIntent, userBKeypair), "1"),
+            "InvalidBuyIntent",
+            "Relayed buy -> other payment mint"
+        );
+    });
+
+    it("Relayed buy user b rejects a payment above the intent max total payment", async () => {
+        const buyIntent = relayBuyIntent(program, "1", 10, 3600);
+
+        // 10 tokens at 4 cost 40, one unit less than that is signed
+        buyIntent.maxTotalPayment = new BN(tokensToLamports(40, paymentTokenDecimal)).subn(1);
+
+        await assertAnchorError(
+            relayedBuy(program, buyIntent, signBuyIntent(program, buyIntent, userBKeypair), "1"),
+            "BuyIntentMaxTotalPaymentExceeded",
+            "Relayed buy -> above max total payment"
+        );
+    });
+
+    it("Relayed buy user b - 1", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, relayPadName, sellingTokenMintAccount);
+
+        const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, userBKeypair.publicKey);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        const userSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, userBKeypair.publicKey, true, sellingTokenProgramAccount);
+
+        const userPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, userBKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const buyIntent = relayBuyIntent(program, "1", 10, 3600);
+
+        // user b only signs the intent, the fee and rent payer submits the buy
+        const tx = await relayedBuy(program, buyIntent, signBuyIntent(program, buyIntent, userBKeypair), "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertUserAuctionAccount(
+            program,
+            userAuctionConfigPda,
+            userBKeypair.publicKey,
+            new BN(1),
+            new BN(tokensToLamports(10, 9).toString()),
+            new BN(tokensToLamports(40, 9).toString()),
+            UserAuctionStatus.None
+        );
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 90, "Auction Config Selling token account", "Auction Config Selling token account");
+
+        await assertTokenBalance(connection, userSellingTokenAccount, 10, "User Selling token account", "User Selling token account");
+
+        await assertTokenBalance(connection, userPaymentTokenAccount, 960, "User Payment token account", "User Payment token account");
+    });
+
+    it("Relayed buy user b rejects a replayed intent", async () => {
+        // the intent's nonce is the buy index it was signed for, the next buy index no longer matches
+        const buyIntent = relayBuyIntent(program, "1", 10, 3600);
+
+        await assertAnchorError(
+            relayedBuy(program, buyIntent, signBuyIntent(program, buyIntent, userBKeypair), "2"),
+            "InvalidBuyIntent",
+            "Relayed buy -> replayed intent"
+        );
+    });
+
+    it("Initialize Cancel Pad Config", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, cancelPadName, sellingTokenMintAccount);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        // refunds only come out of escrowed payments
+        const tx = await initializeTestPad(program, cancelPadName, {havePaymentEscrow: true});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 20, "Auction Config Selling token account", "Auction Config Selling token account");
+    });
+
+    it("Buy cancel pad user a - 1", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, cancelPadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const auctionVaultConfigPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, auctionVaultConfigPda, true, paymentTokenProgramAccount);
+
+        const tx = await buyTestPad(program, cancelPadName, userAKeypair, "1", 2);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionVaultConfigPaymentTokenAccount, 8, "Auction Vault Payment token account", "Auction Vault Payment token account");
+    });
+
+    it("Refund rejects a pad that is not cancelled", async () => {
+        await assertAnchorError(
+            refundTestPad(program, cancelPadName, userAKeypair, "1"),
+            "AuctionNotCancelled",
+            "Refund -> pad not cancelled"
+        );
+    });
+
+    it("Withdraw cancelled supply rejects a pad that is not cancelled", async () => {
+        await assertAnchorError(
+            withdrawCancelledSupply(program, cancelPadName),
+            "AuctionNotCancelled",
+            "Withdraw cancelled supply -> pad not cancelled"
+        );
+    });
+
+    it("Cancel Pad rejects a non creator", async () => {
+        await assertAnchorError(
+            cancelTestPad(program, cancelPadName, userAKeypair),
+            "InvalidCreator",
+            "Cancel pad -> not the creator"
+        );
+    });
+
+    it("Cancel Pad rejects a pad with buys that are not escrowed", async () => {
+        await assertAnchorError(
+            cancelTestPad(program, relayPadName, creatorKeypair),
+            "AuctionCannotBeCancelled",
+            "Cancel pad -> payments already left to the receivers"
+        );
+    });
+
+    it("Cancel Pad", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, cancelPadName, sellingTokenMintAccount);
+
+        const tx = await cancelTestPad(program, cancelPadName, creatorKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.Cancelled);
+    });
+
+    it("Refund user a - 1", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, cancelPadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        const auctionVaultConfigPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, auctionVaultConfigPda, true, paymentTokenProgramAccount);
+
+        const userPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, userAKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const userPaymentBalance = await tokenBalance(connection, userPaymentTokenAccount);
+
+        const tx = await refundTestPad(program, cancelPadName, userAKeypair, "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 20, "Auction Config Selling token account", "Auction Config Selling token account");
+
+        await assertTokenBalance(connection, auctionVaultConfigPaymentTokenAccount, 0, "Auction Vault Payment token account", "Auction Vault Payment token account");
+
+        await assertTokenBalance(connection, userPaymentTokenAccount, userPaymentBalance + 8, "User Payment token account", "User Payment token account");
+    });
+
+    it("Refund rejects a receipt that was already refunded", async () => {
+        await assertAnchorError(
+            refundTestPad(program, cancelPadName, userAKeypair, "1"),
+            "ReceiptAlreadyRefunded",
+            "Refund -> same receipt twice"
+        );
+    });
+
+    it("Withdraw cancelled supply", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, cancelPadName, sellingTokenMintAccount);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        const creatorSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, creatorKeypair.publicKey, true, sellingTokenProgramAccount);
+
+        const creatorSellingBalance = await tokenBalance(connection, creatorSellingTokenAccount);
+
+        const tx = await withdrawCancelledSupply(program, cancelPadName);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 0, "Auction Config Selling token account", "Auction Config Selling token account");
+
+        await assertTokenBalance(connection, creatorSellingTokenAccount, creatorSellingBalance + 20, "Creator Selling token account", "Creator Selling token account");
+    });
+
+    it("Initialize Settle Pad Config", async () => {
+        const tx = await initializeTestPad(program, settlePadName, {havePaymentEscrow: true, roundDuration: new BN(5)});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Buy settle pad user a - 1", async () => {
+        const tx = await buyTestPad(program, settlePadName, userAKeypair, "1", 2);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Settle Pad rejects a settler that is neither the creator nor the back authority", async () => {
+        await assertAnchorError(
+            settleTestPad(program, settlePadName, userAKeypair),
+            "InvalidPaymentSettler",
+            "Settle pad -> not the creator"
+        );
+    });
+
+    it("Settle Pad rejects a pad that is still selling", async () => {
+        await assertAnchorError(
+            settleTestPad(program, settlePadName, creatorKeypair),
+            "AuctionPaymentCannotBeSettled",
+            "Settle pad -> pad started"
+        );
+    });
+
+    it("End settle pad Round 1", async () => {
+        await delay(5000);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, settlePadName, sellingTokenMintAccount);
+
+        const tx = await endTestPadRound(program, settlePadName, "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.Ended);
+    });
+
+    it("Settle Pad", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, settlePadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const auctionVaultConfigPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, auctionVaultConfigPda, true, paymentTokenProgramAccount);
+
+        const paymentReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, paymentReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const feeReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, feeReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const receiversBalance = await tokenBalance(connection, paymentReceiverPaymentTokenAccount) + await tokenBalance(connection, feeReceiverPaymentTokenAccount);
+
+        const tx = await settleTestPad(program, settlePadName, creatorKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionVaultConfigPaymentTokenAccount, 0, "Auction Vault Payment token account", "Auction Vault Payment token account");
+
+        // the escrowed 8 payment tokens are split between the fee and the payment receiver
+        assert(
+            await tokenBalance(connection, paymentReceiverPaymentTokenAccount) + await tokenBalance(connection, feeReceiverPaymentTokenAccount) === receiversBalance + 8,
+            "Settle pad -> receivers balance"
+        );
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(auctionData.isPaymentSettled, "Auction -> is payment settled");
+    });
+
+    it("Settle Pad rejects a pad that was already settled", async () => {
+        await assertAnchorError(
+            settleTestPad(program, settlePadName, creatorKeypair),
+            "AuctionPaymentAlreadySettled",
+            "Settle pad -> settled twice"
+        );
+    });
+
+    it("Initialize Vesting Pad Config", async () => {
+        // half unlocks at the end of the sale, the other half only after a cliff the suite never reaches
+        const tx = await initializeTestPad(program, vestingPadName, {
+            haveVesting: true,
+            vestingTgeBasePoint: 5000,
+            vestingCliffDuration: new BN(1_000_000),
+            vestingDuration: new BN(1_000_000)
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Buy vesting pad user a - 1", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, vestingPadName, sellingTokenMintAccount);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        const tx = await buyTestPad(program, vestingPadName, userAKeypair, "1", 5);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // vested tokens stay in the pad until claimed
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 20, "Auction Config Selling token account", "Auction Config Selling token account");
+    });
+
+    it("Claim vested rejects a pad that is still selling", async () => {
+        await assertAnchorError(
+            claimVested(program, vestingPadName, userAKeypair),
+            "VestingNotStarted",
+            "Claim vested -> vesting not started"
+        );
+    });
+
+    it("Buy vesting pad user a - 2", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, vestingPadName, sellingTokenMintAccount);
+
+        const tx = await buyTestPad(program, vestingPadName, userAKeypair, "2", 15);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // selling out starts the vesting
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.SoldOut);
+    });
+
+    it("Claim vested user a", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, vestingPadName, sellingTokenMintAccount);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        const userSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, userAKeypair.publicKey, true, sellingTokenProgramAccount);
+
+        const userSellingBalance = await tokenBalance(connection, userSellingTokenAccount);
+
+        const tx = await claimVested(program, vestingPadName, userAKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 10, "Auction Config Selling token account", "Auction Config Selling token account");
+
+        await assertTokenBalance(connection, userSellingTokenAccount, userSellingBalance + 10, "User Selling token account", "User Selling token account");
+    });
+
+    it("Claim vested rejects a claim before the cliff", async () => {
+        await assertAnchorError(
+            claimVested(program, vestingPadName, userAKeypair),
+            "NothingVestedToClaim",
+            "Claim vested -> nothing vested since the last claim"
+        );
+    });
+
+    it("Initialize Pad Config rejects a round schedule that does not cover every round", async () => {
+        await assertAnchorError(
+            initializeTestPad(program, schedulePadName, {
+                tmax: 2,
+                roundDuration: new BN(5),
+                haveRoundSchedule: true,
+                roundSchedule: [{duration: new BN(5), haveBuyLimit: false, buyLimit: new BN(0), gap: new BN(0)}]
+            }),
+            "InvalidRoundSchedule",
+            "Initialize pad -> round schedule shorter than tmax"
+        );
+    });
+
+    it("Initialize Schedule Pad Config", async () => {
+        const tx = await initializeTestPad(program, schedulePadName, {
+            tmax: 2,
+            roundDuration: new BN(5),
+            haveRoundSchedule: true,
+            roundSchedule: [
+                {duration: new BN(5), haveBuyLimit: false, buyLimit: new BN(0), gap: new BN(0)},
+                {duration: new BN(7), haveBuyLimit: true, buyLimit: new BN(tokensToLamports(2, 9).toString()), gap: new BN(3)}
+            ]
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("End schedule pad Round 1", async () => {
+        await delay(5000);
+
+        const tx = await endTestPadRound(program, schedulePadName, "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Start schedule pad Round 2 rejects a start inside the scheduled gap", async () => {
+        await assertAnchorError(
+            startNextTestPadRound(program, schedulePadName, "1", "2", new BN(100)),
+            "RoundGapNotElapsed",
+            "Start next round -> gap not elapsed"
+        );
+    });
+
+    it("Start schedule pad Round 2", async () => {
+        await delay(3000);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, schedulePadName, sellingTokenMintAccount);
+
+        const [auctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, "2");
+
+        // the round starter's duration and buy limit are ignored for the scheduled ones
+        const tx = await startNextTestPadRound(program, schedulePadName, "1", "2", new BN(100));
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const auctionRoundData = await program.account.auctionRoundAccount.fetch(auctionRoundConfigPda);
+
+        assert(auctionRoundData.roundEndAt.sub(auctionRoundData.roundStartAt).eq(new BN(7)), "Auction Round -> scheduled duration");
+        assert(auctionRoundData.haveBuyLimit, "Auction Round -> scheduled have buy limit");
+        assert(auctionRoundData.buyLimit.eq(new BN(tokensToLamports(2, 9).toString())), "Auction Round -> scheduled buy limit");
+    });
+
+    it("Initialize Pad Config rejects a start time that already passed", async () => {
+        await assertAnchorError(
+            initializeTestPad(program, scheduledStartPadName, {
+                haveStartAt: true,
+                startAt: new BN(Math.floor(Date.now() / 1000) - 60)
+            }),
+            "InvalidStartAt",
+            "Initialize pad -> start at in the past"
+        );
+    });
+
+    it("Initialize Scheduled Start Pad Config", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, scheduledStartPadName, sellingTokenMintAccount);
+
+        const tx = await initializeTestPad(program, scheduledStartPadName, {
+            haveStartAt: true,
+            startAt: new BN(Math.floor(Date.now() / 1000) + 5)
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.Scheduled);
+    });
+
+    it("Buy scheduled start pad rejects a buy before the start", async () => {
+        await assertAnchorError(
+            buyTestPad(program, scheduledStartPadName, userAKeypair, "1", 1),
+            "AuctionNotStarted",
+            "Buy -> pad not started"
+        );
+    });
+
+    it("Buy scheduled start pad user a - 1", async () => {
+        await delay(5000);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, scheduledStartPadName, sellingTokenMintAccount);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        const tx = await buyTestPad(program, scheduledStartPadName, userAKeypair, "1", 1);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // the first buy after the start moves the pad out of scheduled
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.Started);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 19, "Auction Config Selling token account", "Auction Config Selling token account");
+    });
+
+    it("Initialize Pad Config rejects an escrowed native payment", async () => {
+        await assertAnchorError(
+            initializeTestPad(program, nativePadName, {
+                paymentMint: SystemProgram.programId,
+                havePaymentEscrow: true
+            }),
+            "NativePaymentCannotBeEscrowed",
+            "Initialize pad -> native payment escrowed"
+        );
+    });
+
+    it("Initialize Native Pad Config", async () => {
+        // priced at 0.01 SOL a token
+        const tx = await initializeTestPad(program, nativePadName, {
+            paymentMint: SystemProgram.programId,
+            p0: new BN(tokensToLamports(0.01, 9).toString()),
+            ptmax: new BN(tokensToLamports(0.005, 9).toString())
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Buy native pad user a - 1", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, nativePadName, sellingTokenMintAccount);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        const receiversBalance = await connection.getBalance(paymentReceiverKeypair.publicKey) + await connection.getBalance(feeReceiverKeypair.publicKey);
+
+        const tx = await buyTestPad(program, nativePadName, userAKeypair, "1", 2);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 18, "Auction Config Selling token account", "Auction Config Selling token account");
+
+        // 0.02 SOL split between the fee and the payment receiver
+        assert(
+            await connection.getBalance(paymentReceiverKeypair.publicKey) + await connection.getBalance(feeReceiverKeypair.publicKey) === receiversBalance + 0.02 * LAMPORTS_PER_SOL,
+            "Buy native pad -> receivers balance"
+        );
+    });
+
+    it("create extra payment token mint account", async () => {
+        extraPaymentTokenMintAccount = await createMint(
+            connection,
+            feeAndRentPayerKeypair,
+            mintAuthorityKeypair.publicKey,
+            mintAuthorityKeypair.publicKey,
+            paymentTokenDecimal,
+            undefined,
+            undefined,
+            extraPaymentTokenProgramAccount
+        );
+        console.log("extra payment token mint account: ", extraPaymentTokenMintAccount.toBase58());
+        await delay(delayTimeCount);
+
+        // Mint extra payment token to user a
+        const userAExtraPaymentTokenAccount = await createAssociatedTokenAccount(
+            connection,
+            feeAndRentPayerKeypair,
+            extraPaymentTokenMintAccount,
+            userAKeypair.publicKey,
+            undefined,
+            extraPaymentTokenProgramAccount
+        );
+        await delay(delayTimeCount);
+
+        await mintToChecked(
+            connection,
+            feeAndRentPayerKeypair,
+            extraPaymentTokenMintAccount,
+            userAExtraPaymentTokenAccount,
+            mintAuthorityKeypair,
+            tokensToLamports(100, paymentTokenDecimal),
+            paymentTokenDecimal,
+            undefined,
+            undefined,
+            extraPaymentTokenProgramAccount
+        );
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, userAExtraPaymentTokenAccount, 100, "user a extra payment token balance", "user a extra payment token balance");
+    });
+
+    it("Initialize Pad Config rejects its own payment mint as an extra payment mint", async () => {
+        await assertAnchorError(
+            initializeTestPad(program, paymentMintsPadName, {
+                paymentMints: [{mint: paymentTokenMintAccount, rate: new BN(1_000_000_000), haveOracle: false, oracle: PublicKey.default, totalPayment: new BN(0), totalFee: new BN(0)}]
+            }),
+            "InvalidPaymentMints",
+            "Initialize pad -> payment mint listed twice"
+        );
+    });
+
+    it("Initialize Payment Mints Pad Config", async () => {
+        // two extra payment tokens for one payment token
+        const tx = await initializeTestPad(program, paymentMintsPadName, {
+            paymentMints: [{mint: extraPaymentTokenMintAccount, rate: new BN(2_000_000_000), haveOracle: false, oracle: PublicKey.default, totalPayment: new BN(0), totalFee: new BN(0)}]
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Buy payment mints pad user a with the extra payment mint", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, paymentMintsPadName, sellingTokenMintAccount);
+
+        const userExtraPaymentTokenAccount = await getAssociatedTokenAddress(extraPaymentTokenMintAccount, userAKeypair.publicKey, true, extraPaymentTokenProgramAccount);
+
+        const tx = await buyTestPad(program, paymentMintsPadName, userAKeypair, "1", 2, {
+            paymentMint: extraPaymentTokenMintAccount,
+            paymentTokenProgram: extraPaymentTokenProgramAccount
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // 8 payment tokens at the fixed rate
+        await assertTokenBalance(connection, userExtraPaymentTokenAccount, 84, "User Extra Payment token account", "User Extra Payment token account");
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(auctionData.totalPayment.eq(new BN(tokensToLamports(8, 9).toString())), "Auction -> total payment in the payment mint");
+        assert(auctionData.paymentMints[0].totalPayment.eq(new BN(tokensToLamports(16, 9).toString())), "Auction -> total payment in the extra payment mint");
+    });
+
+    it("Initialize Referral Pad Config", async () => {
+        // referrers earn 10% of the creator's share
+        const tx = await initializeTestPad(program, referralPadName, {
+            haveReferral: true,
+            referralBasePoint: 1000
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Buy referral pad rejects a user referring themselves", async () => {
+        await assertAnchorError(
+            buyTestPad(program, referralPadName, userAKeypair, "1", 5, {referrer: userAKeypair.publicKey}),
+            "InvalidReferrer",
+            "Buy -> self referral"
+        );
+    });
+
+    it("Buy referral pad user a referred by user b - 1", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, referralPadName, sellingTokenMintAccount);
+
+        const [referrerConfigPda] = getReferrerAccountPdaAndBump(program.programId, auctionConfigPda, userBKeypair.publicKey);
+
+        const tx = await buyTestPad(program, referralPadName, userAKeypair, "1", 5, {referrer: userBKeypair.publicKey});
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const referrerData = await program.account.referrerAccount.fetch(referrerConfigPda);
+
+        assert(referrerData.referrer.equals(userBKeypair.publicKey), "Referrer -> referrer");
+        assert(referrerData.totalReferredBuyCount.eq(new BN(1)), "Referrer -> total referred buy count");
+        assert(referrerData.totalReward.gt(new BN(0)), "Referrer -> total reward");
+    });
+
+    it("Claim referral rewards user b", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, referralPadName, sellingTokenMintAccount);
+
+        const [referrerConfigPda] = getReferrerAccountPdaAndBump(program.programId, auctionConfigPda, userBKeypair.publicKey);
+
+        const referrerConfigPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, referrerConfigPda, true, paymentTokenProgramAccount);
+
+        const userPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, userBKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const reward = await tokenBalance(connection, referrerConfigPaymentTokenAccount);
+
+        const userPaymentBalance = await tokenBalance(connection, userPaymentTokenAccount);
+
+        const tx = await claimReferralRewards(program, referralPadName, userBKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, referrerConfigPaymentTokenAccount, 0, "Referrer Config Payment token account", "Referrer Config Payment token account");
+
+        await assertTokenBalance(connection, userPaymentTokenAccount, userPaymentBalance + reward, "User Payment token account", "User Payment token account");
+    });
+
+    it("Claim referral rewards rejects a referrer with nothing left to claim", async () => {
+        await assertAnchorError(
+            claimReferralRewards(program, referralPadName, userBKeypair),
+            "NoReferralRewardsToClaim",
+            "Claim referral rewards -> claimed twice"
+        );
+    });
+
+    it("Initialize Fee Pad Config", async () => {
+        const tx = await initializeTestPad(program, feePadName);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Set Pad Fee rejects a signer that is not the signing authority", async () => {
+        await assertAnchorError(
+            setPadFee(program, feePadName, creatorKeypair, true, 0),
+            "InvalidSigningAuthority",
+            "Set pad fee -> not the signing authority"
+        );
+    });
+
+    it("Set Pad Fee rejects a fee of the whole payment", async () => {
+        await assertAnchorError(
+            setPadFee(program, feePadName, signingAuthorityKeypair, true, 10000),
+            "InvalidFeeBasePoint",
+            "Set pad fee -> 100% fee"
+        );
+    });
+
+    it("Set Pad Fee", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, feePadName, sellingTokenMintAccount);
+
+        // the pad is waived from the protocol fee
+        const tx = await setPadFee(program, feePadName, signingAuthorityKeypair, true, 0);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(auctionData.haveFeeOverride, "Auction -> have fee override");
+        assert(auctionData.feeOverrideBasePoint === 0, "Auction -> fee override base point");
+    });
+
+    it("Buy fee pad user a - 1", async () => {
+        const paymentReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, paymentReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const feeReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, feeReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const paymentReceiverBalance = await tokenBalance(connection, paymentReceiverPaymentTokenAccount);
+
+        const feeReceiverBalance = await tokenBalance(connection, feeReceiverPaymentTokenAccount);
+
+        const tx = await buyTestPad(program, feePadName, userAKeypair, "1", 2);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, paymentReceiverPaymentTokenAccount, paymentReceiverBalance + 8, "Payment Receiver Payment token account", "Payment Receiver Payment token account");
+
+        await assertTokenBalance(connection, feeReceiverPaymentTokenAccount, feeReceiverBalance, "Fee Receiver Payment token account", "Fee Receiver Payment token account");
+    });
+
+    it("Set Collection Pad Fee rejects a signer that is not the signing authority", async () => {
+        await assertAnchorError(
+            setCollectionPadFee(program, creatorKeypair, true, 500, new BN(0)),
+            "InvalidSigningAuthority",
+            "Set collection pad fee -> not the signing authority"
+        );
+    });
+
+    it("Set Collection Pad Fee", async () => {
+        const [collectionAuctionConfigPda] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
+
+        const tx = await setCollectionPadFee(program, signingAuthorityKeypair, true, 500, new BN(mintingFee / 2));
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const collectionAuctionData = await program.account.collectionAuctionAccount.fetch(collectionAuctionConfigPda);
+
+        assert(collectionAuctionData.haveFeeOverride, "Collection Auction -> have fee override");
+        assert(collectionAuctionData.feeOverrideBasePoint === 500, "Collection Auction -> fee override base point");
+        assert(collectionAuctionData.feeOverrideMintingFee.eq(new BN(mintingFee / 2)), "Collection Auction -> fee override minting fee");
+    });
+
+    it("Enable governance", async () => {
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+        const tx = await program.methods.updateConfig({
+            ...programConfigChange(),
+            creamPadConfigBump: creamPadConfigBump
+        })
+            .accounts({
+                signingAuthority: signingAuthorityKeypair.publicKey,
+                creamPadConfig: creamPadConfigPda,
+            })
+            .signers([signingAuthorityKeypair])
+            .rpc({
+                skipPreflight: false
+            });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const creamPadData = await program.account.creamPadAccount.fetch(creamPadConfigPda);
+
+        assert(creamPadData.haveGovernance, "Cream Pad -> have governance");
+    });
+
+    it("update program config rejects a governed config", async () => {
+        const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+        await assertAnchorError(
+            program.methods.updateConfig({
+                ...programConfigChange({roundLimit: 50}),
+                creamPadConfigBump: creamPadConfigBump
+            })
+                .accounts({
+                    signingAuthority: signingAuthorityKeypair.publicKey,
+                    creamPadConfig: creamPadConfigPda,
+                })
+                .signers([signingAuthorityKeypair])
+                .rpc(),
+            "GovernanceRequired",
+            "Update config -> governed"
+        );
+    });
+
+    it("Propose Config Change rejects a proposer that is not a governance signer", async () => {
+        await assertAnchorError(
+            proposeConfigChange(program, "1", programConfigChange({roundLimit: 50}), creatorKeypair),
+            "InvalidGovernanceSigner",
+            "Propose config change -> not a governance signer"
+        );
+    });
+
+    it("Propose Config Change - 1", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        const [pendingConfigChangePda] = getPendingConfigChangeAccountPdaAndBump(programId, creamPadConfigPda, "1");
+
+        const tx = await proposeConfigChange(program, "1", programConfigChange({roundLimit: 50}), signingAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const pendingConfigChangeData = await program.account.pendingConfigChangeAccount.fetch(pendingConfigChangePda);
+
+        assert(JSON.stringify(pendingConfigChangeData.status) === JSON.stringify(ConfigChangeStatus.Pending), "Pending Config Change -> status");
+        assert(pendingConfigChangeData.approvals.length === 1, "Pending Config Change -> the proposer approves");
+    });
+
+    it("Execute Config Change rejects a change below the threshold", async () => {
+        await assertAnchorError(
+            governConfigChange(program, "executeConfigChange", "1", signingAuthorityKeypair),
+            "NotEnoughConfigChangeApprovals",
+            "Execute config change -> one approval of two"
+        );
+    });
+
+    it("Approve Config Change - 1", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        const [pendingConfigChangePda] = getPendingConfigChangeAccountPdaAndBump(programId, creamPadConfigPda, "1");
+
+        const tx = await governConfigChange(program, "approveConfigChange", "1", backAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const pendingConfigChangeData = await program.account.pendingConfigChangeAccount.fetch(pendingConfigChangePda);
+
+        assert(pendingConfigChangeData.approvals.length === 2, "Pending Config Change -> approvals");
+    });
+
+    it("Approve Config Change rejects a second approval of the same signer", async () => {
+        await assertAnchorError(
+            governConfigChange(program, "approveConfigChange", "1", backAuthorityKeypair),
+            "ConfigChangeAlreadyApproved",
+            "Approve config change -> approved twice"
+        );
+    });
+
+    it("Execute Config Change rejects a change inside its timelock", async () => {
+        await assertAnchorError(
+            governConfigChange(program, "executeConfigChange", "1", signingAuthorityKeypair),
+            "ConfigChangeTimelocked",
+            "Execute config change -> timelocked"
+        );
+    });
+
+    it("Execute Config Change - 1", async () => {
+        await delay(governanceTimelock * 1000);
+
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        const tx = await governConfigChange(program, "executeConfigChange", "1", signingAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const creamPadData = await program.account.creamPadAccount.fetch(creamPadConfigPda);
+
+        assert(creamPadData.roundLimit === 50, "Cream Pad -> round limit");
+    });
+
+    it("Cancel Config Change - 2", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        const [pendingConfigChangePda] = getPendingConfigChangeAccountPdaAndBump(programId, creamPadConfigPda, "2");
+
+        let tx = await proposeConfigChange(program, "2", programConfigChange({roundLimit: 10}), backAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await governConfigChange(program, "cancelConfigChange", "2", signingAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const pendingConfigChangeData = await program.account.pendingConfigChangeAccount.fetch(pendingConfigChangePda);
+
+        assert(JSON.stringify(pendingConfigChangeData.status) === JSON.stringify(ConfigChangeStatus.Cancelled), "Pending Config Change -> status");
+    });
+
+    it("Approve Config Change rejects a cancelled change", async () => {
+        await assertAnchorError(
+            governConfigChange(program, "approveConfigChange", "2", signingAuthorityKeypair),
+            "ConfigChangeNotPending",
+            "Approve config change -> cancelled"
+        );
+    });
+
+    it("Disable governance", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        // back to the config the rest of the suite runs on
+        let tx = await proposeConfigChange(program, "3", programConfigChange({
+            haveGovernance: false,
+            governanceSigners: [],
+            governanceThreshold: 0,
+            governanceTimelock: new BN(0)
+        }), signingAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await governConfigChange(program, "approveConfigChange", "3", backAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(governanceTimelock * 1000);
+
+        tx = await governConfigChange(program, "executeConfigChange", "3", backAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const creamPadData = await program.account.creamPadAccount.fetch(creamPadConfigPda);
+
+        assert(!creamPadData.haveGovernance, "Cream Pad -> have governance");
+        assert(creamPadData.roundLimit === 100, "Cream Pad -> round limit");
+    });
+
+    it("Propose Authority Transfer rejects a signer that is not the signing authority", async () => {
+        await assertAnchorError(
+            proposeAuthorityTransfer(program, AuthorityType.SigningAuthority, creatorKeypair.publicKey, creatorKeypair),
+            "InvalidSigningAuthority",
+            "Propose authority transfer -> not the signing authority"
+        );
+    });
+
+    it("Propose Authority Transfer", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        const tx = await proposeAuthorityTransfer(program, AuthorityType.SigningAuthority, nextSigningAuthorityKeypair.publicKey, signingAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const creamPadData = await program.account.creamPadAccount.fetch(creamPadConfigPda);
+
+        // the old authority stays until the new one accepts
+        assert(creamPadData.signingAuthority.equals(signingAuthorityKeypair.publicKey), "Cream Pad -> signing authority");
+        assert(creamPadData.pendingSigningAuthority.equals(nextSigningAuthorityKeypair.publicKey), "Cream Pad -> pending signing authority");
+    });
+
+    it("Accept Authority Transfer rejects a signer that is not the pending authority", async () => {
+        await assertAnchorError(
+            acceptAuthorityTransfer(program, AuthorityType.SigningAuthority, userAKeypair),
+            "InvalidPendingAuthority",
+            "Accept authority transfer -> not the pending authority"
+        );
+    });
+
+    it("Accept Authority Transfer", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        const tx = await acceptAuthorityTransfer(program, AuthorityType.SigningAuthority, nextSigningAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const creamPadData = await program.account.creamPadAccount.fetch(creamPadConfigPda);
+
+        assert(creamPadData.signingAuthority.equals(nextSigningAuthorityKeypair.publicKey), "Cream Pad -> signing authority");
+        assert(creamPadData.pendingSigningAuthority.equals(PublicKey.default), "Cream Pad -> pending signing authority");
+    });
+
+    it("Transfer the signing authority back", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId);
+
+        let tx = await proposeAuthorityTransfer(program, AuthorityType.SigningAuthority, signingAuthorityKeypair.publicKey, nextSigningAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await acceptAuthorityTransfer(program, AuthorityType.SigningAuthority, signingAuthorityKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const creamPadData = await program.account.creamPadAccount.fetch(creamPadConfigPda);
+
+        assert(creamPadData.signingAuthority.equals(signingAuthorityKeypair.publicKey), "Cream Pad -> signing authority");
+    });
+
+    it("Set Pause Flags rejects a signer that is not the signing authority", async () => {
+        await assertAnchorError(
+            setPauseFlags(program, creatorKeypair, pauseFlags({pauseNewPads: true})),
+            "InvalidSigningAuthority",
+            "Set pause flags -> not the signing authority"
+        );
+    });
+
+    it("Set Pause Flags", async () => {
+        const tx = await setPauseFlags(program, signingAuthorityKeypair, pauseFlags({pauseNewPads: true}));
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAnchorError(
+            initializeTestPad(program, pausedPadName),
+            "NewPadsPaused",
+            "Initialize pad -> new pads paused"
+        );
+    });
+
+    it("Set Pause Flags unpauses new pads", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, pausedPadName, sellingTokenMintAccount);
+
+        let tx = await setPauseFlags(program, signingAuthorityKeypair, pauseFlags());
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await initializeTestPad(program, pausedPadName);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.Started);
+    });
+
+    it("Set Pad Pause Flags rejects a signer that is not the signing authority", async () => {
+        await assertAnchorError(
+            setPadPauseFlags(program, pausedPadName, creatorKeypair, pauseFlags({pauseBuys: true})),
+            "InvalidSigningAuthority",
+            "Set pad pause flags -> not the signing authority"
+        );
+    });
+
+    it("Set Pad Pause Flags", async () => {
+        const tx = await setPadPauseFlags(program, pausedPadName, signingAuthorityKeypair, pauseFlags({pauseBuys: true}));
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAnchorError(
+            buyTestPad(program, pausedPadName, userAKeypair, "1", 1),
+            "BuysPaused",
+            "Buy -> pad buys paused"
+        );
+    });
+
+    it("Set Pad Pause Flags unpauses buys", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, pausedPadName, sellingTokenMintAccount);
+
+        const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+        let tx = await setPadPauseFlags(program, pausedPadName, signingAuthorityKeypair, pauseFlags());
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await buyTestPad(program, pausedPadName, userAKeypair, "1", 1);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertTokenBalance(connection, auctionConfigSellingTokenAccount, 19, "Auction Config Selling token account", "Auction Config Selling token account");
+    });
+
+    it("Set Collection Pad Pause Flags rejects a signer that is not the signing authority", async () => {
+        await assertAnchorError(
+            setCollectionPadPauseFlags(program, creatorKeypair, pauseFlags({pauseCollectionMinting: true})),
+            "InvalidSigningAuthority",
+            "Set collection pad pause flags -> not the signing authority"
+        );
+    });
+
+    it("Set Collection Pad Pause Flags", async () => {
+        const [collectionAuctionConfigPda] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
+
+        const tx = await setCollectionPadPauseFlags(program, signingAuthorityKeypair, pauseFlags({pauseClaims: true, pauseCollectionMinting: true}));
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const collectionAuctionData = await program.account.collectionAuctionAccount.fetch(collectionAuctionConfigPda);
+
+        assert(JSON.stringify(collectionAuctionData.pauseFlags) === JSON.stringify(pauseFlags({pauseClaims: true, pauseCollectionMinting: true})), "Collection Auction -> pause flags");
+    });
+
+    it("initialize tenant program config", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId, tenantId);
+        console.log("tenant creamPadConfigPda: ", creamPadConfigPda.toBase58());
+
+        const tx = await program.methods.initialize({
+            backAuthority: backAuthorityKeypair.publicKey,
+            isBackAuthorityRequired: true,
+            isFeeRequired: true,
+            feeBasePoint: 500,
+            feeReceiver: feeReceiverKeypair.publicKey,
+            roundLimit: 100,
+            distributionBasePoint: 5000,
+            lockBasePoint: 5000,
+            lockDuration: new BN(5),
+            mintingFee: new BN(mintingFee),
+            treasury: treasuryKeypair.publicKey,
+            feeTiers: [],
+            tenantId: tenantId,
+        })
+            .accounts({
+                feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
+                signingAuthority: tenantSigningAuthorityKeypair.publicKey,
+                creamPadConfig: creamPadConfigPda,
+                systemProgram: SystemProgram.programId,
+                rent: SYSVAR_RENT_PUBKEY
+            })
+            .signers([feeAndRentPayerKeypair, tenantSigningAuthorityKeypair])
+            .rpc({
+                skipPreflight: false
+            });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertCreamPadAccount(
+            program,
+            creamPadConfigPda,
+            tenantSigningAuthorityKeypair.publicKey,
+            backAuthorityKeypair.publicKey,
+            true,
+            ProgramStatus.Normal,
+            true,
+            500,
+            feeReceiverKeypair.publicKey,
+            100,
+            5000,
+            5000,
+            new BN(5),
+            new BN(mintingFee),
+            treasuryKeypair.publicKey
+        );
+
+        // the default config is a separate account
+        const creamPadData = await program.account.creamPadAccount.fetch(getCreamPadAccountPdaAndBump(programId)[0]);
+
+        assert(creamPadData.signingAuthority.equals(signingAuthorityKeypair.publicKey), "Cream Pad -> default signing authority");
+    });
+
+    it("Initialize Tenant Pad Config", async () => {
+        const [creamPadConfigPda] = getCreamPadAccountPdaAndBump(programId, tenantId);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, tenantPadName, sellingTokenMintAccount);
+
+        const tx = await initializeTestPad(program, tenantPadName, {}, tenantId);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(auctionData.creamPadConfig.equals(creamPadConfigPda), "Auction -> cream pad config");
+        assert(auctionData.feeBasePoint === 500, "Auction -> tenant fee base point");
+    });
+
+    it("Set Pad Fee rejects a tenant pad under the default config", async () => {
+        await assertAnchorError(
+            setPadFee(program, tenantPadName, signingAuthorityKeypair, true, 0),
+            "InvalidCreamPadConfig",
+            "Set pad fee -> pad created under another config"
+        );
+    });
+
+    it("Set Pad Fee rejects the default signing authority on a tenant config", async () => {
+        await assertAnchorError(
+            setPadFee(program, tenantPadName, signingAuthorityKeypair, true, 0, tenantId),
+            "InvalidSigningAuthority",
+            "Set pad fee -> signing authority of another config"
+        );
+    });
+
+    it("Set Pad Fee tenant pad", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, tenantPadName, sellingTokenMintAccount);
+
+        const tx = await setPadFee(program, tenantPadName, tenantSigningAuthorityKeypair, true, 0, tenantId);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(auctionData.haveFeeOverride, "Auction -> have fee override");
+    });
+
+    it("Initialize Whitelist Pad Config", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, whitelistPadName, sellingTokenMintAccount);
+
+        whitelistTree = getWhitelistTree([
+            {user: userAKeypair.publicKey, allocation: whitelistAllocationA},
+            {user: userBKeypair.publicKey, allocation: whitelistAllocationB},
+            {user: userCKeypair.publicKey, allocation: whitelistAllocationB},
+        ]);
+
+        const tx = await initializeTestPad(program, whitelistPadName, {
+            haveWhitelist: true,
+            whitelistMerkleRoot: whitelistTree.root,
+            whitelistRounds: 1,
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(auctionData.haveWhitelist, "Auction -> have whitelist");
+        assert(JSON.stringify(auctionData.whitelistMerkleRoot) === JSON.stringify(whitelistTree.root), "Auction -> whitelist merkle root");
+    });
+
+    it("Buy whitelist pad rejects the proof of another user", async () => {
+        await assertAnchorError(
+            buyTestPad(program, whitelistPadName, userBKeypair, "1", 1, {
+                whitelistAllocation: whitelistAllocationA,
+                whitelistProof: whitelistTree.proofs[0]
+            }),
+            "InvalidWhitelistProof",
+            "Buy -> proof of another user"
+        );
+    });
+
+    it("Buy whitelist pad rejects a raised allocation", async () => {
+        await assertAnchorError(
+            buyTestPad(program, whitelistPadName, userAKeypair, "1", 1, {
+                whitelistAllocation: whitelistAllocationA.addn(1),
+                whitelistProof: whitelistTree.proofs[0]
+            }),
+            "InvalidWhitelistProof",
+            "Buy -> allocation not in the tree"
+        );
+    });
+
+    it("Buy whitelist pad rejects a missing proof", async () => {
+        await assertAnchorError(
+            buyTestPad(program, whitelistPadName, userAKeypair, "1", 1),
+            "InvalidWhitelistProof",
+            "Buy -> no proof"
+        );
+    });
+
+    it("Buy whitelist pad", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, whitelistPadName, sellingTokenMintAccount);
+
+        const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, userAKeypair.publicKey);
+
+        const whitelist: TestPadBuyOptions = {
+            whitelistAllocation: whitelistAllocationA,
+            whitelistProof: whitelistTree.proofs[0]
+        };
+
+        let tx = await buyTestPad(program, whitelistPadName, userAKeypair, "1", 2, whitelist);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        let userAuctionData = await program.account.userAuctionAccount.fetch(userAuctionConfigPda);
+
+        assert(userAuctionData.totalWhitelistBuyAmount.toString() === tokensToLamports(2, 9).toString(), "User Auction -> total whitelist buy amount after the first buy");
+
+        tx = await buyTestPad(program, whitelistPadName, userAKeypair, "2", 3, whitelist);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        userAuctionData = await program.account.userAuctionAccount.fetch(userAuctionConfigPda);
+
+        assert(userAuctionData.totalWhitelistBuyAmount.toString() === whitelistAllocationA.toString(), "User Auction -> total whitelist buy amount at the allocation");
+    });
+
+    it("Buy whitelist pad rejects a buy over the allocation", async () => {
+        await assertAnchorError(
+            buyTestPad(program, whitelistPadName, userAKeypair, "3", 1, {
+                whitelistAllocation: whitelistAllocationA,
+                whitelistProof: whitelistTree.proofs[0]
+            }),
+            "WhitelistAllocationExceeded",
+            "Buy -> over the whitelist allocation"
+        );
+    });
+
+    it("Buy whitelist pad as another whitelisted user", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, whitelistPadName, sellingTokenMintAccount);
+
+        const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, userBKeypair.publicKey);
+
+        const tx = await buyTestPad(program, whitelistPadName, userBKeypair, "1", 3, {
+            whitelistAllocation: whitelistAllocationB,
+            whitelistProof: whitelistTree.proofs[1]
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const userAuctionData = await program.account.userAuctionAccount.fetch(userAuctionConfigPda);
+
+        assert(userAuctionData.totalWhitelistBuyAmount.toString() === whitelistAllocationB.toString(), "User Auction -> total whitelist buy amount");
+    });
+
+    it("Initialize Rebate Pad Config", async () => {
+        const tx = await initializeTestPad(program, rebatePadName, {
+            tmax: 2,
+            havePaymentEscrow: true,
+            haveUniformClearingPrice: true,
+            roundDuration: new BN(5)
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Buy rebate pad Round 1 user a - 1", async () => {
+        const tx = await buyTestPad(program, rebatePadName, userAKeypair, "1", 2);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("End rebate pad Round 1 and start Round 2", async () => {
+        await delay(5000);
+
+        let tx = await endTestPadRound(program, rebatePadName, "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await startNextTestPadRound(program, rebatePadName, "1", "2", new BN(5));
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, rebatePadName, sellingTokenMintAccount);
+
+        const firstRoundData = await program.account.auctionRoundAccount.fetch(getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, "1")[0]);
+
+        const secondRoundData = await program.account.auctionRoundAccount.fetch(getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, "2")[0]);
+
+        // an undersold round lowers the price, round 1 buyers are owed the difference
+        assert(firstRoundData.price.gt(secondRoundData.price), "Auction Round -> round 2 price under round 1");
+    });
+
+    it("Buy rebate pad Round 2 user b - 1", async () => {
+        const tx = await buyTestPad(program, rebatePadName, userBKeypair, "1", 1);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Claim Rebate rejects a pad that is not settled", async () => {
+        await assertAnchorError(
+            claimRebate(program, rebatePadName, userAKeypair, "1"),
+            "AuctionPaymentNotSettled",
+            "Claim rebate -> not settled"
+        );
+    });
+
+    it("End rebate pad Round 2", async () => {
+        await delay(5000);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, rebatePadName, sellingTokenMintAccount);
+
+        const tx = await endTestPadRound(program, rebatePadName, "2");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.Ended);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(auctionData.clearingPrice.eq(auctionData.currentPrice), "Auction -> clearing price at the last round price");
+    });
+
+    it("Settle rebate pad rejects a missing round account", async () => {
+        await assertAnchorError(
+            settleTestPad(program, rebatePadName, creatorKeypair, ["2"]),
+            "InvalidAuctionRoundAccounts",
+            "Settle pad -> round 1 left out"
+        );
+    });
+
+    it("Settle rebate pad rejects out of order round accounts", async () => {
+        await assertAnchorError(
+            settleTestPad(program, rebatePadName, creatorKeypair, ["2", "1"]),
+            "InvalidAuctionRoundAccounts",
+            "Settle pad -> rounds out of order"
+        );
+    });
+
+    it("Settle rebate pad", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, rebatePadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const auctionVaultConfigPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, auctionVaultConfigPda, true, paymentTokenProgramAccount);
+
+        const paymentReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, paymentReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const feeReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, feeReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        // what each round paid above the clearing price, one unit per buy for the receipt rounding
+        let rebateReserve = new BN(0);
+
+        for (const roundIndex of ["1", "2"]) {
+            const roundData = await program.account.auctionRoundAccount.fetch(getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, roundIndex)[0]);
+
+            if (roundData.price.gt(auctionData.clearingPrice)) {
+                rebateReserve = rebateReserve.add(BN.min(
+                    roundData.totalSupplySold.mul(roundData.price.sub(auctionData.clearingPrice)).div(new BN(1_000_000_000)).add(roundData.totalUserBuyCount),
+                    roundData.totalPayment
+                ));
+            }
+        }
+
+        // from 9 to the payment mint decimals
+        const rebateReserveAmount: number = rebateReserve.div(new BN(10 ** (9 - paymentTokenDecimal))).toNumber() / 10 ** paymentTokenDecimal;
+
+        assert(rebateReserveAmount > 0, "Settle pad -> round 1 owes a rebate");
+
+        const vaultBalance = await tokenBalance(connection, auctionVaultConfigPaymentTokenAccount);
+
+        const receiversBalance = await tokenBalance(connection, paymentReceiverPaymentTokenAccount) + await tokenBalance(connection, feeReceiverPaymentTokenAccount);
+
+        const tx = await settleTestPad(program, rebatePadName, creatorKeypair, ["1", "2"]);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // the settle leaves exactly the rebate reserve behind
+        await assertTokenBalance(connection, auctionVaultConfigPaymentTokenAccount, rebateReserveAmount, "Auction Vault Payment token account", "Auction Vault Payment token account");
+
+        assert(
+            Math.abs(await tokenBalance(connection, paymentReceiverPaymentTokenAccount) + await tokenBalance(connection, feeReceiverPaymentTokenAccount) - (receiversBalance + vaultBalance - rebateReserveAmount)) < 1e-9,
+            "Settle pad -> receivers balance"
+        );
+
+        const settledAuctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        assert(settledAuctionData.isPaymentSettled, "Auction -> is payment settled");
+    });
+
+    it("Claim Rebate", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, rebatePadName, sellingTokenMintAccount);
+
+        const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, userAKeypair.publicKey);
+
+        const [userAuctionBuyReceiptConfigPda] = getUserAuctionBuyReceiptAccountPdaAndBump(programId, userAuctionConfigPda, "1");
+
+        const userAPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, userAKeypair.publicKey, true, paymentTokenProgramAccount);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        const receiptData = await program.account.userAuctionBuyReceiptAccount.fetch(userAuctionBuyReceiptConfigPda);
+
+        // payment - amount * clearing price, at 9 decimals and then in the payment mint decimals
+        const rebate: BN = receiptData.payment.sub(receiptData.buyAmount.mul(auctionData.clearingPrice).div(new BN(1_000_000_000)));
+
+        const rebateAmount: number = rebate.div(new BN(10 ** (9 - paymentTokenDecimal))).toNumber() / 10 ** paymentTokenDecimal;
+
+        assert(rebateAmount > 0, "Claim rebate -> round 1 paid above the clearing price");
+
+        const userABalance = await tokenBalance(connection, userAPaymentTokenAccount);
+
+        const tx = await claimRebate(program, rebatePadName, userAKeypair, "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        assert(
+            Math.abs(await tokenBalance(connection, userAPaymentTokenAccount) - (userABalance + rebateAmount)) < 1e-9,
+            "Claim rebate -> user a balance"
+        );
+
+        const claimedReceiptData = await program.account.userAuctionBuyReceiptAccount.fetch(userAuctionBuyReceiptConfigPda);
+
+        assert(claimedReceiptData.isRebateClaimed, "User Auction Buy Receipt -> is rebate claimed");
+    });
+
+    it("Claim Rebate rejects a receipt that was already claimed", async () => {
+        await assertAnchorError(
+            claimRebate(program, rebatePadName, userAKeypair, "1"),
+            "RebateAlreadyClaimed",
+            "Claim rebate -> claimed twice"
+        );
+    });
+
+    it("Initialize Crank Pad Config", async () => {
+        const tx = await initializeTestPad(program, crankPadName, {
+            tmax: 2,
+            roundDuration: new BN(5),
+            haveCrank: true,
+            crankRoundDuration: new BN(5),
+            crankReward: new BN(crankReward)
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Fund Crank Reward rejects a signer that is not the creator", async () => {
+        await assertAnchorError(
+            fundCrankReward(program, crankPadName, userAKeypair, crankReward),
+            "InvalidCreator",
+            "Fund crank reward -> not the creator"
+        );
+    });
+
+    it("Fund Crank Reward below one crank reward", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, crankPadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const vaultRent: number = await connection.getMinimumBalanceForRentExemption(0);
+
+        // the first crank can only pay half a reward on top of the vault rent
+        const tx = await fundCrankReward(program, crankPadName, creatorKeypair, vaultRent + crankReward / 2);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        assert(await connection.getBalance(auctionVaultConfigPda) === vaultRent + crankReward / 2, "Auction Vault -> funded lamports");
+    });
+
+    it("Crank Round rejects a round that still has time", async () => {
+        await assertAnchorError(
+            crankTestPad(program, crankPadName, userBKeypair, "1", "2"),
+            "AuctionRoundStillHaveTime",
+            "Crank round -> before round end at"
+        );
+    });
+
+    it("Withdraw Crank Reward rejects a pad that is still running", async () => {
+        await assertAnchorError(
+            withdrawCrankReward(program, crankPadName, creatorKeypair),
+            "AuctionNotFinished",
+            "Withdraw crank reward -> pad running"
+        );
+    });
+
+    it("Crank Round 1 by a third party", async () => {
+        await delay(5000);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, crankPadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const [firstAuctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, "1");
+
+        const [secondAuctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, "2");
+
+        const vaultRent: number = await connection.getMinimumBalanceForRentExemption(0);
+
+        const crankerBalance: number = await connection.getBalance(userBKeypair.publicKey);
+
+        const tx = await crankTestPad(program, crankPadName, userBKeypair, "1", "2");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+        const firstRoundData = await program.account.auctionRoundAccount.fetch(firstAuctionRoundConfigPda);
+
+        const secondRoundData = await program.account.auctionRoundAccount.fetch(secondAuctionRoundConfigPda);
+
+        assert(auctionData.currentRound === 2, "Auction -> current round");
+        assert(JSON.stringify(firstRoundData.status) === JSON.stringify(AuctionRoundStatus.Ended), "Auction Round 1 -> status");
+        assert(secondRoundData.roundEndAt.sub(secondRoundData.roundStartAt).toNumber() === 5, "Auction Round 2 -> crank round duration");
+        assert(secondRoundData.rentPayer.equals(userBKeypair.publicKey), "Auction Round 2 -> rent payer");
+
+        const boost: bigint = calculateBoost(
+            BigInt(firstRoundData.totalSupplySold.toString()),
+            BigInt(auctionData.totalSupply.div(new BN(auctionData.tmax)).toString()),
+            BigInt(auctionData.omega.toString()),
+            BigInt(auctionData.alpha.toString()),
+            BigInt(auctionData.timeShiftMax.toString())
+        );
+
+        assert(firstRoundData.boost.toString() === boost.toString(), "Auction Round 1 -> boost");
+        assert(auctionData.boostHistory[0].toString() === boost.toString(), "Auction -> boost history");
+
+        // the reward stops at the vault rent, the cranker paid the round 2 rent
+        const secondRoundRent: number = (await connection.getAccountInfo(secondAuctionRoundConfigPda)).lamports;
+
+        assert(await connection.getBalance(auctionVaultConfigPda) === vaultRent, "Auction Vault -> left at its rent");
+        assert(auctionData.totalCrankRewardPaid.toNumber() === crankReward / 2, "Auction -> total crank reward paid");
+        assert(await connection.getBalance(userBKeypair.publicKey) === crankerBalance + crankReward / 2 - secondRoundRent, "Cranker -> reward");
+    });
+
+    it("Fund Crank Reward", async () => {
+        const tx = await fundCrankReward(program, crankPadName, creatorKeypair, 4 * crankReward);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Crank Round 2 by a third party", async () => {
+        await delay(5000);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, crankPadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const vaultBalance: number = await connection.getBalance(auctionVaultConfigPda);
+
+        const crankerBalance: number = await connection.getBalance(userBKeypair.publicKey);
+
+        // the last round opens no next round
+        const tx = await crankTestPad(program, crankPadName, userBKeypair, "2", null);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.Ended);
+
+        assert(await connection.getBalance(auctionVaultConfigPda) === vaultBalance - crankReward, "Auction Vault -> paid one crank reward");
+        assert(await connection.getBalance(userBKeypair.publicKey) === crankerBalance + crankReward, "Cranker -> reward");
+    });
+
+    it("Withdraw Crank Reward", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, crankPadName, sellingTokenMintAccount);
+
+        const [auctionVaultConfigPda] = getAuctionVaultAccountPdaAndBump(program.programId, auctionConfigPda);
+
+        const vaultRent: number = await connection.getMinimumBalanceForRentExemption(0);
+
+        const creatorBalance: number = await connection.getBalance(creatorKeypair.publicKey);
+
+        const tx = await withdrawCrankReward(program, crankPadName, creatorKeypair);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        // 4 rewards funded, 1 paid, the vault keeps its rent
+        assert(await connection.getBalance(auctionVaultConfigPda) === vaultRent, "Auction Vault -> left at its rent");
+        assert(await connection.getBalance(creatorKeypair.publicKey) === creatorBalance + 3 * crankReward, "Creator -> crank reward surplus");
+    });
+
+    it("Withdraw Crank Reward rejects an empty pool", async () => {
+        await assertAnchorError(
+            withdrawCrankReward(program, crankPadName, creatorKeypair),
+            "ValueIsZero",
+            "Withdraw crank reward -> nothing above the vault rent"
+        );
+    });
+
+    it("Initialize Close Pad Config", async () => {
+        const tx = await initializeTestPad(program, closePadName, {
+            tmax: 2,
+            havePaymentEscrow: true,
+            haveUniformClearingPrice: true,
+            roundDuration: new BN(5)
+        });
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Buy close pad Round 1 user a - 1", async () => {
+        const tx = await buyTestPad(program, closePadName, userAKeypair, "1", 2);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Close Buy Receipt, User Round and Round reject a pad that is still selling", async () => {
+        await assertAnchorError(
+            closeBuyReceipt(program, closePadName, userBKeypair, userAKeypair.publicKey, "1", feeAndRentPayerKeypair.publicKey),
+            "AuctionNotFinished",
+            "Close buy receipt -> pad started"
+        );
+
+        await assertAnchorError(
+            closeUserRound(program, closePadName, userBKeypair, userAKeypair.publicKey, "1", feeAndRentPayerKeypair.publicKey),
+            "AuctionNotFinished",
+            "Close user round -> pad started"
+        );
+
+        await assertAnchorError(
+            closeTestPadRound(program, closePadName, userBKeypair, "1", feeAndRentPayerKeypair.publicKey),
+            "AuctionNotFinished",
+            "Close round -> pad started"
+        );
+    });
+
+    it("Sell out close pad in Round 2", async () => {
+        await delay(5000);
+
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, closePadName, sellingTokenMintAccount);
+
+        let tx = await endTestPadRound(program, closePadName, "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await startNextTestPadRound(program, closePadName, "1", "2", new BN(1000));
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        tx = await buyTestPad(program, closePadName, userBKeypair, "1", 18);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+
+        await assertAuctionStatus(program, auctionConfigPda, AuctionStatus.SoldOut);
+    });
+
+    it("Close Buy Receipt rejects an escrowed pad that is not settled", async () => {
+        await assertAnchorError(
+            closeBuyReceipt(program, closePadName, userBKeypair, userBKeypair.publicKey, "1", feeAndRentPayerKeypair.publicKey),
+            "AuctionNotFinished",
+            "Close buy receipt -> escrow not settled"
+        );
+    });
+
+    it("Settle close pad", async () => {
+        const tx = await settleTestPad(program, closePadName, creatorKeypair, ["1", "2"]);
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Close Buy Receipt rejects a receipt with an unclaimed rebate", async () => {
+        await assertAnchorError(
+            closeBuyReceipt(program, closePadName, userBKeypair, userAKeypair.publicKey, "1", feeAndRentPayerKeypair.publicKey),
+            "RebateNotClaimed",
+            "Close buy receipt -> rebate not claimed"
+        );
+    });
+
+    it("Claim close pad rebate", async () => {
+        const tx = await claimRebate(program, closePadName, userAKeypair, "1");
+
+        console.log("Your transaction signature", tx);
+
+        await delay(delayTimeCount);
+    });
+
+    it("Close Buy Receipt, User Round and Round reject another rent payer", async () => {
+        await assertAnchorError(
+            closeBuyReceipt(program, closePadName, userBKeypair, userAKeypair.publicKey, "1", userAKeypair.publicKey),
+            "InvalidRentPayer",
+            "Close buy receipt -> rent payer is the fee and rent payer"
+        );
+
+        await assertAnchorError(
+            closeUserRound(program, closePadName, userBKeypair, userAKeypair.publicKey, "1", userBKeypair.publicKey),
+            "InvalidRentPayer",
+            "Close user round -> rent payer is the fee and rent payer"
+        );
+
+        await assertAnchorError(
+            closeTestPadRound(program, closePadName, userBKeypair, "1", creatorKeypair.publicKey),
+            "InvalidRentPayer",
+            "Close round -> rent payer is the fee and rent payer"
+        );
+    });
+
+    it("Close Buy Receipt", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, closePadName, sellingTokenMintAccount);
+
+        const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, userAKeypair.publicKey);
+
+        const [userAuctionBuyReceiptConfigPda] = getUserAuctionBuyReceiptAccountPdaAndBump(programId, userAuctionConfigPda, "1");
+
+        // anyone can close, the rent goes back to who paid it
+        await assertClosedToRentPayer(
+            connection,
+            userAuctionBuyReceiptConfigPda,
+            feeAndRentPayerKeypair.publicKey,
+            () => closeBuyReceipt(program, closePadName, userBKeypair, userAKeypair.publicKey, "1", feeAndRentPayerKeypair.publicKey)
+        );
+    });
+
+    it("Close User Round", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, closePadName, sellingTokenMintAccount);
+
+        const [auctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, "1");
+
+        const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, userAKeypair.publicKey);
+
+        const [userAuctionRoundConfigPda] = getUserAuctionRoundAccountPdaAndBump(programId, auctionRoundConfigPda, userAuctionConfigPda);
+
+        await assertClosedToRentPayer(
+            connection,
+            userAuctionRoundConfigPda,
+            feeAndRentPayerKeypair.publicKey,
+            () => closeUserRound(program, closePadName, userBKeypair, userAKeypair.publicKey, "1", feeAndRentPayerKeypair.publicKey)
+        );
+    });
+
+    it("Close Round", async () => {
+        const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, closePadName, sellingTokenMintAccount);
+
+        const [auctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, "1");
+
+        await assertClosedToRentPayer(
+            connection,
+            auctionRoundConfigPda,
+            feeAndRentPayerKeypair.publicKey,
+            () => closeTestPadRound(program, closePadName, userBKeypair, "1", feeAndRentPayerKeypair.publicKey)
+        );
+    });
+
+    it("Remove Events", async () => {
+        await delay(delayTimeCount);
+
+        await program.removeEventListener(initializePadEventListener);
+        await program.removeEventListener(updatePadEventListener);
+        await program.removeEventListener(endRoundEventListener);
+        await program.removeEventListener(startRoundEventListener);
+        await program.removeEventListener(lockAndDistributionEventListener);
+        await program.removeEventListener(unlockUnsoldSupplyEventListener);
+        await program.removeEventListener(buyEventListener);
+        await program.removeEventListener(claimDistributionEventListener);
+        await program.removeEventListener(cancelPadEventListener);
+        await program.removeEventListener(withdrawCancelledSupplyEventListener);
+        await program.removeEventListener(refundEventListener);
+        await program.removeEventListener(settlePadEventListener);
+        await program.removeEventListener(claimRebateEventListener);
+        await program.removeEventListener(claimVestedEventListener);
+        await program.removeEventListener(crankRoundEventListener);
+        await program.removeEventListener(fundCrankRewardEventListener);
+        await program.removeEventListener(roundScheduleEventListener);
+        await program.removeEventListener(collectionRoundScheduleEventListener);
+        await program.removeEventListener(paymentMintsEventListener);
+        await program.removeEventListener(claimReferralRewardsEventListener);
+        await program.removeEventListener(setPadFeeEventListener);
+        await program.removeEventListener(setCollectionPadFeeEventListener);
+        await program.removeEventListener(migrateConfigEventListener);
+        await program.removeEventListener(proposeConfigChangeEventListener);
+        await program.removeEventListener(approveConfigChangeEventListener);
+        await program.removeEventListener(executeConfigChangeEventListener);
+        await program.removeEventListener(cancelConfigChangeEventListener);
+        await program.removeEventListener(proposeAuthorityTransferEventListener);
+        await program.removeEventListener(acceptAuthorityTransferEventListener);
+        await program.removeEventListener(setPauseFlagsEventListener);
+        await program.removeEventListener(setPadPauseFlagsEventListener);
+        await program.removeEventListener(setCollectionPadPauseFlagsEventListener);
+        await program.removeEventListener(migrateUserPadEventListener);
+        await program.removeEventListener(migrateUserPadRoundEventListener);
+        await program.removeEventListener(migrateUserPadBuyReceiptEventListener);
+        await program.removeEventListener(migrateUserPadUnsoldDistributionEventListener);
+        await program.removeEventListener(migrateUserCollectionPadEventListener);
+        await program.removeEventListener(migrateUserCollectionPadRoundEventListener);
+        await program.removeEventListener(migrateUserCollectionPadBuyReceiptEventListener);
+        await program.removeEventListener(migrateUserCollectionPadUnsoldDistributionEventListener);
+        await program.removeEventListener(closeBuyReceiptEventListener);
+        await program.removeEventListener(closeUserRoundEventListener);
+        await program.removeEventListener(closeRoundEventListener);
+        await program.removeEventListener(withdrawCrankRewardEventListener);
+
+        // Collection
+        await program.removeEventListener(initializeCollectionPadEventListener);
+        await program.removeEventListener(updateCollectionPadEventListener);
+        await program.removeEventListener(endCollectionRoundEventListener);
+        await program.removeEventListener(startCollectionRoundEventListener);
+        await program.removeEventListener(takeCollectionUpdateAuthorityEventListener);
+        await program.removeEventListener(giveCollectionUpdateAuthorityEventListener);
+        await program.removeEventListener(treasuryAndDistributionEventListener);
+        await program.removeEventListener(mintTreasuryAssetEventListener);
+        await program.removeEventListener(buyCollectionAssetEventListener);
+        await program.removeEventListener(fillBoughtCollectionAssetEventListener);
+        await program.removeEventListener(collectionClaimDistributionEventListener);
+        await program.removeEventListener(fillClaimedCollectionAssetDistributionEventListener);
+    });
+});
+
+/// Helper
+function delay(ms: number) {
+    return new Promise(resolve => setTimeout(resolve, ms));
+}
+
+function relayBuyIntent(program: Program<CreamPad>, buyIndex: string, amount: number, expiresIn: number) {
+    const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, relayPadName, sellingTokenMintAccount);
+
+    return {
+        pad: auctionConfigPda,
+        round: 1,
+        amount: new BN(tokensToLamports(amount, 9)),
+        paymentMint: paymentTokenMintAccount,
+        maxPrice: new BN(tokensToLamports(4, 9)),
+        maxTotalPayment: new BN(tokensToLamports(amount * 4, paymentTokenDecimal)),
+        expiresAt: new BN(Math.floor(Date.now() / 1000) + expiresIn),
+        nonce: new BN(buyIndex),
+        referrer: PublicKey.default
+    };
+}
+
+// Ed25519 program instruction carrying the signer's signature over the borsh encoded intent
+function signBuyIntent(program: Program<CreamPad>, buyIntent: any, signer: Keypair): TransactionInstruction {
+    const message: Buffer = program.coder.types.encode("BuyIntent", buyIntent);
+
+    return Ed25519Program.createInstructionWithPublicKey({
+        publicKey: signer.publicKey.toBytes(),
+        message: message,
+        signature: nacl.sign.detached(message, signer.secretKey)
+    });
+}
+
+// Buy for user b submitted by the fee and rent payer, the ed25519 instruction goes right before the buy
+async function relayedBuy(program: Program<CreamPad>, buyIntent: any, ed25519Ix: TransactionInstruction, buyIndex: string, ixsAfterEd25519: TransactionInstruction[] = []): Promise<string> {
+    const roundIndex = "1";
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, relayPadName, sellingTokenMintAccount);
+
+    const [auctionRoundConfigPda, auctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex);
+
+    const [, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+    const userSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, userBKeypair.publicKey, true, sellingTokenProgramAccount);
+
+    const userPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, userBKeypair.publicKey, true, paymentTokenProgramAccount);
+
+    const paymentReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, paymentReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+    const feeReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentTokenMintAccount, feeReceiverKeypair.publicKey, true, paymentTokenProgramAccount);
+
+    const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, userBKeypair.publicKey);
+
+    const [userAuctionRoundConfigPda] = getUserAuctionRoundAccountPdaAndBump(programId, auctionRoundConfigPda, userAuctionConfigPda);
+
+    const [userAuctionBuyReceiptConfigPda] = getUserAuctionBuyReceiptAccountPdaAndBump(programId, userAuctionConfigPda, buyIndex);
+
+    const ixs: TransactionInstruction[] = [];
+
+    if ((await program.provider.connection.getAccountInfo(userSellingTokenAccount)) == null) {
+        const createUserSellingTokenAccountIx: TransactionInstruction = createAssociatedTokenAccountInstruction(
+            feeAndRentPayerKeypair.publicKey,
+            userSellingTokenAccount,
+            userBKeypair.publicKey,
+            sellingTokenMintAccount,
+            sellingTokenProgramAccount,
+            ASSOCIATED_TOKEN_PROGRAM_ID
+        );
+
+        ixs.push(createUserSellingTokenAccountIx)
+    }
+
+    return await program.methods.buy({
+        padName: relayPadName,
+        currentRoundIndex: roundIndex,
+        buyIndex: buyIndex,
+        amount: buyIntent.amount,
+        whitelistAllocation: new BN(0),
+        whitelistProof: [],
+        referrer: PublicKey.default,
+        maxPricePerToken: null,
+        maxTotalPayment: null,
+        buyIntent: buyIntent,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionRoundConfigBump: auctionRoundConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump
+    })
+        .accounts({
+            feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
+            user: userBKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionRoundConfig: auctionRoundConfigPda,
+            userAuctionConfig: userAuctionConfigPda,
+            userAuctionRoundConfig: userAuctionRoundConfigPda,
+            userAuctionBuyReceiptConfig: userAuctionBuyReceiptConfigPda,
+            userAuctionVestingConfig: null,
+            tokenMintAccount: sellingTokenMintAccount,
+            paymentTokenMintAccount: paymentTokenMintAccount,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            tokenProgram: sellingTokenProgramAccount,
+            paymentTokenProgram: paymentTokenProgramAccount,
+            userPaymentTokenAccount: userPaymentTokenAccount,
+            userTokenAccount: userSellingTokenAccount,
+            auctionConfigTokenAccount: auctionConfigSellingTokenAccount,
+            paymentReceiver: paymentReceiverKeypair.publicKey,
+            paymentReceiverTokenAccount: paymentReceiverPaymentTokenAccount,
+            feeReceiver: feeReceiverKeypair.publicKey,
+            feeReceiverPaymentTokenAccount: feeReceiverPaymentTokenAccount,
+            paymentOracle: null,
+            referrerConfig: null,
+            referrerConfigPaymentTokenAccount: null,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            auctionVaultConfig: null,
+            auctionVaultConfigPaymentTokenAccount: null
+        })
+        .preInstructions([...ixs, ed25519Ix, ...ixsAfterEd25519])
+        .signers([feeAndRentPayerKeypair, backAuthorityKeypair])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+// Single round pad selling 20 tokens at 4 payment tokens each, the overrides set what a test pad covers
+async function initializeTestPad(program: Program<CreamPad>, name: string, overrides: object = {}, tenantId: string = ""): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId, tenantId);
+
+    const [auctionConfigPda] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, "1");
+
+    const creatorSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, creatorKeypair.publicKey, true, sellingTokenProgramAccount);
+
+    const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+    return await program.methods.initializePad({
+        paymentMint: paymentTokenMintAccount,
+        paymentReceiver: paymentReceiverKeypair.publicKey,
+        p0: new BN(tokensToLamports(4, 9).toString()),
+        ptmax: new BN(tokensToLamports(1.2, 9).toString()),
+        tmax: 1,
+        omega: new BN(tokensToLamports(2, 9).toString()),
+        alpha: new BN(tokensToLamports(2, 9).toString()),
+        timeShiftMax: new BN(2),
+        roundDuration: new BN(1000),
+        supply: new BN(tokensToLamports(20, 9).toString()),
+        decayModel: DecayModel.Linear,
+        haveBuyLimit: false,
+        buyLimit: new BN(0),
+        haveWhitelist: false,
+        whitelistMerkleRoot: Array(32).fill(0),
+        whitelistRounds: 0,
+        havePaymentEscrow: false,
+        haveUniformClearingPrice: false,
+        haveVesting: false,
+        vestingTgeBasePoint: 0,
+        vestingCliffDuration: new BN(0),
+        vestingDuration: new BN(0),
+        haveCrank: false,
+        crankRoundDuration: new BN(0),
+        crankReward: new BN(0),
+        haveRoundSchedule: false,
+        roundSchedule: [],
+        haveStartAt: false,
+        startAt: new BN(0),
+        paymentOracleProgram: PublicKey.default,
+        paymentOracleMaxAge: new BN(0),
+        paymentMints: [],
+        haveReferral: false,
+        referralBasePoint: 0,
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        ...overrides
+    })
+        .accounts({
+            feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
+            backAuthority: backAuthorityKeypair.publicKey,
+            creator: creatorKeypair.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            auctionConfig: auctionConfigPda,
+            auctionRoundConfig: auctionRoundConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            auctionConfigTokenAccount: auctionConfigSellingTokenAccount,
+            creatorTokenAccount: creatorSellingTokenAccount,
+            tokenProgram: sellingTokenProgramAccount,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY
+        })
+        .signers([feeAndRentPayerKeypair, backAuthorityKeypair, creatorKeypair])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+interface TestPadBuyOptions {
+    // one of the pad's extra payment mints instead of its payment mint
+    paymentMint?: PublicKey;
+    paymentTokenProgram?: PublicKey;
+    referrer?: PublicKey;
+    // leaf allocation and proof for whitelist rounds
+    whitelistAllocation?: BN;
+    whitelistProof?: number[][];
+}
+
+// Buy in the test pad's current round, escrowed pads pay into the vault instead of the receivers and native
+// pads pay in lamports
+async function buyTestPad(program: Program<CreamPad>, name: string, user: Keypair, buyIndex: string, amount: number, options: TestPadBuyOptions = {}): Promise<string> {
+    const programId = program.programId;
+    const connection = program.provider.connection;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const auctionData = await program.account.auctionAccount.fetch(auctionConfigPda);
+
+    const paymentMint: PublicKey = options.paymentMint ?? paymentTokenMintAccount;
+    const paymentTokenProgram: PublicKey = options.paymentTokenProgram ?? paymentTokenProgramAccount;
+
+    const isNativePayment: boolean = options.paymentMint == undefined && auctionData.paymentMint.equals(SystemProgram.programId);
+
+    const roundIndex: string = auctionData.currentRound.toString();
+
+    const [auctionRoundConfigPda, auctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex);
+
+    const [auctionVaultConfigPda, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+    const userSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, user.publicKey, true, sellingTokenProgramAccount);
+
+    const userPaymentTokenAccount = await getAssociatedTokenAddress(paymentMint, user.publicKey, true, paymentTokenProgram);
+
+    const paymentReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentMint, paymentReceiverKeypair.publicKey, true, paymentTokenProgram);
+
+    const feeReceiverPaymentTokenAccount = await getAssociatedTokenAddress(paymentMint, feeReceiverKeypair.publicKey, true, paymentTokenProgram);
+
+    const auctionVaultConfigPaymentTokenAccount = await getAssociatedTokenAddress(paymentMint, auctionVaultConfigPda, true, paymentTokenProgram);
+
+    const [userAuctionConfigPda] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, user.publicKey);
+
+    const [userAuctionRoundConfigPda] = getUserAuctionRoundAccountPdaAndBump(programId, auctionRoundConfigPda, userAuctionConfigPda);
+
+    const [userAuctionBuyReceiptConfigPda] = getUserAuctionBuyReceiptAccountPdaAndBump(programId, userAuctionConfigPda, buyIndex);
+
+    const [userAuctionVestingConfigPda] = getUserAuctionVestingAccountPdaAndBump(programId, userAuctionConfigPda);
+
+    const referrer: PublicKey = options.referrer ?? PublicKey.default;
+
+    const [referrerConfigPda] = getReferrerAccountPdaAndBump(programId, auctionConfigPda, referrer);
+
+    const referrerConfigPaymentTokenAccount = await getAssociatedTokenAddress(paymentMint, referrerConfigPda, true, paymentTokenProgram);
+
+    const ixs: TransactionInstruction[] = [];
+
+    const tokenAccounts: [PublicKey, PublicKey, PublicKey, PublicKey][] = [
+        [userSellingTokenAccount, user.publicKey, sellingTokenMintAccount, sellingTokenProgramAccount]
+    ];
+
+    if (auctionData.havePaymentEscrow) {
+        tokenAccounts.push([auctionVaultConfigPaymentTokenAccount, auctionVaultConfigPda, paymentMint, paymentTokenProgram]);
+    } else if (!isNativePayment) {
+        tokenAccounts.push([paymentReceiverPaymentTokenAccount, paymentReceiverKeypair.publicKey, paymentMint, paymentTokenProgram]);
+        tokenAccounts.push([feeReceiverPaymentTokenAccount, feeReceiverKeypair.publicKey, paymentMint, paymentTokenProgram]);
+    }
+
+    for (const [tokenAccount, owner, mint, tokenProgram] of tokenAccounts) {
+        if ((await connection.getAccountInfo(tokenAccount)) == null) {
+            ixs.push(createAssociatedTokenAccountInstruction(
+                feeAndRentPayerKeypair.publicKey,
+                tokenAccount,
+                owner,
+                mint,
+                tokenProgram,
+                ASSOCIATED_TOKEN_PROGRAM_ID
+            ));
+        }
+    }
+
+    return await program.methods.buy({
+        padName: name,
+        currentRoundIndex: roundIndex,
+        buyIndex: buyIndex,
+        amount: new BN(tokensToLamports(amount, 9).toString()),
+        whitelistAllocation: options.whitelistAllocation ?? new BN(0),
+        whitelistProof: options.whitelistProof ?? [],
+        referrer: referrer,
+        maxPricePerToken: null,
+        maxTotalPayment: null,
+        buyIntent: null,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionRoundConfigBump: auctionRoundConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump
+    })
+        .accounts({
+            feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
+            user: user.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionRoundConfig: auctionRoundConfigPda,
+            userAuctionConfig: userAuctionConfigPda,
+            userAuctionRoundConfig: userAuctionRoundConfigPda,
+            userAuctionBuyReceiptConfig: userAuctionBuyReceiptConfigPda,
+            userAuctionVestingConfig: auctionData.haveVesting ? userAuctionVestingConfigPda : null,
+            tokenMintAccount: sellingTokenMintAccount,
+            paymentTokenMintAccount: isNativePayment ? null : paymentMint,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            tokenProgram: sellingTokenProgramAccount,
+            paymentTokenProgram: paymentTokenProgram,
+            userPaymentTokenAccount: isNativePayment ? null : userPaymentTokenAccount,
+            userTokenAccount: userSellingTokenAccount,
+            auctionConfigTokenAccount: auctionConfigSellingTokenAccount,
+            paymentReceiver: auctionData.havePaymentEscrow ? null : paymentReceiverKeypair.publicKey,
+            paymentReceiverTokenAccount: auctionData.havePaymentEscrow || isNativePayment ? null : paymentReceiverPaymentTokenAccount,
+            feeReceiver: auctionData.havePaymentEscrow ? null : feeReceiverKeypair.publicKey,
+            feeReceiverPaymentTokenAccount: auctionData.havePaymentEscrow || isNativePayment ? null : feeReceiverPaymentTokenAccount,
+            paymentOracle: null,
+            referrerConfig: options.referrer == undefined ? null : referrerConfigPda,
+            referrerConfigPaymentTokenAccount: options.referrer == undefined ? null : referrerConfigPaymentTokenAccount,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            auctionVaultConfig: auctionData.havePaymentEscrow ? auctionVaultConfigPda : null,
+            auctionVaultConfigPaymentTokenAccount: auctionData.havePaymentEscrow ? auctionVaultConfigPaymentTokenAccount : null
+        })
+        .preInstructions(ixs)
+        .signers([feeAndRentPayerKeypair, backAuthorityKeypair, user])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function cancelTestPad(program: Program<CreamPad>, name: string, creator: Keypair): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, name, sellingTokenMintAccount);
+
+    return await program.methods.cancelPad({
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump
+    })
+        .accounts({
+            creator: creator.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, creator])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function withdrawCancelledSupply(program: Program<CreamPad>, name: string): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, name, sellingTokenMintAccount);
+
+    const auctionConfigSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount);
+
+    const creatorSellingTokenAccount = await getAssociatedTokenAddress(sellingTokenMintAccount, creatorKeypair.publicKey, true, sellingTokenProgramAccount);
+
+    return await program.methods.withdrawCancelledSupply({
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump
+    })
+        .accounts({
+            creator: creatorKeypair.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            auctionConfigTokenAccount: auctionConfigSellingTokenAccount,
+            creatorTokenAccount: creatorSellingTokenAccount,
+            tokenProgram: sellingTokenProgramAccount,
+            associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, creatorKeypair])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function refundTestPad(program: Program<CreamPad>, name: string, user: Keypair, buyIndex: string): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionVaultConfigPda, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    const [userAuctionConfigPda, userAuctionConfigBump] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, user.publicKey);
+
+    const [userAuctionBuyReceiptConfigPda, userAuctionBuyReceiptConfigBump] = getUserAuctionBuyReceiptAccountPdaAndBump(programId, userAuctionConfigPda, buyIndex);
+
+    return await program.methods.refund({
+        padName: name,
+        buyIndex: buyIndex,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump,
+        userAuctionConfigBump: userAuctionConfigBump,
+        userAuctionBuyReceiptConfigBump: userAuctionBuyReceiptConfigBump
+    })
+        .accounts({
+            user: user.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionVaultConfig: auctionVaultConfigPda,
+            userAuctionConfig: userAuctionConfigPda,
+            userAuctionBuyReceiptConfig: userAuctionBuyReceiptConfigPda,
+            userAuctionVestingConfig: null,
+            tokenMintAccount: sellingTokenMintAccount,
+            paymentTokenMintAccount: paymentTokenMintAccount,
+            userTokenAccount: await getAssociatedTokenAddress(sellingTokenMintAccount, user.publicKey, true, sellingTokenProgramAccount),
+            userPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, user.publicKey, true, paymentTokenProgramAccount),
+            auctionConfigTokenAccount: await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount),
+            auctionVaultConfigPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, auctionVaultConfigPda, true, paymentTokenProgramAccount),
+            tokenProgram: sellingTokenProgramAccount,
+            paymentTokenProgram: paymentTokenProgramAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, user])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function tokenBalance(connection: anchor.web3.Connection, ata: PublicKey): Promise<number> {
+    return (await connection.getTokenAccountBalance(ata)).value.uiAmount;
+}
+
+async function endTestPadRound(program: Program<CreamPad>, name: string, roundIndex: string): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, name, sellingTokenMintAccount);
+
+    const [auctionRoundConfigPda, auctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, roundIndex);
+
+    return await program.methods.endRound({
+        padName: name,
+        roundIndex: roundIndex,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionRoundConfigBump: auctionRoundConfigBump
+    })
+        .accounts({
+            ender: creatorKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionRoundConfig: auctionRoundConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey
+        })
+        .signers([backAuthorityKeypair, creatorKeypair])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+// Uniform clearing price pads pass every round with a buy, in increasing order
+async function settleTestPad(program: Program<CreamPad>, name: string, settler: Keypair, roundIndexes: string[] = []): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionVaultConfigPda, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    return await program.methods.settlePad({
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump
+    })
+        .accounts({
+            settler: settler.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionVaultConfig: auctionVaultConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            paymentTokenMintAccount: paymentTokenMintAccount,
+            auctionVaultConfigPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, auctionVaultConfigPda, true, paymentTokenProgramAccount),
+            paymentReceiver: paymentReceiverKeypair.publicKey,
+            paymentReceiverTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, paymentReceiverKeypair.publicKey, true, paymentTokenProgramAccount),
+            feeReceiver: feeReceiverKeypair.publicKey,
+            feeReceiverPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, feeReceiverKeypair.publicKey, true, paymentTokenProgramAccount),
+            paymentTokenProgram: paymentTokenProgramAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .remainingAccounts(roundIndexes.map((roundIndex) => ({
+            pubkey: getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex)[0],
+            isSigner: false,
+            isWritable: false
+        })))
+        .signers([backAuthorityKeypair, settler])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function claimRebate(program: Program<CreamPad>, name: string, user: Keypair, buyIndex: string): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionVaultConfigPda, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    const [userAuctionConfigPda, userAuctionConfigBump] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, user.publicKey);
+
+    const [userAuctionBuyReceiptConfigPda, userAuctionBuyReceiptConfigBump] = getUserAuctionBuyReceiptAccountPdaAndBump(programId, userAuctionConfigPda, buyIndex);
+
+    return await program.methods.claimRebate({
+        padName: name,
+        buyIndex: buyIndex,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump,
+        userAuctionConfigBump: userAuctionConfigBump,
+        userAuctionBuyReceiptConfigBump: userAuctionBuyReceiptConfigBump
+    })
+        .accounts({
+            user: user.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionVaultConfig: auctionVaultConfigPda,
+            userAuctionConfig: userAuctionConfigPda,
+            userAuctionBuyReceiptConfig: userAuctionBuyReceiptConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            paymentTokenMintAccount: paymentTokenMintAccount,
+            userPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, user.publicKey, true, paymentTokenProgramAccount),
+            auctionVaultConfigPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, auctionVaultConfigPda, true, paymentTokenProgramAccount),
+            paymentTokenProgram: paymentTokenProgramAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, user])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function crankTestPad(program: Program<CreamPad>, name: string, cranker: Keypair, roundIndex: string, nextRoundIndex: string | null): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionVaultConfigPda, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    const [auctionRoundConfigPda, auctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex);
+
+    return await program.methods.crankRound({
+        padName: name,
+        roundIndex: roundIndex,
+        nextRoundIndex: nextRoundIndex ?? "",
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionRoundConfigBump: auctionRoundConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump
+    })
+        .accounts({
+            cranker: cranker.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            auctionConfig: auctionConfigPda,
+            auctionVaultConfig: auctionVaultConfigPda,
+            auctionRoundConfig: auctionRoundConfigPda,
+            nextAuctionRoundConfig: nextRoundIndex == null ? null : getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, nextRoundIndex)[0],
+            tokenMintAccount: sellingTokenMintAccount,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY
+        })
+        .signers([cranker])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function fundCrankReward(program: Program<CreamPad>, name: string, creator: Keypair, amount: number): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionVaultConfigPda, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    return await program.methods.fundCrankReward({
+        padName: name,
+        amount: new BN(amount),
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump
+    })
+        .accounts({
+            creator: creator.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionVaultConfig: auctionVaultConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, creator])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function withdrawCrankReward(program: Program<CreamPad>, name: string, creator: Keypair): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionVaultConfigPda, auctionVaultConfigBump] = getAuctionVaultAccountPdaAndBump(programId, auctionConfigPda);
+
+    return await program.methods.withdrawCrankReward({
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionVaultConfigBump: auctionVaultConfigBump
+    })
+        .accounts({
+            creator: creator.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionVaultConfig: auctionVaultConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, creator])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function closeBuyReceipt(program: Program<CreamPad>, name: string, closer: Keypair, user: PublicKey, buyIndex: string, rentPayer: PublicKey): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [userAuctionConfigPda, userAuctionConfigBump] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, user);
+
+    const [userAuctionBuyReceiptConfigPda, userAuctionBuyReceiptConfigBump] = getUserAuctionBuyReceiptAccountPdaAndBump(programId, userAuctionConfigPda, buyIndex);
+
+    return await program.methods.closeBuyReceipt({
+        padName: name,
+        buyIndex: buyIndex,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        userAuctionConfigBump: userAuctionConfigBump,
+        userAuctionBuyReceiptConfigBump: userAuctionBuyReceiptConfigBump
+    })
+        .accounts({
+            closer: closer.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            user: user,
+            userAuctionConfig: userAuctionConfigPda,
+            userAuctionBuyReceiptConfig: userAuctionBuyReceiptConfigPda,
+            rentPayer: rentPayer,
+            tokenMintAccount: sellingTokenMintAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, closer])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function closeUserRound(program: Program<CreamPad>, name: string, closer: Keypair, user: PublicKey, roundIndex: string, rentPayer: PublicKey): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionRoundConfigPda, auctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex);
+
+    const [userAuctionConfigPda, userAuctionConfigBump] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, user);
+
+    const [userAuctionRoundConfigPda, userAuctionRoundConfigBump] = getUserAuctionRoundAccountPdaAndBump(programId, auctionRoundConfigPda, userAuctionConfigPda);
+
+    return await program.methods.closeUserRound({
+        padName: name,
+        roundIndex: roundIndex,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        userAuctionConfigBump: userAuctionConfigBump,
+        auctionRoundConfigBump: auctionRoundConfigBump,
+        userAuctionRoundConfigBump: userAuctionRoundConfigBump
+    })
+        .accounts({
+            closer: closer.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionRoundConfig: auctionRoundConfigPda,
+            user: user,
+            userAuctionConfig: userAuctionConfigPda,
+            userAuctionRoundConfig: userAuctionRoundConfigPda,
+            rentPayer: rentPayer,
+            tokenMintAccount: sellingTokenMintAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, closer])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function closeTestPadRound(program: Program<CreamPad>, name: string, closer: Keypair, roundIndex: string, rentPayer: PublicKey): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [auctionRoundConfigPda, auctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(programId, auctionConfigPda, roundIndex);
+
+    return await program.methods.closeRound({
+        padName: name,
+        roundIndex: roundIndex,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        auctionRoundConfigBump: auctionRoundConfigBump
+    })
+        .accounts({
+            closer: closer.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            auctionRoundConfig: auctionRoundConfigPda,
+            rentPayer: rentPayer,
+            tokenMintAccount: sellingTokenMintAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, closer])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+// Closes the account and checks its rent went back to the payer recorded on it
+async function assertClosedToRentPayer(connection: anchor.web3.Connection, account: PublicKey, rentPayer: PublicKey, close: () => Promise<string>) {
+    const rent: number = (await connection.getAccountInfo(account)).lamports;
+
+    const rentPayerBalance: number = await connection.getBalance(rentPayer);
+
+    const tx = await close();
+
+    console.log("Your transaction signature", tx);
+
+    await delay(delayTimeCount);
+
+    assert(await connection.getAccountInfo(account) === null, "Close -> account removed");
+    assert(await connection.getBalance(rentPayer) === rentPayerBalance + rent, "Close -> rent back to the rent payer");
+}
+
+async function claimVested(program: Program<CreamPad>, name: string, user: Keypair): Promise<string> {
+    const programId = program.programId;
+
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(programId, name, sellingTokenMintAccount);
+
+    const [userAuctionConfigPda, userAuctionConfigBump] = getUserAuctionAccountPdaAndBump(programId, auctionConfigPda, user.publicKey);
+
+    const [userAuctionVestingConfigPda, userAuctionVestingConfigBump] = getUserAuctionVestingAccountPdaAndBump(programId, userAuctionConfigPda);
+
+    return await program.methods.claimVested({
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        userAuctionConfigBump: userAuctionConfigBump,
+        userAuctionVestingConfigBump: userAuctionVestingConfigBump
+    })
+        .accounts({
+            user: user.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            userAuctionConfig: userAuctionConfigPda,
+            userAuctionVestingConfig: userAuctionVestingConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            auctionConfigTokenAccount: await getAssociatedTokenAddress(sellingTokenMintAccount, auctionConfigPda, true, sellingTokenProgramAccount),
+            userTokenAccount: await getAssociatedTokenAddress(sellingTokenMintAccount, user.publicKey, true, sellingTokenProgramAccount),
+            tokenProgram: sellingTokenProgramAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, user])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function startNextTestPadRound(program: Program<CreamPad>, name: string, previousRoundIndex: string, nextRoundIndex: string, nextRoundDuration: BN): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, name, sellingTokenMintAccount);
+
+    const [previousAuctionRoundConfigPda, previousAuctionRoundConfigBump] = getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, previousRoundIndex);
+
+    const [nextAuctionRoundConfigPda] = getAuctionRoundAccountPdaAndBump(program.programId, auctionConfigPda, nextRoundIndex);
+
+    return await program.methods.startNextRound({
+        padName: name,
+        previousRoundIndex: previousRoundIndex,
+        nextRoundIndex: nextRoundIndex,
+        nextRoundDuration: nextRoundDuration,
+        nextHaveBuyLimit: false,
+        nextBuyLimit: new BN(0),
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump,
+        previousAuctionRoundConfigBump: previousAuctionRoundConfigBump
+    })
+        .accounts({
+            feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
+            starter: creatorKeypair.publicKey,
+            auctionConfig: auctionConfigPda,
+            previousAuctionRoundConfig: previousAuctionRoundConfigPda,
+            nextAuctionRoundConfig: nextAuctionRoundConfigPda,
+            tokenMintAccount: sellingTokenMintAccount,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey
+        })
+        .signers([feeAndRentPayerKeypair, backAuthorityKeypair, creatorKeypair])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function claimReferralRewards(program: Program<CreamPad>, name: string, referrer: Keypair): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [auctionConfigPda] = getAuctionAccountPdaAndBump(program.programId, name, sellingTokenMintAccount);
+
+    const [referrerConfigPda, referrerConfigBump] = getReferrerAccountPdaAndBump(program.programId, auctionConfigPda, referrer.publicKey);
+
+    return await program.methods.claimReferralRewards({
+        creamPadConfigBump: creamPadConfigBump,
+        referrerConfigBump: referrerConfigBump
+    })
+        .accounts({
+            referrer: referrer.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            backAuthority: backAuthorityKeypair.publicKey,
+            referrerConfig: referrerConfigPda,
+            padConfig: auctionConfigPda,
+            paymentTokenMintAccount: paymentTokenMintAccount,
+            referrerConfigPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, referrerConfigPda, true, paymentTokenProgramAccount),
+            referrerPaymentTokenAccount: await getAssociatedTokenAddress(paymentTokenMintAccount, referrer.publicKey, true, paymentTokenProgramAccount),
+            paymentTokenProgram: paymentTokenProgramAccount,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY
+        })
+        .signers([backAuthorityKeypair, referrer])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function setPadFee(program: Program<CreamPad>, name: string, signingAuthority: Keypair, haveFeeOverride: boolean, feeOverrideBasePoint: number, tenantId: string = ""): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId, tenantId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, name, sellingTokenMintAccount);
+
+    return await program.methods.setPadFee({
+        haveFeeOverride: haveFeeOverride,
+        feeOverrideBasePoint: feeOverrideBasePoint,
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump
+    })
+        .accounts({
+            signingAuthority: signingAuthority.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            auctionConfig: auctionConfigPda,
+            tokenMintAccount: sellingTokenMintAccount
+        })
+        .signers([signingAuthority])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function setCollectionPadFee(program: Program<CreamPad>, signingAuthority: Keypair, haveFeeOverride: boolean, feeOverrideBasePoint: number, feeOverrideMintingFee: BN): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
+
+    return await program.methods.setCollectionPadFee({
+        haveFeeOverride: haveFeeOverride,
+        feeOverrideBasePoint: feeOverrideBasePoint,
+        feeOverrideMintingFee: feeOverrideMintingFee,
+        padName: collectionPadName,
+        creamPadConfigBump: creamPadConfigBump,
+        collectionAuctionConfigBump: collectionAuctionConfigBump
+    })
+        .accounts({
+            signingAuthority: signingAuthority.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            collectionAuctionConfig: collectionAuctionConfigPda,
+            collectionMintAccount: collectionMintAccount
+        })
+        .signers([signingAuthority])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+// The config the suite runs on, the overrides set what a config change changes
+function programConfigChange(overrides: object = {}) {
+    return {
+        isBackAuthorityRequired: true,
+        isFeeRequired: true,
+        feeBasePoint: 2500,
+        feeReceiver: feeReceiverKeypair.publicKey,
+        roundLimit: 100,
+        programStatus: ProgramStatus.Normal,
+        distributionBasePoint: 5000,
+        lockBasePoint: 5000,
+        lockDuration: new BN(5),
+        mintingFee: new BN(mintingFee),
+        treasury: treasuryKeypair.publicKey,
+        feeTiers: [],
+        haveGovernance: true,
+        governanceSigners: [signingAuthorityKeypair.publicKey, backAuthorityKeypair.publicKey],
+        governanceThreshold: 2,
+        governanceTimelock: new BN(governanceTimelock),
+        ...overrides
+    };
+}
+
+async function proposeConfigChange(program: Program<CreamPad>, configChangeIndex: string, change: any, proposer: Keypair): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [pendingConfigChangePda] = getPendingConfigChangeAccountPdaAndBump(program.programId, creamPadConfigPda, configChangeIndex);
+
+    return await program.methods.proposeConfigChange({
+        change: change,
+        configChangeIndex: configChangeIndex,
+        creamPadConfigBump: creamPadConfigBump
+    })
+        .accounts({
+            feeAndRentPayer: feeAndRentPayerKeypair.publicKey,
+            proposer: proposer.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            pendingConfigChange: pendingConfigChangePda,
+            systemProgram: SystemProgram.programId,
+            rent: SYSVAR_RENT_PUBKEY
+        })
+        .signers([feeAndRentPayerKeypair, proposer])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+// Approve, execute or cancel a pending config change, they take the same params and accounts
+async function governConfigChange(program: Program<CreamPad>, instruction: "approveConfigChange" | "executeConfigChange" | "cancelConfigChange", configChangeIndex: string, governanceSigner: Keypair): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [pendingConfigChangePda, pendingConfigChangeBump] = getPendingConfigChangeAccountPdaAndBump(program.programId, creamPadConfigPda, configChangeIndex);
+
+    return await program.methods[instruction]({
+        configChangeIndex: configChangeIndex,
+        creamPadConfigBump: creamPadConfigBump,
+        pendingConfigChangeBump: pendingConfigChangeBump
+    })
+        .accounts({
+            governanceSigner: governanceSigner.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            pendingConfigChange: pendingConfigChangePda
+        })
+        .signers([governanceSigner])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function proposeAuthorityTransfer(program: Program<CreamPad>, authorityType: AuthorityTypeType, newAuthority: PublicKey, signingAuthority: Keypair): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    return await program.methods.proposeAuthorityTransfer({
+        authorityType: authorityType,
+        newAuthority: newAuthority,
+        creamPadConfigBump: creamPadConfigBump
+    })
+        .accounts({
+            signingAuthority: signingAuthority.publicKey,
+            creamPadConfig: creamPadConfigPda
+        })
+        .signers([signingAuthority])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function acceptAuthorityTransfer(program: Program<CreamPad>, authorityType: AuthorityTypeType, newAuthority: Keypair): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    return await program.methods.acceptAuthorityTransfer({
+        authorityType: authorityType,
+        creamPadConfigBump: creamPadConfigBump
+    })
+        .accounts({
+            newAuthority: newAuthority.publicKey,
+            creamPadConfig: creamPadConfigPda
+        })
+        .signers([newAuthority])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+function pauseFlags(overrides: object = {}) {
+    return {
+        pauseBuys: false,
+        pauseNewPads: false,
+        pauseClaims: false,
+        pauseCollectionMinting: false,
+        ...overrides
+    };
+}
+
+async function setPauseFlags(program: Program<CreamPad>, signingAuthority: Keypair, flags: any): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    return await program.methods.setPauseFlags({
+        pauseFlags: flags,
+        creamPadConfigBump: creamPadConfigBump
+    })
+        .accounts({
+            signingAuthority: signingAuthority.publicKey,
+            creamPadConfig: creamPadConfigPda
+        })
+        .signers([signingAuthority])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function setPadPauseFlags(program: Program<CreamPad>, name: string, signingAuthority: Keypair, flags: any): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [auctionConfigPda, auctionConfigBump] = getAuctionAccountPdaAndBump(program.programId, name, sellingTokenMintAccount);
+
+    return await program.methods.setPadPauseFlags({
+        pauseFlags: flags,
+        padName: name,
+        creamPadConfigBump: creamPadConfigBump,
+        auctionConfigBump: auctionConfigBump
+    })
+        .accounts({
+            signingAuthority: signingAuthority.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            auctionConfig: auctionConfigPda,
+            tokenMintAccount: sellingTokenMintAccount
+        })
+        .signers([signingAuthority])
+        .rpc({
+            skipPreflight: false
+        });
+}
+
+async function setCollectionPadPauseFlags(program: Program<CreamPad>, signingAuthority: Keypair, flags: any): Promise<string> {
+    const [creamPadConfigPda, creamPadConfigBump] = getCreamPadAccountPdaAndBump(program.programId);
+
+    const [collectionAuctionConfigPda, collectionAuctionConfigBump] = getCollectionAuctionAccountPdaAndBump(program.programId, collectionPadName, collectionMintAccount);
+
+    return await program.methods.setCollectionPadPauseFlags({
+        pauseFlags: flags,
+        padName: collectionPadName,
+        creamPadConfigBump: creamPadConfigBump,
+        collectionAuctionConfigBump: collectionAuctionConfigBump
+    })
+        .accounts({
+            signingAuthority: signingAuthority.publicKey,
+            creamPadConfig: creamPadConfigPda,
+            collectionAuctionConfig: collectionAuctionConfigPda,
+            collectionMintAccount: collectionMintAccount
+        })
+        .signers([signingAuthority])
+        .rpc({
+            skipPreflight: false
+        });
+}